@@ -0,0 +1,246 @@
+//! # Shell Completions
+//!
+//! Both `fern-obs` and `fernctl` have a `completions <shell>` subcommand
+//! that prints a completion script to stdout. The static part (subcommands,
+//! flags, enum values) comes from `clap_complete`; [`generate`] appends a
+//! small helper for values only known at runtime, like OBS scene names.
+//!
+//! The helper asks the binary itself for candidates, e.g. `fern-obs scenes`
+//! after `fern-obs scene`. Each [`DynamicValues`] names the subcommand, the
+//! option (if any), and the command that prints one candidate per line.
+//!
+//! ```rust,ignore
+//! use fern_core::completions::{generate, DynamicValues, Shell};
+//!
+//! let dynamic = [DynamicValues {
+//!     subcommand: "scene",
+//!     option: None,
+//!     command: "fern-obs scenes",
+//! }];
+//! generate(Shell::Bash, &mut Cli::command(), &dynamic, &mut std::io::stdout())?;
+//! ```
+//!
+//! ## Installing
+//!
+//! ```bash
+//! # bash (~/.bashrc) and zsh (~/.zshrc, after compinit)
+//! source <(fern-obs completions bash)
+//! source <(fern-obs completions zsh)
+//!
+//! # fish
+//! fern-obs completions fish > ~/.config/fish/completions/fern-obs.fish
+//!
+//! # nushell (static completions only)
+//! fern-obs completions nushell | save -f ~/.config/nushell/fern-obs.nu
+//! ```
+//!
+//! The bash and zsh helpers wrap the function `clap_complete` registered,
+//! so the script has to be sourced as a whole rather than split up.
+
+use std::fmt::Write as _;
+use std::io::{self, Write};
+
+/// Shells that completion scripts can be generated for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Shell {
+    /// Bash
+    Bash,
+    /// Zsh
+    Zsh,
+    /// Fish
+    Fish,
+    /// Nushell (no dynamic values)
+    Nushell,
+}
+
+/// Argument values that are completed by running a command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DynamicValues {
+    /// Subcommand the argument belongs to (e.g., `scene`).
+    pub subcommand: &'static str,
+    /// Long option taking the value, without dashes. `None` completes the
+    /// subcommand's positional argument.
+    pub option: Option<&'static str>,
+    /// Shell command printing one candidate per line.
+    pub command: &'static str,
+}
+
+impl DynamicValues {
+    /// Returns the word that precedes the value on the command line.
+    fn trigger(&self) -> String {
+        self.option.map_or_else(
+            || self.subcommand.to_string(),
+            |option| format!("--{option}"),
+        )
+    }
+}
+
+/// Writes the completion script for `cmd` to `out`.
+///
+/// # Errors
+///
+/// Returns an error if writing to `out` fails.
+pub fn generate(
+    shell: Shell,
+    cmd: &mut clap::Command,
+    dynamic: &[DynamicValues],
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let bin = cmd.get_name().to_string();
+
+    match shell {
+        Shell::Bash => clap_complete::generate(clap_complete::Shell::Bash, cmd, &bin, out),
+        Shell::Zsh => clap_complete::generate(clap_complete::Shell::Zsh, cmd, &bin, out),
+        Shell::Fish => clap_complete::generate(clap_complete::Shell::Fish, cmd, &bin, out),
+        Shell::Nushell => clap_complete::generate(clap_complete_nushell::Nushell, cmd, &bin, out),
+    }
+
+    if dynamic.is_empty() {
+        return Ok(());
+    }
+
+    let helper = match shell {
+        Shell::Bash => bash_helper(&bin, dynamic),
+        Shell::Zsh => zsh_helper(&bin, dynamic),
+        Shell::Fish => fish_helper(&bin, dynamic),
+        Shell::Nushell => return Ok(()),
+    };
+    write!(out, "\n{helper}")
+}
+
+/// Turns a binary name into a shell identifier (`fern-obs` → `fern_obs`).
+fn ident(bin: &str) -> String {
+    bin.replace('-', "_")
+}
+
+/// Wraps the registered bash completion function.
+fn bash_helper(bin: &str, dynamic: &[DynamicValues]) -> String {
+    let name = ident(bin);
+    let cases = dynamic.iter().fold(String::new(), |mut cases, value| {
+        let _ = writeln!(
+            cases,
+            "        {}) values=$({} 2>/dev/null) ;;",
+            value.trigger(),
+            value.command
+        );
+        cases
+    });
+
+    format!(
+        r#"__{name}_clap_complete=$(complete -p {bin} 2>/dev/null | sed -n 's/.*-F \([^ ]*\).*/\1/p')
+_{name}_dynamic() {{
+    local cur="${{COMP_WORDS[COMP_CWORD]}}" prev="${{COMP_WORDS[COMP_CWORD-1]}}" values=""
+    case "$prev" in
+{cases}    esac
+    if [[ -n "$values" ]]; then
+        local IFS=$'\n'
+        COMPREPLY=($(compgen -W "$values" -- "$cur"))
+        [[ ${{#COMPREPLY[@]}} -gt 0 ]] && return 0
+    fi
+    "$__{name}_clap_complete" "$@"
+}}
+complete -F _{name}_dynamic -o bashdefault -o default {bin}
+"#
+    )
+}
+
+/// Wraps the registered zsh completion function.
+fn zsh_helper(bin: &str, dynamic: &[DynamicValues]) -> String {
+    let name = ident(bin);
+    let cases = dynamic.iter().fold(String::new(), |mut cases, value| {
+        let _ = writeln!(
+            cases,
+            "        {}) values=(${{(f)\"$({} 2>/dev/null)\"}}) ;;",
+            value.trigger(),
+            value.command
+        );
+        cases
+    });
+
+    format!(
+        r#"__{name}_clap_complete=$_comps[{bin}]
+_{name}_dynamic() {{
+    local -a values
+    case $words[CURRENT-1] in
+{cases}    esac
+    if (( $#values )); then
+        compadd -a values && return 0
+    fi
+    $__{name}_clap_complete "$@"
+}}
+compdef _{name}_dynamic {bin}
+"#
+    )
+}
+
+/// Adds fish completions that call the candidate commands.
+fn fish_helper(bin: &str, dynamic: &[DynamicValues]) -> String {
+    dynamic.iter().fold(String::new(), |mut script, value| {
+        let option = value
+            .option
+            .map_or_else(|| "-f".to_string(), |option| format!("-l {option} -x"));
+        let _ = writeln!(
+            script,
+            "complete -c {bin} -n '__fish_seen_subcommand_from {}' {option} -a '({} 2>/dev/null)'",
+            value.subcommand, value.command
+        );
+        script
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SCENES: DynamicValues = DynamicValues {
+        subcommand: "scene",
+        option: None,
+        command: "demo scenes",
+    };
+
+    const TRANSITIONS: DynamicValues = DynamicValues {
+        subcommand: "scene",
+        option: Some("transition"),
+        command: "demo transitions",
+    };
+
+    fn script(shell: Shell) -> String {
+        let mut cmd = clap::Command::new("demo-bin")
+            .subcommand(clap::Command::new("scene").arg(clap::Arg::new("name")));
+        let mut out = Vec::new();
+        generate(shell, &mut cmd, &[SCENES, TRANSITIONS], &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn bash_wraps_clap_function() {
+        let script = script(Shell::Bash);
+        assert!(script.contains("complete -p demo-bin"));
+        assert!(script.contains("scene) values=$(demo scenes 2>/dev/null) ;;"));
+        assert!(script.contains("--transition) values=$(demo transitions 2>/dev/null) ;;"));
+        assert!(
+            script.ends_with("complete -F _demo_bin_dynamic -o bashdefault -o default demo-bin\n")
+        );
+    }
+
+    #[test]
+    fn zsh_registers_wrapper() {
+        let script = script(Shell::Zsh);
+        assert!(script.contains("__demo_bin_clap_complete=$_comps[demo-bin]"));
+        assert!(script.ends_with("compdef _demo_bin_dynamic demo-bin\n"));
+    }
+
+    #[test]
+    fn fish_completes_positional_and_option() {
+        let script = script(Shell::Fish);
+        assert!(script.contains(
+            "complete -c demo-bin -n '__fish_seen_subcommand_from scene' -f -a '(demo scenes 2>/dev/null)'"
+        ));
+        assert!(script.contains("-l transition -x -a '(demo transitions 2>/dev/null)'"));
+    }
+
+    #[test]
+    fn nushell_has_no_helper() {
+        assert!(!script(Shell::Nushell).contains("demo scenes"));
+    }
+}
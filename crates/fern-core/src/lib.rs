@@ -7,7 +7,10 @@
 //! - [`paths`] - XDG-compliant configuration and state directories
 //! - [`state`] - Service state types for inter-process communication
 //! - [`config`] - Common configuration loading patterns
+//! - [`log`] - Bounded JSON log files shared with `fernctl logs`
 //! - [`error`] - Shared error types
+//! - `completions` - Shell completion scripts for the CLIs
+//! - `man` - Man pages for the CLIs
 //!
 //! ## Crate Ecosystem
 //!
@@ -29,9 +32,17 @@
 //!
 //! - `async` - Enable async runtime support for service daemons
 //! - `dbus` - Enable D-Bus IPC support
+//! - `tracing` - Enable `log::JsonRingLayer`, a tracing layer for log files
+//! - `completions` - Enable `completions`, shared `completions <shell>` output
+//! - `man` - Enable `man`, man page rendering for the hidden `man` subcommand
 
+#[cfg(feature = "completions")]
+pub mod completions;
 pub mod config;
 pub mod error;
+pub mod log;
+#[cfg(feature = "man")]
+pub mod man;
 pub mod paths;
 pub mod state;
 
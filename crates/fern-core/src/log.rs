@@ -0,0 +1,492 @@
+//! # Bounded JSON Log Files
+//!
+//! Services write their logs to `~/.local/state/fern/<service>-log.json`
+//! so `fernctl logs` and the TUI can show them side by side. Each file is a
+//! JSON array of entries.
+//!
+//! [`JsonRingLogger`] keeps such a file bounded. It holds at most
+//! [`LogLimits::max_entries`] entries and at most [`LogLimits::max_bytes`]
+//! bytes on disk, dropping the oldest entries first, so a crash-looping
+//! daemon cannot fill the disk.
+//!
+//! ## Tracing
+//!
+//! With the `tracing` feature, `JsonRingLayer` mirrors tracing events
+//! into a service's log file:
+//!
+//! ```rust,ignore
+//! use fern_core::log::JsonRingLayer;
+//! use tracing_subscriber::prelude::*;
+//!
+//! tracing_subscriber::registry()
+//!     .with(JsonRingLayer::for_service("obs"))
+//!     .init();
+//! ```
+
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+/// Default maximum number of entries in a log file.
+pub const DEFAULT_MAX_ENTRIES: usize = 500;
+
+/// Default maximum size of a log file in bytes (1 MiB).
+pub const DEFAULT_MAX_BYTES: usize = 1024 * 1024;
+
+/// Size limits for a [`JsonRingLogger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LogLimits {
+    /// Maximum number of entries kept.
+    pub max_entries: usize,
+
+    /// Maximum file size in bytes.
+    ///
+    /// A single entry larger than this is still written on its own, so the
+    /// file can exceed the limit by at most one entry.
+    pub max_bytes: usize,
+}
+
+impl LogLimits {
+    /// Creates limits with the given entry count and the default byte cap.
+    #[must_use]
+    pub const fn entries(max_entries: usize) -> Self {
+        Self {
+            max_entries,
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+impl Default for LogLimits {
+    fn default() -> Self {
+        Self {
+            max_entries: DEFAULT_MAX_ENTRIES,
+            max_bytes: DEFAULT_MAX_BYTES,
+        }
+    }
+}
+
+/// A single log entry as `fernctl logs` reads it.
+///
+/// `timestamp` is milliseconds since the Unix epoch and `level` is a
+/// lowercase level name.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LogRecord {
+    /// Milliseconds since the Unix epoch.
+    pub timestamp: u64,
+
+    /// Lowercase level name (`trace`, `debug`, `info`, `warn`, `error`).
+    pub level: String,
+
+    /// Source component.
+    pub source: String,
+
+    /// Service that produced the entry.
+    pub service: String,
+
+    /// Log message.
+    pub message: String,
+
+    /// Structured fields attached to the entry.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<serde_json::Value>,
+}
+
+/// A JSON array log file bounded by entry count and size.
+///
+/// Entries are kept in memory as serialized JSON, so appending only
+/// serializes the new entry before the file is rewritten. Writes are
+/// atomic (temp file, then rename).
+///
+/// # Example
+///
+/// ```rust
+/// use fern_core::log::{JsonRingLogger, LogLimits};
+///
+/// let dir = tempfile::tempdir()?;
+/// let mut log = JsonRingLogger::open(dir.path().join("demo-log.json"), LogLimits::entries(2));
+///
+/// for i in 0..3 {
+///     log.append(&serde_json::json!({ "message": i }))?;
+/// }
+/// assert_eq!(log.len(), 2);
+/// # Ok::<(), Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Debug)]
+pub struct JsonRingLogger {
+    path: PathBuf,
+    limits: LogLimits,
+    entries: VecDeque<String>,
+    /// Sum of the serialized entry lengths.
+    entry_bytes: usize,
+}
+
+impl JsonRingLogger {
+    /// Opens the log file at `path`, keeping any entries already in it.
+    ///
+    /// A missing or unreadable file starts an empty log. Existing entries
+    /// beyond the limits are dropped on the next write.
+    #[must_use]
+    pub fn open(path: impl Into<PathBuf>, limits: LogLimits) -> Self {
+        let path = path.into();
+        let existing: Vec<serde_json::Value> = std::fs::read_to_string(&path)
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default();
+
+        let mut logger = Self {
+            path,
+            limits,
+            entries: VecDeque::new(),
+            entry_bytes: 0,
+        };
+        for value in existing {
+            logger.push(value.to_string());
+        }
+        logger.trim();
+        logger
+    }
+
+    /// Returns the path of the log file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the number of entries kept.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the log has no entries.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns the size of the file as last written, in bytes.
+    #[must_use]
+    pub fn file_bytes(&self) -> usize {
+        // "[" + entries joined by "," + "]"
+        2 + self.entry_bytes + self.entries.len().saturating_sub(1)
+    }
+
+    /// Appends an entry, drops the oldest entries beyond the limits, and
+    /// rewrites the file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the entry cannot be serialized or the file
+    /// cannot be written.
+    pub fn append<T: Serialize>(&mut self, entry: &T) -> Result<()> {
+        let json = serde_json::to_string(entry).map_err(|e| Error::Parse {
+            context: format!("serializing entry for {}", self.path.display()),
+            message: e.to_string(),
+        })?;
+
+        self.push(json);
+        self.trim();
+        self.write()
+    }
+
+    fn push(&mut self, json: String) {
+        self.entry_bytes += json.len();
+        self.entries.push_back(json);
+    }
+
+    /// Drops the oldest entries until both limits hold, always keeping
+    /// the newest entry.
+    fn trim(&mut self) {
+        while self.entries.len() > 1
+            && (self.entries.len() > self.limits.max_entries
+                || self.file_bytes() > self.limits.max_bytes)
+        {
+            if let Some(dropped) = self.entries.pop_front() {
+                self.entry_bytes -= dropped.len();
+            }
+        }
+    }
+
+    fn write(&self) -> Result<()> {
+        let io_error = |context: &str, source| Error::Io {
+            context: format!("{context} {}", self.path.display()),
+            source,
+        };
+
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent).map_err(|e| io_error("creating directory for", e))?;
+        }
+
+        let mut json = String::with_capacity(self.file_bytes());
+        json.push('[');
+        for (i, entry) in self.entries.iter().enumerate() {
+            if i > 0 {
+                json.push(',');
+            }
+            json.push_str(entry);
+        }
+        json.push(']');
+
+        let temp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&temp_path, json).map_err(|e| io_error("writing", e))?;
+        std::fs::rename(&temp_path, &self.path).map_err(|e| io_error("replacing", e))
+    }
+}
+
+#[cfg(feature = "tracing")]
+pub use layer::JsonRingLayer;
+
+#[cfg(feature = "tracing")]
+mod layer {
+    use super::{JsonRingLogger, LogLimits, LogRecord};
+    use crate::paths::FernPaths;
+    use std::path::PathBuf;
+    use std::sync::Mutex;
+    use tracing::field::{Field, Visit};
+    use tracing::{Event, Level, Subscriber};
+    use tracing_subscriber::layer::{Context, Layer};
+
+    /// Tracing layer that mirrors events into a bounded JSON log file.
+    ///
+    /// Write failures are ignored: logging must never take a service down,
+    /// and there is nowhere to report them without recursing into tracing.
+    #[derive(Debug)]
+    pub struct JsonRingLayer {
+        service: String,
+        logger: Mutex<JsonRingLogger>,
+    }
+
+    impl JsonRingLayer {
+        /// Creates a layer writing `service`'s entries to `path`.
+        #[must_use]
+        pub fn new(
+            service: impl Into<String>,
+            path: impl Into<PathBuf>,
+            limits: LogLimits,
+        ) -> Self {
+            Self {
+                service: service.into(),
+                logger: Mutex::new(JsonRingLogger::open(path, limits)),
+            }
+        }
+
+        /// Creates a layer for `service`'s standard log file with default
+        /// limits (see [`FernPaths::service_log`]).
+        #[must_use]
+        pub fn for_service(service: &str) -> Self {
+            Self::new(
+                service,
+                FernPaths::new().service_log(service),
+                LogLimits::default(),
+            )
+        }
+
+        /// Appends a record to the log file.
+        pub fn record(&self, record: &LogRecord) {
+            if let Ok(mut logger) = self.logger.lock() {
+                let _ = logger.append(record);
+            }
+        }
+    }
+
+    impl<S: Subscriber> Layer<S> for JsonRingLayer {
+        fn on_event(&self, event: &Event<'_>, _ctx: Context<'_, S>) {
+            let metadata = event.metadata();
+
+            let mut visitor = FieldVisitor::default();
+            event.record(&mut visitor);
+
+            let timestamp = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| u64::try_from(d.as_millis()).unwrap_or(u64::MAX));
+
+            let source = metadata
+                .target()
+                .rsplit("::")
+                .next()
+                .unwrap_or(self.service.as_str())
+                .to_string();
+
+            self.record(&LogRecord {
+                timestamp,
+                level: level_name(*metadata.level()).to_string(),
+                source,
+                service: self.service.clone(),
+                message: visitor.message,
+                data: if visitor.fields.is_empty() {
+                    None
+                } else {
+                    Some(serde_json::Value::Object(visitor.fields))
+                },
+            });
+        }
+    }
+
+    /// Returns the lowercase name fernctl uses for a tracing level.
+    const fn level_name(level: Level) -> &'static str {
+        match level {
+            Level::TRACE => "trace",
+            Level::DEBUG => "debug",
+            Level::INFO => "info",
+            Level::WARN => "warn",
+            Level::ERROR => "error",
+        }
+    }
+
+    /// Collects the message and structured fields of an event.
+    #[derive(Default)]
+    struct FieldVisitor {
+        message: String,
+        fields: serde_json::Map<String, serde_json::Value>,
+    }
+
+    impl FieldVisitor {
+        fn insert(&mut self, field: &Field, value: impl Into<serde_json::Value>) {
+            self.fields.insert(field.name().to_string(), value.into());
+        }
+    }
+
+    impl Visit for FieldVisitor {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            if field.name() == "message" {
+                self.message = value.to_string();
+            } else {
+                self.insert(field, value);
+            }
+        }
+
+        fn record_bool(&mut self, field: &Field, value: bool) {
+            self.insert(field, value);
+        }
+
+        fn record_i64(&mut self, field: &Field, value: i64) {
+            self.insert(field, value);
+        }
+
+        fn record_u64(&mut self, field: &Field, value: u64) {
+            self.insert(field, value);
+        }
+
+        fn record_f64(&mut self, field: &Field, value: f64) {
+            self.insert(field, value);
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                self.message = format!("{value:?}");
+            } else {
+                self.insert(field, format!("{value:?}"));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(message: &str) -> LogRecord {
+        LogRecord {
+            timestamp: 1,
+            level: "info".into(),
+            source: "test".into(),
+            service: "test".into(),
+            message: message.into(),
+            data: None,
+        }
+    }
+
+    fn read_records(path: &Path) -> Vec<LogRecord> {
+        serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn append_writes_json_array() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test-log.json");
+        let mut log = JsonRingLogger::open(&path, LogLimits::default());
+
+        log.append(&record("connected")).unwrap();
+
+        let records = read_records(&path);
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].message, "connected");
+    }
+
+    #[test]
+    fn entry_cap_drops_oldest() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test-log.json");
+        let mut log = JsonRingLogger::open(&path, LogLimits::entries(3));
+
+        for i in 0..5 {
+            log.append(&record(&format!("event {i}"))).unwrap();
+        }
+
+        let records = read_records(&path);
+        assert_eq!(records.len(), 3);
+        assert_eq!(records[0].message, "event 2");
+        assert_eq!(records[2].message, "event 4");
+    }
+
+    #[test]
+    fn byte_cap_bounds_file_size() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test-log.json");
+        let entry_len = serde_json::to_string(&record("event 00")).unwrap().len();
+        let limits = LogLimits {
+            max_entries: 1000,
+            max_bytes: 1024,
+        };
+        let mut log = JsonRingLogger::open(&path, limits);
+
+        for i in 0..100 {
+            log.append(&record(&format!("event {i:02}"))).unwrap();
+
+            let size = std::fs::metadata(&path).unwrap().len();
+            assert!(usize::try_from(size).unwrap() <= limits.max_bytes + entry_len);
+            assert_eq!(usize::try_from(size).unwrap(), log.file_bytes());
+        }
+
+        let records = read_records(&path);
+        assert!(records.len() < 100);
+        assert_eq!(records.last().unwrap().message, "event 99");
+    }
+
+    #[test]
+    fn oversized_entry_is_kept_alone() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test-log.json");
+        let mut log = JsonRingLogger::open(
+            &path,
+            LogLimits {
+                max_entries: 10,
+                max_bytes: 16,
+            },
+        );
+
+        log.append(&record("small")).unwrap();
+        log.append(&record(&"x".repeat(100))).unwrap();
+
+        assert_eq!(log.len(), 1);
+        assert_eq!(read_records(&path)[0].message.len(), 100);
+    }
+
+    #[test]
+    fn open_keeps_existing_entries() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("test-log.json");
+
+        let mut log = JsonRingLogger::open(&path, LogLimits::default());
+        log.append(&record("before restart")).unwrap();
+
+        let mut log = JsonRingLogger::open(&path, LogLimits::default());
+        log.append(&record("after restart")).unwrap();
+
+        let records = read_records(&path);
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].message, "before restart");
+    }
+}
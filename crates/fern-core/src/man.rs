@@ -0,0 +1,85 @@
+//! # Man Pages
+//!
+//! Renders roff man pages from a clap command tree with `clap_mangen`, one
+//! page per command: `fernctl.1`, `fernctl-theme.1`, `fernctl-theme-apply.1`
+//! and so on. Both CLIs expose this as a hidden `man` subcommand, which the
+//! Nix packages run at install time:
+//!
+//! ```bash
+//! fernctl man --out-dir share/man/man1
+//! man -l share/man/man1/fernctl-theme.1
+//! ```
+//!
+//! Hidden subcommands (including `man` itself) and clap's generated `help`
+//! subcommand get no page.
+
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Writes a man page for `cmd` and each visible subcommand into `dir`.
+///
+/// Creates `dir` if needed and returns the written paths, parent pages
+/// before their subcommands.
+///
+/// # Errors
+///
+/// Returns an error if the directory cannot be created or a page cannot be
+/// rendered or written.
+pub fn generate_to(mut cmd: clap::Command, dir: &Path) -> io::Result<Vec<PathBuf>> {
+    std::fs::create_dir_all(dir)?;
+
+    // Building fills in each subcommand's display name (`fernctl-theme`)
+    cmd.build();
+
+    let mut written = Vec::new();
+    write_pages(&cmd, dir, &mut written)?;
+    Ok(written)
+}
+
+/// Writes the page for `cmd`, then recurses into its subcommands.
+fn write_pages(cmd: &clap::Command, dir: &Path, written: &mut Vec<PathBuf>) -> io::Result<()> {
+    let name = cmd.get_display_name().unwrap_or_else(|| cmd.get_name());
+    let path = dir.join(format!("{name}.1"));
+
+    let mut page = Vec::new();
+    clap_mangen::Man::new(cmd.clone()).render(&mut page)?;
+    std::fs::write(&path, page)?;
+    written.push(path);
+
+    for sub in cmd
+        .get_subcommands()
+        .filter(|sub| !sub.is_hide_set() && sub.get_name() != "help")
+    {
+        write_pages(sub, dir, written)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writes_page_per_visible_subcommand() {
+        let dir = tempfile::tempdir().unwrap();
+        let cmd = clap::Command::new("demo")
+            .about("Demo tool")
+            .subcommand(
+                clap::Command::new("theme")
+                    .about("Themes")
+                    .subcommand(clap::Command::new("apply").about("Apply one")),
+            )
+            .subcommand(clap::Command::new("man").hide(true));
+
+        let written = generate_to(cmd, dir.path()).unwrap();
+        let names: Vec<_> = written
+            .iter()
+            .map(|path| path.file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+
+        assert_eq!(names, ["demo.1", "demo-theme.1", "demo-theme-apply.1"]);
+        let page = std::fs::read_to_string(dir.path().join("demo-theme.1")).unwrap();
+        assert!(page.contains(".TH"));
+        assert!(page.contains("Themes"));
+    }
+}
@@ -8,11 +8,18 @@
 //! ```text
 //! ~/.config/fern/           # Configuration (XDG_CONFIG_HOME)
 //! ├── config.toml           # User configuration
-//! └── config.json           # Generated JSON for QuickShell
+//! ├── config.json           # Generated JSON for QuickShell
+//! ├── config.json.bak       # config.json before the last theme change
+//! ├── backups/              # Timestamped config.json backups
+//! ├── obs.toml              # OBS bridge settings (command aliases)
+//! ├── services.toml         # User-defined managed services
+//! └── tui.toml              # Dashboard preferences, saved on exit
 //!
 //! ~/.local/state/fern/      # Runtime state (XDG_STATE_HOME)
 //! ├── services.json         # Service registry state
 //! ├── obs-state.json        # OBS bridge state
+//! ├── obs-log.json          # OBS bridge log ring buffer
+//! ├── obs.sock              # OBS daemon control socket
 //! └── theme-state.json      # Theme service state
 //!
 //! ~/.local/share/fern/      # Persistent data (XDG_DATA_HOME)
@@ -96,6 +103,55 @@ impl FernPaths {
         self.config_dir.join("config.json")
     }
 
+    /// Returns the path to the copy of `config.json` made before the last
+    /// theme change.
+    ///
+    /// `fernctl theme rollback` restores from here by default.
+    #[must_use]
+    pub fn config_json_backup(&self) -> PathBuf {
+        self.config_dir.join("config.json.bak")
+    }
+
+    /// Returns the directory holding timestamped `config.json` backups.
+    #[must_use]
+    pub fn config_backups_dir(&self) -> PathBuf {
+        self.config_dir.join("backups")
+    }
+
+    /// Returns the path to the user-defined services file.
+    ///
+    /// Declares extra daemons for fernctl to manage alongside the built-in
+    /// services.
+    #[must_use]
+    pub fn services_toml(&self) -> PathBuf {
+        self.config_dir.join("services.toml")
+    }
+
+    /// Returns the path to the dashboard preferences file.
+    ///
+    /// `fernctl tui` restores the focused panel and log view from it and
+    /// rewrites it on exit.
+    #[must_use]
+    pub fn tui_toml(&self) -> PathBuf {
+        self.config_dir.join("tui.toml")
+    }
+
+    /// Returns the path to a service's own settings file.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_core::FernPaths;
+    ///
+    /// let paths = FernPaths::new();
+    /// let obs_config = paths.service_config("obs");
+    /// // Returns: ~/.config/fern/obs.toml
+    /// ```
+    #[must_use]
+    pub fn service_config(&self, service: &str) -> PathBuf {
+        self.config_dir.join(format!("{service}.toml"))
+    }
+
     /// Returns the path to a service's state file.
     ///
     /// # Arguments
@@ -116,6 +172,63 @@ impl FernPaths {
         self.state_dir.join(format!("{service}-state.json"))
     }
 
+    /// Returns the path to a service's log file.
+    ///
+    /// Log files hold a bounded JSON array of entries that `fernctl logs`
+    /// aggregates.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_core::FernPaths;
+    ///
+    /// let paths = FernPaths::new();
+    /// let obs_log = paths.service_log("obs");
+    /// // Returns: ~/.local/state/fern/obs-log.json
+    /// ```
+    #[must_use]
+    pub fn service_log(&self, service: &str) -> PathBuf {
+        self.state_dir.join(format!("{service}-log.json"))
+    }
+
+    /// Returns the path to a service's control socket.
+    ///
+    /// Daemons that accept commands while running listen on this Unix
+    /// socket.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_core::FernPaths;
+    ///
+    /// let paths = FernPaths::new();
+    /// let obs_socket = paths.service_socket("obs");
+    /// // Returns: ~/.local/state/fern/obs.sock
+    /// ```
+    #[must_use]
+    pub fn service_socket(&self, service: &str) -> PathBuf {
+        self.state_dir.join(format!("{service}.sock"))
+    }
+
+    /// Returns the path to a service's PID file.
+    ///
+    /// A running daemon holds a lock on this file and writes its PID into
+    /// it, so only one instance runs per state directory.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_core::FernPaths;
+    ///
+    /// let paths = FernPaths::new();
+    /// let obs_pid = paths.service_pid("obs");
+    /// // Returns: ~/.local/state/fern/obs.pid
+    /// ```
+    #[must_use]
+    pub fn service_pid(&self, service: &str) -> PathBuf {
+        self.state_dir.join(format!("{service}.pid"))
+    }
+
     /// Returns the path to the service registry state file.
     ///
     /// This file tracks all running services and their status.
@@ -157,6 +270,15 @@ mod tests {
         assert!(paths.data_dir().ends_with("fern"));
     }
 
+    #[test]
+    fn config_backup_paths_format() {
+        let paths = FernPaths::new();
+
+        assert!(paths.config_json_backup().starts_with(paths.config_dir()));
+        assert!(paths.config_json_backup().ends_with("config.json.bak"));
+        assert!(paths.config_backups_dir().ends_with("backups"));
+    }
+
     #[test]
     fn service_state_path_format() {
         let paths = FernPaths::new();
@@ -165,11 +287,44 @@ mod tests {
         assert!(obs_state.to_string_lossy().contains("obs-state.json"));
     }
 
+    #[test]
+    fn service_log_path_format() {
+        let paths = FernPaths::new();
+        let obs_log = paths.service_log("obs");
+
+        assert!(obs_log.starts_with(paths.state_dir()));
+        assert!(obs_log.to_string_lossy().ends_with("obs-log.json"));
+    }
+
+    #[test]
+    fn service_socket_path_format() {
+        let paths = FernPaths::new();
+        let obs_socket = paths.service_socket("obs");
+
+        assert!(obs_socket.starts_with(paths.state_dir()));
+        assert!(obs_socket.to_string_lossy().ends_with("obs.sock"));
+    }
+
+    #[test]
+    fn service_pid_path_format() {
+        let paths = FernPaths::new();
+        let obs_pid = paths.service_pid("obs");
+
+        assert!(obs_pid.starts_with(paths.state_dir()));
+        assert!(obs_pid.to_string_lossy().ends_with("obs.pid"));
+    }
+
     #[test]
     fn config_files_in_config_dir() {
         let paths = FernPaths::new();
 
         assert!(paths.config_toml().starts_with(paths.config_dir()));
         assert!(paths.config_json().starts_with(paths.config_dir()));
+        assert!(paths.services_toml().starts_with(paths.config_dir()));
+        assert!(paths.tui_toml().starts_with(paths.config_dir()));
+        assert!(paths
+            .service_config("obs")
+            .to_string_lossy()
+            .ends_with("fern/obs.toml"));
     }
 }
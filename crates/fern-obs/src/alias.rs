@@ -0,0 +1,328 @@
+//! Command aliases from `~/.config/fern/obs.toml`.
+//!
+//! An alias names an ordered list of commands, so a multi-step setup runs
+//! with one `fern-obs run <alias>`:
+//!
+//! ```toml
+//! [aliases]
+//! golive = ['scene "Starting Soon"', "start-streaming", "start-recording"]
+//! wrapup = ["scene --transition Fade Outro", "stop-streaming", "stop-recording"]
+//! ```
+//!
+//! Each step is written like the matching `fern-obs` command line, without
+//! the `fern-obs`. Quote arguments that contain spaces. Every step is
+//! checked before anything runs, so a typo in the last step cannot leave a
+//! stream half set up.
+//!
+//! The running daemon executes the steps over its OBS connection and stops
+//! at the first one that fails.
+
+use crate::daemon::Command;
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// On-disk layout of `obs.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct ObsFile {
+    #[serde(default)]
+    aliases: BTreeMap<String, Vec<String>>,
+}
+
+/// Aliases defined in `obs.toml`, by name.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Aliases {
+    aliases: BTreeMap<String, Vec<String>>,
+}
+
+impl Aliases {
+    /// Loads the aliases from `path`.
+    ///
+    /// A missing file has no aliases.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read, or if
+    /// [`from_toml`](Self::from_toml) rejects it.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| Error::io(format!("reading {}", path.display()), e))?;
+        Self::from_toml(&content)
+    }
+
+    /// Parses the `[aliases]` table of `obs.toml` content.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] for invalid TOML, an alias without steps,
+    /// or a step that [`parse_step`] rejects.
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let file: ObsFile =
+            toml::from_str(content).map_err(|e| Error::Config(format!("obs.toml: {e}")))?;
+
+        for (name, steps) in &file.aliases {
+            if steps.is_empty() {
+                return Err(Error::Config(format!("alias '{name}' has no steps")));
+            }
+            parse_steps(steps)?;
+        }
+
+        Ok(Self {
+            aliases: file.aliases,
+        })
+    }
+
+    /// Returns the steps of the alias `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] listing the defined aliases if there is
+    /// no alias called `name`.
+    pub fn get(&self, name: &str) -> Result<&[String]> {
+        self.aliases.get(name).map(Vec::as_slice).ok_or_else(|| {
+            let known = if self.aliases.is_empty() {
+                "none defined".to_string()
+            } else {
+                self.names().collect::<Vec<_>>().join(", ")
+            };
+            Error::Config(format!("unknown alias '{name}' (aliases: {known})"))
+        })
+    }
+
+    /// Returns the alias names in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.aliases.keys().map(String::as_str)
+    }
+}
+
+/// Outcome of one step of an alias run.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepResult {
+    /// The step as written in the alias.
+    pub step: String,
+    /// Whether the step succeeded.
+    pub ok: bool,
+    /// The command's result message, or the error if it failed.
+    pub message: String,
+}
+
+/// Parses every step of an alias.
+///
+/// # Errors
+///
+/// Returns the error for the first step that [`parse_step`] rejects.
+pub fn parse_steps(steps: &[String]) -> Result<Vec<Command>> {
+    steps.iter().map(|step| parse_step(step)).collect()
+}
+
+/// Parses one alias step into a command.
+///
+/// Accepts the recording and streaming commands (including their short
+/// aliases such as `rec`), `scene <name> [--transition <name>]
+/// [--duration <ms>]`, `scene --next`, `scene --prev`, and
+/// `hotkey <name>`.
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] naming the step if it is empty, has an
+/// unterminated quote, or is not one of the accepted commands.
+pub fn parse_step(step: &str) -> Result<Command> {
+    let invalid = |reason: &str| Error::Config(format!("invalid alias step `{step}`: {reason}"));
+
+    let words = split_words(step).ok_or_else(|| invalid("unterminated quote"))?;
+    let Some((name, args)) = words.split_first() else {
+        return Err(invalid("empty step"));
+    };
+
+    if let Some(command) = command_without_args(name) {
+        return if args.is_empty() {
+            Ok(command)
+        } else {
+            Err(invalid("takes no arguments"))
+        };
+    }
+
+    match (name.as_str(), args) {
+        ("scene", [flag]) if flag == "--next" => Ok(Command::NextScene),
+        ("scene", [flag]) if flag == "--prev" => Ok(Command::PrevScene),
+        ("scene", args) => parse_scene(args).map_err(invalid),
+        ("hotkey", [hotkey]) => Ok(Command::TriggerHotkey(hotkey.clone())),
+        ("hotkey", _) => Err(invalid("hotkey takes one name")),
+        _ => Err(invalid("not a command that can run in an alias")),
+    }
+}
+
+/// Returns the command for a recording or streaming command name.
+fn command_without_args(name: &str) -> Option<Command> {
+    let command = match name {
+        "start-recording" | "rec" => Command::StartRecording,
+        "stop-recording" | "stop-rec" => Command::StopRecording,
+        "toggle-pause" | "pause" => Command::TogglePause,
+        "toggle-recording" | "toggle-rec" => Command::ToggleRecording,
+        "start-streaming" | "stream" => Command::StartStreaming,
+        "stop-streaming" | "stop-stream" => Command::StopStreaming,
+        "toggle-streaming" | "toggle-stream" => Command::ToggleStreaming,
+        _ => return None,
+    };
+    Some(command)
+}
+
+/// Parses the arguments of `scene <name> [--transition <name>] [--duration <ms>]`.
+fn parse_scene(args: &[String]) -> std::result::Result<Command, &'static str> {
+    let mut name = None;
+    let mut transition = None;
+    let mut duration_ms = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--transition" => {
+                transition = Some(args.next().ok_or("--transition needs a value")?.clone());
+            }
+            "--duration" => {
+                let value = args.next().ok_or("--duration needs a value")?;
+                duration_ms = Some(
+                    value
+                        .parse()
+                        .map_err(|_| "--duration must be milliseconds")?,
+                );
+            }
+            flag if flag.starts_with("--") => return Err("unknown scene option"),
+            _ if name.is_some() => return Err("scene takes one name (quote names with spaces)"),
+            _ => name = Some(arg.clone()),
+        }
+    }
+
+    Ok(Command::SetScene {
+        name: name.ok_or("scene needs a name, --next, or --prev")?,
+        transition,
+        duration_ms,
+    })
+}
+
+/// Splits a step into words on whitespace, keeping quoted text together.
+///
+/// Single and double quotes both group; there are no escapes. Returns
+/// `None` for an unterminated quote.
+fn split_words(step: &str) -> Option<Vec<String>> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    let mut in_word = false;
+    let mut quote = None;
+
+    for c in step.chars() {
+        match quote {
+            Some(q) if c == q => quote = None,
+            Some(_) => word.push(c),
+            None if c == '"' || c == '\'' => {
+                quote = Some(c);
+                in_word = true;
+            }
+            None if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+            }
+            None => {
+                word.push(c);
+                in_word = true;
+            }
+        }
+    }
+
+    if quote.is_some() {
+        return None;
+    }
+    if in_word {
+        words.push(word);
+    }
+    Some(words)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_words_keeps_quoted_text() {
+        assert_eq!(
+            split_words(r#"scene "Starting Soon" --transition 'Fade'"#).unwrap(),
+            ["scene", "Starting Soon", "--transition", "Fade"]
+        );
+        assert_eq!(split_words(r#"scene """#).unwrap(), ["scene", ""]);
+        assert!(split_words(r#"scene "BRB"#).is_none());
+    }
+
+    #[test]
+    fn parses_recording_and_scene_steps() {
+        assert!(matches!(
+            parse_step("rec").unwrap(),
+            Command::StartRecording
+        ));
+        assert!(matches!(
+            parse_step("scene --next").unwrap(),
+            Command::NextScene
+        ));
+
+        assert!(matches!(
+            parse_step("scene 'Starting Soon' --transition Fade --duration 300").unwrap(),
+            Command::SetScene {
+                name,
+                transition: Some(transition),
+                duration_ms: Some(300),
+            } if name == "Starting Soon" && transition == "Fade"
+        ));
+    }
+
+    #[test]
+    fn rejects_invalid_steps() {
+        for step in [
+            "",
+            "status",
+            "rec now",
+            "scene",
+            "scene Gaming BRB",
+            "scene Gaming --duration soon",
+            "raw obs:GetVersion",
+        ] {
+            assert!(
+                matches!(parse_step(step), Err(Error::Config(_))),
+                "`{step}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn loads_aliases_from_toml() {
+        let aliases = Aliases::from_toml(
+            r#"
+            [aliases]
+            golive = ['scene "Starting Soon"', "start-streaming", "start-recording"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(aliases.names().collect::<Vec<_>>(), ["golive"]);
+        assert_eq!(aliases.get("golive").unwrap().len(), 3);
+        assert!(aliases.get("brb").is_err());
+    }
+
+    #[test]
+    fn bad_alias_fails_whole_file() {
+        assert!(Aliases::from_toml("[aliases]\nempty = []").is_err());
+        assert!(Aliases::from_toml("[aliases]\ntypo = [\"strat-recording\"]").is_err());
+    }
+
+    #[test]
+    fn missing_file_has_no_aliases() {
+        let dir = tempfile::tempdir().unwrap();
+        let aliases = Aliases::load(&dir.path().join("obs.toml")).unwrap();
+        assert_eq!(aliases.names().count(), 0);
+    }
+}
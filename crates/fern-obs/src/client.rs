@@ -5,8 +5,19 @@
 
 use crate::config::ObsConfig;
 use crate::error::{Error, Result};
+use crate::raw;
 use crate::state::{ObsStats, StateTracker};
+use obws::requests::filters::SetEnabled;
+use obws::requests::hotkeys::KeyModifiers;
+use obws::requests::inputs::InputId;
+use obws::requests::sources::SourceId;
+use obws::responses::StatusCode;
 use obws::Client;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Input kinds that play media files, without their version suffix.
+const MEDIA_INPUT_KINDS: [&str; 2] = ["ffmpeg_source", "vlc_source"];
 
 /// High-level OBS client wrapper.
 ///
@@ -31,6 +42,41 @@ impl ObsClient {
         Ok(Self { client, config })
     }
 
+    /// Checks that OBS has finished starting up.
+    ///
+    /// OBS accepts WebSocket connections before it has loaded its scene
+    /// collection, and fails requests until then. Fetching the version and
+    /// a non-empty scene list shows it is ready.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Connection`] if OBS is not ready yet.
+    pub async fn check_ready(&self) -> Result<()> {
+        let not_ready = |message: String| {
+            Error::connection(
+                &self.config.host,
+                self.config.port,
+                format!("OBS is not ready yet: {message}"),
+            )
+        };
+
+        self.client
+            .general()
+            .version()
+            .await
+            .map_err(|e| not_ready(e.to_string()))?;
+        let scenes = self
+            .client
+            .scenes()
+            .list()
+            .await
+            .map_err(|e| not_ready(e.to_string()))?;
+        if scenes.scenes.is_empty() {
+            return Err(not_ready("no scenes loaded".into()));
+        }
+        Ok(())
+    }
+
     /// Returns the configuration.
     #[must_use]
     pub fn config(&self) -> &ObsConfig {
@@ -80,6 +126,32 @@ impl ObsClient {
             .map_err(|e| Error::Request(e.to_string()))
     }
 
+    /// Pauses the active recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn pause_recording(&self) -> Result<()> {
+        self.client
+            .recording()
+            .pause()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    /// Resumes a paused recording.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn resume_recording(&self) -> Result<()> {
+        self.client
+            .recording()
+            .resume()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
     /// Gets the current recording status.
     ///
     /// # Errors
@@ -97,6 +169,7 @@ impl ObsClient {
             active: status.active,
             paused: status.paused,
             timecode: status.timecode.to_string(),
+            elapsed: Duration::try_from(status.duration).unwrap_or_default(),
             bytes: status.bytes,
         })
     }
@@ -148,6 +221,7 @@ impl ObsClient {
             active: status.active,
             reconnecting: status.reconnecting,
             timecode: status.timecode.to_string(),
+            elapsed: Duration::try_from(status.duration).unwrap_or_default(),
             bytes: status.bytes,
         })
     }
@@ -201,6 +275,250 @@ impl ObsClient {
             .map_err(|e| Error::Request(e.to_string()))
     }
 
+    // ========================================================================
+    // Transitions
+    // ========================================================================
+
+    /// Gets the names of all scene transitions.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn list_transitions(&self) -> Result<Vec<String>> {
+        let list = self
+            .client
+            .transitions()
+            .list()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))?;
+
+        Ok(list.transitions.into_iter().map(|t| t.id.name).collect())
+    }
+
+    /// Sets the current scene transition (e.g., `Cut` or `Fade`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn set_transition(&self, name: &str) -> Result<()> {
+        self.client
+            .transitions()
+            .set_current(name)
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    /// Sets the duration of the current scene transition.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn set_transition_duration(&self, duration_ms: u32) -> Result<()> {
+        self.client
+            .transitions()
+            .set_current_duration(time::Duration::milliseconds(i64::from(duration_ms)))
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    // ========================================================================
+    // Hotkeys
+    // ========================================================================
+
+    /// Gets the names of all hotkeys registered in OBS.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn list_hotkeys(&self) -> Result<Vec<String>> {
+        self.client
+            .hotkeys()
+            .list()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    /// Triggers a hotkey by its OBS name (e.g., `OBSBasic.StartRecording`).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn trigger_hotkey(&self, name: &str) -> Result<()> {
+        self.client
+            .hotkeys()
+            .trigger_by_name(name, None)
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    /// Triggers a hotkey by key sequence (e.g., `OBS_KEY_F9` with `["ctrl"]`).
+    ///
+    /// Recognized modifiers are `shift`, `ctrl`/`control`, `alt`, and
+    /// `cmd`/`command`/`super` (case-insensitive).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a modifier is unknown or the request fails.
+    pub async fn trigger_hotkey_sequence(&self, key: &str, modifiers: &[String]) -> Result<()> {
+        let modifiers = parse_key_modifiers(modifiers)?;
+        self.client
+            .hotkeys()
+            .trigger_by_sequence(key, modifiers)
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    // ========================================================================
+    // Filters
+    // ========================================================================
+
+    /// Gets the filters on a source, in the order OBS applies them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if there is no source called `source`,
+    /// or an error if the request fails.
+    pub async fn list_source_filters(&self, source: &str) -> Result<Vec<SourceFilter>> {
+        let filters = self
+            .client
+            .filters()
+            .list(SourceId::Name(source))
+            .await
+            .map_err(|e| source_error(source, e))?;
+
+        Ok(filters
+            .into_iter()
+            .map(|f| SourceFilter {
+                name: f.name,
+                kind: f.kind,
+                enabled: f.enabled,
+            })
+            .collect())
+    }
+
+    /// Enables or disables a filter on a source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if there is no source called `source`
+    /// or it has no filter called `filter`, or an error if the request
+    /// fails.
+    pub async fn set_source_filter_enabled(
+        &self,
+        source: &str,
+        filter: &str,
+        enabled: bool,
+    ) -> Result<()> {
+        // OBS reports a missing source and a missing filter alike, so look
+        // the filter up first to say which one it was
+        let filters = self.list_source_filters(source).await?;
+        if !filters.iter().any(|f| f.name == filter) {
+            return Err(Error::NotFound {
+                what: format!("filter '{filter}' on source '{source}'"),
+            });
+        }
+
+        self.client
+            .filters()
+            .set_enabled(SetEnabled {
+                source: SourceId::Name(source),
+                filter,
+                enabled,
+            })
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    // ========================================================================
+    // Media Inputs
+    // ========================================================================
+
+    /// Plays, pauses, restarts, stops, or skips within a media source.
+    ///
+    /// `Next` and `Previous` only do something for playlist sources such
+    /// as the VLC source.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotFound`] if there is no input called `source`,
+    /// [`Error::Request`] if it is not a media source, or an error if the
+    /// request fails.
+    pub async fn trigger_media_action(&self, source: &str, action: MediaAction) -> Result<()> {
+        // OBS accepts media actions for any input and silently ignores them
+        // for the wrong kind, so check first
+        let inputs = self
+            .client
+            .inputs()
+            .list(None)
+            .await
+            .map_err(|e| Error::Request(e.to_string()))?;
+        let input = inputs
+            .iter()
+            .find(|input| input.id.name == source)
+            .ok_or_else(|| Error::NotFound {
+                what: format!("input '{source}'"),
+            })?;
+        if !is_media_input_kind(&input.unversioned_kind) {
+            return Err(Error::Request(format!(
+                "'{source}' is a {} input, not a media source",
+                input.kind
+            )));
+        }
+
+        self.client
+            .media_inputs()
+            .trigger_action(InputId::Name(source), action.to_obws())
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
+    // ========================================================================
+    // Raw Requests
+    // ========================================================================
+
+    /// Sends an untyped request and returns the raw JSON response.
+    ///
+    /// `request_type` is any obs-websocket request name (e.g.,
+    /// `GetInputList`); see [`raw`](crate::raw) for how it is sent.
+    ///
+    /// Raw requests bypass the typed API and are never reflected in
+    /// [`ObsState`](crate::state::ObsState).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the connection fails or OBS rejects the request.
+    pub async fn raw_request(
+        &self,
+        request_type: &str,
+        request_data: &serde_json::Value,
+    ) -> Result<serde_json::Value> {
+        raw::send(
+            &self.config.host,
+            self.config.port,
+            self.config.password.as_deref(),
+            request_type,
+            request_data,
+        )
+        .await
+    }
+
+    // ========================================================================
+    // Recording Output
+    // ========================================================================
+
+    /// Gets the directory OBS saves recordings to.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails.
+    pub async fn record_directory(&self) -> Result<String> {
+        self.client
+            .config()
+            .record_directory()
+            .await
+            .map_err(|e| Error::Request(e.to_string()))
+    }
+
     // ========================================================================
     // Stats
     // ========================================================================
@@ -251,13 +569,15 @@ impl ObsClient {
         // Get recording status
         if let Ok(rec_status) = self.recording_status().await {
             if rec_status.active {
-                if rec_status.paused {
-                    tracker.state.recording.paused = true;
-                }
-                // Note: We can't easily get elapsed time from OBS timecode,
-                // so we rely on the tracker's internal timer
+                // Seed the timer from OBS, so a recording that was already
+                // running (e.g. across a daemon restart) keeps its timecode
                 if !tracker.state.recording.active {
-                    tracker.start_recording();
+                    tracker.start_recording_at(rec_status.elapsed);
+                }
+                if rec_status.paused {
+                    tracker.pause_recording();
+                } else {
+                    tracker.resume_recording();
                 }
             } else if tracker.state.recording.active {
                 tracker.stop_recording();
@@ -268,7 +588,7 @@ impl ObsClient {
         if let Ok(stream_status) = self.streaming_status().await {
             if stream_status.active {
                 if !tracker.state.streaming.active {
-                    tracker.start_streaming();
+                    tracker.start_streaming_at(stream_status.elapsed);
                 }
                 tracker.state.streaming.reconnecting = stream_status.reconnecting;
             } else if tracker.state.streaming.active {
@@ -286,6 +606,11 @@ impl ObsClient {
             tracker.set_scenes(scenes);
         }
 
+        // OBS sends no event when the output path changes, so poll it
+        if let Ok(directory) = self.record_directory().await {
+            tracker.set_record_directory(directory);
+        }
+
         // Get stats if configured
         if self.config.show_stats {
             if let Ok(stats) = self.get_stats().await {
@@ -297,6 +622,98 @@ impl ObsClient {
     }
 }
 
+/// Maps a failed request about `source`, calling out a missing source.
+fn source_error(source: &str, error: obws::error::Error) -> Error {
+    match error {
+        obws::error::Error::Api {
+            code: StatusCode::ResourceNotFound,
+            ..
+        } => Error::NotFound {
+            what: format!("source '{source}'"),
+        },
+        other => Error::Request(other.to_string()),
+    }
+}
+
+/// Returns true if inputs of `unversioned_kind` play media files.
+fn is_media_input_kind(unversioned_kind: &str) -> bool {
+    MEDIA_INPUT_KINDS.contains(&unversioned_kind)
+}
+
+/// Converts modifier names into the obws key modifier set.
+fn parse_key_modifiers(modifiers: &[String]) -> Result<KeyModifiers> {
+    let mut parsed = KeyModifiers::default();
+    for modifier in modifiers {
+        match modifier.to_ascii_lowercase().as_str() {
+            "shift" => parsed.shift = true,
+            "ctrl" | "control" => parsed.control = true,
+            "alt" => parsed.alt = true,
+            "cmd" | "command" | "super" => parsed.command = true,
+            other => {
+                return Err(Error::Request(format!("unknown hotkey modifier: {other}")));
+            }
+        }
+    }
+    Ok(parsed)
+}
+
+/// A filter on an OBS source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceFilter {
+    /// Filter name, as shown in OBS.
+    pub name: String,
+    /// Filter kind (e.g., `color_filter_v2`).
+    pub kind: String,
+    /// Whether the filter is enabled.
+    pub enabled: bool,
+}
+
+/// An action for a media source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum MediaAction {
+    /// Start or resume playback.
+    Play,
+    /// Pause playback.
+    Pause,
+    /// Play from the beginning.
+    Restart,
+    /// Stop playback.
+    Stop,
+    /// Skip to the next playlist item.
+    Next,
+    /// Go back to the previous playlist item.
+    Previous,
+}
+
+impl MediaAction {
+    /// Returns the action name as used on the command line.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Play => "play",
+            Self::Pause => "pause",
+            Self::Restart => "restart",
+            Self::Stop => "stop",
+            Self::Next => "next",
+            Self::Previous => "previous",
+        }
+    }
+
+    /// Converts to the obws media action.
+    const fn to_obws(self) -> obws::common::MediaAction {
+        match self {
+            Self::Play => obws::common::MediaAction::Play,
+            Self::Pause => obws::common::MediaAction::Pause,
+            Self::Restart => obws::common::MediaAction::Restart,
+            Self::Stop => obws::common::MediaAction::Stop,
+            Self::Next => obws::common::MediaAction::Next,
+            Self::Previous => obws::common::MediaAction::Previous,
+        }
+    }
+}
+
 /// Recording status from OBS.
 #[derive(Debug, Clone)]
 pub struct RecordingStatus {
@@ -306,6 +723,8 @@ pub struct RecordingStatus {
     pub paused: bool,
     /// Timecode string.
     pub timecode: String,
+    /// How long the recording has been running.
+    pub elapsed: Duration,
     /// Bytes recorded.
     pub bytes: u64,
 }
@@ -319,6 +738,42 @@ pub struct StreamingStatus {
     pub reconnecting: bool,
     /// Timecode string.
     pub timecode: String,
+    /// How long the stream has been live.
+    pub elapsed: Duration,
     /// Bytes sent.
     pub bytes: u64,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn media_input_kinds() {
+        assert!(is_media_input_kind("ffmpeg_source"));
+        assert!(is_media_input_kind("vlc_source"));
+        assert!(!is_media_input_kind("image_source"));
+        assert!(!is_media_input_kind("v4l2_input"));
+    }
+
+    #[test]
+    fn hotkey_modifiers() {
+        let names = |names: &[&str]| names.iter().map(ToString::to_string).collect::<Vec<_>>();
+
+        let parsed = parse_key_modifiers(&names(&["Ctrl", "shift"])).unwrap();
+        assert!(parsed.control && parsed.shift);
+        assert!(!parsed.alt && !parsed.command);
+
+        let parsed = parse_key_modifiers(&names(&["control", "alt", "super"])).unwrap();
+        assert!(parsed.control && parsed.alt && parsed.command);
+        assert!(!parsed.shift);
+
+        let parsed = parse_key_modifiers(&[]).unwrap();
+        assert!(!parsed.shift && !parsed.control && !parsed.alt && !parsed.command);
+
+        assert!(matches!(
+            parse_key_modifiers(&names(&["ctrl", "hyper"])),
+            Err(Error::Request(message)) if message.contains("hyper")
+        ));
+    }
+}
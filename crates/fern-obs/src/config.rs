@@ -1,6 +1,11 @@
 //! Configuration for fern-obs.
 
+use crate::drop_rate::DEFAULT_DROP_WINDOW;
+use crate::error::{Error, Result};
+use crate::stats_history::DEFAULT_STATS_HISTORY;
 use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr};
+use std::time::Duration;
 
 /// Configuration for connecting to OBS.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -32,6 +37,84 @@ pub struct ObsConfig {
     /// Whether to show stats in the state file.
     #[serde(default = "default_show_stats")]
     pub show_stats: bool,
+
+    /// Whether untyped raw requests may be forwarded to OBS.
+    ///
+    /// Raw requests bypass the typed API, so they are disabled by default.
+    #[serde(default)]
+    pub allow_raw: bool,
+
+    /// Number of stats samples kept for the CPU/FPS history in the state
+    /// file. 0 disables the history.
+    #[serde(default = "default_stats_history_samples")]
+    pub stats_history_samples: usize,
+
+    /// Scenes that pause recording while they are active.
+    ///
+    /// When one of these scenes becomes the program scene during a
+    /// recording, the daemon pauses it, and resumes when a scene outside
+    /// the list takes over. Useful for a "Private" or "BRB" scene.
+    #[serde(default)]
+    pub auto_pause_scenes: Vec<String>,
+
+    /// Free disk space (in MB) below which a recording raises a low-disk
+    /// warning. 0 disables the warning.
+    #[serde(default = "default_low_disk_threshold")]
+    pub low_disk_threshold_mb: u32,
+
+    /// Render or output drop rate (in percent) above which the daemon
+    /// raises a dropped-frames warning. 0 disables the warning.
+    #[serde(default = "default_drop_warning_percent")]
+    pub drop_warning_percent: f64,
+
+    /// Number of stats samples the drop rate is averaged over, so a single
+    /// bad frame does not raise a warning.
+    #[serde(default = "default_drop_window_samples")]
+    pub drop_window_samples: usize,
+
+    /// Whether a new warning (low disk, dropped frames) also sends a
+    /// desktop notification.
+    #[serde(default, alias = "notify_low_disk")]
+    pub notify_warnings: bool,
+
+    /// Shell command that launches OBS when the daemon cannot connect
+    /// (e.g., `obs --minimize-to-tray --startreplaybuffer`).
+    ///
+    /// Run at most once per run of failed connection attempts, and not
+    /// while an `obs` process is already running.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auto_start_obs: Option<String>,
+
+    /// How long a one-shot command may take, connection included
+    /// (in milliseconds).
+    #[serde(default = "default_command_timeout")]
+    pub command_timeout_ms: u64,
+
+    /// Port for the daemon's HTTP API. `None` leaves it off.
+    ///
+    /// Needs fern-obs built with the `http` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_port: Option<u16>,
+
+    /// Address the HTTP API listens on. Only loopback by default, so other
+    /// machines cannot reach it.
+    #[serde(default = "default_bind")]
+    pub http_bind: IpAddr,
+
+    /// Token that `POST` requests to the HTTP API must send as
+    /// `Authorization: Bearer <token>`. Without one, the API is read-only.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub http_token: Option<String>,
+
+    /// Port for the daemon's WebSocket state feed. `None` leaves it off.
+    ///
+    /// Needs fern-obs built with the `websocket` feature.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ws_port: Option<u16>,
+
+    /// Address the WebSocket feed listens on. Only loopback by default.
+    #[serde(default = "default_bind")]
+    pub ws_bind: IpAddr,
 }
 
 fn default_host() -> String {
@@ -54,6 +137,30 @@ fn default_show_stats() -> bool {
     true
 }
 
+fn default_stats_history_samples() -> usize {
+    DEFAULT_STATS_HISTORY
+}
+
+fn default_low_disk_threshold() -> u32 {
+    1024 // 1 GB
+}
+
+fn default_drop_warning_percent() -> f64 {
+    5.0
+}
+
+fn default_drop_window_samples() -> usize {
+    DEFAULT_DROP_WINDOW
+}
+
+fn default_command_timeout() -> u64 {
+    5000 // 5 seconds
+}
+
+const fn default_bind() -> IpAddr {
+    IpAddr::V4(Ipv4Addr::LOCALHOST)
+}
+
 impl Default for ObsConfig {
     fn default() -> Self {
         Self {
@@ -64,6 +171,20 @@ impl Default for ObsConfig {
             reconnect_interval_ms: default_reconnect_interval(),
             max_reconnect_attempts: 0,
             show_stats: default_show_stats(),
+            allow_raw: false,
+            stats_history_samples: default_stats_history_samples(),
+            auto_pause_scenes: Vec::new(),
+            low_disk_threshold_mb: default_low_disk_threshold(),
+            drop_warning_percent: default_drop_warning_percent(),
+            drop_window_samples: default_drop_window_samples(),
+            notify_warnings: false,
+            auto_start_obs: None,
+            command_timeout_ms: default_command_timeout(),
+            http_port: None,
+            http_bind: default_bind(),
+            http_token: None,
+            ws_port: None,
+            ws_bind: default_bind(),
         }
     }
 }
@@ -86,6 +207,86 @@ impl ObsConfig {
         self
     }
 
+    /// Checks that the settings can run a daemon.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Config`] naming the first bad setting: an empty
+    /// host, port 0, a zero interval or timeout, a drop-warning threshold
+    /// outside 0–100, a drop window shorter than 2 samples, an HTTP or
+    /// WebSocket port of 0 or in a build without its feature, both on the
+    /// same port, or an empty HTTP token or OBS launch command.
+    pub fn validate(&self) -> Result<()> {
+        if self.host.trim().is_empty() {
+            return Err(Error::Config("host must not be empty".into()));
+        }
+        if self.port == 0 {
+            return Err(Error::Config("port must be between 1 and 65535".into()));
+        }
+        for (key, value) in [
+            ("stats_interval_ms", self.stats_interval_ms),
+            ("reconnect_interval_ms", self.reconnect_interval_ms),
+            ("command_timeout_ms", self.command_timeout_ms),
+        ] {
+            if value == 0 {
+                return Err(Error::Config(format!("{key} must be greater than 0")));
+            }
+        }
+        if !(0.0..=100.0).contains(&self.drop_warning_percent) {
+            return Err(Error::Config(format!(
+                "drop_warning_percent must be between 0 and 100 (got {})",
+                self.drop_warning_percent
+            )));
+        }
+        if self.drop_window_samples < 2 {
+            return Err(Error::Config(format!(
+                "drop_window_samples must be at least 2 (got {})",
+                self.drop_window_samples
+            )));
+        }
+        for (key, port) in [("http_port", self.http_port), ("ws_port", self.ws_port)] {
+            if port == Some(0) {
+                return Err(Error::Config(format!("{key} must be between 1 and 65535")));
+            }
+        }
+        if self.http_port.is_some() && !cfg!(feature = "http") {
+            return Err(Error::Config(
+                "http_port needs fern-obs built with the `http` feature".into(),
+            ));
+        }
+        if self.ws_port.is_some() && !cfg!(feature = "websocket") {
+            return Err(Error::Config(
+                "ws_port needs fern-obs built with the `websocket` feature".into(),
+            ));
+        }
+        if self.http_port.is_some() && self.http_port == self.ws_port {
+            return Err(Error::Config(
+                "http_port and ws_port must be different".into(),
+            ));
+        }
+        for (key, value) in [
+            ("http_token", &self.http_token),
+            ("auto_start_obs", &self.auto_start_obs),
+        ] {
+            if value.as_deref().is_some_and(|v| v.trim().is_empty()) {
+                return Err(Error::Config(format!("{key} must not be empty")));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns `true` if recording should pause while `scene` is active.
+    #[must_use]
+    pub fn is_auto_pause_scene(&self, scene: &str) -> bool {
+        self.auto_pause_scenes.iter().any(|s| s == scene)
+    }
+
+    /// Returns the one-shot command timeout.
+    #[must_use]
+    pub const fn command_timeout(&self) -> Duration {
+        Duration::from_millis(self.command_timeout_ms)
+    }
+
     /// Returns the WebSocket URL.
     #[must_use]
     pub fn websocket_url(&self) -> String {
@@ -103,6 +304,78 @@ mod tests {
         assert_eq!(config.host, "localhost");
         assert_eq!(config.port, 4455);
         assert!(config.password.is_none());
+        assert!(!config.allow_raw);
+        assert_eq!(config.stats_history_samples, 60);
+        assert!(config.auto_pause_scenes.is_empty());
+        assert_eq!(config.low_disk_threshold_mb, 1024);
+        assert!((config.drop_warning_percent - 5.0).abs() < f64::EPSILON);
+        assert_eq!(config.drop_window_samples, 10);
+        assert!(!config.notify_warnings);
+        assert!(config.auto_start_obs.is_none());
+        assert_eq!(config.command_timeout(), Duration::from_secs(5));
+        assert!(config.http_port.is_none());
+        assert!(config.http_bind.is_loopback());
+        assert!(config.http_token.is_none());
+        assert!(config.ws_port.is_none());
+        assert!(config.ws_bind.is_loopback());
+    }
+
+    #[test]
+    fn validate_rejects_unusable_settings() {
+        assert!(ObsConfig::default().validate().is_ok());
+
+        let invalid = [
+            ObsConfig::new(" ", 4455),
+            ObsConfig::new("localhost", 0),
+            ObsConfig {
+                stats_interval_ms: 0,
+                ..Default::default()
+            },
+            ObsConfig {
+                command_timeout_ms: 0,
+                ..Default::default()
+            },
+            ObsConfig {
+                drop_warning_percent: f64::NAN,
+                ..Default::default()
+            },
+            ObsConfig {
+                drop_window_samples: 1,
+                ..Default::default()
+            },
+            ObsConfig {
+                http_port: Some(0),
+                ..Default::default()
+            },
+            ObsConfig {
+                http_token: Some(String::new()),
+                ..Default::default()
+            },
+            ObsConfig {
+                ws_port: Some(0),
+                ..Default::default()
+            },
+            ObsConfig {
+                auto_start_obs: Some(" ".into()),
+                ..Default::default()
+            },
+        ];
+        for config in invalid {
+            assert!(
+                matches!(config.validate(), Err(Error::Config(_))),
+                "{config:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn auto_pause_scene_lookup() {
+        let config = ObsConfig {
+            auto_pause_scenes: vec!["Private".into()],
+            ..Default::default()
+        };
+        assert!(config.is_auto_pause_scene("Private"));
+        assert!(!config.is_auto_pause_scene("Gaming"));
     }
 
     #[test]
@@ -0,0 +1,265 @@
+//! Control socket for the running daemon.
+//!
+//! The daemon listens on `~/.local/state/fern/obs.sock` for commands that
+//! only make sense while it is running, such as dropping the OBS
+//! connection during an OBS restart:
+//!
+//! ```bash
+//! fern-obs disconnect   # drop the connection, stop reconnecting
+//! # ... restart OBS ...
+//! fern-obs connect      # reconnect now
+//! ```
+//!
+//! The protocol is one JSON request line, answered with one JSON response
+//! line:
+//!
+//! ```text
+//! → {"command":"disconnect"}
+//! ← {"state":{"connected":false,...}}
+//! ```
+//!
+//! `fern-obs run <alias>` also goes through the daemon, so the steps share
+//! its OBS connection:
+//!
+//! ```text
+//! → {"command":"run","alias":"golive","steps":["start-streaming",...]}
+//! ← {"steps":[{"step":"start-streaming","ok":true,"message":"..."},...]}
+//! ```
+//!
+//! Any other [`Command`] can run over the daemon's connection too:
+//!
+//! ```text
+//! → {"command":"execute","request":{"set_scene":{"name":"Gaming"}}}
+//! ← {"result":{"success":"Scene set to: Gaming"}}
+//! ```
+
+use crate::alias::StepResult;
+use crate::daemon::{Command, CommandResult};
+use crate::error::{Error, Result};
+use crate::state::ObsState;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::{mpsc, oneshot};
+use tracing::{debug, warn};
+
+/// A request sent to the daemon over the control socket.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "snake_case")]
+pub enum ControlRequest {
+    /// Connect to OBS now, ending a previous [`Disconnect`](Self::Disconnect).
+    Connect,
+    /// Drop the OBS connection and stop reconnecting until a
+    /// [`Connect`](Self::Connect).
+    Disconnect,
+    /// Report the daemon's state, including its connection history.
+    Health,
+    /// Run the steps of a command alias in order, stopping at the first
+    /// failure.
+    Run {
+        /// Alias name, for logging.
+        alias: String,
+        /// The alias steps (see [`crate::alias::parse_step`]).
+        steps: Vec<String>,
+    },
+    /// Run a command over the daemon's OBS connection.
+    Execute {
+        /// The command.
+        request: Command,
+    },
+}
+
+/// The daemon's answer to a [`ControlRequest`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ControlResponse {
+    /// The resulting OBS state.
+    State(ObsState),
+    /// The outcome of each step that ran; only the last can have failed.
+    Steps(Vec<StepResult>),
+    /// The result of an [`Execute`](ControlRequest::Execute) request.
+    Result(CommandResult),
+    /// The request could not be handled.
+    Error(String),
+}
+
+/// A request received by the listener, waiting for the daemon's answer.
+#[derive(Debug)]
+pub struct ControlMessage {
+    /// The request.
+    pub request: ControlRequest,
+    /// Where to send the response.
+    pub reply: oneshot::Sender<ControlResponse>,
+}
+
+/// Binds the control socket, replacing a stale socket file.
+///
+/// # Errors
+///
+/// Returns an error if the socket cannot be bound.
+pub fn bind(path: &Path) -> Result<UnixListener> {
+    if path.exists() {
+        std::fs::remove_file(path).map_err(|e| Error::io("removing stale control socket", e))?;
+    }
+    UnixListener::bind(path).map_err(|e| Error::io("binding control socket", e))
+}
+
+/// Accepts control connections and forwards their requests to the daemon.
+///
+/// Returns at the first connection after the daemon has stopped
+/// receiving requests.
+pub async fn serve(listener: UnixListener, tx: mpsc::Sender<ControlMessage>) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!(error = %e, "Failed to accept control connection");
+                continue;
+            }
+        };
+        if tx.is_closed() {
+            return;
+        }
+
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, &tx).await {
+                debug!(error = %e, "Control connection failed");
+            }
+        });
+    }
+}
+
+/// Reads one request, waits for the daemon, and writes the response.
+async fn handle_connection(stream: UnixStream, tx: &mpsc::Sender<ControlMessage>) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut line = String::new();
+    BufReader::new(reader)
+        .read_line(&mut line)
+        .await
+        .map_err(|e| Error::io("reading control request", e))?;
+
+    let response = match serde_json::from_str::<ControlRequest>(&line) {
+        Ok(request) => dispatch(tx, request).await,
+        Err(e) => ControlResponse::Error(format!("invalid request: {e}")),
+    };
+
+    let mut json = serde_json::to_string(&response)?;
+    json.push('\n');
+    writer
+        .write_all(json.as_bytes())
+        .await
+        .map_err(|e| Error::io("writing control response", e))
+}
+
+/// Hands a request to the daemon and waits for its answer.
+pub(crate) async fn dispatch(
+    tx: &mpsc::Sender<ControlMessage>,
+    request: ControlRequest,
+) -> ControlResponse {
+    let (reply, answer) = oneshot::channel();
+    let sent = tx.send(ControlMessage { request, reply }).await;
+    match (sent, answer.await) {
+        (Ok(()), Ok(response)) => response,
+        _ => ControlResponse::Error("daemon is shutting down".into()),
+    }
+}
+
+/// Sends a request to the daemon listening on `path`.
+///
+/// Never returns [`ControlResponse::Error`]; a rejected request is an
+/// [`Error::Request`].
+///
+/// # Errors
+///
+/// Returns an error if the daemon is not running or rejects the request.
+pub async fn request(path: &Path, request: ControlRequest) -> Result<ControlResponse> {
+    let stream = UnixStream::connect(path).await.map_err(|e| {
+        Error::io(
+            format!(
+                "connecting to daemon at {} (is it running?)",
+                path.display()
+            ),
+            e,
+        )
+    })?;
+    let (reader, mut writer) = stream.into_split();
+
+    let mut json = serde_json::to_string(&request)?;
+    json.push('\n');
+    writer
+        .write_all(json.as_bytes())
+        .await
+        .map_err(|e| Error::io("sending control request", e))?;
+
+    let mut line = String::new();
+    BufReader::new(reader)
+        .read_line(&mut line)
+        .await
+        .map_err(|e| Error::io("reading control response", e))?;
+
+    match serde_json::from_str(&line)? {
+        ControlResponse::Error(message) => Err(Error::Request(message)),
+        response => Ok(response),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_wire_format() {
+        assert_eq!(
+            serde_json::to_string(&ControlRequest::Disconnect).unwrap(),
+            r#"{"command":"disconnect"}"#
+        );
+        let request: ControlRequest = serde_json::from_str(r#"{"command":"connect"}"#).unwrap();
+        assert_eq!(request, ControlRequest::Connect);
+
+        let run = ControlRequest::Run {
+            alias: "golive".into(),
+            steps: vec!["rec".into()],
+        };
+        assert_eq!(
+            serde_json::to_string(&run).unwrap(),
+            r#"{"command":"run","alias":"golive","steps":["rec"]}"#
+        );
+
+        let request: ControlRequest = serde_json::from_str(
+            r#"{"command":"execute","request":{"trigger_hotkey_sequence":{"key":"OBS_KEY_F9"}}}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            request,
+            ControlRequest::Execute {
+                request: Command::TriggerHotkeySequence {
+                    key: "OBS_KEY_F9".into(),
+                    modifiers: Vec::new(),
+                },
+            }
+        );
+    }
+
+    #[tokio::test]
+    async fn request_roundtrip_over_socket() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("obs.sock");
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(serve(bind(&path).unwrap(), tx));
+
+        // Stand-in for the daemon: answer with a disconnected state
+        tokio::spawn(async move {
+            while let Some(message) = rx.recv().await {
+                assert_eq!(message.request, ControlRequest::Disconnect);
+                let _ = message
+                    .reply
+                    .send(ControlResponse::State(ObsState::disconnected()));
+            }
+        });
+
+        let response = request(&path, ControlRequest::Disconnect).await.unwrap();
+        assert!(matches!(response, ControlResponse::State(state) if !state.connected));
+    }
+}
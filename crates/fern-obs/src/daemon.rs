@@ -2,14 +2,25 @@
 //!
 //! The daemon maintains a connection to OBS, handles events, and writes
 //! state updates to the state file for the QML interface to consume.
+//! Scripts can drop and restore the connection and run
+//! [command aliases](crate::alias) through the
+//! [control socket](crate::control), or over HTTP with the `http` feature.
 
-use crate::client::ObsClient;
+use crate::alias::{self, StepResult};
+use crate::client::{MediaAction, ObsClient, SourceFilter};
 use crate::config::ObsConfig;
+use crate::control::{self, ControlMessage, ControlRequest, ControlResponse};
+use crate::drop_rate::DropRateWindow;
 use crate::error::{Error, Result};
-use crate::state::{ObsState, StateTracker};
+use crate::history::{SceneHistory, DEFAULT_HISTORY_CAPACITY, HISTORY_FILE_NAME};
+use crate::pid_file::PidLock;
+use crate::state::{unix_now, ObsState, RecordingState, StateTracker};
+use crate::stats_history::{StatsHistory, STATS_HISTORY_POINTS};
 use fern_core::FernPaths;
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, watch};
 use tokio::time::{interval, sleep};
 use tracing::{error, info, warn};
 
@@ -22,13 +33,169 @@ const FAST_RETRY_THRESHOLD: u32 = 10;
 /// Slow retry interval in milliseconds (60 seconds).
 const SLOW_RETRY_INTERVAL_MS: u64 = 60_000;
 
+/// How long an auto-started OBS gets before the next connection attempt.
+const OBS_STARTUP_WAIT: Duration = Duration::from_secs(10);
+
+/// Control requests queued before the listener waits for the daemon.
+const CONTROL_QUEUE: usize = 8;
+
+/// Why a connected session ended without an error.
+enum SessionEnd {
+    /// Shutdown was requested.
+    Shutdown,
+    /// A control request dropped the connection.
+    Disconnected,
+}
+
+/// Reconnection backoff: fast retries, then a slow interval once OBS
+/// looks unavailable.
+///
+/// Every failed attempt counts, including connections dropped because
+/// OBS was not ready yet, so a restarting OBS is retried at the normal
+/// pace instead of in a tight loop.
+#[derive(Debug, Default)]
+struct Backoff {
+    /// Failed attempts so far.
+    attempts: u32,
+    /// Whether retries have switched to [`SLOW_RETRY_INTERVAL_MS`].
+    slow: bool,
+}
+
+impl Backoff {
+    /// Starts over with fast retries.
+    fn reset(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Records a failed attempt and returns how long to wait before the
+    /// next one, or `None` once `max_attempts` (0 for unlimited) is
+    /// exceeded.
+    ///
+    /// `was_connected` is whether the attempt had been connected, which
+    /// puts a slow backoff back to fast retries.
+    fn next_delay(
+        &mut self,
+        was_connected: bool,
+        max_attempts: u32,
+        interval: Duration,
+    ) -> Option<Duration> {
+        self.attempts += 1;
+        if max_attempts > 0 && self.attempts > max_attempts {
+            return None;
+        }
+
+        if was_connected && self.slow {
+            self.slow = false;
+            self.attempts = 1;
+            info!("Connection lost, resuming fast retry");
+        }
+
+        // Circuit breaker: switch to slow mode after threshold
+        if self.attempts > FAST_RETRY_THRESHOLD {
+            if !self.slow {
+                self.slow = true;
+                warn!(
+                    interval_secs = SLOW_RETRY_INTERVAL_MS / 1000,
+                    "OBS appears to be unavailable, switching to slow retry"
+                );
+            }
+            Some(Duration::from_millis(SLOW_RETRY_INTERVAL_MS))
+        } else {
+            Some(interval)
+        }
+    }
+}
+
+/// What auto-start does after a failed connection attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AutoStart {
+    /// OBS was already launched since the last successful connection.
+    AlreadyAttempted,
+    /// OBS is running but not answering yet.
+    Running,
+    /// Launch OBS.
+    Launch,
+}
+
+impl AutoStart {
+    /// Decides whether to launch OBS. `running` is only asked when
+    /// auto-start has not been attempted yet.
+    fn decide(attempted: bool, running: impl FnOnce() -> bool) -> Self {
+        if attempted {
+            Self::AlreadyAttempted
+        } else if running() {
+            Self::Running
+        } else {
+            Self::Launch
+        }
+    }
+
+    /// Stretches a retry delay so a freshly launched OBS has time to
+    /// start.
+    fn retry_delay(launched: bool, delay: Duration) -> Duration {
+        if launched {
+            delay.max(OBS_STARTUP_WAIT)
+        } else {
+            delay
+        }
+    }
+}
+
+/// Kinds of warning the daemon raises in [`ObsState::warning`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Warning {
+    /// Free disk space is below the threshold while recording.
+    LowDisk,
+    /// Frames are being dropped across the drop-rate window.
+    Drops,
+}
+
+impl Warning {
+    /// Title for the desktop notification.
+    const fn title(self) -> &'static str {
+        match self {
+            Self::LowDisk => "OBS recording",
+            Self::Drops => "OBS output",
+        }
+    }
+
+    /// Log message when the warning clears.
+    const fn cleared(self) -> &'static str {
+        match self {
+            Self::LowDisk => "Disk space recovered",
+            Self::Drops => "Frame drops recovered",
+        }
+    }
+}
+
 /// The OBS daemon.
 ///
 /// Manages the connection to OBS and writes state updates.
 pub struct Daemon {
     config: ObsConfig,
     state_path: PathBuf,
+    socket_path: PathBuf,
+    pid_path: PathBuf,
     tracker: StateTracker,
+    history: SceneHistory,
+    /// Whether the daemon paused the current recording itself.
+    auto_paused: bool,
+    /// Whether a `Disconnect` request is holding the connection down.
+    held_disconnected: bool,
+    /// Recent frame counters for sustained drop detection.
+    drop_window: DropRateWindow,
+    /// Warnings already announced, so each is logged once.
+    active_warnings: Vec<Warning>,
+    /// Recent CPU and FPS samples for the state file.
+    stats_history: StatsHistory,
+    /// `Connect` requests waiting for the next connection attempt.
+    pending_connects: Vec<oneshot::Sender<ControlResponse>>,
+    /// The last state written, as JSON, for the WebSocket feed.
+    state_updates: watch::Sender<String>,
+    /// Whether OBS was auto-started since the last successful connection.
+    auto_start_attempted: bool,
+    /// The OBS process the daemon started, if any.
+    obs_process: Option<std::process::Child>,
 }
 
 impl Daemon {
@@ -37,11 +204,35 @@ impl Daemon {
     pub fn new(config: ObsConfig) -> Self {
         let paths = FernPaths::new();
         let state_path = paths.service_state("obs");
+        let socket_path = paths.service_socket("obs");
+        let pid_path = paths.service_pid("obs");
+        let history = SceneHistory::open(
+            paths.state_dir().join(HISTORY_FILE_NAME),
+            DEFAULT_HISTORY_CAPACITY,
+        );
+
+        let drop_window = DropRateWindow::new(config.drop_window_samples);
+        let stats_history = StatsHistory::new(config.stats_history_samples);
+        let tracker = StateTracker::new();
+        // WebSocket clients that connect before the first write get this
+        let initial_state = serde_json::to_string(&tracker.state).unwrap_or_default();
 
         Self {
             config,
             state_path,
-            tracker: StateTracker::new(),
+            socket_path,
+            pid_path,
+            tracker,
+            history,
+            auto_paused: false,
+            held_disconnected: false,
+            drop_window,
+            active_warnings: Vec::new(),
+            stats_history,
+            pending_connects: Vec::new(),
+            state_updates: watch::Sender::new(initial_state),
+            auto_start_attempted: false,
+            obs_process: None,
         }
     }
 
@@ -52,98 +243,312 @@ impl Daemon {
     ///
     /// # Errors
     ///
-    /// Returns an error if the daemon encounters an unrecoverable error.
+    /// Returns an error if the configuration is invalid (see
+    /// [`ObsConfig::validate`]), another daemon is already running
+    /// ([`Error::AlreadyRunning`]), or the daemon encounters an
+    /// unrecoverable error.
     pub async fn run(&mut self) -> Result<()> {
+        self.config.validate()?;
+
         // Ensure state directory exists
         if let Some(parent) = self.state_path.parent() {
-            std::fs::create_dir_all(parent).map_err(|e| Error::io("creating state directory", e))?;
+            std::fs::create_dir_all(parent)
+                .map_err(|e| Error::io("creating state directory", e))?;
         }
 
+        // Only one daemon per state directory; held until we return
+        let _pid_lock = PidLock::acquire(&self.pid_path)?;
+
         // Write initial disconnected state
         self.write_state()?;
 
+        let (control_tx, mut control_rx) = mpsc::channel(CONTROL_QUEUE);
+        #[cfg(feature = "http")]
+        self.serve_http(control_tx.clone()).await?;
+        #[cfg(feature = "websocket")]
+        self.serve_websocket().await?;
+        tokio::spawn(control::serve(
+            control::bind(&self.socket_path)?,
+            control_tx,
+        ));
+
         info!(
             host = %self.config.host,
             port = self.config.port,
             state_file = %self.state_path.display(),
+            control_socket = %self.socket_path.display(),
             "Starting OBS daemon"
         );
 
-        let mut reconnect_attempts = 0u32;
-        let mut in_slow_mode = false;
+        let mut backoff = Backoff::default();
 
         loop {
-            match self.run_connected().await {
-                Ok(()) => {
+            if self.held_disconnected {
+                if !self.wait_for_connect(&mut control_rx).await? {
+                    info!("Shutting down");
+                    break;
+                }
+                backoff.reset();
+                self.auto_start_attempted = false;
+            }
+
+            match self.run_connected(&mut control_rx).await {
+                Ok(SessionEnd::Shutdown) => {
                     // Clean shutdown requested
                     info!("Shutting down");
                     self.tracker.set_disconnected(None);
                     self.write_state()?;
                     break;
                 }
+                Ok(SessionEnd::Disconnected) => {}
                 Err(e) => {
                     // Check if this was a connection that was established but then lost
                     // (vs never connected at all). If we were connected, reset counter.
                     let was_connected = self.tracker.state.connected;
 
-                    reconnect_attempts += 1;
                     let max = self.config.max_reconnect_attempts;
-
-                    if max > 0 && reconnect_attempts > max {
+                    let interval = Duration::from_millis(self.config.reconnect_interval_ms);
+                    let Some(delay) = backoff.next_delay(was_connected, max, interval) else {
                         error!(max_attempts = max, "Max reconnection attempts exceeded");
                         return Err(e);
-                    }
+                    };
 
-                    warn!(error = %e, "Connection error");
+                    if was_connected {
+                        warn!(
+                            error = %e,
+                            reconnect_count = self.tracker.state.reconnect_count,
+                            "Connection to OBS lost"
+                        );
+                    } else {
+                        warn!(error = %e, "Connection error");
+                    }
                     self.tracker.set_disconnected(Some(e.to_string()));
                     self.write_state()?;
+                    self.answer_pending_connects();
 
-                    // If we were connected before, reset to fast mode
-                    if was_connected && in_slow_mode {
-                        in_slow_mode = false;
-                        reconnect_attempts = 1;
-                        info!("Connection lost, resuming fast retry");
-                    }
-
-                    // Circuit breaker: switch to slow mode after threshold
-                    let delay = if reconnect_attempts > FAST_RETRY_THRESHOLD {
-                        if !in_slow_mode {
-                            in_slow_mode = true;
-                            warn!(
-                                interval_secs = SLOW_RETRY_INTERVAL_MS / 1000,
-                                "OBS appears to be unavailable, switching to slow retry"
-                            );
-                        }
-                        Duration::from_millis(SLOW_RETRY_INTERVAL_MS)
-                    } else {
-                        Duration::from_millis(self.config.reconnect_interval_ms)
-                    };
+                    let launched = !was_connected && self.auto_start_obs();
+                    let delay = AutoStart::retry_delay(launched, delay);
 
                     info!(
                         delay_secs = delay.as_secs(),
-                        attempt = reconnect_attempts,
+                        attempt = backoff.attempts,
                         "Reconnecting"
                     );
-                    sleep(delay).await;
+                    self.wait_for_retry(delay, &mut control_rx).await?;
+                    if !self.pending_connects.is_empty() {
+                        backoff.reset();
+                        self.auto_start_attempted = false;
+                    }
+                }
+            }
+        }
+
+        let _ = std::fs::remove_file(&self.socket_path);
+        Ok(())
+    }
+
+    /// Launches OBS with [`ObsConfig::auto_start_obs`] after a failed
+    /// connection.
+    ///
+    /// Launches at most once until the next successful connection (or
+    /// `Connect` request), and never while the OBS it started earlier, or
+    /// any other `obs` process, is still running. Returns `true` if OBS
+    /// was launched.
+    fn auto_start_obs(&mut self) -> bool {
+        let Some(command) = &self.config.auto_start_obs else {
+            return false;
+        };
+        let obs_process = &mut self.obs_process;
+        let decision = AutoStart::decide(self.auto_start_attempted, || {
+            let ours_running = obs_process
+                .as_mut()
+                .is_some_and(|child| matches!(child.try_wait(), Ok(None)));
+            ours_running || obs_running()
+        });
+        self.auto_start_attempted = true;
+        match decision {
+            AutoStart::AlreadyAttempted => return false,
+            AutoStart::Running => {
+                info!("OBS is running but not answering yet, not starting another");
+                return false;
+            }
+            AutoStart::Launch => {}
+        }
+
+        let spawned = std::process::Command::new("sh")
+            .arg("-c")
+            .arg(command)
+            .stdin(std::process::Stdio::null())
+            .stdout(std::process::Stdio::null())
+            .stderr(std::process::Stdio::null())
+            .spawn();
+        match spawned {
+            Ok(child) => {
+                info!(command = %command, pid = child.id(), "Started OBS");
+                self.obs_process = Some(child);
+                true
+            }
+            Err(e) => {
+                warn!(error = %e, command = %command, "Failed to start OBS");
+                false
+            }
+        }
+    }
+
+    /// Waits out a reconnect delay, cut short by a control request that
+    /// asks to connect now or to stay disconnected.
+    async fn wait_for_retry(
+        &mut self,
+        delay: Duration,
+        control: &mut mpsc::Receiver<ControlMessage>,
+    ) -> Result<()> {
+        let retry = sleep(delay);
+        tokio::pin!(retry);
+
+        loop {
+            tokio::select! {
+                () = &mut retry => return Ok(()),
+                Some(message) = control.recv() => {
+                    self.handle_control(message)?;
+                    if self.held_disconnected || !self.pending_connects.is_empty() {
+                        return Ok(());
+                    }
                 }
             }
         }
+    }
+
+    /// Stays disconnected until a `Connect` request arrives.
+    ///
+    /// Returns `false` if shutdown was requested instead.
+    async fn wait_for_connect(
+        &mut self,
+        control: &mut mpsc::Receiver<ControlMessage>,
+    ) -> Result<bool> {
+        info!("Disconnected by request, waiting for connect");
+
+        while self.held_disconnected {
+            tokio::select! {
+                Some(message) = control.recv() => self.handle_control(message)?,
+                _ = tokio::signal::ctrl_c() => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+
+    /// Starts the HTTP API when [`ObsConfig::http_port`] is set.
+    #[cfg(feature = "http")]
+    async fn serve_http(&self, control_tx: mpsc::Sender<ControlMessage>) -> Result<()> {
+        let Some(port) = self.config.http_port else {
+            return Ok(());
+        };
+        let addr = std::net::SocketAddr::new(self.config.http_bind, port);
+        let listener = crate::http::bind(addr).await?;
+        info!(address = %addr, "Serving HTTP API");
+
+        let token = self.config.http_token.clone();
+        tokio::spawn(crate::http::serve(listener, token, control_tx));
+        Ok(())
+    }
+
+    /// Starts the WebSocket state feed when [`ObsConfig::ws_port`] is set.
+    #[cfg(feature = "websocket")]
+    async fn serve_websocket(&self) -> Result<()> {
+        let Some(port) = self.config.ws_port else {
+            return Ok(());
+        };
+        let addr = std::net::SocketAddr::new(self.config.ws_bind, port);
+        let listener = crate::ws::bind(addr).await?;
+        info!(address = %addr, "Serving WebSocket state feed");
 
+        tokio::spawn(crate::ws::serve(listener, self.state_updates.subscribe()));
         Ok(())
     }
 
+    /// Applies a control request.
+    ///
+    /// `Disconnect` and `Health` are answered at once. `Connect` is answered
+    /// at once when already connected, otherwise after the next connection
+    /// attempt. `Run` and `Execute` need the OBS client, so
+    /// [`run_connected`](Self::run_connected) handles them; here they are
+    /// only rejected.
+    fn handle_control(&mut self, message: ControlMessage) -> Result<()> {
+        match message.request {
+            ControlRequest::Disconnect => {
+                info!("Disconnect requested");
+                self.held_disconnected = true;
+                if self.tracker.state.connected {
+                    self.tracker.set_disconnected(None);
+                    self.write_state()?;
+                }
+                let _ = message.reply.send(self.state_response());
+            }
+            ControlRequest::Health => {
+                let _ = message.reply.send(self.state_response());
+            }
+            ControlRequest::Connect => {
+                info!("Connect requested");
+                self.held_disconnected = false;
+                if self.tracker.state.connected {
+                    let _ = message.reply.send(self.state_response());
+                } else {
+                    self.pending_connects.push(message.reply);
+                }
+            }
+            ControlRequest::Run { .. } | ControlRequest::Execute { .. } => {
+                let _ = message
+                    .reply
+                    .send(ControlResponse::Error(Error::NotConnected.to_string()));
+            }
+        }
+        Ok(())
+    }
+
+    /// Sends the current state to every waiting `Connect` request.
+    fn answer_pending_connects(&mut self) {
+        for reply in self.pending_connects.drain(..) {
+            let _ = reply.send(ControlResponse::State(self.tracker.state.clone()));
+        }
+    }
+
+    /// Returns the current state as a control response.
+    fn state_response(&self) -> ControlResponse {
+        ControlResponse::State(self.tracker.state.clone())
+    }
+
     /// Runs while connected to OBS.
     ///
-    /// Returns when the connection is lost or shutdown is requested.
-    async fn run_connected(&mut self) -> Result<()> {
+    /// Returns when the connection is lost, a control request drops it,
+    /// or shutdown is requested.
+    async fn run_connected(
+        &mut self,
+        control: &mut mpsc::Receiver<ControlMessage>,
+    ) -> Result<SessionEnd> {
         // Connect to OBS
         let client = ObsClient::connect(self.config.clone()).await?;
+        // A restarting OBS takes connections before it can answer them;
+        // treat that as a failed attempt so the bar never shows a broken
+        // connection as connected
+        client.check_ready().await?;
+        self.tracker.set_connected();
+        self.auto_start_attempted = false;
 
-        info!("Connected to OBS");
+        info!(
+            reconnect_count = self.tracker.state.reconnect_count,
+            "Connected to OBS"
+        );
+        self.drop_window.clear();
+        self.stats_history.clear();
 
         // Initial state sync
+        let was_recording = self.tracker.state.recording.active;
         client.sync_state(&mut self.tracker).await?;
+        self.update_history(was_recording);
+        self.apply_auto_pause(&client).await;
+        self.update_warnings();
+        self.update_stats_history();
         self.write_state()?;
+        self.answer_pending_connects();
 
         // Set up update interval
         let update_interval = Duration::from_millis(self.config.stats_interval_ms);
@@ -158,23 +563,162 @@ impl Daemon {
                     self.tracker.update_elapsed();
 
                     // Sync state from OBS (this also updates stats)
+                    let was_recording = self.tracker.state.recording.active;
                     if let Err(e) = client.sync_state(&mut self.tracker).await {
                         // Connection lost
                         return Err(e);
                     }
+                    self.update_history(was_recording);
+                    self.apply_auto_pause(&client).await;
+                    self.update_warnings();
+                    self.update_stats_history();
 
                     self.write_state()?;
                 }
 
+                // Handle control socket requests
+                Some(ControlMessage { request, reply }) = control.recv() => match request {
+                    ControlRequest::Run { alias, steps } => {
+                        let _ = reply.send(run_alias(&client, &alias, &steps).await);
+                    }
+                    ControlRequest::Execute { request } => {
+                        let response = execute_request(&client, &self.config, request).await;
+                        let _ = reply.send(response);
+                    }
+                    request => {
+                        self.handle_control(ControlMessage { request, reply })?;
+                        if self.held_disconnected {
+                            return Ok(SessionEnd::Disconnected);
+                        }
+                    }
+                },
+
                 // Handle shutdown signal
                 _ = tokio::signal::ctrl_c() => {
                     info!("Received shutdown signal");
-                    return Ok(());
+                    return Ok(SessionEnd::Shutdown);
+                }
+            }
+        }
+    }
+
+    /// Records scene changes in the scene history.
+    ///
+    /// A recording that started since the last sync begins a new session,
+    /// so the history is cleared first. History write failures are logged
+    /// and otherwise ignored.
+    fn update_history(&mut self, was_recording: bool) {
+        let now = unix_now();
+
+        if self.tracker.state.recording.active && !was_recording {
+            info!("Recording started, starting new scene history");
+            if let Err(e) = self.history.clear() {
+                warn!(error = %e, "Failed to clear scene history");
+            }
+        }
+
+        let Some(scene) = self.tracker.state.current_scene.as_deref() else {
+            return;
+        };
+        if self.history.current_scene() == Some(scene) {
+            return;
+        }
+
+        info!(scene, "Scene changed");
+        if let Err(e) = self.history.enter(scene, now) {
+            warn!(error = %e, "Failed to write scene history");
+        }
+    }
+
+    /// Pauses or resumes recording for [`ObsConfig::auto_pause_scenes`].
+    ///
+    /// Runs after every sync, so a scene change is picked up within one
+    /// stats interval. Only recordings the daemon paused itself are
+    /// resumed; a manual pause is left alone. Failures are logged.
+    async fn apply_auto_pause(&mut self, client: &ObsClient) {
+        let scene = self.tracker.state.current_scene.clone().unwrap_or_default();
+        let in_pause_scene = self.config.is_auto_pause_scene(&scene);
+
+        match auto_pause_action(
+            in_pause_scene,
+            &self.tracker.state.recording,
+            self.auto_paused,
+        ) {
+            Some(AutoPauseAction::Pause) => match client.pause_recording().await {
+                Ok(()) => {
+                    info!(scene = %scene, "Auto-paused recording");
+                    self.tracker.pause_recording();
+                    self.auto_paused = true;
+                }
+                Err(e) => warn!(scene = %scene, error = %e, "Failed to auto-pause recording"),
+            },
+            Some(AutoPauseAction::Resume) => match client.resume_recording().await {
+                Ok(()) => {
+                    info!(scene = %scene, "Auto-resumed recording");
+                    self.tracker.resume_recording();
+                    self.auto_paused = false;
+                }
+                Err(e) => warn!(scene = %scene, error = %e, "Failed to auto-resume recording"),
+            },
+            None => {
+                if !in_pause_scene || !self.tracker.state.recording.active {
+                    self.auto_paused = false;
                 }
             }
         }
     }
 
+    /// Sets or clears the state warning from the latest stats.
+    ///
+    /// Low disk space and sustained frame drops are checked separately and
+    /// joined into one message. Each is logged when it first appears and,
+    /// if [`ObsConfig::notify_warnings`] is set, sent as a desktop
+    /// notification.
+    fn update_warnings(&mut self) {
+        let state = &self.tracker.state;
+        if let Some(stats) = &state.stats {
+            self.drop_window.push(stats);
+        }
+
+        let low_disk = state.low_disk_warning(self.config.low_disk_threshold_mb);
+        let drops = (state.recording.active || state.streaming.active)
+            .then(|| self.drop_window.warning(self.config.drop_warning_percent))
+            .flatten();
+
+        self.announce(Warning::LowDisk, low_disk.as_deref());
+        self.announce(Warning::Drops, drops.as_deref());
+
+        let messages: Vec<String> = [low_disk, drops].into_iter().flatten().collect();
+        self.tracker.state.warning = (!messages.is_empty()).then(|| messages.join("; "));
+    }
+
+    /// Records the latest stats and refreshes the history in the state.
+    fn update_stats_history(&mut self) {
+        if let Some(stats) = &self.tracker.state.stats {
+            self.stats_history.push(stats);
+        }
+        self.tracker.state.stats_history = self.stats_history.points(STATS_HISTORY_POINTS);
+    }
+
+    /// Logs (and optionally notifies) when a warning appears or clears.
+    fn announce(&mut self, kind: Warning, message: Option<&str>) {
+        let was_active = self.active_warnings.contains(&kind);
+        match (was_active, message) {
+            (false, Some(message)) => {
+                warn!("{message}");
+                if self.config.notify_warnings {
+                    notify(kind.title(), message);
+                }
+                self.active_warnings.push(kind);
+            }
+            (true, None) => {
+                info!("{}", kind.cleared());
+                self.active_warnings.retain(|active| *active != kind);
+            }
+            _ => {}
+        }
+    }
+
     /// Writes the current state to the state file.
     ///
     /// Uses atomic write (write to temp, then rename) to prevent partial reads.
@@ -205,16 +749,159 @@ impl Daemon {
         std::fs::rename(&temp_path, &self.state_path)
             .map_err(|e| Error::io("renaming state file", e))?;
 
+        // Pass real changes on to WebSocket clients
+        self.state_updates.send_if_modified(|last| {
+            let changed = *last != json;
+            if changed {
+                *last = json;
+            }
+            changed
+        });
+
         Ok(())
     }
 }
 
+/// What the daemon should do to the recording for an auto-pause scene.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum AutoPauseAction {
+    Pause,
+    Resume,
+}
+
+/// Decides whether to pause or resume recording.
+///
+/// `auto_paused` is whether the daemon made the current pause itself.
+fn auto_pause_action(
+    in_pause_scene: bool,
+    recording: &RecordingState,
+    auto_paused: bool,
+) -> Option<AutoPauseAction> {
+    if !recording.active {
+        return None;
+    }
+
+    if in_pause_scene {
+        (!recording.paused && !auto_paused).then_some(AutoPauseAction::Pause)
+    } else {
+        (recording.paused && auto_paused).then_some(AutoPauseAction::Resume)
+    }
+}
+
+/// Sends a critical desktop notification, ignoring failures.
+fn notify(title: &str, body: &str) {
+    let _ = std::process::Command::new("notify-send")
+        .arg("--app-name=fern-obs")
+        .arg("--urgency=critical")
+        .arg(title)
+        .arg(body)
+        .spawn();
+}
+
+/// Returns `true` if an `obs` process is running, as far as `pgrep` can
+/// tell.
+fn obs_running() -> bool {
+    std::process::Command::new("pgrep")
+        .args(["-x", "obs"])
+        .stdout(std::process::Stdio::null())
+        .status()
+        .is_ok_and(|status| status.success())
+}
+
+/// Reads the daemon's last written state, if there is one.
+#[must_use]
+pub fn read_daemon_state() -> Option<ObsState> {
+    let content = std::fs::read_to_string(FernPaths::new().service_state("obs")).ok()?;
+    serde_json::from_str(&content).ok()
+}
+
 /// Sends a command to OBS via a one-shot connection.
 ///
 /// This is used by CLI commands that don't need to maintain a connection.
+/// [`Command::Connect`], [`Command::Disconnect`], [`Command::Health`],
+/// [`Command::RunAlias`], and [`Command::Raw`] go to the running daemon's
+/// control socket instead.
+///
+/// # Errors
+///
+/// Returns [`Error::CommandTimeout`] if connecting and getting a response
+/// takes longer than [`ObsConfig::command_timeout`].
 pub async fn send_command(config: &ObsConfig, command: Command) -> Result<CommandResult> {
+    tokio::time::timeout(config.command_timeout(), run_command(config, command))
+        .await
+        .map_err(|_| Error::CommandTimeout {
+            timeout_ms: config.command_timeout_ms,
+        })?
+}
+
+/// Runs a command for [`send_command`], without the timeout.
+async fn run_command(config: &ObsConfig, command: Command) -> Result<CommandResult> {
+    let socket_path = FernPaths::new().service_socket("obs");
+
+    if let Command::RunAlias { alias, steps } = command {
+        let request = ControlRequest::Run {
+            alias: alias.clone(),
+            steps,
+        };
+        let response = control::request(&socket_path, request).await?;
+        return alias_result(alias, response);
+    }
+
+    if answered_by_daemon(&command) {
+        return match control::request(&socket_path, ControlRequest::from(command)).await? {
+            ControlResponse::State(state) => Ok(CommandResult::State(state)),
+            ControlResponse::Result(result) => Ok(result),
+            ControlResponse::Steps(_) | ControlResponse::Error(_) => {
+                Err(Error::Request("unexpected response from the daemon".into()))
+            }
+        };
+    }
+
     let client = ObsClient::connect(config.clone()).await?;
+    execute(&client, command).await
+}
 
+/// Returns `true` for commands [`send_command`] hands to the daemon as a
+/// single control request.
+///
+/// Raw requests are among them, so the daemon's
+/// [`ObsConfig::allow_raw`] decides whether they run.
+const fn answered_by_daemon(command: &Command) -> bool {
+    matches!(
+        command,
+        Command::Connect | Command::Disconnect | Command::Health | Command::Raw { .. }
+    )
+}
+
+/// Fails with [`Error::Config`] for a raw request unless
+/// [`ObsConfig::allow_raw`] is set.
+fn check_raw_allowed(config: &ObsConfig, command: &Command) -> Result<()> {
+    if matches!(command, Command::Raw { .. }) && !config.allow_raw {
+        return Err(Error::Config(
+            "raw requests are disabled (pass --allow-raw to enable)".into(),
+        ));
+    }
+    Ok(())
+}
+
+/// Runs a command for a [`ControlRequest::Execute`] over the daemon's
+/// connection.
+async fn execute_request(
+    client: &ObsClient,
+    config: &ObsConfig,
+    command: Command,
+) -> ControlResponse {
+    if let Err(e) = check_raw_allowed(config, &command) {
+        return ControlResponse::Error(e.to_string());
+    }
+    match execute(client, command).await {
+        Ok(result) => ControlResponse::Result(result),
+        Err(e) => ControlResponse::Error(e.to_string()),
+    }
+}
+
+/// Runs one command over an OBS connection.
+async fn execute(client: &ObsClient, command: Command) -> Result<CommandResult> {
     match command {
         Command::StartRecording => {
             client.start_recording().await?;
@@ -222,7 +909,9 @@ pub async fn send_command(config: &ObsConfig, command: Command) -> Result<Comman
         }
         Command::StopRecording => {
             let path = client.stop_recording().await?;
-            Ok(CommandResult::Success(format!("Recording saved to: {path}")))
+            Ok(CommandResult::Success(format!(
+                "Recording saved to: {path}"
+            )))
         }
         Command::TogglePause => {
             let paused = client.toggle_recording_pause().await?;
@@ -233,6 +922,14 @@ pub async fn send_command(config: &ObsConfig, command: Command) -> Result<Comman
             };
             Ok(CommandResult::Success(msg.into()))
         }
+        Command::ToggleRecording => {
+            let active = client.recording_status().await?.active;
+            Box::pin(execute(
+                client,
+                Command::ToggleRecording.resolve_toggle(active),
+            ))
+            .await
+        }
         Command::StartStreaming => {
             client.start_streaming().await?;
             Ok(CommandResult::Success("Streaming started".into()))
@@ -241,20 +938,171 @@ pub async fn send_command(config: &ObsConfig, command: Command) -> Result<Comman
             client.stop_streaming().await?;
             Ok(CommandResult::Success("Streaming stopped".into()))
         }
-        Command::SetScene(name) => {
+        Command::ToggleStreaming => {
+            let active = client.streaming_status().await?.active;
+            Box::pin(execute(
+                client,
+                Command::ToggleStreaming.resolve_toggle(active),
+            ))
+            .await
+        }
+        Command::SetScene {
+            name,
+            transition,
+            duration_ms,
+        } => {
+            // OBS has no per-switch override, so change the current
+            // transition first. It stays in effect for later switches.
+            if let Some(transition) = &transition {
+                client.set_transition(transition).await?;
+            }
+            if let Some(duration_ms) = duration_ms {
+                client.set_transition_duration(duration_ms).await?;
+            }
+            client.set_scene(&name).await?;
+            Ok(CommandResult::Success(format!("Scene set to: {name}")))
+        }
+        Command::NextScene | Command::PrevScene => {
+            let mut tracker = StateTracker::new();
+            client.sync_state(&mut tracker).await?;
+
+            let target = if matches!(command, Command::NextScene) {
+                tracker.state.next_scene()
+            } else {
+                tracker.state.prev_scene()
+            };
+            let name = target
+                .ok_or_else(|| Error::Request("OBS has no scenes".into()))?
+                .to_string();
+
             client.set_scene(&name).await?;
             Ok(CommandResult::Success(format!("Scene set to: {name}")))
         }
+        Command::TriggerHotkey(name) => {
+            client.trigger_hotkey(&name).await?;
+            Ok(CommandResult::Success(format!("Triggered hotkey: {name}")))
+        }
+        Command::TriggerHotkeySequence { key, modifiers } => {
+            client.trigger_hotkey_sequence(&key, &modifiers).await?;
+            Ok(CommandResult::Success(format!(
+                "Triggered key sequence: {key}"
+            )))
+        }
+        Command::ListTransitions => {
+            let transitions = client.list_transitions().await?;
+            Ok(CommandResult::List(transitions))
+        }
+        Command::ListHotkeys => {
+            let hotkeys = client.list_hotkeys().await?;
+            Ok(CommandResult::List(hotkeys))
+        }
+        Command::ListSourceFilters(source) => {
+            let filters = client.list_source_filters(&source).await?;
+            Ok(CommandResult::Filters(filters))
+        }
+        Command::SetSourceFilterEnabled {
+            source,
+            filter,
+            enabled,
+        } => {
+            client
+                .set_source_filter_enabled(&source, &filter, enabled)
+                .await?;
+            let state = if enabled { "enabled" } else { "disabled" };
+            Ok(CommandResult::Success(format!(
+                "Filter {filter} on {source} {state}"
+            )))
+        }
+        Command::MediaAction { source, action } => {
+            client.trigger_media_action(&source, action).await?;
+            Ok(CommandResult::Success(format!(
+                "Media source {source}: {}",
+                action.name()
+            )))
+        }
+        Command::Raw {
+            request_type,
+            request_data,
+        } => {
+            let response = client.raw_request(&request_type, &request_data).await?;
+            Ok(CommandResult::Raw(response))
+        }
         Command::GetStatus => {
             let mut tracker = StateTracker::new();
             client.sync_state(&mut tracker).await?;
+
+            // Connection history is the daemon's, not this one-shot client's
+            let daemon = read_daemon_state();
+            let state = &mut tracker.state;
+            state.reconnect_count = daemon.as_ref().map_or(0, |d| d.reconnect_count);
+            state.last_connected_secs = daemon.as_ref().and_then(|d| d.last_connected_secs);
+            state.last_disconnected_secs = daemon.and_then(|d| d.last_disconnected_secs);
+
             Ok(CommandResult::State(tracker.state))
         }
+        // Sent to the daemon by `run_command`, before connecting to OBS
+        Command::Connect | Command::Disconnect | Command::Health | Command::RunAlias { .. } => {
+            Err(Error::Request(
+                "connect, disconnect, health, and aliases are handled by the daemon".into(),
+            ))
+        }
+    }
+}
+
+/// Runs the steps of an alias in order, stopping at the first failure.
+///
+/// Nothing runs if any step does not parse.
+async fn run_alias(client: &ObsClient, alias: &str, steps: &[String]) -> ControlResponse {
+    let commands = match alias::parse_steps(steps) {
+        Ok(commands) => commands,
+        Err(e) => return ControlResponse::Error(e.to_string()),
+    };
+
+    info!(alias, steps = steps.len(), "Running alias");
+    let mut results = Vec::with_capacity(steps.len());
+    for (step, command) in steps.iter().zip(commands) {
+        let (ok, message) = match execute(client, command).await {
+            Ok(result) => (true, result.into_message()),
+            Err(e) => (false, e.to_string()),
+        };
+        results.push(StepResult {
+            step: step.clone(),
+            ok,
+            message,
+        });
+        if !ok {
+            warn!(alias, step = %step, "Alias step failed");
+            break;
+        }
+    }
+    ControlResponse::Steps(results)
+}
+
+/// Turns the daemon's answer to an alias run into a command result.
+///
+/// Fails with [`Error::AliasFailed`] if the last step that ran failed.
+fn alias_result(alias: String, response: ControlResponse) -> Result<CommandResult> {
+    let ControlResponse::Steps(steps) = response else {
+        return Err(Error::Request("unexpected response from the daemon".into()));
+    };
+
+    match steps.last() {
+        Some(last) if !last.ok => Err(Error::AliasFailed {
+            alias,
+            step: last.step.clone(),
+            completed: steps.len() - 1,
+            message: last.message.clone(),
+        }),
+        _ => Ok(CommandResult::Steps(steps)),
     }
 }
 
 /// Commands that can be sent to OBS.
-#[derive(Debug, Clone)]
+///
+/// Serialized externally tagged in `snake_case`, as the HTTP API takes
+/// them: `"start_recording"`, `{"set_scene":{"name":"Gaming"}}`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Command {
     /// Start recording.
     StartRecording,
@@ -262,21 +1110,352 @@ pub enum Command {
     StopRecording,
     /// Toggle recording pause.
     TogglePause,
+    /// Start recording if idle, stop it if active.
+    ToggleRecording,
     /// Start streaming.
     StartStreaming,
     /// Stop streaming.
     StopStreaming,
+    /// Start streaming if idle, stop it if active.
+    ToggleStreaming,
     /// Set the current scene.
-    SetScene(String),
+    SetScene {
+        /// Scene to switch to.
+        name: String,
+        /// Transition to make current before switching (e.g., `Fade`).
+        transition: Option<String>,
+        /// Transition duration to set before switching, in milliseconds.
+        duration_ms: Option<u32>,
+    },
+    /// Switch to the next scene in the scene list, wrapping around.
+    NextScene,
+    /// Switch to the previous scene in the scene list, wrapping around.
+    PrevScene,
+    /// List the names of all scene transitions.
+    ListTransitions,
+    /// Trigger an OBS hotkey by name.
+    TriggerHotkey(String),
+    /// Trigger an OBS hotkey by key sequence.
+    TriggerHotkeySequence {
+        /// OBS key identifier (e.g., `OBS_KEY_F9`).
+        key: String,
+        /// Modifier names (`shift`, `ctrl`, `alt`, `cmd`).
+        #[serde(default)]
+        modifiers: Vec<String>,
+    },
+    /// List the names of all available hotkeys.
+    ListHotkeys,
+    /// List the filters on a source with their enabled states.
+    ListSourceFilters(String),
+    /// Enable or disable a filter on a source.
+    SetSourceFilterEnabled {
+        /// Source the filter is on.
+        source: String,
+        /// Filter name.
+        filter: String,
+        /// Whether to enable the filter.
+        enabled: bool,
+    },
+    /// Play, pause, restart, stop, or skip within a media source.
+    MediaAction {
+        /// Media source name.
+        source: String,
+        /// What to do.
+        action: MediaAction,
+    },
+    /// Forward an untyped obs-websocket request.
+    ///
+    /// Only honoured when the daemon runs with [`ObsConfig::allow_raw`]
+    /// set. Raw requests do not update [`ObsState`].
+    Raw {
+        /// obs-websocket request type (e.g., `GetInputList`).
+        request_type: String,
+        /// JSON payload sent as the request data.
+        #[serde(default)]
+        request_data: serde_json::Value,
+    },
     /// Get the current status.
     GetStatus,
+    /// Ask the running daemon to connect to OBS now.
+    Connect,
+    /// Ask the running daemon to drop its OBS connection and stop
+    /// reconnecting until [`Command::Connect`].
+    Disconnect,
+    /// Ask the running daemon for its state and connection history.
+    Health,
+    /// Ask the running daemon to run the steps of a command alias.
+    RunAlias {
+        /// Alias name.
+        alias: String,
+        /// The alias steps (see [`alias::parse_step`]).
+        steps: Vec<String>,
+    },
+}
+
+impl Command {
+    /// Returns what a toggle command does when its output is `active`:
+    /// stop if it is, start if not. Other commands are returned as is.
+    #[must_use]
+    pub fn resolve_toggle(self, active: bool) -> Self {
+        match self {
+            Self::ToggleRecording if active => Self::StopRecording,
+            Self::ToggleRecording => Self::StartRecording,
+            Self::ToggleStreaming if active => Self::StopStreaming,
+            Self::ToggleStreaming => Self::StartStreaming,
+            other => other,
+        }
+    }
+}
+
+impl From<Command> for ControlRequest {
+    /// Maps daemon commands to their own requests and runs everything
+    /// else through [`ControlRequest::Execute`].
+    fn from(command: Command) -> Self {
+        match command {
+            Command::Connect => Self::Connect,
+            Command::Disconnect => Self::Disconnect,
+            Command::Health => Self::Health,
+            Command::RunAlias { alias, steps } => Self::Run { alias, steps },
+            request => Self::Execute { request },
+        }
+    }
 }
 
 /// Result of a command execution.
-#[derive(Debug)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
 pub enum CommandResult {
     /// Command succeeded with a message.
     Success(String),
     /// Command returned state information.
     State(ObsState),
+    /// Command returned a list of names.
+    List(Vec<String>),
+    /// Command returned the filters on a source.
+    Filters(Vec<SourceFilter>),
+    /// Command returned a raw JSON response.
+    Raw(serde_json::Value),
+    /// An alias ran; one result per step.
+    Steps(Vec<StepResult>),
+}
+
+impl CommandResult {
+    /// Returns a one-line description of the result.
+    #[must_use]
+    pub fn into_message(self) -> String {
+        match self {
+            Self::Success(message) => message,
+            Self::State(state) => format!("Connected: {}", state.connected),
+            Self::List(items) => items.join(", "),
+            Self::Filters(filters) => filters
+                .iter()
+                .map(|f| format!("{}: {}", f.name, if f.enabled { "on" } else { "off" }))
+                .collect::<Vec<_>>()
+                .join(", "),
+            Self::Raw(value) => value.to_string(),
+            Self::Steps(steps) => steps
+                .into_iter()
+                .map(|step| step.message)
+                .collect::<Vec<_>>()
+                .join("; "),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn step(step: &str, ok: bool) -> StepResult {
+        StepResult {
+            step: step.into(),
+            ok,
+            message: if ok { "done" } else { "scene not found" }.into(),
+        }
+    }
+
+    #[test]
+    fn alias_result_fails_on_failed_step() {
+        let response = ControlResponse::Steps(vec![step("rec", true), step("scene Oops", false)]);
+        let error = alias_result("golive".into(), response).unwrap_err();
+        assert!(matches!(
+            error,
+            Error::AliasFailed { ref step, completed: 1, .. } if step == "scene Oops"
+        ));
+
+        let response = ControlResponse::Steps(vec![step("rec", true)]);
+        assert!(matches!(
+            alias_result("golive".into(), response).unwrap(),
+            CommandResult::Steps(steps) if steps.len() == 1
+        ));
+    }
+
+    #[test]
+    fn state_feed_starts_with_initial_state() {
+        let daemon = Daemon::new(ObsConfig::default());
+        let json = daemon.state_updates.borrow().clone();
+        let state: ObsState = serde_json::from_str(&json).unwrap();
+        assert!(!state.connected);
+    }
+
+    #[test]
+    fn toggles_stop_active_outputs_and_start_idle_ones() {
+        assert_eq!(
+            Command::ToggleRecording.resolve_toggle(true),
+            Command::StopRecording
+        );
+        assert_eq!(
+            Command::ToggleRecording.resolve_toggle(false),
+            Command::StartRecording
+        );
+        assert_eq!(
+            Command::ToggleStreaming.resolve_toggle(true),
+            Command::StopStreaming
+        );
+        assert_eq!(
+            Command::ToggleStreaming.resolve_toggle(false),
+            Command::StartStreaming
+        );
+        assert_eq!(
+            Command::TogglePause.resolve_toggle(true),
+            Command::TogglePause
+        );
+    }
+
+    #[test]
+    fn raw_requests_need_allow_raw() {
+        let raw = Command::Raw {
+            request_type: "GetVersion".into(),
+            request_data: serde_json::Value::Null,
+        };
+        let mut config = ObsConfig::default();
+        assert!(matches!(
+            check_raw_allowed(&config, &raw),
+            Err(Error::Config(_))
+        ));
+        // Typed commands are never gated
+        assert!(check_raw_allowed(&config, &Command::GetStatus).is_ok());
+
+        config.allow_raw = true;
+        assert!(check_raw_allowed(&config, &raw).is_ok());
+    }
+
+    #[test]
+    fn raw_requests_go_through_the_daemon() {
+        let raw = Command::Raw {
+            request_type: "GetVersion".into(),
+            request_data: serde_json::Value::Null,
+        };
+        assert!(answered_by_daemon(&raw));
+        assert!(matches!(
+            ControlRequest::from(raw),
+            ControlRequest::Execute { .. }
+        ));
+        assert!(!answered_by_daemon(&Command::GetStatus));
+    }
+
+    #[test]
+    fn filters_result_message_lists_states() {
+        let filter = |name: &str, enabled| SourceFilter {
+            name: name.into(),
+            kind: "color_filter_v2".into(),
+            enabled,
+        };
+        let result = CommandResult::Filters(vec![filter("Grade", true), filter("Blur", false)]);
+        assert_eq!(result.into_message(), "Grade: on, Blur: off");
+    }
+
+    #[test]
+    fn auto_pause_on_entering_pause_scene() {
+        let recording = RecordingState::active(10);
+        assert_eq!(
+            auto_pause_action(true, &recording, false),
+            Some(AutoPauseAction::Pause)
+        );
+        assert_eq!(auto_pause_action(false, &recording, false), None);
+        assert_eq!(
+            auto_pause_action(true, &RecordingState::idle(), false),
+            None
+        );
+    }
+
+    #[test]
+    fn auto_resume_only_after_auto_pause() {
+        let paused = RecordingState::paused(10);
+        assert_eq!(
+            auto_pause_action(false, &paused, true),
+            Some(AutoPauseAction::Resume)
+        );
+        // A manual pause is left alone
+        assert_eq!(auto_pause_action(false, &paused, false), None);
+        // Still in the pause scene
+        assert_eq!(auto_pause_action(true, &paused, true), None);
+    }
+
+    #[test]
+    fn manual_resume_in_pause_scene_is_respected() {
+        let recording = RecordingState::active(10);
+        assert_eq!(auto_pause_action(true, &recording, true), None);
+    }
+
+    const INTERVAL: Duration = Duration::from_secs(5);
+    const SLOW: Duration = Duration::from_millis(SLOW_RETRY_INTERVAL_MS);
+
+    #[test]
+    fn unready_obs_backs_off_like_a_failed_connection() {
+        // A restarting OBS that never gets ready is never connected, so
+        // its failures count toward slow mode like refused connections
+        let mut backoff = Backoff::default();
+        for _ in 0..FAST_RETRY_THRESHOLD {
+            assert_eq!(backoff.next_delay(false, 0, INTERVAL), Some(INTERVAL));
+        }
+        assert_eq!(backoff.next_delay(false, 0, INTERVAL), Some(SLOW));
+        assert!(backoff.slow);
+    }
+
+    #[test]
+    fn lost_connection_resumes_fast_retry() {
+        let mut backoff = Backoff {
+            attempts: FAST_RETRY_THRESHOLD + 5,
+            slow: true,
+        };
+        assert_eq!(backoff.next_delay(true, 0, INTERVAL), Some(INTERVAL));
+        assert_eq!(backoff.attempts, 1);
+        assert!(!backoff.slow);
+    }
+
+    #[test]
+    fn backoff_gives_up_after_max_attempts() {
+        let mut backoff = Backoff::default();
+        assert_eq!(backoff.next_delay(false, 2, INTERVAL), Some(INTERVAL));
+        assert_eq!(backoff.next_delay(false, 2, INTERVAL), Some(INTERVAL));
+        assert_eq!(backoff.next_delay(false, 2, INTERVAL), None);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(false, 2, INTERVAL), Some(INTERVAL));
+    }
+
+    #[test]
+    fn auto_start_launches_once_per_cycle() {
+        assert_eq!(AutoStart::decide(false, || false), AutoStart::Launch);
+        // pgrep is not consulted once OBS was launched
+        assert_eq!(
+            AutoStart::decide(true, || unreachable!()),
+            AutoStart::AlreadyAttempted
+        );
+    }
+
+    #[test]
+    fn auto_start_leaves_running_obs_alone() {
+        assert_eq!(AutoStart::decide(false, || true), AutoStart::Running);
+    }
+
+    #[test]
+    fn launched_obs_gets_time_to_start() {
+        let short = Duration::from_secs(5);
+        assert_eq!(AutoStart::retry_delay(true, short), OBS_STARTUP_WAIT);
+        assert_eq!(AutoStart::retry_delay(false, short), short);
+        let slow = Duration::from_millis(SLOW_RETRY_INTERVAL_MS);
+        assert_eq!(AutoStart::retry_delay(true, slow), slow);
+    }
 }
@@ -0,0 +1,196 @@
+//! Sustained dropped-frame detection.
+//!
+//! OBS reports dropped frames as counters that only grow, so the
+//! percentages in [`ObsStats`] are averages since OBS started. They react
+//! slowly to new trouble and never recover from an old spike.
+//! [`DropRateWindow`] instead keeps the last few stats samples and measures
+//! drops across just that window, so one bad frame does not raise a
+//! warning but a struggling stream does within a few samples.
+
+use crate::state::ObsStats;
+use std::collections::VecDeque;
+
+/// Default number of stats samples in the window.
+pub const DEFAULT_DROP_WINDOW: usize = 10;
+
+/// Frame counters from one stats sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Sample {
+    render_missed: u64,
+    render_total: u64,
+    output_skipped: u64,
+    output_total: u64,
+}
+
+impl Sample {
+    /// Returns `true` if any counter went backwards (e.g. OBS restarted).
+    const fn went_back_from(&self, earlier: &Self) -> bool {
+        self.render_missed < earlier.render_missed
+            || self.render_total < earlier.render_total
+            || self.output_skipped < earlier.output_skipped
+            || self.output_total < earlier.output_total
+    }
+}
+
+/// Drop rates over the last few stats samples.
+#[derive(Debug, Clone)]
+pub struct DropRateWindow {
+    samples: VecDeque<Sample>,
+    capacity: usize,
+}
+
+impl DropRateWindow {
+    /// Creates a window over the last `capacity` samples (at least 2).
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(2);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Adds a stats sample, dropping the oldest beyond the window.
+    ///
+    /// Counters that go backwards mean OBS restarted, so the window starts
+    /// over.
+    pub fn push(&mut self, stats: &ObsStats) {
+        let sample = Sample {
+            render_missed: stats.render_missed_frames,
+            render_total: stats.render_total_frames,
+            output_skipped: stats.output_skipped_frames,
+            output_total: stats.output_total_frames,
+        };
+
+        if self
+            .samples
+            .back()
+            .is_some_and(|last| sample.went_back_from(last))
+        {
+            self.samples.clear();
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+    }
+
+    /// Clears the window.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the render drop percentage across the window, once full.
+    #[must_use]
+    pub fn render_drop_percent(&self) -> Option<f64> {
+        let (first, last) = self.span()?;
+        percent(
+            last.render_missed - first.render_missed,
+            last.render_total - first.render_total,
+        )
+    }
+
+    /// Returns the output drop percentage across the window, once full.
+    #[must_use]
+    pub fn output_drop_percent(&self) -> Option<f64> {
+        let (first, last) = self.span()?;
+        percent(
+            last.output_skipped - first.output_skipped,
+            last.output_total - first.output_total,
+        )
+    }
+
+    /// Returns a warning if either drop rate is above `threshold_percent`.
+    ///
+    /// A threshold of 0 never warns.
+    #[must_use]
+    pub fn warning(&self, threshold_percent: f64) -> Option<String> {
+        if threshold_percent <= 0.0 {
+            return None;
+        }
+
+        let high: Vec<String> = [
+            ("render", self.render_drop_percent()),
+            ("output", self.output_drop_percent()),
+        ]
+        .into_iter()
+        .filter_map(|(kind, rate)| {
+            rate.filter(|rate| *rate > threshold_percent)
+                .map(|rate| format!("{kind} {rate:.1}%"))
+        })
+        .collect();
+
+        (!high.is_empty()).then(|| format!("Dropping frames ({})", high.join(", ")))
+    }
+
+    /// Returns the oldest and newest samples of a full window.
+    fn span(&self) -> Option<(&Sample, &Sample)> {
+        if self.samples.len() < self.capacity {
+            return None;
+        }
+        Some((self.samples.front()?, self.samples.back()?))
+    }
+}
+
+impl Default for DropRateWindow {
+    fn default() -> Self {
+        Self::new(DEFAULT_DROP_WINDOW)
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn percent(dropped: u64, total: u64) -> Option<f64> {
+    (total > 0).then(|| dropped as f64 / total as f64 * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stats(output_skipped: u64, output_total: u64) -> ObsStats {
+        ObsStats {
+            output_skipped_frames: output_skipped,
+            output_total_frames: output_total,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn single_spike_is_averaged_out() {
+        let mut window = DropRateWindow::new(5);
+        // 60 frames per sample; one sample drops 10 of them
+        for (skipped, total) in [(0, 60), (0, 120), (10, 180), (10, 240), (10, 300)] {
+            window.push(&stats(skipped, total));
+        }
+        // 10 of 240 frames across the window
+        assert!(window.output_drop_percent().unwrap() < 5.0);
+        assert_eq!(window.warning(5.0), None);
+    }
+
+    #[test]
+    fn sustained_drops_warn() {
+        let mut window = DropRateWindow::new(3);
+        window.push(&stats(0, 60));
+        window.push(&stats(6, 120));
+        assert_eq!(window.warning(5.0), None, "window not full yet");
+
+        window.push(&stats(12, 180));
+        assert_eq!(
+            window.warning(5.0).as_deref(),
+            Some("Dropping frames (output 10.0%)")
+        );
+        assert_eq!(window.warning(0.0), None);
+    }
+
+    #[test]
+    fn counter_reset_restarts_window() {
+        let mut window = DropRateWindow::new(2);
+        window.push(&stats(100, 1000));
+        window.push(&stats(200, 1100));
+        assert!(window.warning(5.0).is_some());
+
+        // OBS restarted
+        window.push(&stats(0, 60));
+        assert_eq!(window.output_drop_percent(), None);
+    }
+}
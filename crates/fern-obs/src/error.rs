@@ -31,6 +31,13 @@ pub enum Error {
     #[error("authentication failed: {0}")]
     Auth(String),
 
+    /// A source or filter named in a command does not exist in OBS.
+    #[error("{what} not found")]
+    NotFound {
+        /// What was looked up (e.g., `filter 'Blur' on source 'Camera'`).
+        what: String,
+    },
+
     /// OBS is not connected.
     #[error("not connected to OBS")]
     NotConnected,
@@ -52,6 +59,33 @@ pub enum Error {
     /// Configuration error.
     #[error("configuration error: {0}")]
     Config(String),
+
+    /// A command got no response in time.
+    #[error("command timed out after {timeout_ms}ms (is OBS responding?)")]
+    CommandTimeout {
+        /// The timeout that expired, in milliseconds.
+        timeout_ms: u64,
+    },
+
+    /// A step of a command alias failed; later steps did not run.
+    #[error("alias '{alias}' stopped at `{step}` after {completed} step(s): {message}")]
+    AliasFailed {
+        /// The alias being run.
+        alias: String,
+        /// The step that failed, as written in the alias.
+        step: String,
+        /// Number of steps that succeeded before it.
+        completed: usize,
+        /// Why the step failed.
+        message: String,
+    },
+
+    /// Another daemon holds the PID file lock.
+    #[error("daemon already running (pid {pid})")]
+    AlreadyRunning {
+        /// PID read from the lock file (0 if unreadable).
+        pid: u32,
+    },
 }
 
 impl Error {
@@ -72,6 +106,25 @@ impl Error {
         }
     }
 
+    /// Returns a stable, machine-readable code for this kind of error.
+    #[must_use]
+    pub const fn code(&self) -> &'static str {
+        match self {
+            Self::Connection { .. } => "connection",
+            Self::WebSocket(_) => "websocket",
+            Self::Request(_) => "request",
+            Self::Auth(_) => "auth",
+            Self::NotFound { .. } => "not_found",
+            Self::NotConnected => "not_connected",
+            Self::Io { .. } => "io",
+            Self::Json(_) => "json",
+            Self::Config(_) => "config",
+            Self::CommandTimeout { .. } => "command_timeout",
+            Self::AliasFailed { .. } => "alias_failed",
+            Self::AlreadyRunning { .. } => "already_running",
+        }
+    }
+
     /// Returns true if this error indicates OBS is not running.
     #[must_use]
     pub fn is_connection_refused(&self) -> bool {
@@ -0,0 +1,226 @@
+//! Persistent scene-change history.
+//!
+//! The daemon appends an entry to `~/.local/state/fern/obs-scene-history.json`
+//! each time the program scene changes, so `fern-obs history` can summarize
+//! which scenes were used and for how long.
+//!
+//! The file is a bounded JSON array (oldest entries are dropped first) and
+//! is cleared when a recording starts, so each recording session's history
+//! stands on its own.
+
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+
+/// Default number of entries kept in the history file.
+pub const DEFAULT_HISTORY_CAPACITY: usize = 1000;
+
+/// File name of the history file inside the state directory.
+pub const HISTORY_FILE_NAME: &str = "obs-scene-history.json";
+
+/// One visit to a scene.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SceneHistoryEntry {
+    /// Scene name.
+    pub scene: String,
+
+    /// Unix timestamp (seconds) when the scene became active.
+    pub entered_at: u64,
+
+    /// Seconds spent in the scene, or `None` while it is still active.
+    #[serde(default)]
+    pub duration_secs: Option<u64>,
+}
+
+/// Bounded, file-backed list of scene visits.
+#[derive(Debug)]
+pub struct SceneHistory {
+    path: PathBuf,
+    capacity: usize,
+    entries: VecDeque<SceneHistoryEntry>,
+}
+
+impl SceneHistory {
+    /// Opens the history at `path`, keeping at most `capacity` entries.
+    ///
+    /// A missing or unreadable file starts an empty history.
+    #[must_use]
+    pub fn open(path: impl Into<PathBuf>, capacity: usize) -> Self {
+        let path = path.into();
+        let mut entries = read_entries(&path).into_iter().collect::<VecDeque<_>>();
+
+        while entries.len() > capacity {
+            entries.pop_front();
+        }
+
+        Self {
+            path,
+            capacity,
+            entries,
+        }
+    }
+
+    /// Returns the path of the history file.
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the recorded entries, oldest first.
+    pub fn entries(&self) -> impl Iterator<Item = &SceneHistoryEntry> {
+        self.entries.iter()
+    }
+
+    /// Returns the scene of the most recent entry.
+    #[must_use]
+    pub fn current_scene(&self) -> Option<&str> {
+        self.entries.back().map(|e| e.scene.as_str())
+    }
+
+    /// Records entering `scene` at `now` (Unix seconds).
+    ///
+    /// Closes the previous entry with its duration. Entering the scene that
+    /// is already current does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history file cannot be written.
+    pub fn enter(&mut self, scene: &str, now: u64) -> std::io::Result<()> {
+        if self.current_scene() == Some(scene) {
+            return Ok(());
+        }
+
+        if let Some(last) = self.entries.back_mut() {
+            last.duration_secs = Some(now.saturating_sub(last.entered_at));
+        }
+        if self.entries.len() >= self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(SceneHistoryEntry {
+            scene: scene.to_string(),
+            entered_at: now,
+            duration_secs: None,
+        });
+
+        self.save()
+    }
+
+    /// Drops every entry, starting a new session.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the history file cannot be written.
+    pub fn clear(&mut self) -> std::io::Result<()> {
+        self.entries.clear();
+        self.save()
+    }
+
+    /// Writes the history atomically (temp file, then rename).
+    fn save(&self) -> std::io::Result<()> {
+        if let Some(parent) = self.path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let json = serde_json::to_string(&self.entries)?;
+        let temp_path = self.path.with_extension("json.tmp");
+        std::fs::write(&temp_path, json)?;
+        std::fs::rename(&temp_path, &self.path)
+    }
+}
+
+/// Reads history entries from `path`, returning an empty list on any error.
+#[must_use]
+pub fn read_entries(path: &Path) -> Vec<SceneHistoryEntry> {
+    std::fs::read_to_string(path)
+        .ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+/// Sums the time spent in each scene, in order of first use.
+///
+/// The still-active entry (if any) is counted up to `now`.
+#[must_use]
+pub fn totals(entries: &[SceneHistoryEntry], now: u64) -> Vec<(String, u64)> {
+    let mut totals: Vec<(String, u64)> = Vec::new();
+    for entry in entries {
+        let secs = entry
+            .duration_secs
+            .unwrap_or_else(|| now.saturating_sub(entry.entered_at));
+        match totals.iter_mut().find(|(scene, _)| *scene == entry.scene) {
+            Some((_, total)) => *total += secs,
+            None => totals.push((entry.scene.clone(), secs)),
+        }
+    }
+    totals
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(capacity: usize) -> (tempfile::TempDir, SceneHistory) {
+        let dir = tempfile::tempdir().unwrap();
+        let history = SceneHistory::open(dir.path().join(HISTORY_FILE_NAME), capacity);
+        (dir, history)
+    }
+
+    #[test]
+    fn enter_closes_previous_entry() {
+        let (_dir, mut history) = history(10);
+        history.enter("Gaming", 100).unwrap();
+        history.enter("BRB", 160).unwrap();
+
+        let entries: Vec<_> = history.entries().cloned().collect();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].duration_secs, Some(60));
+        assert_eq!(entries[1].scene, "BRB");
+        assert_eq!(entries[1].duration_secs, None);
+    }
+
+    #[test]
+    fn entering_current_scene_is_ignored() {
+        let (_dir, mut history) = history(10);
+        history.enter("Gaming", 100).unwrap();
+        history.enter("Gaming", 130).unwrap();
+        assert_eq!(history.entries().count(), 1);
+    }
+
+    #[test]
+    fn history_is_bounded_and_persisted() {
+        let (_dir, mut history) = history(2);
+        history.enter("A", 0).unwrap();
+        history.enter("B", 10).unwrap();
+        history.enter("C", 20).unwrap();
+
+        let saved = read_entries(history.path());
+        assert_eq!(saved.len(), 2);
+        assert_eq!(saved[0].scene, "B");
+        assert_eq!(saved[0].duration_secs, Some(10));
+
+        let reopened = SceneHistory::open(history.path(), 2);
+        assert_eq!(reopened.current_scene(), Some("C"));
+    }
+
+    #[test]
+    fn clear_empties_the_file() {
+        let (_dir, mut history) = history(10);
+        history.enter("Gaming", 0).unwrap();
+        history.clear().unwrap();
+        assert!(read_entries(history.path()).is_empty());
+    }
+
+    #[test]
+    fn totals_sum_repeat_visits() {
+        let (_dir, mut history) = history(10);
+        history.enter("Gaming", 0).unwrap();
+        history.enter("BRB", 100).unwrap();
+        history.enter("Gaming", 130).unwrap();
+
+        let entries: Vec<_> = history.entries().cloned().collect();
+        assert_eq!(
+            totals(&entries, 200),
+            vec![("Gaming".to_string(), 170), ("BRB".to_string(), 30)]
+        );
+    }
+}
@@ -0,0 +1,427 @@
+//! HTTP API for the running daemon.
+//!
+//! Built with the `http` feature, the daemon can also take
+//! [control requests](crate::control) over HTTP, for tools that cannot
+//! open a Unix socket, such as a Stream Deck plugin or a browser source:
+//!
+//! ```bash
+//! fern-obs daemon --http-port 4456 --http-token "$TOKEN"
+//!
+//! curl http://127.0.0.1:4456/state
+//! curl -X POST -H "Authorization: Bearer $TOKEN" \
+//!     -d '{"set_scene":{"name":"Gaming"}}' http://127.0.0.1:4456/command
+//! ```
+//!
+//! - `GET /state` answers with the daemon's
+//!   [`ObsState`](crate::state::ObsState).
+//! - `POST /command` takes a [`Command`] and answers with the same
+//!   [`ControlResponse`] the control socket would send, e.g.
+//!   `{"result":{"success":"Scene set to: Gaming"}}`. A failed command is
+//!   a `502` with `{"error":"..."}`.
+//!
+//! The API listens on `127.0.0.1` unless `--http-bind` says otherwise.
+//! `POST` requests must send the `--http-token` as a bearer token; without
+//! a token the API is read-only. Each connection serves one request.
+
+use crate::control::{self, ControlMessage, ControlRequest, ControlResponse};
+use crate::daemon::Command;
+use crate::error::{Error, Result};
+use serde::Serialize;
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+/// Largest request line plus headers accepted, in bytes.
+const MAX_HEAD_BYTES: u64 = 8 * 1024;
+
+/// Largest request body accepted, in bytes.
+const MAX_BODY_BYTES: usize = 64 * 1024;
+
+/// How long a client may take to send its request.
+const READ_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// HTTP status codes the API answers with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Status {
+    Ok,
+    BadRequest,
+    Unauthorized,
+    Forbidden,
+    NotFound,
+    MethodNotAllowed,
+    RequestTimeout,
+    PayloadTooLarge,
+    BadGateway,
+}
+
+impl Status {
+    const fn code(self) -> u16 {
+        match self {
+            Self::Ok => 200,
+            Self::BadRequest => 400,
+            Self::Unauthorized => 401,
+            Self::Forbidden => 403,
+            Self::NotFound => 404,
+            Self::MethodNotAllowed => 405,
+            Self::RequestTimeout => 408,
+            Self::PayloadTooLarge => 413,
+            Self::BadGateway => 502,
+        }
+    }
+
+    const fn reason(self) -> &'static str {
+        match self {
+            Self::Ok => "OK",
+            Self::BadRequest => "Bad Request",
+            Self::Unauthorized => "Unauthorized",
+            Self::Forbidden => "Forbidden",
+            Self::NotFound => "Not Found",
+            Self::MethodNotAllowed => "Method Not Allowed",
+            Self::RequestTimeout => "Request Timeout",
+            Self::PayloadTooLarge => "Payload Too Large",
+            Self::BadGateway => "Bad Gateway",
+        }
+    }
+}
+
+/// A parsed HTTP request.
+#[derive(Debug)]
+struct Request {
+    method: String,
+    /// Request path, without the query string.
+    path: String,
+    /// Bearer token from the `Authorization` header.
+    token: Option<String>,
+    body: Vec<u8>,
+}
+
+/// An HTTP response with a JSON body.
+#[derive(Debug)]
+struct Response {
+    status: Status,
+    body: String,
+}
+
+impl Response {
+    fn json(status: Status, body: &impl Serialize) -> Self {
+        let body = serde_json::to_string(body).unwrap_or_else(|_| "null".into());
+        Self { status, body }
+    }
+
+    /// An error response, with the control socket's `{"error":"..."}` body.
+    fn error(status: Status, message: impl Into<String>) -> Self {
+        Self::json(status, &ControlResponse::Error(message.into()))
+    }
+
+    /// Returns the response as sent on the wire.
+    fn to_http(&self) -> String {
+        let mut head = format!(
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n",
+            self.status.code(),
+            self.status.reason(),
+            self.body.len()
+        );
+        if self.status == Status::Unauthorized {
+            head.push_str("WWW-Authenticate: Bearer\r\n");
+        }
+        head + "\r\n" + &self.body
+    }
+}
+
+impl From<ControlResponse> for Response {
+    fn from(response: ControlResponse) -> Self {
+        let status = match response {
+            ControlResponse::Error(_) => Status::BadGateway,
+            _ => Status::Ok,
+        };
+        Self::json(status, &response)
+    }
+}
+
+/// Binds the HTTP API listener.
+///
+/// # Errors
+///
+/// Returns an error if the address cannot be bound.
+pub async fn bind(addr: SocketAddr) -> Result<TcpListener> {
+    TcpListener::bind(addr)
+        .await
+        .map_err(|e| Error::io(format!("binding HTTP API to {addr}"), e))
+}
+
+/// Accepts HTTP connections and forwards their requests to the daemon.
+///
+/// `token` is the bearer token `POST /command` requires; `None` turns
+/// commands off. Returns at the first connection after the daemon has
+/// stopped receiving requests.
+pub async fn serve(listener: TcpListener, token: Option<String>, tx: mpsc::Sender<ControlMessage>) {
+    let token: Option<Arc<str>> = token.map(Into::into);
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!(error = %e, "Failed to accept HTTP connection");
+                continue;
+            }
+        };
+        if tx.is_closed() {
+            return;
+        }
+
+        let token = token.clone();
+        let tx = tx.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, token.as_deref(), &tx).await {
+                debug!(error = %e, "HTTP connection failed");
+            }
+        });
+    }
+}
+
+/// Reads one request, answers it, and closes the connection.
+async fn handle_connection(
+    stream: TcpStream,
+    token: Option<&str>,
+    tx: &mpsc::Sender<ControlMessage>,
+) -> Result<()> {
+    let (reader, mut writer) = stream.into_split();
+    let mut reader = BufReader::new(reader);
+
+    let response = match tokio::time::timeout(READ_TIMEOUT, read_request(&mut reader)).await {
+        Ok(Ok(request)) => respond(&request, token, tx).await,
+        Ok(Err(response)) => response,
+        Err(_) => Response::error(Status::RequestTimeout, "request took too long"),
+    };
+
+    writer
+        .write_all(response.to_http().as_bytes())
+        .await
+        .map_err(|e| Error::io("writing HTTP response", e))
+}
+
+/// Reads a request head and its body.
+///
+/// Fails with the response to send when the request is malformed or too
+/// large.
+async fn read_request<R>(reader: &mut R) -> std::result::Result<Request, Response>
+where
+    R: AsyncBufRead + Unpin,
+{
+    let malformed = |what: &str| Response::error(Status::BadRequest, format!("malformed {what}"));
+
+    let mut head = reader.take(MAX_HEAD_BYTES);
+    let mut line = String::new();
+    head.read_line(&mut line)
+        .await
+        .map_err(|_| malformed("request line"))?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(target), Some(_version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(malformed("request line"));
+    };
+    let method = method.to_string();
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut content_length = 0;
+    let mut token = None;
+    loop {
+        line.clear();
+        let read = head
+            .read_line(&mut line)
+            .await
+            .map_err(|_| malformed("header"))?;
+        // A head cut off by the size limit ends without its blank line
+        if read == 0 {
+            return Err(malformed("request head"));
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+
+        let (name, value) = header.split_once(':').ok_or_else(|| malformed("header"))?;
+        let value = value.trim();
+        if name.eq_ignore_ascii_case("content-length") {
+            content_length = value.parse().map_err(|_| malformed("Content-Length"))?;
+        } else if name.eq_ignore_ascii_case("authorization") {
+            token = value.strip_prefix("Bearer ").map(|t| t.trim().to_string());
+        }
+    }
+
+    if content_length > MAX_BODY_BYTES {
+        return Err(Response::error(
+            Status::PayloadTooLarge,
+            format!("request body is over {MAX_BODY_BYTES} bytes"),
+        ));
+    }
+    let mut body = vec![0; content_length];
+    head.into_inner()
+        .read_exact(&mut body)
+        .await
+        .map_err(|_| malformed("body"))?;
+
+    Ok(Request {
+        method,
+        path,
+        token,
+        body,
+    })
+}
+
+/// Routes a request to the daemon.
+async fn respond(
+    request: &Request,
+    token: Option<&str>,
+    tx: &mpsc::Sender<ControlMessage>,
+) -> Response {
+    match (request.method.as_str(), request.path.as_str()) {
+        ("GET", "/state") => match control::dispatch(tx, ControlRequest::Health).await {
+            ControlResponse::State(state) => Response::json(Status::Ok, &state),
+            response => Response::from(response),
+        },
+        ("POST", "/command") => {
+            if let Err(response) = authorize(token, request.token.as_deref()) {
+                return response;
+            }
+            match serde_json::from_slice::<Command>(&request.body) {
+                Ok(command) => Response::from(control::dispatch(tx, command.into()).await),
+                Err(e) => Response::error(Status::BadRequest, format!("invalid command: {e}")),
+            }
+        }
+        (_, "/state" | "/command") => {
+            Response::error(Status::MethodNotAllowed, "method not allowed")
+        }
+        _ => Response::error(Status::NotFound, "not found"),
+    }
+}
+
+/// Checks a request's bearer token against the configured one.
+fn authorize(expected: Option<&str>, given: Option<&str>) -> std::result::Result<(), Response> {
+    let Some(expected) = expected else {
+        return Err(Response::error(
+            Status::Forbidden,
+            "commands are disabled (start the daemon with --http-token)",
+        ));
+    };
+    match given {
+        Some(given) if tokens_match(expected, given) => Ok(()),
+        _ => Err(Response::error(
+            Status::Unauthorized,
+            "missing or wrong bearer token",
+        )),
+    }
+}
+
+/// Compares tokens without stopping at the first difference, so the
+/// response time does not reveal how much of a guess was right.
+fn tokens_match(expected: &str, given: &str) -> bool {
+    expected.len() == given.len()
+        && expected
+            .bytes()
+            .zip(given.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::daemon::CommandResult;
+    use crate::state::ObsState;
+
+    async fn parse(raw: &str) -> std::result::Result<Request, Response> {
+        read_request(&mut raw.as_bytes()).await
+    }
+
+    #[tokio::test]
+    async fn parses_request_with_token_and_body() {
+        let body = r#"{"set_scene":{"name":"Gaming"}}"#;
+        let raw = format!(
+            "POST /command?x=1 HTTP/1.1\r\nHost: localhost\r\nauthorization: Bearer s3cret\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        );
+        let request = parse(&raw).await.unwrap();
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/command");
+        assert_eq!(request.token.as_deref(), Some("s3cret"));
+        assert_eq!(request.body, body.as_bytes());
+    }
+
+    #[tokio::test]
+    async fn rejects_malformed_and_oversized_requests() {
+        let error = parse("GARBAGE\r\n\r\n").await.unwrap_err();
+        assert_eq!(error.status, Status::BadRequest);
+
+        let raw = format!(
+            "POST /command HTTP/1.1\r\nContent-Length: {}\r\n\r\n",
+            MAX_BODY_BYTES + 1
+        );
+        let error = parse(&raw).await.unwrap_err();
+        assert_eq!(error.status, Status::PayloadTooLarge);
+    }
+
+    #[test]
+    fn commands_need_the_configured_token() {
+        assert!(authorize(Some("s3cret"), Some("s3cret")).is_ok());
+        let wrong = authorize(Some("s3cret"), Some("s3cre7")).unwrap_err();
+        assert_eq!(wrong.status, Status::Unauthorized);
+        assert!(wrong.to_http().contains("WWW-Authenticate: Bearer\r\n"));
+        let missing = authorize(Some("s3cret"), None).unwrap_err();
+        assert_eq!(missing.status, Status::Unauthorized);
+        let disabled = authorize(None, Some("s3cret")).unwrap_err();
+        assert_eq!(disabled.status, Status::Forbidden);
+    }
+
+    #[tokio::test]
+    async fn serves_state_and_commands() {
+        let listener = bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, mut rx) = mpsc::channel(1);
+        tokio::spawn(serve(listener, Some("s3cret".into()), tx));
+
+        // Stand-in for the daemon
+        tokio::spawn(async move {
+            while let Some(message) = rx.recv().await {
+                let response = match message.request {
+                    ControlRequest::Health => ControlResponse::State(ObsState::disconnected()),
+                    ControlRequest::Execute { request } => {
+                        assert_eq!(request, Command::StartRecording);
+                        ControlResponse::Result(CommandResult::Success("Recording started".into()))
+                    }
+                    request => ControlResponse::Error(format!("unexpected {request:?}")),
+                };
+                let _ = message.reply.send(response);
+            }
+        });
+
+        let send = |raw: String| async move {
+            let mut stream = TcpStream::connect(addr).await.unwrap();
+            stream.write_all(raw.as_bytes()).await.unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).await.unwrap();
+            response
+        };
+
+        let state = send("GET /state HTTP/1.1\r\n\r\n".into()).await;
+        assert!(state.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(state.contains(r#""connected":false"#));
+
+        let command = |token: &str| {
+            format!(
+                "POST /command HTTP/1.1\r\nAuthorization: Bearer {token}\r\nContent-Length: 17\r\n\r\n\"start_recording\""
+            )
+        };
+        let denied = send(command("guess")).await;
+        assert!(denied.starts_with("HTTP/1.1 401 Unauthorized\r\n"));
+        let done = send(command("s3cret")).await;
+        assert!(done.starts_with("HTTP/1.1 200 OK\r\n"));
+        assert!(done.ends_with(r#"{"result":{"success":"Recording started"}}"#));
+
+        let missing = send("GET /nope HTTP/1.1\r\n\r\n".into()).await;
+        assert!(missing.starts_with("HTTP/1.1 404 Not Found\r\n"));
+    }
+}
@@ -64,11 +64,24 @@
 #![forbid(unsafe_code)]
 #![warn(missing_docs, rust_2018_idioms)]
 
+pub mod alias;
 pub mod client;
 pub mod config;
+pub mod control;
 pub mod daemon;
+pub mod drop_rate;
 pub mod error;
+pub mod history;
+#[cfg(feature = "http")]
+pub mod http;
+pub mod pid_file;
+pub mod prometheus;
+pub mod raw;
 pub mod state;
+pub mod stats_history;
+pub mod waybar;
+#[cfg(feature = "websocket")]
+pub mod ws;
 
 pub use client::ObsClient;
 pub use config::ObsConfig;
@@ -11,26 +11,97 @@
 //! # With custom connection settings
 //! fern-obs daemon --host 192.168.1.100 --port 4455 --password secret
 //!
+//! # Pause recording while the "Private" scene is live
+//! fern-obs daemon --auto-pause-scene Private
+//!
+//! # Notify when a recording is about to fill the disk or frames drop
+//! fern-obs daemon --low-disk-mb 2048 --notify-warnings
+//!
 //! # Recording controls
 //! fern-obs start-recording
 //! fern-obs stop-recording
 //! fern-obs toggle-pause
+//! fern-obs toggle-recording
 //!
 //! # Streaming controls
 //! fern-obs start-streaming
 //! fern-obs stop-streaming
+//! fern-obs toggle-streaming
 //!
 //! # Scene control
 //! fern-obs scene "Gaming"
+//! fern-obs scene --next
+//! fern-obs scene --prev
+//! fern-obs scene "BRB" --transition Fade --duration 300
+//! fern-obs scenes
+//! fern-obs transitions
+//!
+//! # Hotkeys
+//! fern-obs hotkeys
+//! fern-obs hotkey OBSBasic.Screenshot
+//! fern-obs hotkey-sequence OBS_KEY_F9 -m ctrl -m shift
 //!
 //! # Get current status
 //! fern-obs status
+//!
+//! # One line for a waybar custom module
+//! fern-obs status --waybar
+//!
+//! # Metrics for Prometheus (node_exporter textfile collector)
+//! fern-obs status --format prometheus
+//!
+//! # Any command as a JSON result object
+//! fern-obs --json scene "Gaming"
+//!
+//! # Drop the daemon's OBS connection around an OBS restart
+//! fern-obs disconnect
+//! fern-obs connect
+//!
+//! # How often the daemon lost and regained OBS
+//! fern-obs health
+//!
+//! # Scenes used in the current recording session
+//! fern-obs history
+//!
+//! # Open the folder OBS records to
+//! fern-obs open-recordings
+//!
+//! # Run an alias from ~/.config/fern/obs.toml through the daemon
+//! fern-obs run golive
+//! fern-obs run --list
+//!
+//! # Emit JSON log lines (also via FERN_LOG_FORMAT=json)
+//! fern-obs --log-format json daemon
+//!
+//! # Mirror logs to obs-log.json for `fernctl logs --service obs`
+//! fern-obs --log-to-file daemon
+//!
+//! # Only warnings and errors, whatever RUST_LOG says
+//! fern-obs --quiet daemon
+//! fern-obs --log-level debug daemon
+//!
+//! # Shell completions, including scene and transition names
+//! source <(fern-obs completions bash)
 //! ```
 
-use clap::{Parser, Subcommand};
+use clap::{Args, CommandFactory, Parser, Subcommand, ValueEnum};
+use fern_core::completions::{self, DynamicValues};
+use fern_core::log::JsonRingLayer;
+use fern_core::FernPaths;
+use fern_obs::alias::Aliases;
+use fern_obs::client::MediaAction;
 use fern_obs::config::ObsConfig;
-use fern_obs::daemon::{send_command, Command, CommandResult, Daemon};
-use fern_obs::error::Result;
+use fern_obs::daemon::{read_daemon_state, send_command, Command, CommandResult, Daemon};
+use fern_obs::error::{Error, Result};
+use fern_obs::history::{self, SceneHistoryEntry, HISTORY_FILE_NAME};
+use fern_obs::prometheus;
+use fern_obs::raw;
+use fern_obs::state::{unix_now, ObsState, ObsStats, RecordingState};
+use fern_obs::waybar::WaybarOutput;
+use serde::Serialize;
+use std::net::IpAddr;
+use std::path::Path;
+use std::process::ExitCode;
 use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
 /// fern-obs - OBS WebSocket bridge for Fern Shell
@@ -54,11 +125,101 @@ struct Cli {
     #[arg(long, global = true, env = "OBS_PASSWORD")]
     password: Option<String>,
 
+    /// Print every result and error as a JSON object
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Timeout for one-shot commands, in milliseconds
+    #[arg(long, default_value_t = 5000, global = true, env = "FERN_OBS_TIMEOUT")]
+    timeout: u64,
+
+    /// Log output format
+    #[arg(
+        long,
+        value_enum,
+        default_value_t = LogFormat::Text,
+        global = true,
+        env = "FERN_LOG_FORMAT"
+    )]
+    log_format: LogFormat,
+
+    /// Also append logs to ~/.local/state/fern/obs-log.json for fernctl
+    #[arg(long, global = true, env = "FERN_OBS_LOG_TO_FILE")]
+    log_to_file: bool,
+
+    /// Log level, overriding RUST_LOG
+    #[arg(long, value_enum, global = true)]
+    log_level: Option<LogLevel>,
+
+    /// Only log warnings and errors (same as --log-level warn)
+    #[arg(short, long, global = true, conflicts_with = "log_level")]
+    quiet: bool,
+
     /// Subcommand to execute
     #[command(subcommand)]
     command: Commands,
 }
 
+impl Cli {
+    /// Returns the log level set on the command line; `--quiet` is `warn`.
+    const fn level(&self) -> Option<LogLevel> {
+        if self.quiet {
+            Some(LogLevel::Warn)
+        } else {
+            self.log_level
+        }
+    }
+}
+
+/// Log output formats
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum LogFormat {
+    /// Compact human-readable text
+    Text,
+    /// One JSON object per line (timestamp, level, target, message)
+    Json,
+}
+
+/// Output formats for `status --format`
+#[derive(ValueEnum, Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum StatusFormat {
+    /// Human-readable text, or JSON with `--json` (default)
+    #[default]
+    Text,
+    /// One line for a waybar custom module (`return-type: json`)
+    Waybar,
+    /// Prometheus text exposition format
+    Prometheus,
+}
+
+/// Log levels for `--log-level`
+#[derive(ValueEnum, Clone, Copy, Debug, PartialEq, Eq)]
+enum LogLevel {
+    /// Errors only
+    Error,
+    /// Warnings and errors
+    Warn,
+    /// Connection changes and actions (default)
+    Info,
+    /// Per-request detail
+    Debug,
+    /// Everything
+    Trace,
+}
+
+impl LogLevel {
+    /// Returns the level as a tracing filter directive.
+    const fn as_str(self) -> &'static str {
+        match self {
+            Self::Error => "error",
+            Self::Warn => "warn",
+            Self::Info => "info",
+            Self::Debug => "debug",
+            Self::Trace => "trace",
+        }
+    }
+}
+
 /// Available commands
 #[derive(Subcommand, Debug)]
 enum Commands {
@@ -66,23 +227,7 @@ enum Commands {
     ///
     /// Maintains a persistent connection to OBS and writes state updates
     /// to ~/.local/state/fern/obs-state.json
-    Daemon {
-        /// How often to update stats (milliseconds)
-        #[arg(long, default_value_t = 1000)]
-        stats_interval: u64,
-
-        /// How long to wait before reconnecting (milliseconds)
-        #[arg(long, default_value_t = 5000)]
-        reconnect_interval: u64,
-
-        /// Maximum reconnection attempts (0 = unlimited)
-        #[arg(long, default_value_t = 0)]
-        max_reconnects: u32,
-
-        /// Disable stats collection
-        #[arg(long)]
-        no_stats: bool,
-    },
+    Daemon(DaemonArgs),
 
     /// Start recording
     #[command(alias = "rec")]
@@ -96,6 +241,10 @@ enum Commands {
     #[command(alias = "pause")]
     TogglePause,
 
+    /// Start recording if idle, stop it if active
+    #[command(alias = "toggle-rec")]
+    ToggleRecording,
+
     /// Start streaming
     #[command(alias = "stream")]
     StartStreaming,
@@ -104,182 +253,974 @@ enum Commands {
     #[command(alias = "stop-stream")]
     StopStreaming,
 
-    /// Set the current scene
+    /// Start streaming if idle, stop it if active
+    #[command(alias = "toggle-stream")]
+    ToggleStreaming,
+
+    /// Set the current scene, or cycle through the scene list
     Scene {
         /// Name of the scene to switch to
+        #[arg(required_unless_present_any = ["next", "prev"])]
+        name: Option<String>,
+
+        /// Switch to the next scene, wrapping around
+        #[arg(long, conflicts_with_all = ["name", "prev"])]
+        next: bool,
+
+        /// Switch to the previous scene, wrapping around
+        #[arg(long, conflicts_with = "name")]
+        prev: bool,
+
+        /// Transition to use (e.g., Cut, Fade); stays current afterwards
+        #[arg(long, conflicts_with_all = ["next", "prev"])]
+        transition: Option<String>,
+
+        /// Transition duration in milliseconds
+        #[arg(long = "duration", conflicts_with_all = ["next", "prev"])]
+        duration_ms: Option<u32>,
+    },
+
+    /// List scene names
+    Scenes,
+
+    /// List available scene transitions
+    Transitions,
+
+    /// Trigger an OBS hotkey by name
+    ///
+    /// Use `fern-obs hotkeys` to list the available names.
+    Hotkey {
+        /// Hotkey name (e.g., OBSBasic.StartRecording)
         name: String,
     },
 
+    /// Trigger an OBS hotkey by key sequence
+    HotkeySequence {
+        /// OBS key identifier (e.g., OBS_KEY_F9)
+        key: String,
+
+        /// Modifier keys to hold (shift, ctrl, alt, cmd)
+        #[arg(long = "modifier", short = 'm')]
+        modifiers: Vec<String>,
+    },
+
+    /// List available OBS hotkey names
+    Hotkeys,
+
+    /// List the filters on a source and whether each is enabled
+    Filters {
+        /// Source name (e.g., Camera)
+        source: String,
+    },
+
+    /// Enable or disable a filter on a source
+    Filter {
+        /// Source name (e.g., Camera)
+        source: String,
+
+        /// Filter name (e.g., "Color Correction")
+        filter: String,
+
+        /// Disable the filter instead of enabling it
+        #[arg(long)]
+        disable: bool,
+    },
+
+    /// Play, pause, restart, stop, or skip within a media source
+    Media {
+        /// Media source name (e.g., Stinger)
+        source: String,
+
+        /// What to do
+        #[arg(value_enum)]
+        action: MediaAction,
+    },
+
+    /// Send a raw obs-websocket request through the daemon
+    ///
+    /// The daemon must run with --allow-raw. Raw requests are not
+    /// reflected in the state file.
+    Raw {
+        /// obs-websocket request type (e.g., GetInputList)
+        request_type: String,
+
+        /// JSON request data
+        #[arg(default_value = "{}")]
+        request_data: String,
+    },
+
+    /// Show the scene history of the current recording session
+    ///
+    /// Recorded by the daemon; cleared each time a recording starts.
+    History,
+
+    /// Run a command alias from ~/.config/fern/obs.toml
+    ///
+    /// The daemon runs the alias steps in order over its OBS connection
+    /// and stops at the first one that fails.
+    Run {
+        /// Alias name (e.g., golive)
+        #[arg(required_unless_present = "list")]
+        alias: Option<String>,
+
+        /// List the defined aliases instead
+        #[arg(long, conflicts_with = "alias")]
+        list: bool,
+    },
+
+    /// Open the OBS recordings folder with xdg-open
+    ///
+    /// Asks OBS for the folder, or uses the one the daemon last saw if
+    /// OBS is not running.
+    OpenRecordings,
+
     /// Get current OBS status
     Status {
-        /// Output as JSON
-        #[arg(long)]
-        json: bool,
+        /// Print one line for a waybar custom module (same as `--format waybar`)
+        #[arg(long, conflicts_with_all = ["json", "format"])]
+        waybar: bool,
+
+        /// Output format
+        #[arg(long, value_enum, default_value_t = StatusFormat::Text)]
+        format: StatusFormat,
+    },
+
+    /// Show OBS performance stats from the running daemon
+    ///
+    /// Prints one line (CPU, FPS, frame time, memory, drops), or the stats
+    /// and a connected flag with `--json`.
+    Stats,
+
+    /// Ask the running daemon to connect to OBS now
+    ///
+    /// Ends a previous `disconnect` and skips any pending reconnect delay.
+    Connect,
+
+    /// Ask the running daemon to drop its OBS connection
+    ///
+    /// The daemon keeps running but stops reconnecting until `connect`.
+    Disconnect,
+
+    /// Show the running daemon's connection health
+    ///
+    /// Includes how often it reconnected and when it last connected and
+    /// lost the connection.
+    Health,
+
+    /// Print a shell completion script to stdout
+    ///
+    /// Scene and transition names are completed by asking OBS, so they
+    /// stay current without regenerating the script.
+    Completions {
+        /// Shell to generate the script for
+        #[arg(value_enum)]
+        shell: completions::Shell,
+    },
+
+    /// Write man pages for fern-obs and each subcommand
+    #[command(hide = true)]
+    Man {
+        /// Directory to write the pages to
+        #[arg(long, default_value = "man")]
+        out_dir: std::path::PathBuf,
     },
 }
 
-#[tokio::main]
-async fn main() -> Result<()> {
-    // Initialize tracing subscriber with env filter
-    // Default to "info" level, can be overridden with RUST_LOG env var
-    // e.g., RUST_LOG=debug or RUST_LOG=fern_obs=trace
-    tracing_subscriber::registry()
-        .with(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
-        .with(
-            fmt::layer()
-                .with_target(false)
-                .with_thread_ids(false)
-                .with_file(false)
-                .with_line_number(false)
-                .compact(),
-        )
-        .init();
+/// Options for `fern-obs daemon`
+#[derive(Args, Debug)]
+struct DaemonArgs {
+    /// How often to update stats (milliseconds)
+    #[arg(long, default_value_t = 1000)]
+    stats_interval: u64,
+
+    /// How long to wait before reconnecting (milliseconds)
+    #[arg(long, default_value_t = 5000)]
+    reconnect_interval: u64,
+
+    /// Maximum reconnection attempts (0 = unlimited)
+    #[arg(long, default_value_t = 0)]
+    max_reconnects: u32,
 
+    /// Disable stats collection
+    #[arg(long)]
+    no_stats: bool,
+
+    /// Number of stats samples kept for the CPU/FPS history (0 = off)
+    #[arg(long, default_value_t = 60)]
+    stats_history: usize,
+
+    /// Pause recording while this scene is active (repeatable)
+    #[arg(long = "auto-pause-scene", value_name = "SCENE")]
+    auto_pause_scenes: Vec<String>,
+
+    /// Warn when recording with less free disk space than this (MB, 0 = off)
+    #[arg(long, default_value_t = 1024)]
+    low_disk_mb: u32,
+
+    /// Warn when render or output drops exceed this percentage (0 = off)
+    #[arg(long, default_value_t = 5.0)]
+    drop_warning_percent: f64,
+
+    /// Number of stats samples the drop rate is averaged over
+    #[arg(long, default_value_t = 10)]
+    drop_window: usize,
+
+    /// Also send a desktop notification for each new warning
+    #[arg(long, alias = "notify-low-disk")]
+    notify_warnings: bool,
+
+    /// Launch OBS with this shell command when it is not reachable
+    #[arg(long, env = "FERN_OBS_AUTO_START", value_name = "COMMAND")]
+    auto_start_obs: Option<String>,
+
+    /// Serve the HTTP API on this port (needs the `http` feature)
+    #[arg(long, value_name = "PORT")]
+    http_port: Option<u16>,
+
+    /// Address the HTTP API listens on
+    #[arg(long, default_value = "127.0.0.1", value_name = "ADDR")]
+    http_bind: IpAddr,
+
+    /// Bearer token required to send commands over HTTP
+    #[arg(long, env = "FERN_OBS_HTTP_TOKEN", value_name = "TOKEN")]
+    http_token: Option<String>,
+
+    /// Push state changes over WebSocket on this port (needs the
+    /// `websocket` feature)
+    #[arg(long, value_name = "PORT")]
+    ws_port: Option<u16>,
+
+    /// Address the WebSocket feed listens on
+    #[arg(long, default_value = "127.0.0.1", value_name = "ADDR")]
+    ws_bind: IpAddr,
+
+    /// Run untyped `raw` requests (bypasses the typed API)
+    #[arg(long, env = "FERN_OBS_ALLOW_RAW")]
+    allow_raw: bool,
+}
+
+impl DaemonArgs {
+    /// Builds the daemon configuration on top of the connection settings.
+    fn into_config(self, base: ObsConfig) -> ObsConfig {
+        ObsConfig {
+            stats_interval_ms: self.stats_interval,
+            reconnect_interval_ms: self.reconnect_interval,
+            max_reconnect_attempts: self.max_reconnects,
+            show_stats: !self.no_stats,
+            stats_history_samples: self.stats_history,
+            auto_pause_scenes: self.auto_pause_scenes,
+            low_disk_threshold_mb: self.low_disk_mb,
+            drop_warning_percent: self.drop_warning_percent,
+            drop_window_samples: self.drop_window,
+            notify_warnings: self.notify_warnings,
+            auto_start_obs: self.auto_start_obs,
+            http_port: self.http_port,
+            http_bind: self.http_bind,
+            http_token: self.http_token,
+            ws_port: self.ws_port,
+            ws_bind: self.ws_bind,
+            allow_raw: self.allow_raw,
+            ..base
+        }
+    }
+}
+
+/// Values completed at runtime by `fern-obs completions`.
+///
+/// A short timeout keeps the shell responsive when OBS is not running.
+const DYNAMIC_COMPLETIONS: [DynamicValues; 3] = [
+    DynamicValues {
+        subcommand: "scene",
+        option: None,
+        command: "fern-obs --timeout 1000 scenes",
+    },
+    DynamicValues {
+        subcommand: "scene",
+        option: Some("transition"),
+        command: "fern-obs --timeout 1000 transitions",
+    },
+    DynamicValues {
+        subcommand: "run",
+        option: None,
+        command: "fern-obs run --list",
+    },
+];
+
+#[tokio::main]
+async fn main() -> ExitCode {
     let cli = Cli::parse();
 
+    init_tracing(cli.log_format, cli.level(), cli.log_to_file);
+
+    let json = cli.json;
+    match run(cli).await {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(e) if json => {
+            JsonOutput::failure(&e).print();
+            ExitCode::FAILURE
+        }
+        Err(e) => {
+            eprintln!("Error: {e}");
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Runs the selected command.
+async fn run(cli: Cli) -> Result<()> {
+    let json = cli.json;
     let base_config = ObsConfig {
         host: cli.host,
         port: cli.port,
         password: cli.password,
+        command_timeout_ms: cli.timeout,
         ..Default::default()
     };
 
-    match cli.command {
-        Commands::Daemon {
-            stats_interval,
-            reconnect_interval,
-            max_reconnects,
-            no_stats,
+    let command = match cli.command {
+        Commands::Daemon(args) => {
+            let mut daemon = Daemon::new(args.into_config(base_config));
+            return Box::pin(daemon.run()).await;
+        }
+        Commands::StartRecording => Command::StartRecording,
+        Commands::StopRecording => Command::StopRecording,
+        Commands::TogglePause => Command::TogglePause,
+        Commands::ToggleRecording => Command::ToggleRecording,
+        Commands::StartStreaming => Command::StartStreaming,
+        Commands::StopStreaming => Command::StopStreaming,
+        Commands::ToggleStreaming => Command::ToggleStreaming,
+        Commands::Scene {
+            name,
+            next,
+            transition,
+            duration_ms,
+            ..
+        } => scene_command(name, next, transition, duration_ms),
+        Commands::Hotkey { name } => Command::TriggerHotkey(name),
+        Commands::HotkeySequence { key, modifiers } => {
+            Command::TriggerHotkeySequence { key, modifiers }
+        }
+        Commands::Transitions => Command::ListTransitions,
+        Commands::Hotkeys => Command::ListHotkeys,
+        Commands::Filters { source } => Command::ListSourceFilters(source),
+        Commands::Filter {
+            source,
+            filter,
+            disable,
+        } => Command::SetSourceFilterEnabled {
+            source,
+            filter,
+            enabled: !disable,
+        },
+        Commands::Media { source, action } => Command::MediaAction { source, action },
+        Commands::Raw {
+            request_type,
+            request_data,
         } => {
-            let config = ObsConfig {
-                stats_interval_ms: stats_interval,
-                reconnect_interval_ms: reconnect_interval,
-                max_reconnect_attempts: max_reconnects,
-                show_stats: !no_stats,
-                ..base_config
-            };
-
-            let mut daemon = Daemon::new(config);
-            daemon.run().await
+            let (request_type, request_data) = raw::parse_request(&request_type, &request_data)?;
+            Command::Raw {
+                request_type,
+                request_data,
+            }
         }
+        Commands::Connect => Command::Connect,
+        Commands::Disconnect => Command::Disconnect,
+        Commands::Health => Command::Health,
 
-        Commands::StartRecording => {
-            let result = send_command(&base_config, Command::StartRecording).await?;
-            print_result(result, false);
-            Ok(())
+        Commands::Scenes => return cmd_scenes(&base_config, json).await,
+        Commands::History => return cmd_history(json),
+        Commands::Run { alias, list } => return cmd_run(&base_config, alias, list, json).await,
+        Commands::OpenRecordings => return cmd_open_recordings(&base_config, json).await,
+        Commands::Status { waybar, format } => {
+            let format = if waybar { StatusFormat::Waybar } else { format };
+            return cmd_status(&base_config, format, json).await;
         }
-
-        Commands::StopRecording => {
-            let result = send_command(&base_config, Command::StopRecording).await?;
-            print_result(result, false);
-            Ok(())
+        Commands::Stats => return cmd_stats(&base_config, json).await,
+        Commands::Completions { shell } => {
+            return completions::generate(
+                shell,
+                &mut Cli::command(),
+                &DYNAMIC_COMPLETIONS,
+                &mut std::io::stdout(),
+            )
+            .map_err(|e| Error::io("writing completions", e));
         }
+        Commands::Man { out_dir } => return cmd_man(&out_dir),
+    };
 
-        Commands::TogglePause => {
-            let result = send_command(&base_config, Command::TogglePause).await?;
-            print_result(result, false);
-            Ok(())
-        }
+    let result = send_command(&base_config, command).await?;
+    print_result(result, json);
+    Ok(())
+}
 
-        Commands::StartStreaming => {
-            let result = send_command(&base_config, Command::StartStreaming).await?;
-            print_result(result, false);
-            Ok(())
-        }
+/// `fern-obs scenes`: the scene names from the current status.
+async fn cmd_scenes(config: &ObsConfig, json: bool) -> Result<()> {
+    let result = match send_command(config, Command::GetStatus).await? {
+        CommandResult::State(state) => CommandResult::List(state.scenes),
+        other => other,
+    };
+    print_result(result, json);
+    Ok(())
+}
 
-        Commands::StopStreaming => {
-            let result = send_command(&base_config, Command::StopStreaming).await?;
-            print_result(result, false);
-            Ok(())
-        }
+/// `fern-obs history`: the scene history the daemon recorded.
+fn cmd_history(json: bool) -> Result<()> {
+    let path = FernPaths::new().state_dir().join(HISTORY_FILE_NAME);
+    let entries = history::read_entries(&path);
+    if json {
+        JsonOutput::data(serde_json::to_value(&entries)?).print();
+    } else {
+        print_history(&entries);
+    }
+    Ok(())
+}
 
-        Commands::Scene { name } => {
-            let result = send_command(&base_config, Command::SetScene(name)).await?;
-            print_result(result, false);
-            Ok(())
-        }
+/// `fern-obs run`: runs an alias through the daemon, or lists them.
+async fn cmd_run(config: &ObsConfig, alias: Option<String>, list: bool, json: bool) -> Result<()> {
+    let aliases = Aliases::load(&FernPaths::new().service_config("obs"))?;
+    if list {
+        let names = aliases.names().map(str::to_string).collect();
+        print_result(CommandResult::List(names), json);
+        return Ok(());
+    }
+
+    // clap requires an alias unless --list is given
+    let alias = alias.unwrap_or_default();
+    let steps = aliases.get(&alias)?.to_vec();
+    let result = send_command(config, Command::RunAlias { alias, steps }).await?;
+    print_result(result, json);
+    Ok(())
+}
+
+/// `fern-obs open-recordings`: opens the recordings folder.
+async fn cmd_open_recordings(config: &ObsConfig, json: bool) -> Result<()> {
+    let directory = record_directory(config).await?;
+    if !Path::new(&directory).is_dir() {
+        return Err(Error::io(
+            format!("opening {directory}"),
+            std::io::ErrorKind::NotFound.into(),
+        ));
+    }
+    std::process::Command::new("xdg-open")
+        .arg(&directory)
+        .spawn()
+        .map_err(|e| Error::io("running xdg-open", e))?;
+    print_result(CommandResult::Success(format!("Opened {directory}")), json);
+    Ok(())
+}
+
+/// `fern-obs status`: the current state in the requested format.
+async fn cmd_status(config: &ObsConfig, format: StatusFormat, json: bool) -> Result<()> {
+    if format == StatusFormat::Text {
+        let result = send_command(config, Command::GetStatus).await?;
+        print_result(result, json);
+        return Ok(());
+    }
+
+    // The bar and the scraper get output even when OBS is unreachable
+    let state = match send_command(config, Command::GetStatus).await {
+        Ok(CommandResult::State(state)) => state,
+        Ok(_) => ObsState::disconnected(),
+        Err(e) => ObsState::with_error(e.to_string()),
+    };
+    if format == StatusFormat::Prometheus {
+        print!("{}", prometheus::render(&state));
+    } else {
+        println!(
+            "{}",
+            serde_json::to_string(&WaybarOutput::from_state(&state))?
+        );
+    }
+    Ok(())
+}
+
+/// `fern-obs stats`: the daemon's latest OBS stats.
+async fn cmd_stats(config: &ObsConfig, json: bool) -> Result<()> {
+    let stats = match send_command(config, Command::Health).await? {
+        CommandResult::State(state) => daemon_stats(state)?,
+        _ => return Err(Error::Request("unexpected response from the daemon".into())),
+    };
+    if json {
+        let data = serde_json::json!({ "connected": true, "stats": stats });
+        JsonOutput::data(data).print();
+    } else {
+        println!("{}", stats.summary());
+    }
+    Ok(())
+}
 
-        Commands::Status { json } => {
-            let result = send_command(&base_config, Command::GetStatus).await?;
-            print_result(result, json);
-            Ok(())
+/// `fern-obs man`: writes the man pages and lists them.
+fn cmd_man(out_dir: &Path) -> Result<()> {
+    let pages = fern_core::man::generate_to(Cli::command(), out_dir)
+        .map_err(|e| Error::io("writing man pages", e))?;
+    for page in pages {
+        println!("{}", page.display());
+    }
+    Ok(())
+}
+
+/// Initializes the tracing subscriber in the requested format.
+///
+/// The level comes from `level` (`--log-level` or `--quiet`) if given,
+/// then the `RUST_LOG` env var (e.g., `RUST_LOG=debug` or
+/// `RUST_LOG=fern_obs=trace`), then defaults to "info".
+/// When `log_to_file` is set, events are also mirrored to the service log
+/// file that `fernctl logs` reads.
+fn init_tracing(format: LogFormat, level: Option<LogLevel>, log_to_file: bool) {
+    let rust_log = std::env::var(EnvFilter::DEFAULT_ENV).ok();
+    let directive = log_directive(level, rust_log.as_deref());
+    let filter = EnvFilter::try_new(directive).unwrap_or_else(|_| EnvFilter::new("info"));
+    let file_layer = log_to_file.then(|| JsonRingLayer::for_service("obs"));
+
+    match format {
+        LogFormat::Text => {
+            tracing_subscriber::registry()
+                .with(filter)
+                .with(file_layer)
+                .with(
+                    fmt::layer()
+                        .with_target(false)
+                        .with_thread_ids(false)
+                        .with_file(false)
+                        .with_line_number(false)
+                        .compact(),
+                )
+                .init();
+        }
+        LogFormat::Json => {
+            // Flatten event fields so `message` sits next to `timestamp`,
+            // `level`, and `target` at the top level of each line.
+            tracing_subscriber::registry()
+                .with(filter)
+                .with(file_layer)
+                .with(
+                    fmt::layer()
+                        .json()
+                        .flatten_event(true)
+                        .with_target(true)
+                        .with_current_span(false)
+                        .with_span_list(false),
+                )
+                .init();
         }
     }
 }
 
+/// Picks the tracing filter: the command-line level, then `RUST_LOG`
+/// (if set and non-empty), then "info".
+fn log_directive(level: Option<LogLevel>, rust_log: Option<&str>) -> &str {
+    match (level, rust_log) {
+        (Some(level), _) => level.as_str(),
+        (None, Some(rust_log)) if !rust_log.trim().is_empty() => rust_log,
+        (None, _) => "info",
+    }
+}
+
+/// Formats the time since a Unix timestamp as a timecode.
+fn time_since(secs: u64) -> String {
+    RecordingState::format_timecode(unix_now().saturating_sub(secs))
+}
+
+/// Prints each scene visit, then the total time per scene.
+fn print_history(entries: &[SceneHistoryEntry]) {
+    let Some(first) = entries.first() else {
+        println!("No scene history recorded");
+        return;
+    };
+
+    let now = unix_now();
+
+    for entry in entries {
+        let offset =
+            RecordingState::format_timecode(entry.entered_at.saturating_sub(first.entered_at));
+        let duration = entry
+            .duration_secs
+            .map_or_else(|| "(current)".to_string(), RecordingState::format_timecode);
+        println!("+{offset:>8}  {duration:>9}  {}", entry.scene);
+    }
+
+    println!();
+    println!("Totals:");
+    for (scene, secs) in history::totals(entries, now) {
+        println!("  {:>9}  {scene}", RecordingState::format_timecode(secs));
+    }
+}
+
+/// Maps the `scene` arguments to a daemon command.
+///
+/// clap guarantees exactly one of a name, `--next`, or `--prev`, and only
+/// allows a transition override with a name.
+fn scene_command(
+    name: Option<String>,
+    next: bool,
+    transition: Option<String>,
+    duration_ms: Option<u32>,
+) -> Command {
+    match name {
+        Some(name) => Command::SetScene {
+            name,
+            transition,
+            duration_ms,
+        },
+        None if next => Command::NextScene,
+        None => Command::PrevScene,
+    }
+}
+
 fn print_result(result: CommandResult, as_json: bool) {
+    if as_json {
+        JsonOutput::success(result).print();
+        return;
+    }
+
     match result {
         CommandResult::Success(msg) => {
             println!("{msg}");
         }
+        CommandResult::List(items) => {
+            for item in items {
+                println!("{item}");
+            }
+        }
+        CommandResult::Filters(filters) => {
+            for filter in filters {
+                let state = if filter.enabled { "on" } else { "off" };
+                println!("{state}\t{}", filter.name);
+            }
+        }
+        CommandResult::Raw(value) => {
+            if let Ok(json) = serde_json::to_string_pretty(&value) {
+                println!("{json}");
+            }
+        }
+        CommandResult::Steps(steps) => {
+            for step in steps {
+                println!("{}: {}", step.step, step.message);
+            }
+        }
         CommandResult::State(state) => {
-            if as_json {
-                if let Ok(json) = serde_json::to_string_pretty(&state) {
-                    println!("{json}");
-                }
-            } else {
-                println!("Connected: {}", state.connected);
+            println!("Connected: {}", state.connected);
 
-                if let Some(scene) = &state.current_scene {
-                    println!("Scene: {scene}");
-                }
+            if let Some(error) = &state.error {
+                println!("Error: {error}");
+            }
+            if let Some(warning) = &state.warning {
+                println!("Warning: {warning}");
+            }
+            if let Some(connected_at) = state.last_connected_secs {
+                println!("Reconnects: {}", state.reconnect_count);
+                println!("Last connected: {} ago", time_since(connected_at));
+            }
+            if let Some(disconnected_at) = state.last_disconnected_secs {
+                println!("Last disconnected: {} ago", time_since(disconnected_at));
+            }
 
-                println!(
-                    "Recording: {}{}",
-                    if state.recording.active {
-                        "active"
-                    } else {
-                        "inactive"
-                    },
-                    if state.recording.paused {
-                        " (paused)"
-                    } else {
-                        ""
-                    }
-                );
+            if let Some(scene) = &state.current_scene {
+                println!("Scene: {scene}");
+            }
 
+            println!(
+                "Recording: {}{}",
                 if state.recording.active {
-                    if let Some(tc) = &state.recording.timecode {
-                        println!("  Duration: {tc}");
-                    }
+                    "active"
+                } else {
+                    "inactive"
+                },
+                if state.recording.paused {
+                    " (paused)"
+                } else {
+                    ""
                 }
+            );
 
-                println!(
-                    "Streaming: {}{}",
-                    if state.streaming.active {
-                        "active"
-                    } else {
-                        "inactive"
-                    },
-                    if state.streaming.reconnecting {
-                        " (reconnecting)"
-                    } else {
-                        ""
-                    }
-                );
+            if state.recording.active {
+                if let Some(tc) = &state.recording.timecode {
+                    println!("  Duration: {tc}");
+                }
+            }
 
+            println!(
+                "Streaming: {}{}",
                 if state.streaming.active {
-                    if let Some(tc) = &state.streaming.timecode {
-                        println!("  Duration: {tc}");
-                    }
+                    "active"
+                } else {
+                    "inactive"
+                },
+                if state.streaming.reconnecting {
+                    " (reconnecting)"
+                } else {
+                    ""
                 }
+            );
 
-                if let Some(stats) = &state.stats {
-                    println!("Stats:");
-                    println!("  CPU: {:.1}%", stats.cpu_usage);
-                    println!("  FPS: {:.1}", stats.active_fps);
-                    if let Some(drop) = stats.render_drop_percent {
-                        println!("  Render drops: {:.2}%", drop);
-                    }
-                    if let Some(drop) = stats.output_drop_percent {
-                        println!("  Output drops: {:.2}%", drop);
-                    }
+            if state.streaming.active {
+                if let Some(tc) = &state.streaming.timecode {
+                    println!("  Duration: {tc}");
                 }
+            }
 
-                if !state.scenes.is_empty() {
-                    println!("Scenes: {}", state.scenes.join(", "));
+            if let Some(stats) = &state.stats {
+                println!("Stats:");
+                println!("  CPU: {:.1}%", stats.cpu_usage);
+                println!("  FPS: {:.1}", stats.active_fps);
+                if let Some(drop) = stats.render_drop_percent {
+                    println!("  Render drops: {:.2}%", drop);
                 }
+                if let Some(drop) = stats.output_drop_percent {
+                    println!("  Output drops: {:.2}%", drop);
+                }
+            }
+
+            if !state.scenes.is_empty() {
+                println!("Scenes: {}", state.scenes.join(", "));
+            }
+
+            if let Some(directory) = &state.record_directory {
+                println!("Recordings: {directory}");
             }
         }
     }
 }
+
+/// Returns the stats from the daemon's state for `stats`.
+///
+/// Fails if the daemon is not connected to OBS or not collecting stats.
+fn daemon_stats(state: ObsState) -> Result<ObsStats> {
+    if !state.connected {
+        return Err(Error::NotConnected);
+    }
+    state.stats.ok_or_else(|| {
+        Error::Config("the daemon is not collecting stats (started with --no-stats)".into())
+    })
+}
+
+/// Returns the OBS recordings folder.
+///
+/// Asks OBS first and falls back to the daemon's state file, so the folder
+/// can still be opened while OBS is closed.
+async fn record_directory(config: &ObsConfig) -> Result<String> {
+    let reported = match send_command(config, Command::GetStatus).await {
+        Ok(CommandResult::State(state)) => state.record_directory,
+        _ => None,
+    };
+    reported
+        .or_else(|| read_daemon_state().and_then(|state| state.record_directory))
+        .ok_or_else(|| Error::Request("OBS has not reported a recording folder".into()))
+}
+
+/// Result object printed for every command with `--json`.
+#[derive(Debug, Serialize)]
+struct JsonOutput {
+    /// Whether the command succeeded.
+    ok: bool,
+    /// Human-readable result or error message.
+    message: Option<String>,
+    /// OBS state, for commands that return it.
+    state: Option<ObsState>,
+    /// Lists, raw responses, and history entries.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    data: Option<serde_json::Value>,
+    /// Machine-readable error code (see [`Error::code`]).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code: Option<&'static str>,
+}
+
+impl JsonOutput {
+    fn success(result: CommandResult) -> Self {
+        let mut output = Self {
+            ok: true,
+            message: None,
+            state: None,
+            data: None,
+            code: None,
+        };
+        match result {
+            CommandResult::Success(message) => output.message = Some(message),
+            CommandResult::State(state) => output.state = Some(state),
+            CommandResult::List(items) => output.data = Some(items.into()),
+            CommandResult::Filters(filters) => output.data = serde_json::to_value(filters).ok(),
+            CommandResult::Raw(value) => output.data = Some(value),
+            CommandResult::Steps(steps) => output.data = serde_json::to_value(steps).ok(),
+        }
+        output
+    }
+
+    const fn data(data: serde_json::Value) -> Self {
+        Self {
+            ok: true,
+            message: None,
+            state: None,
+            data: Some(data),
+            code: None,
+        }
+    }
+
+    fn failure(error: &Error) -> Self {
+        Self {
+            ok: false,
+            message: Some(error.to_string()),
+            state: None,
+            data: None,
+            code: Some(error.code()),
+        }
+    }
+
+    fn print(&self) {
+        if let Ok(json) = serde_json::to_string_pretty(self) {
+            println!("{json}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn to_json(output: &JsonOutput) -> serde_json::Value {
+        serde_json::to_value(output).unwrap()
+    }
+
+    /// Parses `fern-obs scene ...` into the command it sends.
+    fn parse_scene(args: &[&str]) -> std::result::Result<Command, clap::Error> {
+        let cli = Cli::try_parse_from(["fern-obs", "scene"].iter().chain(args))?;
+        let Commands::Scene {
+            name,
+            next,
+            transition,
+            duration_ms,
+            ..
+        } = cli.command
+        else {
+            unreachable!("parsed a scene command");
+        };
+        Ok(scene_command(name, next, transition, duration_ms))
+    }
+
+    #[test]
+    fn scene_takes_transition_override() {
+        assert_eq!(
+            parse_scene(&["Gaming", "--transition", "Fade", "--duration", "300"]).unwrap(),
+            Command::SetScene {
+                name: "Gaming".into(),
+                transition: Some("Fade".into()),
+                duration_ms: Some(300),
+            }
+        );
+        assert_eq!(
+            parse_scene(&["Gaming"]).unwrap(),
+            Command::SetScene {
+                name: "Gaming".into(),
+                transition: None,
+                duration_ms: None,
+            }
+        );
+        assert_eq!(parse_scene(&["--next"]).unwrap(), Command::NextScene);
+        assert_eq!(parse_scene(&["--prev"]).unwrap(), Command::PrevScene);
+
+        // An override needs a scene name
+        assert!(parse_scene(&["--next", "--transition", "Cut"]).is_err());
+        assert!(parse_scene(&["--prev", "--duration", "300"]).is_err());
+    }
+
+    #[test]
+    fn daemon_options_map_to_config() {
+        let cli = Cli::try_parse_from([
+            "fern-obs",
+            "--port",
+            "4460",
+            "daemon",
+            "--no-stats",
+            "--allow-raw",
+            "--auto-pause-scene",
+            "Private",
+        ])
+        .unwrap();
+        let Commands::Daemon(args) = cli.command else {
+            unreachable!("parsed a daemon command");
+        };
+        let base = ObsConfig {
+            port: cli.port,
+            ..Default::default()
+        };
+
+        let config = args.into_config(base);
+        assert_eq!(config.port, 4460);
+        assert!(!config.show_stats);
+        assert!(config.allow_raw);
+        assert_eq!(config.auto_pause_scenes, ["Private"]);
+    }
+
+    #[test]
+    fn log_level_flags_beat_rust_log() {
+        let level = |args: &[&str]| {
+            Cli::try_parse_from(["fern-obs"].iter().chain(args).chain(&["health"]))
+                .map(|cli| cli.level())
+        };
+        assert_eq!(level(&[]).unwrap(), None);
+        assert_eq!(level(&["--quiet"]).unwrap(), Some(LogLevel::Warn));
+        assert_eq!(
+            level(&["--log-level", "debug"]).unwrap(),
+            Some(LogLevel::Debug)
+        );
+        assert!(level(&["--quiet", "--log-level", "debug"]).is_err());
+
+        // Flag > RUST_LOG > default
+        assert_eq!(log_directive(Some(LogLevel::Warn), Some("debug")), "warn");
+        assert_eq!(
+            log_directive(None, Some("fern_obs=trace")),
+            "fern_obs=trace"
+        );
+        assert_eq!(log_directive(None, Some("")), "info");
+        assert_eq!(log_directive(None, None), "info");
+    }
+
+    #[test]
+    fn json_success_carries_message_or_state() {
+        let output = to_json(&JsonOutput::success(CommandResult::Success(
+            "Scene set to: Gaming".into(),
+        )));
+        assert_eq!(
+            output,
+            json!({ "ok": true, "message": "Scene set to: Gaming", "state": null })
+        );
+
+        let output = to_json(&JsonOutput::success(CommandResult::State(
+            ObsState::disconnected(),
+        )));
+        assert_eq!(output["ok"], true);
+        assert_eq!(output["state"]["connected"], false);
+        assert!(output.get("data").is_none());
+
+        let output = to_json(&JsonOutput::success(CommandResult::List(vec![
+            "Cut".into(),
+            "Fade".into(),
+        ])));
+        assert_eq!(output["data"], json!(["Cut", "Fade"]));
+    }
+
+    #[test]
+    fn json_failure_carries_error_code() {
+        let output = to_json(&JsonOutput::failure(&Error::CommandTimeout {
+            timeout_ms: 1000,
+        }));
+        assert_eq!(
+            output,
+            json!({
+                "ok": false,
+                "message": "command timed out after 1000ms (is OBS responding?)",
+                "state": null,
+                "code": "command_timeout",
+            })
+        );
+    }
+
+    #[test]
+    fn json_data_carries_value() {
+        let output = to_json(&JsonOutput::data(json!({ "connected": true })));
+        assert_eq!(
+            output,
+            json!({ "ok": true, "message": null, "state": null, "data": { "connected": true } })
+        );
+    }
+}
@@ -0,0 +1,98 @@
+//! Single-instance lock for the daemon.
+//!
+//! The daemon takes an advisory lock on `~/.local/state/fern/obs.pid` and
+//! writes its PID into it. A second daemon fails to take the lock and
+//! exits instead of fighting the first over the state file and control
+//! socket. The lock goes away with the process, so a crashed daemon never
+//! leaves a stale lock behind.
+
+use crate::error::{Error, Result};
+use nix::errno::Errno;
+use nix::fcntl::{Flock, FlockArg};
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, Write};
+use std::path::Path;
+
+/// A held PID file lock, released on drop.
+#[derive(Debug)]
+pub struct PidLock {
+    file: Flock<File>,
+}
+
+impl PidLock {
+    /// Locks the PID file at `path` and writes the current PID into it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::AlreadyRunning`] if another process holds the
+    /// lock, or an I/O error if the file cannot be opened or written.
+    pub fn acquire(path: &Path) -> Result<Self> {
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)
+            .map_err(|e| Error::io(format!("opening {}", path.display()), e))?;
+
+        let mut file = match Flock::lock(file, FlockArg::LockExclusiveNonblock) {
+            Ok(file) => file,
+            Err((mut file, Errno::EWOULDBLOCK)) => {
+                return Err(Error::AlreadyRunning {
+                    pid: read_pid(&mut file).unwrap_or(0),
+                });
+            }
+            Err((_, errno)) => {
+                return Err(Error::io("locking PID file", errno.into()));
+            }
+        };
+
+        file.set_len(0)
+            .and_then(|()| file.rewind())
+            .and_then(|()| writeln!(file, "{}", std::process::id()))
+            .and_then(|()| file.flush())
+            .map_err(|e| Error::io("writing PID file", e))?;
+
+        Ok(Self { file })
+    }
+}
+
+impl Drop for PidLock {
+    fn drop(&mut self) {
+        // Leave the file in place: removing it could let a daemon that
+        // already opened it lock a file nobody else can find.
+        let _ = self.file.set_len(0);
+    }
+}
+
+/// Reads the PID written in an open PID file.
+fn read_pid(file: &mut File) -> Option<u32> {
+    let mut content = String::new();
+    file.read_to_string(&mut content).ok()?;
+    content.trim().parse().ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn second_lock_reports_running_pid() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("obs.pid");
+
+        let lock = PidLock::acquire(&path).unwrap();
+        let pid = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(pid.trim(), std::process::id().to_string());
+
+        let err = PidLock::acquire(&path).unwrap_err();
+        assert!(
+            matches!(err, Error::AlreadyRunning { pid } if pid == std::process::id()),
+            "{err}"
+        );
+
+        drop(lock);
+        assert!(std::fs::read_to_string(&path).unwrap().is_empty());
+        assert!(PidLock::acquire(&path).is_ok());
+    }
+}
@@ -0,0 +1,243 @@
+//! Prometheus metrics output.
+//!
+//! `fern-obs status --format prometheus` prints the OBS state in the
+//! Prometheus text exposition format, with `HELP` and `TYPE` lines:
+//!
+//! ```text
+//! # HELP obs_connected Whether OBS could be reached.
+//! # TYPE obs_connected gauge
+//! obs_connected 1
+//! # HELP obs_recording_active Whether OBS is recording.
+//! # TYPE obs_recording_active gauge
+//! obs_recording_active 1
+//! ```
+//!
+//! Point `node_exporter`'s textfile collector at the output, e.g. from a
+//! systemd timer:
+//!
+//! ```bash
+//! fern-obs status --format prometheus > /var/lib/node_exporter/obs.prom.tmp
+//! mv /var/lib/node_exporter/obs.prom.tmp /var/lib/node_exporter/obs.prom
+//! ```
+//!
+//! While OBS is unreachable only `obs_connected 0` and the daemon's
+//! reconnect count are written, so stale recording metrics never linger.
+//! Stats metrics need stats collection (on unless the daemon runs with
+//! `--no-stats`).
+
+use crate::state::{ObsState, ObsStats, RecordingState, StreamingState};
+use std::fmt::{Display, Write};
+
+/// Renders an OBS state as Prometheus text exposition format.
+#[must_use]
+pub fn render(state: &ObsState) -> String {
+    let mut metrics = Metrics::default();
+
+    metrics.gauge(
+        "obs_connected",
+        "Whether OBS could be reached.",
+        u8::from(state.connected),
+    );
+    metrics.counter(
+        "obs_reconnects_total",
+        "Times the daemon has reconnected to OBS.",
+        state.reconnect_count,
+    );
+    if !state.connected {
+        return metrics.0;
+    }
+
+    recording_metrics(&mut metrics, &state.recording);
+    streaming_metrics(&mut metrics, &state.streaming);
+    if let Some(stats) = &state.stats {
+        stats_metrics(&mut metrics, stats);
+    }
+
+    metrics.0
+}
+
+/// Adds the `obs_recording_*` metrics.
+fn recording_metrics(metrics: &mut Metrics, recording: &RecordingState) {
+    metrics.gauge(
+        "obs_recording_active",
+        "Whether OBS is recording.",
+        u8::from(recording.active),
+    );
+    metrics.gauge(
+        "obs_recording_paused",
+        "Whether the recording is paused.",
+        u8::from(recording.paused),
+    );
+    metrics.gauge(
+        "obs_recording_duration_seconds",
+        "How long the current recording has run.",
+        recording.elapsed_secs,
+    );
+}
+
+/// Adds the `obs_streaming_*` metrics.
+fn streaming_metrics(metrics: &mut Metrics, streaming: &StreamingState) {
+    metrics.gauge(
+        "obs_streaming_active",
+        "Whether OBS is streaming.",
+        u8::from(streaming.active),
+    );
+    metrics.gauge(
+        "obs_streaming_reconnecting",
+        "Whether the stream is reconnecting.",
+        u8::from(streaming.reconnecting),
+    );
+    metrics.gauge(
+        "obs_streaming_duration_seconds",
+        "How long the current stream has run.",
+        streaming.elapsed_secs,
+    );
+    if let Some(bitrate) = streaming.bitrate_kbps {
+        metrics.gauge(
+            "obs_streaming_bitrate_kbps",
+            "Current stream bitrate in kilobits per second.",
+            bitrate,
+        );
+    }
+}
+
+/// Adds the resource and frame metrics from OBS's stats.
+fn stats_metrics(metrics: &mut Metrics, stats: &ObsStats) {
+    metrics.gauge(
+        "obs_cpu_usage",
+        "OBS CPU usage in percent.",
+        stats.cpu_usage,
+    );
+    metrics.gauge(
+        "obs_memory_megabytes",
+        "OBS memory usage in megabytes.",
+        stats.memory_mb,
+    );
+    if let Some(disk) = stats.available_disk_mb {
+        metrics.gauge(
+            "obs_available_disk_megabytes",
+            "Free space on the recording disk in megabytes.",
+            disk,
+        );
+    }
+    metrics.gauge(
+        "obs_active_fps",
+        "Frames rendered per second.",
+        stats.active_fps,
+    );
+    metrics.gauge(
+        "obs_average_frame_time_milliseconds",
+        "Average time to render a frame in milliseconds.",
+        stats.average_frame_time_ms,
+    );
+    metrics.counter(
+        "obs_render_missed_frames_total",
+        "Frames missed due to rendering lag.",
+        stats.render_missed_frames,
+    );
+    metrics.counter(
+        "obs_render_frames_total",
+        "Frames rendered.",
+        stats.render_total_frames,
+    );
+    metrics.counter(
+        "obs_output_skipped_frames_total",
+        "Frames skipped due to encoding lag.",
+        stats.output_skipped_frames,
+    );
+    metrics.counter(
+        "obs_output_frames_total",
+        "Frames output.",
+        stats.output_total_frames,
+    );
+    if let Some(percent) = stats.render_drop_percent {
+        metrics.gauge(
+            "obs_render_drop_percent",
+            "Rendered frames missed, in percent.",
+            percent,
+        );
+    }
+    if let Some(percent) = stats.output_drop_percent {
+        metrics.gauge(
+            "obs_output_drop_percent",
+            "Output frames skipped, in percent.",
+            percent,
+        );
+    }
+}
+
+/// Metrics text being built up.
+#[derive(Default)]
+struct Metrics(String);
+
+impl Metrics {
+    fn gauge(&mut self, name: &str, help: &str, value: impl Display) {
+        self.metric(name, "gauge", help, value);
+    }
+
+    fn counter(&mut self, name: &str, help: &str, value: impl Display) {
+        self.metric(name, "counter", help, value);
+    }
+
+    fn metric(&mut self, name: &str, kind: &str, help: &str, value: impl Display) {
+        // Writing to a String cannot fail
+        let _ = writeln!(
+            self.0,
+            "# HELP {name} {help}\n# TYPE {name} {kind}\n{name} {value}"
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn recording_state_with_stats() {
+        let mut obs_stats = ObsStats {
+            cpu_usage: 12.5,
+            render_missed_frames: 150,
+            render_total_frames: 300,
+            ..Default::default()
+        };
+        obs_stats.calculate_percentages();
+        let state = ObsState {
+            connected: true,
+            recording: RecordingState::active(754),
+            stats: Some(obs_stats),
+            ..Default::default()
+        };
+
+        let text = render(&state);
+        assert!(text.contains("# TYPE obs_recording_active gauge\nobs_recording_active 1\n"));
+        assert!(text.contains("obs_recording_duration_seconds 754\n"));
+        assert!(text.contains("obs_streaming_active 0\n"));
+        assert!(text.contains("obs_cpu_usage 12.5\n"));
+        assert!(text.contains("# TYPE obs_render_frames_total counter\n"));
+        assert!(text.contains("obs_render_drop_percent 50\n"));
+        // Only known values are reported
+        assert!(!text.contains("obs_streaming_bitrate_kbps"));
+        assert!(!text.contains("obs_available_disk_megabytes"));
+    }
+
+    #[test]
+    fn disconnected_state_reports_only_connection() {
+        let text = render(&ObsState::with_error("connection refused"));
+        assert!(text.contains("obs_connected 0\n"));
+        assert!(text.contains("obs_reconnects_total 0\n"));
+        assert!(!text.contains("obs_recording_active"));
+    }
+
+    #[test]
+    fn every_sample_has_help_and_type() {
+        let text = render(&ObsState {
+            connected: true,
+            ..Default::default()
+        });
+        for sample in text.lines().filter(|line| !line.starts_with('#')) {
+            let name = sample.split(' ').next().unwrap();
+            assert!(text.contains(&format!("# HELP {name} ")), "{name}");
+            assert!(text.contains(&format!("# TYPE {name} ")), "{name}");
+        }
+    }
+}
@@ -0,0 +1,344 @@
+//! Untyped obs-websocket requests.
+//!
+//! `fern-obs raw` sends any obs-websocket request type fern-obs does not
+//! model, such as `GetInputList` or `GetSourceScreenshot`, and prints the
+//! `responseData` OBS sends back. The CLI hands the request to the daemon
+//! over its control socket, and the daemon only runs it when started with
+//! `--allow-raw`:
+//!
+//! ```bash
+//! fern-obs daemon --allow-raw
+//! fern-obs raw GetInputList '{"inputKind":"wasapi_input_capture"}'
+//! ```
+//!
+//! obws can only send the request types it models: its `Client` builds
+//! requests from a closed enum and keeps the dispatcher that takes JSON
+//! private, and its `CallVendorRequest` only reaches vendor requests. So
+//! each raw request opens a short-lived connection of its own and speaks
+//! the obs-websocket 5 protocol directly: `Hello`, `Identify` (with the
+//! configured password), then a single `Request`. The WebSocket, SHA-256,
+//! and base64 crates this needs are the ones obws already builds on. Raw
+//! requests never update [`ObsState`](crate::state::ObsState).
+
+use crate::error::{Error, Result};
+use base64::engine::general_purpose::STANDARD as BASE64;
+use base64::Engine;
+use futures_util::{SinkExt, StreamExt};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use tokio_tungstenite::tungstenite::Message;
+
+/// obs-websocket RPC version spoken by raw requests.
+const RPC_VERSION: u64 = 1;
+
+/// `op` codes of the obs-websocket messages used here.
+mod op {
+    pub const HELLO: u64 = 0;
+    pub const IDENTIFY: u64 = 1;
+    pub const IDENTIFIED: u64 = 2;
+    pub const REQUEST: u64 = 6;
+    pub const REQUEST_RESPONSE: u64 = 7;
+}
+
+/// Id sent with the request, to match it to its response.
+const REQUEST_ID: &str = "fern-obs-raw";
+
+/// Parses the request type and JSON data given on the command line.
+///
+/// The type is an obs-websocket request name such as `GetVersion`; the
+/// data must be a JSON object.
+///
+/// # Errors
+///
+/// Returns [`Error::Config`] if the type is not a request name or the
+/// data is not a JSON object.
+pub fn parse_request(request_type: &str, request_data: &str) -> Result<(String, Value)> {
+    let request_type = request_type.trim();
+    if request_type.is_empty() || !request_type.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return Err(Error::Config(format!(
+            "'{request_type}' is not an obs-websocket request type (e.g. GetVersion)"
+        )));
+    }
+
+    let request_data: Value = serde_json::from_str(request_data)
+        .map_err(|e| Error::Config(format!("invalid request data: {e}")))?;
+    if !request_data.is_object() {
+        return Err(Error::Config("request data must be a JSON object".into()));
+    }
+
+    Ok((request_type.to_string(), request_data))
+}
+
+/// Sends one request over a new connection and returns its
+/// `responseData` (`null` when OBS sends none).
+///
+/// # Errors
+///
+/// Returns an error if the connection or authentication fails, or OBS
+/// rejects the request.
+pub async fn send(
+    host: &str,
+    port: u16,
+    password: Option<&str>,
+    request_type: &str,
+    request_data: &Value,
+) -> Result<Value> {
+    let (mut socket, _) = tokio_tungstenite::connect_async(format!("ws://{host}:{port}"))
+        .await
+        .map_err(|e| Error::connection(host, port, e.to_string()))?;
+
+    let hello = read_op(&mut socket, op::HELLO).await?;
+    let identify = identify_message(&hello, password)?;
+    write_json(&mut socket, &identify).await?;
+    read_op(&mut socket, op::IDENTIFIED).await?;
+
+    write_json(&mut socket, &request_message(request_type, request_data)).await?;
+    let response = read_op(&mut socket, op::REQUEST_RESPONSE).await?;
+    let _ = socket.close(None).await;
+
+    response_data(&response)
+}
+
+/// Builds the `Identify` message answering a `Hello`.
+fn identify_message(hello: &Value, password: Option<&str>) -> Result<Value> {
+    let mut identify = json!({
+        "rpcVersion": RPC_VERSION,
+        // No events; the connection only carries the one request
+        "eventSubscriptions": 0,
+    });
+
+    if let Some(auth) = hello.get("authentication") {
+        let password = password
+            .ok_or_else(|| Error::Auth("OBS requires a password (pass --password)".into()))?;
+        let (Some(challenge), Some(salt)) = (
+            auth.get("challenge").and_then(Value::as_str),
+            auth.get("salt").and_then(Value::as_str),
+        ) else {
+            return Err(Error::WebSocket("malformed Hello authentication".into()));
+        };
+        identify["authentication"] = auth_string(password, salt, challenge).into();
+    }
+
+    Ok(json!({ "op": op::IDENTIFY, "d": identify }))
+}
+
+/// Computes the obs-websocket authentication string:
+/// `base64(sha256(base64(sha256(password + salt)) + challenge))`.
+fn auth_string(password: &str, salt: &str, challenge: &str) -> String {
+    let secret = BASE64.encode(Sha256::digest(format!("{password}{salt}")));
+    BASE64.encode(Sha256::digest(format!("{secret}{challenge}")))
+}
+
+/// Builds the `Request` message. `null` data is sent as an empty object.
+fn request_message(request_type: &str, request_data: &Value) -> Value {
+    let request_data = if request_data.is_null() {
+        json!({})
+    } else {
+        request_data.clone()
+    };
+    json!({
+        "op": op::REQUEST,
+        "d": {
+            "requestType": request_type,
+            "requestId": REQUEST_ID,
+            "requestData": request_data,
+        },
+    })
+}
+
+/// Extracts `responseData` from a `RequestResponse`, failing if OBS
+/// reported an error.
+fn response_data(response: &Value) -> Result<Value> {
+    let status = response.get("requestStatus");
+    let succeeded = status
+        .and_then(|status| status.get("result"))
+        .and_then(Value::as_bool)
+        .unwrap_or(false);
+    if !succeeded {
+        let code = status
+            .and_then(|status| status.get("code"))
+            .and_then(Value::as_u64)
+            .unwrap_or_default();
+        let comment = status
+            .and_then(|status| status.get("comment"))
+            .and_then(Value::as_str)
+            .unwrap_or("no details");
+        return Err(Error::Request(format!("{comment} (code {code})")));
+    }
+
+    Ok(response.get("responseData").cloned().unwrap_or(Value::Null))
+}
+
+/// Sends a message as a JSON text frame.
+async fn write_json<S>(socket: &mut S, message: &Value) -> Result<()>
+where
+    S: SinkExt<Message> + Unpin,
+    S::Error: std::fmt::Display,
+{
+    socket
+        .send(Message::text(message.to_string()))
+        .await
+        .map_err(|e| Error::WebSocket(e.to_string()))
+}
+
+/// Reads messages until one with the expected `op` arrives and returns
+/// its `d` payload. Other ops are skipped.
+async fn read_op<S, E>(socket: &mut S, expected: u64) -> Result<Value>
+where
+    S: StreamExt<Item = std::result::Result<Message, E>> + Unpin,
+    E: std::fmt::Display,
+{
+    while let Some(message) = socket.next().await {
+        let message = message.map_err(|e| Error::WebSocket(e.to_string()))?;
+        let text = match message {
+            Message::Text(text) => text,
+            Message::Close(frame) => {
+                let reason = frame.map_or_else(String::new, |frame| frame.reason.to_string());
+                return Err(Error::WebSocket(format!(
+                    "OBS closed the connection: {reason}"
+                )));
+            }
+            _ => continue,
+        };
+        let mut value: Value =
+            serde_json::from_str(&text).map_err(|e| Error::Json(e.to_string()))?;
+        if value.get("op").and_then(Value::as_u64) == Some(expected) {
+            return Ok(value.get_mut("d").map(Value::take).unwrap_or_default());
+        }
+    }
+
+    Err(Error::WebSocket("OBS closed the connection".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_request_type_and_data() {
+        let (request_type, data) = parse_request(" GetInputList ", r#"{"inputKind":"x"}"#).unwrap();
+        assert_eq!(request_type, "GetInputList");
+        assert_eq!(data, json!({ "inputKind": "x" }));
+
+        let (_, data) = parse_request("GetVersion", "{}").unwrap();
+        assert_eq!(data, json!({}));
+    }
+
+    #[test]
+    fn rejects_malformed_requests() {
+        for (request_type, data) in [
+            ("", "{}"),
+            ("Get Version", "{}"),
+            ("obs:GetVersion", "{}"),
+            ("GetVersion", "not json"),
+            ("GetVersion", "[1, 2]"),
+        ] {
+            assert!(
+                matches!(parse_request(request_type, data), Err(Error::Config(_))),
+                "`{request_type}` `{data}` should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn auth_string_matches_protocol_example() {
+        // Worked example from the obs-websocket protocol documentation
+        assert_eq!(
+            auth_string(
+                "supersecretpassword",
+                "lM1GncleQOaCu9lT1yeUZhFYnqhsLLP1G5lAGo3ixaI=",
+                "+IxH4CnCiqpX1rM9scsNynZzbOe4KhDeYcTNS3PDaeY="
+            ),
+            "1Ct943GAT+6YQUUX47Ia/ncufilbe6+oD6lY+5kaCu4="
+        );
+    }
+
+    #[test]
+    fn identify_authenticates_only_when_asked() {
+        let open = identify_message(&json!({ "rpcVersion": 1 }), Some("pw")).unwrap();
+        assert_eq!(open["op"], op::IDENTIFY);
+        assert!(open["d"].get("authentication").is_none());
+
+        let hello = json!({
+            "rpcVersion": 1,
+            "authentication": { "challenge": "c", "salt": "s" },
+        });
+        let identify = identify_message(&hello, Some("pw")).unwrap();
+        assert_eq!(identify["d"]["authentication"], auth_string("pw", "s", "c"));
+        assert!(matches!(
+            identify_message(&hello, None),
+            Err(Error::Auth(_))
+        ));
+    }
+
+    #[test]
+    fn response_data_reports_failures() {
+        let ok = json!({
+            "requestStatus": { "result": true, "code": 100 },
+            "responseData": { "obsVersion": "30.0.0" },
+        });
+        assert_eq!(response_data(&ok).unwrap()["obsVersion"], "30.0.0");
+
+        let empty = json!({ "requestStatus": { "result": true, "code": 100 } });
+        assert_eq!(response_data(&empty).unwrap(), Value::Null);
+
+        let failed = json!({
+            "requestStatus": { "result": false, "code": 204, "comment": "Unknown request type" },
+        });
+        let error = response_data(&failed).unwrap_err().to_string();
+        assert!(error.contains("Unknown request type (code 204)"), "{error}");
+    }
+
+    #[tokio::test]
+    async fn sends_request_over_its_own_session() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        // A minimal obs-websocket server with authentication
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut socket = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let hello = json!({
+                "op": op::HELLO,
+                "d": { "rpcVersion": 1, "authentication": { "challenge": "c", "salt": "s" } },
+            });
+            write_json(&mut socket, &hello).await.unwrap();
+
+            let identify = read_op(&mut socket, op::IDENTIFY).await.unwrap();
+            assert_eq!(identify["authentication"], auth_string("pw", "s", "c"));
+            write_json(&mut socket, &json!({ "op": op::IDENTIFIED, "d": {} }))
+                .await
+                .unwrap();
+
+            let request = read_op(&mut socket, op::REQUEST).await.unwrap();
+            assert_eq!(request["requestType"], "GetVersion");
+            let response = json!({
+                "op": op::REQUEST_RESPONSE,
+                "d": {
+                    "requestType": "GetVersion",
+                    "requestId": request["requestId"],
+                    "requestStatus": { "result": true, "code": 100 },
+                    "responseData": { "obsVersion": "30.0.0" },
+                },
+            });
+            write_json(&mut socket, &response).await.unwrap();
+        });
+
+        let data = send("127.0.0.1", port, Some("pw"), "GetVersion", &Value::Null)
+            .await
+            .unwrap();
+        assert_eq!(data["obsVersion"], "30.0.0");
+        server.await.unwrap();
+    }
+
+    #[test]
+    fn request_message_carries_type_and_data() {
+        let message = request_message("GetInputList", &json!({ "inputKind": "x" }));
+        assert_eq!(message["op"], op::REQUEST);
+        assert_eq!(message["d"]["requestType"], "GetInputList");
+        assert_eq!(message["d"]["requestData"]["inputKind"], "x");
+
+        let message = request_message("GetVersion", &Value::Null);
+        assert_eq!(message["d"]["requestData"], json!({}));
+    }
+}
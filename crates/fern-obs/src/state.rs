@@ -3,8 +3,9 @@
 //! These types represent the current state of OBS Studio and are serialized
 //! to JSON for the QML interface to consume via `FileView`.
 
+use crate::stats_history::StatsPoint;
 use serde::{Deserialize, Serialize};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 /// Complete OBS state written to the state file.
 ///
@@ -29,14 +30,41 @@ pub struct ObsState {
     #[serde(default)]
     pub scenes: Vec<String>,
 
+    /// Directory OBS saves recordings to.
+    ///
+    /// Kept while disconnected, so `fern-obs open-recordings` still works
+    /// when OBS is closed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_directory: Option<String>,
+
     /// Performance statistics.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stats: Option<ObsStats>,
 
+    /// Recent CPU and FPS samples, oldest first, for sparklines.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stats_history: Vec<StatsPoint>,
+
     /// Error message if connection failed.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
 
+    /// Warning for the user while connected, e.g. low disk space.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub warning: Option<String>,
+
+    /// Times the daemon has reconnected since its first connection.
+    #[serde(default)]
+    pub reconnect_count: u32,
+
+    /// Unix timestamp when the last connection was established.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_connected_secs: Option<u64>,
+
+    /// Unix timestamp when the last connection was lost.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_disconnected_secs: Option<u64>,
+
     /// Unix timestamp when this state was last updated.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub updated_at_secs: Option<u64>,
@@ -62,14 +90,52 @@ impl ObsState {
         }
     }
 
+    /// Returns a warning if a recording is running with less than
+    /// `threshold_mb` of disk space left.
+    ///
+    /// A threshold of 0 never warns.
+    #[must_use]
+    pub fn low_disk_warning(&self, threshold_mb: u32) -> Option<String> {
+        if !self.recording.active {
+            return None;
+        }
+        let available = self.stats.as_ref()?.available_disk_mb?;
+        (available < f64::from(threshold_mb))
+            .then(|| format!("Low disk space: {available:.0} MB left"))
+    }
+
+    /// Returns the scene after the current one, wrapping to the first.
+    ///
+    /// If the current scene is unknown or not in [`scenes`](Self::scenes),
+    /// returns the first scene. Returns `None` when there are no scenes.
+    #[must_use]
+    pub fn next_scene(&self) -> Option<&str> {
+        self.cycle_scene(1)
+    }
+
+    /// Returns the scene before the current one, wrapping to the last.
+    ///
+    /// If the current scene is unknown or not in [`scenes`](Self::scenes),
+    /// returns the first scene. Returns `None` when there are no scenes.
+    #[must_use]
+    pub fn prev_scene(&self) -> Option<&str> {
+        self.cycle_scene(self.scenes.len().saturating_sub(1))
+    }
+
+    /// Steps `offset` places forward through the scene list.
+    fn cycle_scene(&self, offset: usize) -> Option<&str> {
+        let len = self.scenes.len();
+        let index = self
+            .current_scene
+            .as_ref()
+            .and_then(|current| self.scenes.iter().position(|s| s == current))
+            .map_or(0, |i| (i + offset) % len);
+        self.scenes.get(index).map(String::as_str)
+    }
+
     /// Updates the timestamp to now.
     pub fn touch(&mut self) {
-        self.updated_at_secs = Some(
-            std::time::SystemTime::now()
-                .duration_since(std::time::UNIX_EPOCH)
-                .map(|d| d.as_secs())
-                .unwrap_or(0),
-        );
+        self.updated_at_secs = Some(unix_now());
     }
 }
 
@@ -225,16 +291,46 @@ impl ObsStats {
     /// Calculates drop percentages from frame counts.
     pub fn calculate_percentages(&mut self) {
         if self.render_total_frames > 0 {
-            self.render_drop_percent = Some(
-                (self.render_missed_frames as f64 / self.render_total_frames as f64) * 100.0,
-            );
+            self.render_drop_percent =
+                Some((self.render_missed_frames as f64 / self.render_total_frames as f64) * 100.0);
         }
         if self.output_total_frames > 0 {
-            self.output_drop_percent = Some(
-                (self.output_skipped_frames as f64 / self.output_total_frames as f64) * 100.0,
-            );
+            self.output_drop_percent =
+                Some((self.output_skipped_frames as f64 / self.output_total_frames as f64) * 100.0);
         }
     }
+
+    /// Returns the stats as one line, for `fern-obs stats`.
+    ///
+    /// Drop rates and free disk space appear only when known.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let mut parts = vec![
+            format!("CPU {:.1}%", self.cpu_usage),
+            format!("{:.1} fps", self.active_fps),
+            format!("{:.2} ms/frame", self.average_frame_time_ms),
+            format!("{:.0} MB RAM", self.memory_mb),
+        ];
+        if let Some(render) = self.render_drop_percent {
+            parts.push(format!("render drops {render:.2}%"));
+        }
+        if let Some(output) = self.output_drop_percent {
+            parts.push(format!("output drops {output:.2}%"));
+        }
+        if let Some(disk) = self.available_disk_mb {
+            parts.push(format!("{disk:.0} MB free"));
+        }
+        parts.join(" | ")
+    }
+}
+
+/// Returns the current Unix time in seconds, or 0 if the clock is set
+/// before 1970.
+#[must_use]
+pub fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
 }
 
 /// Internal state tracker with timing information.
@@ -249,6 +345,12 @@ pub struct StateTracker {
     /// When recording started (for elapsed time calculation).
     recording_started: Option<Instant>,
 
+    /// When the current recording pause began, if paused.
+    recording_paused_at: Option<Instant>,
+
+    /// Time spent paused in earlier pauses of this recording.
+    recording_paused_total: Duration,
+
     /// When streaming started (for elapsed time calculation).
     streaming_started: Option<Instant>,
 }
@@ -260,52 +362,119 @@ impl StateTracker {
         Self {
             state: ObsState::disconnected(),
             recording_started: None,
+            recording_paused_at: None,
+            recording_paused_total: Duration::ZERO,
             streaming_started: None,
         }
     }
 
     /// Marks as connected.
+    ///
+    /// A new connection after an earlier one counts as a reconnect.
     pub fn set_connected(&mut self) {
+        if !self.state.connected {
+            if self.state.last_connected_secs.is_some() {
+                self.state.reconnect_count += 1;
+            }
+            self.state.last_connected_secs = Some(unix_now());
+        }
         self.state.connected = true;
         self.state.error = None;
     }
 
     /// Marks as disconnected with optional error.
+    ///
+    /// Losing an established connection records the time it was lost.
     pub fn set_disconnected(&mut self, error: Option<String>) {
+        if self.state.connected {
+            self.state.last_disconnected_secs = Some(unix_now());
+        }
         self.state.connected = false;
         self.state.error = error;
+        self.state.warning = None;
+        self.state.stats_history.clear();
         self.state.recording = RecordingState::idle();
         self.state.streaming = StreamingState::idle();
-        self.recording_started = None;
+        self.reset_recording_timer();
         self.streaming_started = None;
     }
 
     /// Starts recording timer.
     pub fn start_recording(&mut self) {
-        self.recording_started = Some(Instant::now());
+        self.start_recording_at(Duration::ZERO);
+    }
+
+    /// Starts the recording timer for a recording that has already run
+    /// for `elapsed`, so the timecode picks up where OBS is.
+    pub fn start_recording_at(&mut self, elapsed: Duration) {
+        self.reset_recording_timer();
+        self.recording_started = Some(started_before(Instant::now(), elapsed));
         self.state.recording.active = true;
         self.state.recording.paused = false;
     }
 
     /// Stops recording timer.
     pub fn stop_recording(&mut self) {
-        self.recording_started = None;
+        self.reset_recording_timer();
         self.state.recording = RecordingState::idle();
     }
 
     /// Pauses recording.
+    ///
+    /// The timer stops counting until [`resume_recording`](Self::resume_recording).
+    /// Pausing while already paused does nothing.
     pub fn pause_recording(&mut self) {
-        self.state.recording.paused = true;
+        self.pause_recording_at(Instant::now());
     }
 
     /// Resumes recording.
     pub fn resume_recording(&mut self) {
+        self.resume_recording_at(Instant::now());
+    }
+
+    const fn pause_recording_at(&mut self, now: Instant) {
+        if self.recording_paused_at.is_none() {
+            self.recording_paused_at = Some(now);
+        }
+        self.state.recording.paused = true;
+    }
+
+    fn resume_recording_at(&mut self, now: Instant) {
+        if let Some(paused_at) = self.recording_paused_at.take() {
+            self.recording_paused_total += now.saturating_duration_since(paused_at);
+        }
         self.state.recording.paused = false;
     }
 
+    const fn reset_recording_timer(&mut self) {
+        self.recording_started = None;
+        self.recording_paused_at = None;
+        self.recording_paused_total = Duration::ZERO;
+    }
+
+    /// Returns the recorded time at `now`, excluding time spent paused.
+    fn recording_elapsed(&self, now: Instant) -> Option<Duration> {
+        let started = self.recording_started?;
+        let paused_now = self
+            .recording_paused_at
+            .map_or(Duration::ZERO, |at| now.saturating_duration_since(at));
+
+        Some(
+            now.saturating_duration_since(started)
+                .saturating_sub(self.recording_paused_total)
+                .saturating_sub(paused_now),
+        )
+    }
+
     /// Starts streaming timer.
     pub fn start_streaming(&mut self) {
-        self.streaming_started = Some(Instant::now());
+        self.start_streaming_at(Duration::ZERO);
+    }
+
+    /// Starts the streaming timer for a stream that has already been live
+    /// for `elapsed`.
+    pub fn start_streaming_at(&mut self, elapsed: Duration) {
+        self.streaming_started = Some(started_before(Instant::now(), elapsed));
         self.state.streaming.active = true;
     }
 
@@ -317,12 +486,10 @@ impl StateTracker {
 
     /// Updates elapsed times and returns the current state.
     pub fn update_elapsed(&mut self) -> &ObsState {
-        if let Some(started) = self.recording_started {
-            if !self.state.recording.paused {
-                let elapsed = started.elapsed().as_secs();
-                self.state.recording.elapsed_secs = elapsed;
-                self.state.recording.timecode = Some(RecordingState::format_timecode(elapsed));
-            }
+        if let Some(elapsed) = self.recording_elapsed(Instant::now()) {
+            let elapsed = elapsed.as_secs();
+            self.state.recording.elapsed_secs = elapsed;
+            self.state.recording.timecode = Some(RecordingState::format_timecode(elapsed));
         }
 
         if let Some(started) = self.streaming_started {
@@ -345,6 +512,11 @@ impl StateTracker {
         self.state.scenes = scenes;
     }
 
+    /// Sets the directory OBS saves recordings to.
+    pub fn set_record_directory(&mut self, directory: impl Into<String>) {
+        self.state.record_directory = Some(directory.into());
+    }
+
     /// Sets performance stats.
     pub fn set_stats(&mut self, mut stats: ObsStats) {
         stats.calculate_percentages();
@@ -358,6 +530,13 @@ impl Default for StateTracker {
     }
 }
 
+/// Returns the instant `elapsed` before `now`.
+///
+/// Falls back to `now` if that would predate the monotonic clock's origin.
+fn started_before(now: Instant, elapsed: Duration) -> Instant {
+    now.checked_sub(elapsed).unwrap_or(now)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -554,6 +733,29 @@ mod tests {
         assert!((stats.output_drop_percent.unwrap() - 0.0).abs() < 0.001);
     }
 
+    #[test]
+    fn obs_stats_summary() {
+        let mut stats = ObsStats {
+            cpu_usage: 12.34,
+            memory_mb: 512.4,
+            active_fps: 60.0,
+            average_frame_time_ms: 2.1,
+            render_total_frames: 1000,
+            ..Default::default()
+        };
+        assert_eq!(
+            stats.summary(),
+            "CPU 12.3% | 60.0 fps | 2.10 ms/frame | 512 MB RAM"
+        );
+
+        stats.calculate_percentages();
+        stats.available_disk_mb = Some(20480.0);
+        assert_eq!(
+            stats.summary(),
+            "CPU 12.3% | 60.0 fps | 2.10 ms/frame | 512 MB RAM | render drops 0.00% | 20480 MB free"
+        );
+    }
+
     // ========================================================================
     // StateTracker tests
     // ========================================================================
@@ -570,6 +772,23 @@ mod tests {
         assert!(!tracker.state.connected);
     }
 
+    #[test]
+    fn reconnects_are_counted() {
+        let mut tracker = StateTracker::new();
+        tracker.set_disconnected(Some("refused".into()));
+        assert_eq!(tracker.state.last_disconnected_secs, None);
+
+        tracker.set_connected();
+        tracker.set_connected();
+        assert_eq!(tracker.state.reconnect_count, 0);
+        assert!(tracker.state.last_connected_secs.is_some());
+
+        tracker.set_disconnected(None);
+        assert!(tracker.state.last_disconnected_secs.is_some());
+        tracker.set_connected();
+        assert_eq!(tracker.state.reconnect_count, 1);
+    }
+
     #[test]
     fn state_tracker_recording_lifecycle() {
         let mut tracker = StateTracker::new();
@@ -591,6 +810,74 @@ mod tests {
         assert!(!tracker.state.recording.active);
     }
 
+    #[test]
+    fn started_before_subtracts_elapsed() {
+        let now = Instant::now();
+        let elapsed = Duration::from_secs(90);
+        assert_eq!(now.duration_since(started_before(now, elapsed)), elapsed);
+        assert_eq!(started_before(now, Duration::ZERO), now);
+    }
+
+    #[test]
+    fn seeded_timers_keep_obs_elapsed_time() {
+        let mut tracker = StateTracker::new();
+        tracker.start_recording_at(Duration::from_secs(3600));
+        tracker.start_streaming_at(Duration::from_secs(61));
+        tracker.update_elapsed();
+
+        // Allow for a slow test runner crossing a second boundary
+        assert!((3600..3602).contains(&tracker.state.recording.elapsed_secs));
+        assert!((61..63).contains(&tracker.state.streaming.elapsed_secs));
+        assert!(tracker
+            .state
+            .recording
+            .timecode
+            .as_deref()
+            .is_some_and(|tc| tc.starts_with("01:00:0")));
+    }
+
+    #[test]
+    fn recording_elapsed_excludes_paused_time() {
+        let mut tracker = StateTracker::new();
+        tracker.start_recording();
+        let start = tracker.recording_started.unwrap();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        // Record 10s, pause 5s, resume 10s
+        tracker.pause_recording_at(at(10));
+        assert_eq!(
+            tracker.recording_elapsed(at(12)),
+            Some(Duration::from_secs(10))
+        );
+        tracker.resume_recording_at(at(15));
+        assert_eq!(
+            tracker.recording_elapsed(at(25)),
+            Some(Duration::from_secs(20))
+        );
+    }
+
+    #[test]
+    fn repeated_pauses_accumulate() {
+        let mut tracker = StateTracker::new();
+        tracker.start_recording();
+        let start = tracker.recording_started.unwrap();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        tracker.pause_recording_at(at(10));
+        // A second pause request keeps the original pause start
+        tracker.pause_recording_at(at(12));
+        tracker.resume_recording_at(at(15));
+        tracker.pause_recording_at(at(20));
+        tracker.resume_recording_at(at(30));
+        assert_eq!(
+            tracker.recording_elapsed(at(40)),
+            Some(Duration::from_secs(25))
+        );
+
+        tracker.stop_recording();
+        assert_eq!(tracker.recording_elapsed(at(40)), None);
+    }
+
     #[test]
     fn state_tracker_streaming_lifecycle() {
         let mut tracker = StateTracker::new();
@@ -624,6 +911,20 @@ mod tests {
         assert!(!tracker.state.streaming.active);
     }
 
+    #[test]
+    fn record_directory_survives_disconnect() {
+        let mut tracker = StateTracker::new();
+        tracker.set_connected();
+        tracker.set_record_directory("/home/user/Videos");
+
+        tracker.set_disconnected(None);
+
+        assert_eq!(
+            tracker.state.record_directory.as_deref(),
+            Some("/home/user/Videos")
+        );
+    }
+
     #[test]
     fn state_tracker_disconnected_without_error() {
         let mut tracker = StateTracker::new();
@@ -645,6 +946,38 @@ mod tests {
         assert_eq!(tracker.state.current_scene.as_deref(), Some("Desktop"));
     }
 
+    #[test]
+    fn scene_cycling_wraps() {
+        let mut state = ObsState {
+            scenes: vec!["Gaming".into(), "Desktop".into(), "BRB".into()],
+            current_scene: Some("BRB".into()),
+            ..Default::default()
+        };
+        assert_eq!(state.next_scene(), Some("Gaming"));
+        assert_eq!(state.prev_scene(), Some("Desktop"));
+
+        state.current_scene = Some("Gaming".into());
+        assert_eq!(state.next_scene(), Some("Desktop"));
+        assert_eq!(state.prev_scene(), Some("BRB"));
+    }
+
+    #[test]
+    fn scene_cycling_unknown_current_jumps_to_first() {
+        let mut state = ObsState {
+            scenes: vec!["Gaming".into(), "Desktop".into()],
+            current_scene: Some("Deleted".into()),
+            ..Default::default()
+        };
+        assert_eq!(state.next_scene(), Some("Gaming"));
+        assert_eq!(state.prev_scene(), Some("Gaming"));
+
+        state.current_scene = None;
+        assert_eq!(state.next_scene(), Some("Gaming"));
+
+        state.scenes.clear();
+        assert_eq!(state.next_scene(), None);
+    }
+
     #[test]
     fn state_tracker_scenes_list() {
         let mut tracker = StateTracker::new();
@@ -709,8 +1042,7 @@ mod tests {
                 active_fps: 60.0,
                 ..Default::default()
             }),
-            error: None,
-            updated_at_secs: None,
+            ..Default::default()
         };
         state.touch();
 
@@ -719,13 +1051,36 @@ mod tests {
 
         assert_eq!(deserialized.connected, state.connected);
         assert_eq!(deserialized.recording.active, state.recording.active);
-        assert_eq!(deserialized.recording.elapsed_secs, state.recording.elapsed_secs);
+        assert_eq!(
+            deserialized.recording.elapsed_secs,
+            state.recording.elapsed_secs
+        );
         assert_eq!(deserialized.streaming.active, state.streaming.active);
         assert_eq!(deserialized.current_scene, state.current_scene);
         assert_eq!(deserialized.scenes, state.scenes);
         assert!(deserialized.stats.is_some());
     }
 
+    #[test]
+    fn low_disk_warning_only_while_recording() {
+        let mut state = ObsState {
+            stats: Some(ObsStats {
+                available_disk_mb: Some(800.0),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        assert_eq!(state.low_disk_warning(1024), None);
+
+        state.recording = RecordingState::active(10);
+        assert_eq!(
+            state.low_disk_warning(1024).as_deref(),
+            Some("Low disk space: 800 MB left")
+        );
+        assert_eq!(state.low_disk_warning(512), None);
+        assert_eq!(state.low_disk_warning(0), None);
+    }
+
     #[test]
     fn obs_state_serialization_skips_none_fields() {
         let state = ObsState::disconnected();
@@ -735,6 +1090,7 @@ mod tests {
         assert!(!json.contains("current_scene"));
         assert!(!json.contains("stats"));
         assert!(!json.contains("error"));
+        assert!(!json.contains("record_directory"));
         assert!(!json.contains("updated_at_secs"));
     }
 
@@ -766,5 +1122,6 @@ mod tests {
 
         assert!(!state.connected);
         assert!(state.current_scene.is_none());
+        assert!(state.record_directory.is_none());
     }
 }
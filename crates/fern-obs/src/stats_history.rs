@@ -0,0 +1,170 @@
+//! Recent CPU and FPS samples for sparklines.
+//!
+//! The daemon keeps the last few stats samples in a [`StatsHistory`] and
+//! writes a downsampled copy to the state file as
+//! [`ObsState::stats_history`](crate::state::ObsState::stats_history), so
+//! the UI can draw a small graph next to the current values.
+
+use crate::state::ObsStats;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::time::Instant;
+
+/// Default number of samples kept (one minute at the default interval).
+pub const DEFAULT_STATS_HISTORY: usize = 60;
+
+/// Maximum number of points written to the state file.
+pub const STATS_HISTORY_POINTS: usize = 30;
+
+/// One point of the stats history.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StatsPoint {
+    /// Seconds between this point and the latest update.
+    pub secs_ago: u64,
+    /// CPU usage percentage (0-100).
+    pub cpu: f64,
+    /// Active FPS.
+    pub fps: f64,
+}
+
+/// A bounded buffer of recent CPU and FPS samples.
+#[derive(Debug, Clone)]
+pub struct StatsHistory {
+    samples: VecDeque<(Instant, f64, f64)>,
+    capacity: usize,
+}
+
+impl StatsHistory {
+    /// Creates a history of the last `capacity` samples.
+    ///
+    /// A capacity of 0 keeps nothing.
+    #[must_use]
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Records a stats sample taken now.
+    pub fn push(&mut self, stats: &ObsStats) {
+        self.push_at(Instant::now(), stats);
+    }
+
+    /// Records a stats sample taken at `at`.
+    fn push_at(&mut self, at: Instant, stats: &ObsStats) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples
+            .push_back((at, stats.cpu_usage, stats.active_fps));
+    }
+
+    /// Clears the history.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+    }
+
+    /// Returns the history as at most `max_points` points, oldest first.
+    ///
+    /// When there are more samples than points, neighbouring samples are
+    /// averaged so the whole window still fits.
+    #[must_use]
+    pub fn points(&self, max_points: usize) -> Vec<StatsPoint> {
+        self.points_at(Instant::now(), max_points)
+    }
+
+    fn points_at(&self, now: Instant, max_points: usize) -> Vec<StatsPoint> {
+        let len = self.samples.len();
+        let buckets = len.min(max_points);
+
+        (0..buckets)
+            .map(|bucket| {
+                let start = bucket * len / buckets;
+                let end = (bucket + 1) * len / buckets;
+                let (mut cpu, mut fps, mut count) = (0.0, 0.0, 0.0);
+                let mut last = now;
+                for &(at, sample_cpu, sample_fps) in self.samples.range(start..end) {
+                    cpu += sample_cpu;
+                    fps += sample_fps;
+                    count += 1.0;
+                    last = at;
+                }
+                StatsPoint {
+                    secs_ago: now.saturating_duration_since(last).as_secs(),
+                    cpu: cpu / count,
+                    fps: fps / count,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Default for StatsHistory {
+    fn default() -> Self {
+        Self::new(DEFAULT_STATS_HISTORY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn stats(cpu_usage: f64, active_fps: f64) -> ObsStats {
+        ObsStats {
+            cpu_usage,
+            active_fps,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn history_is_bounded() {
+        let start = Instant::now();
+        let mut history = StatsHistory::new(3);
+        for secs in 0..5 {
+            history.push_at(start + Duration::from_secs(secs), &stats(1.0, 60.0));
+        }
+
+        let points = history.points_at(start + Duration::from_secs(4), 10);
+        let secs_ago: Vec<u64> = points.iter().map(|p| p.secs_ago).collect();
+        assert_eq!(secs_ago, vec![2, 1, 0]);
+    }
+
+    #[test]
+    fn points_are_downsampled_by_averaging() {
+        let start = Instant::now();
+        let mut history = StatsHistory::new(4);
+        for (secs, cpu) in [(0, 1.0), (1, 3.0), (2, 5.0), (3, 7.0)] {
+            history.push_at(start + Duration::from_secs(secs), &stats(cpu, 60.0));
+        }
+
+        let points = history.points_at(start + Duration::from_secs(3), 2);
+        assert_eq!(
+            points,
+            vec![
+                StatsPoint {
+                    secs_ago: 2,
+                    cpu: 2.0,
+                    fps: 60.0
+                },
+                StatsPoint {
+                    secs_ago: 0,
+                    cpu: 6.0,
+                    fps: 60.0
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn zero_capacity_keeps_nothing() {
+        let mut history = StatsHistory::new(0);
+        history.push(&stats(1.0, 60.0));
+        assert!(history.points(STATS_HISTORY_POINTS).is_empty());
+    }
+}
@@ -0,0 +1,149 @@
+//! Waybar custom module output.
+//!
+//! `fern-obs status --waybar` prints one line in waybar's custom module
+//! JSON format, so OBS state can sit in the bar:
+//!
+//! ```json
+//! {"text":"⏺ 12:34","tooltip":"Scene: Gaming\nRecording: 12:34","class":["recording"]}
+//! ```
+//!
+//! The classes (`recording`, `paused`, `streaming`, `idle`,
+//! `disconnected`) can be styled from waybar's CSS. Polybar can use the
+//! same output through a small `jq` filter.
+
+use crate::state::{ObsState, RecordingState};
+use serde::Serialize;
+
+/// One update for a waybar custom module (`return-type: json`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct WaybarOutput {
+    /// Text shown in the bar. Empty while disconnected, which hides the
+    /// module.
+    pub text: String,
+    /// Tooltip shown on hover.
+    pub tooltip: String,
+    /// CSS classes for the module.
+    pub class: Vec<&'static str>,
+}
+
+impl WaybarOutput {
+    /// Builds the module output for an OBS state.
+    #[must_use]
+    pub fn from_state(state: &ObsState) -> Self {
+        if !state.connected {
+            return Self {
+                text: String::new(),
+                tooltip: state
+                    .error
+                    .as_ref()
+                    .map_or_else(|| "OBS: disconnected".to_string(), |e| format!("OBS: {e}")),
+                class: vec!["disconnected"],
+            };
+        }
+
+        let recording = &state.recording;
+        let streaming = &state.streaming;
+        let mut text = Vec::new();
+        let mut class = Vec::new();
+        let mut tooltip = Vec::new();
+
+        if let Some(scene) = &state.current_scene {
+            tooltip.push(format!("Scene: {scene}"));
+        }
+
+        if recording.active {
+            let timecode = timecode(recording.timecode.as_deref(), recording.elapsed_secs);
+            if recording.paused {
+                text.push(format!("⏸ {timecode}"));
+                class.push("paused");
+                tooltip.push(format!("Recording: {timecode} (paused)"));
+            } else {
+                text.push(format!("⏺ {timecode}"));
+                class.push("recording");
+                tooltip.push(format!("Recording: {timecode}"));
+            }
+        }
+
+        if streaming.active {
+            let timecode = timecode(streaming.timecode.as_deref(), streaming.elapsed_secs);
+            text.push(format!("◉ LIVE {timecode}"));
+            class.push("streaming");
+            tooltip.push(format!("Streaming: {timecode}"));
+        }
+
+        if class.is_empty() {
+            text.push("⏹".to_string());
+            class.push("idle");
+            tooltip.push("Idle".to_string());
+        }
+
+        if let Some(warning) = &state.warning {
+            class.push("warning");
+            tooltip.push(format!("Warning: {warning}"));
+        }
+
+        Self {
+            text: text.join("  "),
+            tooltip: tooltip.join("\n"),
+            class,
+        }
+    }
+}
+
+/// Returns the state's timecode, or formats it from the elapsed seconds.
+fn timecode(timecode: Option<&str>, elapsed_secs: u64) -> String {
+    timecode.map_or_else(
+        || RecordingState::format_timecode(elapsed_secs),
+        str::to_string,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn connected() -> ObsState {
+        ObsState {
+            connected: true,
+            current_scene: Some("Gaming".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn recording_shows_timecode() {
+        let mut state = connected();
+        state.recording = RecordingState::active(754);
+
+        let output = WaybarOutput::from_state(&state);
+        assert_eq!(output.text, "⏺ 12:34");
+        assert_eq!(output.class, vec!["recording"]);
+        assert_eq!(output.tooltip, "Scene: Gaming\nRecording: 12:34");
+    }
+
+    #[test]
+    fn classes_follow_state() {
+        let mut state = connected();
+        assert_eq!(WaybarOutput::from_state(&state).class, vec!["idle"]);
+
+        state.recording = RecordingState::paused(10);
+        state.streaming.active = true;
+        state.warning = Some("Low disk space: 812 MB left".into());
+        assert_eq!(
+            WaybarOutput::from_state(&state).class,
+            vec!["paused", "streaming", "warning"]
+        );
+
+        let output = WaybarOutput::from_state(&ObsState::disconnected());
+        assert_eq!(output.text, "");
+        assert_eq!(output.class, vec!["disconnected"]);
+    }
+
+    #[test]
+    fn wire_format_matches_waybar() {
+        let json = serde_json::to_value(WaybarOutput::from_state(&connected())).unwrap();
+        assert_eq!(json["text"], "⏹");
+        assert_eq!(json["class"], serde_json::json!(["idle"]));
+        assert!(json["tooltip"].is_string());
+    }
+}
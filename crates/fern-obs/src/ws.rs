@@ -0,0 +1,173 @@
+//! WebSocket feed of the daemon's state, for browser overlays.
+//!
+//! Built with the `websocket` feature, the daemon can push its state to
+//! WebSocket clients instead of making them poll the state file:
+//!
+//! ```bash
+//! fern-obs daemon --ws-port 4457
+//! ```
+//!
+//! ```js
+//! const obs = new WebSocket("ws://127.0.0.1:4457");
+//! obs.onmessage = (event) => {
+//!   const state = JSON.parse(event.data);
+//!   recDot.hidden = !state.recording.active;
+//! };
+//! ```
+//!
+//! ## Messages
+//!
+//! Every message is a text frame holding the whole
+//! [`ObsState`](crate::state::ObsState) as JSON, the same document as the
+//! state file. A client gets the current state as soon as it connects and
+//! the new state after each change the daemon writes; unchanged states are
+//! not repeated.
+//!
+//! The feed is read-only. Messages from clients are ignored, apart from
+//! closing the connection. It listens on `127.0.0.1` unless `--ws-bind`
+//! says otherwise.
+
+use crate::error::{Error, Result};
+use futures_util::{SinkExt, StreamExt};
+use std::net::SocketAddr;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::watch;
+use tokio_tungstenite::tungstenite::{self, Message};
+use tracing::{debug, warn};
+
+/// Binds the WebSocket listener.
+///
+/// # Errors
+///
+/// Returns an error if the address cannot be bound.
+pub async fn bind(addr: SocketAddr) -> Result<TcpListener> {
+    TcpListener::bind(addr)
+        .await
+        .map_err(|e| Error::io(format!("binding WebSocket feed to {addr}"), e))
+}
+
+/// Accepts WebSocket clients and sends each the state JSON in `updates`
+/// whenever it changes.
+///
+/// Returns at the first connection after the daemon has dropped the
+/// sender.
+pub async fn serve(listener: TcpListener, updates: watch::Receiver<String>) {
+    loop {
+        let stream = match listener.accept().await {
+            Ok((stream, _)) => stream,
+            Err(e) => {
+                warn!(error = %e, "Failed to accept WebSocket connection");
+                continue;
+            }
+        };
+        if updates.has_changed().is_err() {
+            return;
+        }
+
+        let updates = updates.clone();
+        tokio::spawn(async move {
+            if let Err(e) = handle_connection(stream, updates).await {
+                debug!(error = %e, "WebSocket connection failed");
+            }
+        });
+    }
+}
+
+/// Sends the current state, then every change, until either side closes.
+async fn handle_connection(stream: TcpStream, mut updates: watch::Receiver<String>) -> Result<()> {
+    let socket = tokio_tungstenite::accept_async(stream)
+        .await
+        .map_err(ws_error)?;
+    let (mut sink, mut incoming) = socket.split();
+
+    // Nothing to send until the daemon has a state
+    let state = updates.borrow_and_update().clone();
+    if !state.is_empty() {
+        sink.send(Message::text(state)).await.map_err(ws_error)?;
+    }
+
+    loop {
+        tokio::select! {
+            changed = updates.changed() => {
+                // The daemon has stopped
+                if changed.is_err() {
+                    break;
+                }
+                let state = updates.borrow_and_update().clone();
+                if !state.is_empty() {
+                    sink.send(Message::text(state)).await.map_err(ws_error)?;
+                }
+            }
+            message = incoming.next() => match message {
+                Some(Ok(Message::Close(_))) | None => break,
+                Some(Ok(_)) => {}
+                Some(Err(e)) => return Err(ws_error(e)),
+            },
+        }
+    }
+
+    let _ = sink.close().await;
+    Ok(())
+}
+
+/// Wraps a WebSocket protocol error for the connection's debug log.
+fn ws_error(e: tungstenite::Error) -> Error {
+    Error::io("serving WebSocket feed", std::io::Error::other(e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn sends_current_state_then_changes() {
+        let listener = bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = watch::channel(r#"{"connected":false}"#.to_string());
+        tokio::spawn(serve(listener, rx));
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{addr}"))
+            .await
+            .unwrap();
+        let first = client.next().await.unwrap().unwrap();
+        assert_eq!(
+            first.into_text().unwrap().as_str(),
+            r#"{"connected":false}"#
+        );
+
+        tx.send_replace(r#"{"connected":true}"#.to_string());
+        let second = client.next().await.unwrap().unwrap();
+        assert_eq!(
+            second.into_text().unwrap().as_str(),
+            r#"{"connected":true}"#
+        );
+
+        // Clients cannot change anything; their messages are ignored
+        client.send(Message::text("start_recording")).await.unwrap();
+        tx.send_replace(r#"{"connected":false}"#.to_string());
+        let third = client.next().await.unwrap().unwrap();
+        assert_eq!(
+            third.into_text().unwrap().as_str(),
+            r#"{"connected":false}"#
+        );
+    }
+
+    #[tokio::test]
+    async fn waits_for_first_state() {
+        let listener = bind("127.0.0.1:0".parse().unwrap()).await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let (tx, rx) = watch::channel(String::new());
+        tokio::spawn(serve(listener, rx));
+
+        let (mut client, _) = tokio_tungstenite::connect_async(format!("ws://{addr}"))
+            .await
+            .unwrap();
+        tx.send_replace(r#"{"connected":false}"#.to_string());
+        // The empty placeholder is never sent, so the first frame parses
+        let first = client.next().await.unwrap().unwrap();
+        assert_eq!(
+            first.into_text().unwrap().as_str(),
+            r#"{"connected":false}"#
+        );
+    }
+}
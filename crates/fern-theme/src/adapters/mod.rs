@@ -17,8 +17,9 @@
 //!        │  NotifyPort  │◄─────────────│  DbusNotifyAdapter  │
 //!        └──────────────┘              └─────────────────────┘
 //!        ┌──────────────┐              ┌─────────────────────┐
-//!        │   IpcPort    │◄─────────────│  DbusIpcAdapter     │
-//!        └──────────────┘              └─────────────────────┘
+//!        │   IpcPort    │◄─────────────│  SignalIpcAdapter   │
+//!        └──────────────┘              │  DbusIpcAdapter     │
+//!                                      └─────────────────────┘
 //! ```
 //!
 //! ## Available Adapters
@@ -28,7 +29,10 @@
 //! | `TomlConfigAdapter` | `ConfigPort` | Load config from TOML files |
 //! | `JsonConfigAdapter` | `ConfigPort` | Load config from JSON files |
 //! | `FileSystemAdapter` | `PersistPort` | Save themes to filesystem |
+//! | `InMemoryConfigAdapter` | `ConfigPort` | Serve a fixed TOML string (testing) |
+//! | `InMemoryPersistAdapter` | `PersistPort` | Store themes in a map (testing) |
 //! | `DbusNotifyAdapter` | `NotifyPort` | Send notifications via D-Bus |
+//! | `SignalIpcAdapter` | `IpcPort` | Reload QuickShell with SIGHUP |
 //! | `DbusIpcAdapter` | `IpcPort` | Communicate with QuickShell via D-Bus |
 //!
 //! ## Feature Flags
@@ -58,11 +62,14 @@
 // Adapters will be implemented in a future PR.
 // This module provides the structure for external system integration.
 
-use crate::domain::theme::Theme;
+use crate::domain::base16;
+use crate::domain::theme::{Theme, THEME_SCHEMA_VERSION};
 use crate::error::{FernError, Result};
 use crate::ports::inbound::{ConfigPort, RawConfig, SourceFormat};
-use crate::ports::outbound::PersistPort;
-use std::path::Path;
+use crate::ports::outbound::{IpcPort, PersistPort};
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, PoisonError};
 
 // ============================================================================
 // TomlConfigAdapter
@@ -85,30 +92,97 @@ use std::path::Path;
 /// let theme = raw.validate()?.into_theme();
 /// ```
 #[derive(Debug, Clone, Default)]
-pub struct TomlConfigAdapter;
+pub struct TomlConfigAdapter {
+    /// Files merged beneath every loaded file, most important first.
+    base_layers: Vec<PathBuf>,
+}
 
 impl TomlConfigAdapter {
     /// Creates a new TOML configuration adapter.
     #[must_use]
     pub fn new() -> Self {
-        Self
+        Self::default()
+    }
+
+    /// Merges `layers` beneath each file loaded with `load_from_file`.
+    ///
+    /// Layers are listed most important first, as [`system_config_paths`]
+    /// returns them, and override each other in that order. Layers that do
+    /// not exist are skipped, as is the loaded file itself.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// // A distro config in /etc/xdg/fern/ fills in what the user leaves out
+    /// let adapter = TomlConfigAdapter::new().with_base_layers(system_config_paths());
+    /// let raw = adapter.load_from_file("~/.config/fern/config.toml")?;
+    /// ```
+    #[must_use]
+    pub fn with_base_layers(mut self, layers: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.base_layers = layers.into_iter().collect();
+        self
+    }
+
+    /// Returns the layers merged beneath loaded files.
+    #[must_use]
+    pub fn base_layers(&self) -> &[PathBuf] {
+        &self.base_layers
+    }
+
+    /// Loads `source` as though it were the contents of the file at `path`.
+    ///
+    /// Includes resolve against `path`'s directory and base layers are
+    /// merged beneath, as with `load_from_file`, so edited text can be
+    /// checked before it is written back to `path`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `source`, an included file, or a base layer
+    /// cannot be read or parsed.
+    pub fn load_at(&self, path: impl AsRef<Path>, source: &str) -> Result<RawConfig> {
+        let path = path.as_ref();
+        let config = load_file_table(path, source)?;
+
+        let mut table = toml::Table::new();
+        for layer in self.base_layers.iter().rev() {
+            if !layer.is_file() || is_same_file(layer, path) {
+                continue;
+            }
+            let layer_source = std::fs::read_to_string(layer).map_err(|e| {
+                FernError::io(
+                    format!("reading configuration file: {}", layer.display()),
+                    e,
+                )
+            })?;
+            merge_tables(&mut table, load_file_table(layer, &layer_source)?);
+        }
+        merge_tables(&mut table, config);
+
+        let json_value = toml_to_json(toml::Value::Table(table));
+        Ok(RawConfig::new(json_value, SourceFormat::Toml).with_source(source))
     }
 }
 
 impl ConfigPort for TomlConfigAdapter {
     fn load(&self, source: &str) -> Result<RawConfig> {
-        // Parse TOML to a generic Value
-        let toml_value: toml::Value = toml::from_str(source).map_err(|e| {
-            FernError::Config(crate::error::ConfigError::ParseError {
-                source: e,
-                src: source.to_string(),
-            })
-        })?;
+        let table = parse_toml(source)?;
 
         // Convert TOML Value to JSON Value for unified internal representation
-        let json_value = toml_to_json(toml_value);
+        let json_value = toml_to_json(toml::Value::Table(table));
 
-        Ok(RawConfig::new(json_value, SourceFormat::Toml))
+        Ok(RawConfig::new(json_value, SourceFormat::Toml).with_source(source))
+    }
+
+    /// Loads a config file, merging in any files listed in its `include`.
+    ///
+    /// Unlike [`load`](Self::load), which has no directory to resolve
+    /// against, this pulls in included files before validation.
+    fn load_from_file(&self, path: impl AsRef<Path>) -> Result<RawConfig> {
+        let path = path.as_ref();
+        let source = std::fs::read_to_string(path).map_err(|e| {
+            FernError::io(format!("reading configuration file: {}", path.display()), e)
+        })?;
+        self.load_at(path, &source)
     }
 
     fn format_name(&self) -> &'static str {
@@ -116,6 +190,149 @@ impl ConfigPort for TomlConfigAdapter {
     }
 }
 
+/// Top-level key listing config files to merge in.
+const INCLUDE_KEY: &str = "include";
+
+/// Parses TOML source into its top-level table.
+fn parse_toml(source: &str) -> Result<toml::Table> {
+    toml::from_str(source).map_err(|e| {
+        FernError::Config(crate::error::ConfigError::ParseError {
+            source: e,
+            src: source.to_string(),
+        })
+    })
+}
+
+/// Parses a config file read from `path`, resolving its includes.
+fn load_file_table(path: &Path, source: &str) -> Result<toml::Table> {
+    let table = parse_toml(source)?;
+    if !table.contains_key(INCLUDE_KEY) {
+        return Ok(table);
+    }
+
+    let canonical = std::fs::canonicalize(path).map_err(|e| {
+        FernError::io(
+            format!("resolving configuration file: {}", path.display()),
+            e,
+        )
+    })?;
+    resolve_includes(table, path, &mut vec![canonical])
+}
+
+/// Returns `true` if both paths exist and name the same file.
+fn is_same_file(a: &Path, b: &Path) -> bool {
+    match (std::fs::canonicalize(a), std::fs::canonicalize(b)) {
+        (Ok(a), Ok(b)) => a == b,
+        _ => false,
+    }
+}
+
+/// Merges the files listed in `table`'s `include` key underneath it.
+///
+/// ```toml
+/// include = ["colors.toml", "bar.toml"]
+/// ```
+///
+/// Paths are relative to the directory of `path`, the file `table` was read
+/// from. Later includes override earlier ones, and the including file
+/// overrides them all. Included files may include others; `stack` holds the
+/// canonical paths of the files being resolved, so a file that includes
+/// itself, directly or not, is reported as a cycle.
+fn resolve_includes(
+    mut table: toml::Table,
+    path: &Path,
+    stack: &mut Vec<PathBuf>,
+) -> Result<toml::Table> {
+    let Some(include) = table.remove(INCLUDE_KEY) else {
+        return Ok(table);
+    };
+
+    let dir = path.parent().unwrap_or_else(|| Path::new(""));
+    let mut merged = toml::Table::new();
+    for file in include_paths(include)? {
+        let included = dir.join(file);
+        let canonical = std::fs::canonicalize(&included).map_err(|e| {
+            if e.kind() == std::io::ErrorKind::NotFound {
+                FernError::Config(crate::error::ConfigError::IncludeNotFound {
+                    path: included.display().to_string(),
+                    from: path.display().to_string(),
+                })
+            } else {
+                FernError::io(
+                    format!("resolving included file: {}", included.display()),
+                    e,
+                )
+            }
+        })?;
+
+        if stack.contains(&canonical) {
+            let chain = stack
+                .iter()
+                .chain(std::iter::once(&canonical))
+                .map(|p| p.display().to_string())
+                .collect();
+            return Err(FernError::Config(crate::error::ConfigError::IncludeCycle {
+                chain,
+            }));
+        }
+
+        let source = std::fs::read_to_string(&included).map_err(|e| {
+            FernError::io(format!("reading included file: {}", included.display()), e)
+        })?;
+
+        stack.push(canonical);
+        let resolved = resolve_includes(parse_toml(&source)?, &included, stack)?;
+        stack.pop();
+
+        merge_tables(&mut merged, resolved);
+    }
+
+    merge_tables(&mut merged, table);
+    Ok(merged)
+}
+
+/// Reads the `include` value: a path or an array of paths.
+fn include_paths(include: toml::Value) -> Result<Vec<String>> {
+    let mismatch = |actual: &toml::Value| {
+        FernError::Config(crate::error::ConfigError::TypeMismatch {
+            key: INCLUDE_KEY.to_string(),
+            expected: "array of file paths".to_string(),
+            actual: actual.type_str().to_string(),
+            span: None,
+            source_code: None,
+        })
+    };
+
+    match include {
+        toml::Value::String(path) => Ok(vec![path]),
+        toml::Value::Array(paths) => paths
+            .into_iter()
+            .map(|path| match path {
+                toml::Value::String(path) => Ok(path),
+                other => Err(mismatch(&other)),
+            })
+            .collect(),
+        other => Err(mismatch(&other)),
+    }
+}
+
+/// Deep-merges `overlay` into `base`.
+///
+/// Tables present in both are merged key by key; any other value in
+/// `overlay`, arrays included, replaces the one in `base`.
+fn merge_tables(base: &mut toml::Table, overlay: toml::Table) {
+    for (key, value) in overlay {
+        match (base.get_mut(&key), value) {
+            (Some(toml::Value::Table(existing)), toml::Value::Table(table)) => {
+                merge_tables(existing, table);
+            }
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
+
 /// Converts a TOML value to a JSON value.
 ///
 /// This allows the domain to work with a single internal representation
@@ -124,11 +341,9 @@ fn toml_to_json(toml: toml::Value) -> serde_json::Value {
     match toml {
         toml::Value::String(s) => serde_json::Value::String(s),
         toml::Value::Integer(i) => serde_json::Value::Number(i.into()),
-        toml::Value::Float(f) => {
-            serde_json::Number::from_f64(f)
-                .map(serde_json::Value::Number)
-                .unwrap_or(serde_json::Value::Null)
-        }
+        toml::Value::Float(f) => serde_json::Number::from_f64(f)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
         toml::Value::Boolean(b) => serde_json::Value::Bool(b),
         toml::Value::Array(arr) => {
             serde_json::Value::Array(arr.into_iter().map(toml_to_json).collect())
@@ -144,6 +359,61 @@ fn toml_to_json(toml: toml::Value) -> serde_json::Value {
     }
 }
 
+// ============================================================================
+// Config file lookup
+// ============================================================================
+
+/// Location of the config file within a config directory.
+const CONFIG_FILE: &str = "fern/config.toml";
+
+/// Returns the user's config file, `$XDG_CONFIG_HOME/fern/config.toml`.
+///
+/// The file may not exist. Returns `None` only if no config directory can
+/// be determined.
+#[must_use]
+pub fn user_config_path() -> Option<PathBuf> {
+    dirs::config_dir().map(|dir| dir.join(CONFIG_FILE))
+}
+
+/// Returns the system-wide config files, most important first.
+///
+/// One `fern/config.toml` per `$XDG_CONFIG_DIRS` entry (default
+/// `/etc/xdg`), whether or not it exists. Relative entries are ignored, as
+/// the XDG spec requires.
+#[must_use]
+pub fn system_config_paths() -> Vec<PathBuf> {
+    system_config_paths_from(std::env::var_os("XDG_CONFIG_DIRS").as_deref())
+}
+
+/// Builds [`system_config_paths`] from a `$XDG_CONFIG_DIRS` value.
+fn system_config_paths_from(config_dirs: Option<&std::ffi::OsStr>) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = config_dirs
+        .map(|value| {
+            std::env::split_paths(value)
+                .filter(|dir| dir.is_absolute())
+                .collect()
+        })
+        .unwrap_or_default();
+    if dirs.is_empty() {
+        dirs.push(PathBuf::from("/etc/xdg"));
+    }
+
+    dirs.into_iter().map(|dir| dir.join(CONFIG_FILE)).collect()
+}
+
+/// Finds the config file to load.
+///
+/// The user's config wins; without one, the first system config that
+/// exists is used, so a distro can ship a default in `/etc/xdg/fern/`.
+/// Returns `None` if there is no config anywhere.
+#[must_use]
+pub fn find_config() -> Option<PathBuf> {
+    user_config_path()
+        .into_iter()
+        .chain(system_config_paths())
+        .find(|path| path.is_file())
+}
+
 // ============================================================================
 // JsonConfigAdapter
 // ============================================================================
@@ -190,6 +460,137 @@ impl ConfigPort for JsonConfigAdapter {
     }
 }
 
+// ============================================================================
+// Theme schema migration
+// ============================================================================
+
+/// Upgrades a serialized theme to [`THEME_SCHEMA_VERSION`].
+///
+/// Files without a `schema_version` predate versioning and are treated as
+/// version 1. Files from a newer version are rejected with
+/// [`ConfigError::UnsupportedVersion`] rather than failing later with a
+/// confusing deserialization error.
+///
+/// Each older version gets one step in the loop below that rewrites the
+/// JSON into the next version's shape.
+///
+/// | From | Change |
+/// |------|--------|
+/// | 1 | Colors with alpha move from `#RRGGBBAA` to Qt's `#AARRGGBB` |
+///
+/// [`ConfigError::UnsupportedVersion`]: crate::error::ConfigError::UnsupportedVersion
+fn migrate_theme(mut value: serde_json::Value) -> Result<serde_json::Value> {
+    let found = value
+        .get("schema_version")
+        .and_then(serde_json::Value::as_u64)
+        .map_or(1, |v| u32::try_from(v).unwrap_or(u32::MAX));
+
+    if found > THEME_SCHEMA_VERSION {
+        return Err(FernError::Config(
+            crate::error::ConfigError::UnsupportedVersion {
+                found,
+                supported: THEME_SCHEMA_VERSION,
+            },
+        ));
+    }
+
+    for version in found..THEME_SCHEMA_VERSION {
+        if version == 1 {
+            migrate_alpha_order(&mut value);
+        }
+    }
+
+    if let Some(object) = value.as_object_mut() {
+        object.insert(
+            "schema_version".to_string(),
+            serde_json::Value::from(THEME_SCHEMA_VERSION),
+        );
+    }
+
+    Ok(value)
+}
+
+/// Parses saved theme JSON, migrating it to the current schema first.
+fn parse_theme_json(content: &str) -> Result<Theme> {
+    let invalid = |e: serde_json::Error| {
+        FernError::Config(crate::error::ConfigError::MissingField {
+            key: "theme".to_string(),
+            expected_type: format!("valid theme JSON: {e}"),
+        })
+    };
+
+    let value: serde_json::Value = serde_json::from_str(content).map_err(invalid)?;
+    serde_json::from_value(migrate_theme(value)?).map_err(invalid)
+}
+
+/// Renders a theme as the JSON that [`FileSystemAdapter::save_theme`]
+/// writes, or compact JSON as [`PersistPort::save_theme_compact`] does.
+///
+/// Useful for sending a theme somewhere other than a file, such as stdout.
+///
+/// # Errors
+///
+/// Returns [`FernError::Io`] if the theme cannot be serialized.
+pub fn render_theme(theme: &Theme, pretty: bool) -> Result<String> {
+    let value = theme_json(theme)?;
+    let json = if pretty {
+        serde_json::to_string_pretty(&value)
+    } else {
+        serde_json::to_string(&value)
+    };
+
+    json.map_err(|e| {
+        FernError::io(
+            format!("serializing theme: {e}"),
+            std::io::Error::other(e.to_string()),
+        )
+    })
+}
+
+/// Serializes a theme for QML, adding the derived component padding.
+///
+/// `components` is computed from the spacing scale rather than stored on
+/// [`Theme`], so loading ignores it and it can never go stale.
+fn theme_json(theme: &Theme) -> Result<serde_json::Value> {
+    let serialize_error = |e: serde_json::Error| {
+        FernError::io(
+            format!("serializing theme: {e}"),
+            std::io::Error::other(e.to_string()),
+        )
+    };
+
+    // Going through a string keeps f32 fields as written; `to_value` would
+    // widen 1.2 to 1.2000000476837158
+    let json = serde_json::to_string(theme).map_err(serialize_error)?;
+    let mut value: serde_json::Value = serde_json::from_str(&json).map_err(serialize_error)?;
+    if let Some(object) = value.as_object_mut() {
+        object.insert(
+            "components".to_string(),
+            serde_json::to_value(theme.components()).map_err(serialize_error)?,
+        );
+    }
+    Ok(value)
+}
+
+/// Rewrites 8-digit `#RRGGBBAA` colors in the palette as `#AARRGGBB`.
+fn migrate_alpha_order(value: &mut serde_json::Value) {
+    let Some(colors) = value
+        .get_mut("colors")
+        .and_then(serde_json::Value::as_object_mut)
+    else {
+        return;
+    };
+
+    for color in colors.values_mut() {
+        if let Some(hex) = color.as_str().and_then(|s| s.strip_prefix('#')) {
+            if hex.len() == 8 && hex.is_ascii() {
+                let (rgb, alpha) = hex.split_at(6);
+                *color = serde_json::Value::from(format!("#{alpha}{rgb}"));
+            }
+        }
+    }
+}
+
 // ============================================================================
 // FileSystemAdapter
 // ============================================================================
@@ -219,17 +620,23 @@ impl FileSystemAdapter {
     }
 }
 
+/// Writes a serialized theme to `path`.
+fn write_theme_file(path: &Path, content: &str) -> Result<()> {
+    std::fs::write(path, content)
+        .map_err(|e| FernError::io(format!("writing {}", path.display()), e))
+}
+
 impl PersistPort for FileSystemAdapter {
     fn save_theme(&self, theme: &Theme, path: impl AsRef<Path>) -> Result<()> {
-        let path = path.as_ref();
-        let json = serde_json::to_string_pretty(theme).map_err(|e| {
-            FernError::io(format!("serializing theme: {e}"), std::io::Error::other(e.to_string()))
-        })?;
+        write_theme_file(path.as_ref(), &render_theme(theme, true)?)
+    }
 
-        std::fs::write(path, json)
-            .map_err(|e| FernError::io(format!("writing {}", path.display()), e))?;
+    fn save_theme_compact(&self, theme: &Theme, path: impl AsRef<Path>) -> Result<()> {
+        write_theme_file(path.as_ref(), &render_theme(theme, false)?)
+    }
 
-        Ok(())
+    fn save_base16(&self, theme: &Theme, scheme: &str, path: impl AsRef<Path>) -> Result<()> {
+        write_theme_file(path.as_ref(), &base16::to_yaml(theme, scheme))
     }
 
     fn load_theme(&self, path: impl AsRef<Path>) -> Result<Theme> {
@@ -237,14 +644,7 @@ impl PersistPort for FileSystemAdapter {
         let content = std::fs::read_to_string(path)
             .map_err(|e| FernError::io(format!("reading {}", path.display()), e))?;
 
-        let theme: Theme = serde_json::from_str(&content).map_err(|e| {
-            FernError::Config(crate::error::ConfigError::MissingField {
-                key: "theme".to_string(),
-                expected_type: format!("valid theme JSON: {e}"),
-            })
-        })?;
-
-        Ok(theme)
+        parse_theme_json(&content)
     }
 
     fn config_dir(&self) -> Option<std::path::PathBuf> {
@@ -272,3 +672,508 @@ impl PersistPort for FileSystemAdapter {
         path.as_ref().exists()
     }
 }
+
+// ============================================================================
+// SignalIpcAdapter
+// ============================================================================
+
+/// An [`IpcPort`] that reloads QuickShell by sending it `SIGHUP`.
+///
+/// QuickShell re-reads its configuration on `SIGHUP`, the same path
+/// `fernctl reload` uses. The process is found by name with `pgrep` and
+/// signalled with `pkill`, so no D-Bus session is needed. Only
+/// [`reload_theme`](IpcPort::reload_theme) does anything; the theme itself
+/// is not sent, so write it to `config.json` first.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fern_theme::adapters::SignalIpcAdapter;
+/// use fern_theme::ports::outbound::IpcPort;
+///
+/// persist.save_theme(&theme, "config.json")?;
+/// SignalIpcAdapter::new().reload_theme(&theme)?;
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SignalIpcAdapter;
+
+impl SignalIpcAdapter {
+    /// Process name QuickShell runs under.
+    pub const PROCESS_NAME: &'static str = "quickshell";
+
+    /// Creates a new signal IPC adapter.
+    #[must_use]
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl IpcPort for SignalIpcAdapter {
+    fn reload_theme(&self, _theme: &Theme) -> Result<()> {
+        let status = std::process::Command::new("pkill")
+            .args(["-HUP", "-x", Self::PROCESS_NAME])
+            .status()
+            .map_err(|e| FernError::ipc(format!("running pkill: {e}")))?;
+
+        if status.success() {
+            Ok(())
+        } else {
+            Err(FernError::ipc("QuickShell is not running"))
+        }
+    }
+
+    fn send_command(&self, command: &str) -> Result<()> {
+        Err(FernError::ipc(format!(
+            "cannot send `{command}`: only reloads are supported over signals"
+        )))
+    }
+
+    fn is_shell_running(&self) -> bool {
+        std::process::Command::new("pgrep")
+            .args(["-x", Self::PROCESS_NAME])
+            .output()
+            .is_ok_and(|output| output.status.success())
+    }
+
+    fn shell_version(&self) -> Option<String> {
+        None
+    }
+
+    fn ping(&self) -> Result<u64> {
+        Err(FernError::ipc("ping is not supported over signals"))
+    }
+}
+
+// ============================================================================
+// In-memory adapters
+// ============================================================================
+
+/// A [`ConfigPort`] that serves a fixed TOML string instead of reading files.
+///
+/// Like the null ports in [`crate::ports::outbound`], this exists so code
+/// built on fern-theme can be tested without touching the filesystem.
+/// [`load_from_file`](ConfigPort::load_from_file) ignores the path and
+/// parses the seeded source.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::adapters::InMemoryConfigAdapter;
+/// use fern_theme::ports::inbound::ConfigPort;
+///
+/// let adapter = InMemoryConfigAdapter::new("[bar]\nheight = 32\n");
+/// let theme = adapter.load_from_file("unused.toml").unwrap().validate().unwrap().into_theme();
+/// assert_eq!(theme.bar.height, 32);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct InMemoryConfigAdapter {
+    source: String,
+}
+
+impl InMemoryConfigAdapter {
+    /// Creates an adapter that serves `source` as TOML.
+    #[must_use]
+    pub fn new(source: impl Into<String>) -> Self {
+        Self {
+            source: source.into(),
+        }
+    }
+
+    /// Returns the seeded source.
+    #[must_use]
+    pub fn source(&self) -> &str {
+        &self.source
+    }
+}
+
+impl ConfigPort for InMemoryConfigAdapter {
+    fn load(&self, source: &str) -> Result<RawConfig> {
+        TomlConfigAdapter::new().load(source)
+    }
+
+    fn load_from_file(&self, _path: impl AsRef<Path>) -> Result<RawConfig> {
+        self.load(&self.source)
+    }
+
+    fn format_name(&self) -> &'static str {
+        "TOML"
+    }
+}
+
+/// A [`PersistPort`] backed by a map from path to file contents.
+///
+/// Saved themes are serialized exactly as [`FileSystemAdapter`] would write
+/// them, so tests can inspect the output via [`written`](Self::written).
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::adapters::InMemoryPersistAdapter;
+/// use fern_theme::domain::theme::Theme;
+/// use fern_theme::ports::outbound::PersistPort;
+///
+/// let adapter = InMemoryPersistAdapter::new();
+/// adapter.save_theme(&Theme::dark(), "config.json").unwrap();
+///
+/// assert!(adapter.exists("config.json"));
+/// assert_eq!(adapter.load_theme("config.json").unwrap(), Theme::dark());
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryPersistAdapter {
+    files: Mutex<HashMap<PathBuf, String>>,
+}
+
+impl InMemoryPersistAdapter {
+    /// Creates an empty in-memory store.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Seeds the store with a file, e.g. a theme to be loaded.
+    pub fn insert(&self, path: impl Into<PathBuf>, content: impl Into<String>) {
+        self.files().insert(path.into(), content.into());
+    }
+
+    /// Returns a snapshot of every file written so far.
+    #[must_use]
+    pub fn written(&self) -> HashMap<PathBuf, String> {
+        self.files().clone()
+    }
+
+    fn files(&self) -> std::sync::MutexGuard<'_, HashMap<PathBuf, String>> {
+        self.files.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl PersistPort for InMemoryPersistAdapter {
+    fn save_theme(&self, theme: &Theme, path: impl AsRef<Path>) -> Result<()> {
+        self.insert(path.as_ref(), render_theme(theme, true)?);
+        Ok(())
+    }
+
+    fn save_theme_compact(&self, theme: &Theme, path: impl AsRef<Path>) -> Result<()> {
+        self.insert(path.as_ref(), render_theme(theme, false)?);
+        Ok(())
+    }
+
+    fn save_base16(&self, theme: &Theme, scheme: &str, path: impl AsRef<Path>) -> Result<()> {
+        self.insert(path.as_ref(), base16::to_yaml(theme, scheme));
+        Ok(())
+    }
+
+    fn load_theme(&self, path: impl AsRef<Path>) -> Result<Theme> {
+        let path = path.as_ref();
+        let content = self.files().get(path).cloned().ok_or_else(|| {
+            FernError::io(
+                format!("reading {}", path.display()),
+                std::io::Error::from(std::io::ErrorKind::NotFound),
+            )
+        })?;
+
+        parse_theme_json(&content)
+    }
+
+    fn config_dir(&self) -> Option<PathBuf> {
+        Some(PathBuf::from("fern"))
+    }
+
+    fn ensure_config_dir(&self) -> Result<PathBuf> {
+        Ok(PathBuf::from("fern"))
+    }
+
+    fn exists(&self, path: impl AsRef<Path>) -> bool {
+        self.files().contains_key(path.as_ref())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_theme_roundtrips_saved_theme() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.json");
+        let adapter = FileSystemAdapter::new();
+
+        adapter.save_theme(&Theme::light(), &path).unwrap();
+        let loaded = adapter.load_theme(&path).unwrap();
+
+        assert_eq!(loaded, Theme::light());
+    }
+
+    #[test]
+    fn save_theme_compact_has_no_newlines() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.json");
+        let adapter = FileSystemAdapter::new();
+
+        adapter.save_theme_compact(&Theme::dark(), &path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(!content.contains('\n'));
+        assert_eq!(adapter.load_theme(&path).unwrap(), Theme::dark());
+    }
+
+    #[test]
+    fn load_theme_rejects_newer_schema() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.json");
+        let json = format!(r#"{{"schema_version": {}}}"#, THEME_SCHEMA_VERSION + 1);
+        std::fs::write(&path, json).unwrap();
+
+        let err = FileSystemAdapter::new().load_theme(&path).unwrap_err();
+        assert!(matches!(
+            err,
+            FernError::Config(crate::error::ConfigError::UnsupportedVersion { .. })
+        ));
+    }
+
+    #[test]
+    fn migrate_stamps_missing_version() {
+        let migrated = migrate_theme(serde_json::json!({"variant": "dark"})).unwrap();
+        assert_eq!(migrated["schema_version"], THEME_SCHEMA_VERSION);
+    }
+
+    #[test]
+    fn migrate_v1_reorders_alpha_for_qml() {
+        let v1 = serde_json::json!({
+            "schema_version": 1,
+            "colors": { "surface": "#31324480", "accent": "#89b4fa" }
+        });
+
+        let migrated = migrate_theme(v1).unwrap();
+        assert_eq!(migrated["colors"]["surface"], "#80313244");
+        assert_eq!(migrated["colors"]["accent"], "#89b4fa");
+    }
+
+    #[test]
+    fn save_theme_writes_qt_ordered_alpha() {
+        use crate::domain::tokens::color::{ColorToken, Surface};
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.json");
+        let mut theme = Theme::dark();
+        theme.colors.surface = ColorToken::<Surface>::from_hex("#31324480").unwrap();
+
+        let adapter = FileSystemAdapter::new();
+        adapter.save_theme(&theme, &path).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.contains("\"#80313244\""));
+        assert_eq!(adapter.load_theme(&path).unwrap(), theme);
+    }
+
+    #[test]
+    fn in_memory_config_ignores_path() {
+        let adapter = InMemoryConfigAdapter::new("[appearance]\ntheme = \"light\"\n");
+        let theme = adapter
+            .load_from_file("/does/not/exist.toml")
+            .unwrap()
+            .validate()
+            .unwrap()
+            .into_theme();
+
+        assert_eq!(theme.variant, crate::domain::theme::ThemeVariant::Light);
+    }
+
+    #[test]
+    fn in_memory_persist_records_writes() {
+        let adapter = InMemoryPersistAdapter::new();
+        adapter
+            .save_theme(&Theme::light(), "out/config.json")
+            .unwrap();
+
+        let written = adapter.written();
+        assert_eq!(written.len(), 1);
+        assert!(written.contains_key(Path::new("out/config.json")));
+        assert_eq!(
+            adapter.load_theme("out/config.json").unwrap(),
+            Theme::light()
+        );
+    }
+
+    #[test]
+    fn saved_theme_includes_component_padding() {
+        let adapter = InMemoryPersistAdapter::new();
+        adapter
+            .save_theme_compact(&Theme::dark(), "theme.json")
+            .unwrap();
+
+        let json: serde_json::Value =
+            serde_json::from_str(&adapter.written()[Path::new("theme.json")]).unwrap();
+        assert_eq!(json["components"]["module"]["horizontal"], 12);
+        assert_eq!(json["components"]["module"]["vertical"], 8);
+        assert_eq!(adapter.load_theme("theme.json").unwrap(), Theme::dark());
+    }
+
+    #[test]
+    fn in_memory_persist_missing_file_errors() {
+        let adapter = InMemoryPersistAdapter::new();
+        assert!(!adapter.exists("missing.json"));
+        assert!(adapter.load_theme("missing.json").is_err());
+    }
+
+    #[test]
+    fn includes_merge_with_later_files_winning() {
+        let dir = tempfile::tempdir().unwrap();
+        let write = |name: &str, content: &str| {
+            std::fs::write(dir.path().join(name), content).unwrap();
+        };
+        write(
+            "config.toml",
+            "include = [\"colors.toml\", \"bar.toml\"]\n[bar]\nmargin = 4\n",
+        );
+        write(
+            "colors.toml",
+            "[appearance]\ntheme = \"light\"\n[bar]\nheight = 30\n",
+        );
+        write("bar.toml", "[bar]\nheight = 48\nmargin = 8\n");
+
+        let theme = TomlConfigAdapter::new()
+            .load_from_file(dir.path().join("config.toml"))
+            .unwrap()
+            .validate()
+            .unwrap()
+            .into_theme();
+
+        assert_eq!(theme.variant, crate::domain::theme::ThemeVariant::Light);
+        assert_eq!(theme.bar.height, 48);
+        assert_eq!(theme.bar.margin, 4);
+    }
+
+    #[test]
+    fn load_at_resolves_includes_next_to_path() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("config.toml");
+        std::fs::write(&config, "").unwrap();
+        std::fs::write(dir.path().join("bar.toml"), "[bar]\nheight = 48\n").unwrap();
+
+        let theme = TomlConfigAdapter::new()
+            .load_at(&config, "include = \"bar.toml\"\n")
+            .unwrap()
+            .validate()
+            .unwrap()
+            .into_theme();
+
+        assert_eq!(theme.bar.height, 48);
+    }
+
+    #[test]
+    fn invalid_included_value_has_no_span_in_including_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("config.toml");
+        std::fs::write(&config, "include = [\"bar.toml\"]\n[bar]\nmargin = 4\n").unwrap();
+        std::fs::write(dir.path().join("bar.toml"), "[bar]\nheight = 999\n").unwrap();
+
+        let err = TomlConfigAdapter::new()
+            .load_from_file(&config)
+            .unwrap()
+            .validate()
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            FernError::Config(crate::error::ConfigError::OutOfRange {
+                ref key,
+                span: None,
+                source_code: None,
+                ..
+            }) if key == "bar.height"
+        ));
+
+        // The same value in the including file is still pointed at
+        std::fs::write(&config, "include = [\"bar.toml\"]\n[bar]\nheight = 998\n").unwrap();
+        let err = TomlConfigAdapter::new()
+            .load_from_file(&config)
+            .unwrap()
+            .validate()
+            .unwrap_err();
+        let FernError::Config(crate::error::ConfigError::OutOfRange {
+            span: Some(span),
+            source_code: Some(source),
+            ..
+        }) = err
+        else {
+            panic!("expected a located range error, got {err:?}");
+        };
+        assert_eq!(&source[span.offset()..span.offset() + span.len()], "998");
+    }
+
+    #[test]
+    fn missing_include_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("config.toml");
+        std::fs::write(&config, "include = [\"colors.toml\"]\n").unwrap();
+
+        let err = TomlConfigAdapter::new()
+            .load_from_file(&config)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            FernError::Config(crate::error::ConfigError::IncludeNotFound { ref path, .. })
+                if path.ends_with("colors.toml")
+        ));
+    }
+
+    #[test]
+    fn include_cycle_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let config = dir.path().join("config.toml");
+        std::fs::write(&config, "include = [\"bar.toml\"]\n").unwrap();
+        std::fs::write(dir.path().join("bar.toml"), "include = [\"config.toml\"]\n").unwrap();
+
+        let err = TomlConfigAdapter::new()
+            .load_from_file(&config)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            FernError::Config(crate::error::ConfigError::IncludeCycle { ref chain })
+                if chain.len() == 3
+        ));
+    }
+
+    #[test]
+    fn system_config_paths_follow_xdg_config_dirs() {
+        let paths = system_config_paths_from(Some("/usr/share/xdg:relative:/etc/xdg".as_ref()));
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("/usr/share/xdg/fern/config.toml"),
+                PathBuf::from("/etc/xdg/fern/config.toml")
+            ]
+        );
+
+        let default = [PathBuf::from("/etc/xdg/fern/config.toml")];
+        assert_eq!(system_config_paths_from(None), default);
+        assert_eq!(system_config_paths_from(Some("".as_ref())), default);
+    }
+
+    #[test]
+    fn base_layers_merge_beneath_the_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let vendor = dir.path().join("vendor.toml");
+        let site = dir.path().join("site.toml");
+        let user = dir.path().join("config.toml");
+        std::fs::write(&vendor, "[bar]\nheight = 30\nmargin = 2\n").unwrap();
+        std::fs::write(&site, "[bar]\nheight = 36\nposition = \"bottom\"\n").unwrap();
+        std::fs::write(&user, "[bar]\nposition = \"left\"\n").unwrap();
+
+        let adapter = TomlConfigAdapter::new().with_base_layers([
+            site,
+            dir.path().join("missing.toml"),
+            vendor,
+            user.clone(),
+        ]);
+        let theme = adapter
+            .load_from_file(&user)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .into_theme();
+
+        assert_eq!(theme.bar.height, 36);
+        assert_eq!(theme.bar.margin, 2);
+        assert_eq!(theme.bar.position.name(), "left");
+    }
+}
@@ -15,6 +15,21 @@
 //!
 //! # With verbose output
 //! fernctl convert config.toml -v
+//!
+//! # Compact output for faster QML startup
+//! fernctl convert config.toml --minify
+//!
+//! # Convert every *.toml in a directory to a sibling *.json
+//! fernctl convert --all themes/
+//!
+//! # Bundle them into dist/ instead, leaving the sources alone
+//! fernctl convert --all themes/ --output-dir dist/ --minify
+//!
+//! # Export a Base16 scheme for terminal and editor templates
+//! fernctl convert config.toml --format base16 --output fern.yaml
+//!
+//! # Pipe generated TOML through without temp files
+//! generate-config | fernctl convert - -o - > config.json
 //! ```
 //!
 //! ## Why Two Formats?
@@ -90,6 +105,50 @@
 //! }
 //! ```
 //!
+//! ## Color Normalization
+//!
+//! Colors are written in one canonical form, not as they were typed. Every
+//! accepted spelling — `#fff`, `rgb(255, 255, 255)`, `white` — comes out as
+//! `#ffffff`, and translucent colors come out in Qt's `#AARRGGBB` order
+//! (see [`ColorToken::to_qml_color`](crate::domain::tokens::color::ColorToken::to_qml_color)).
+//!
+//! Keeping the original text is not an option: QML reads 8-digit hex in
+//! the opposite order to CSS and does not understand `rgb()`, so copying
+//! the input through would change what the shell draws. The upside is that
+//! rewording a color without changing it leaves the JSON untouched.
+//!
+//! ```text
+//! accent = "#fff"        →  "accent": "#ffffff"
+//! accent = "#89b4fa80"   →  "accent": "#8089b4fa"
+//! accent = "tomato"      →  "accent": "#ff6347"
+//! ```
+//!
+//! ## Pretty vs. Minified Output
+//!
+//! Output is pretty-printed by default so the generated file is easy to
+//! inspect. `--minify` drops all whitespace: QML parses the file on every
+//! shell startup, and for large multi-theme bundles compact JSON parses
+//! measurably faster. The tradeoff is a file that is hard to read or diff,
+//! so prefer the default unless startup time matters. `--pretty` and
+//! `--minify` are mutually exclusive.
+//!
+//! ## Base16 Output
+//!
+//! `--format base16` writes a [Base16](https://github.com/chriskempson/base16)
+//! YAML scheme instead of JSON, so the same theme can drive terminal and
+//! editor templates. The scheme is named after the input file's stem, and
+//! the default output extension is `.yaml`. See
+//! [`base16`](crate::domain::base16) for the slot mapping.
+//!
+//! ## Standard Input and Output
+//!
+//! An input path of `-` ([`STDIO_PATH`]) reads the TOML from stdin, and an
+//! output path of `-` writes the result to stdout. Reading stdin defaults
+//! the output to stdout too. Includes and `--merge-system` layers need a
+//! file to resolve against, so they do not apply to stdin; errors are
+//! reported against `<stdin>` rather than a filename, and a Base16 scheme
+//! read from stdin is named `fern`.
+//!
 //! ## Atomicity
 //!
 //! Conversion is atomic — if any step fails, no output file is created or
@@ -111,7 +170,8 @@
 //! | Code | Meaning |
 //! |------|---------|
 //! | `0` | Conversion succeeded |
-//! | `1` | Conversion failed (parse error, validation error, I/O error) |
+//! | `1` | Conversion failed (parse error, validation error) |
+//! | `2` | Input could not be read or output could not be written |
 //!
 //! ## Programmatic Usage
 //!
@@ -121,21 +181,44 @@
 //!
 //! let config_adapter = TomlConfigAdapter::new();
 //! let persist_adapter = FileSystemAdapter::new();
-//! let options = ConvertOptions { verbose: true };
+//! let options = ConvertOptions { verbose: true, ..ConvertOptions::default() };
 //!
 //! run(
 //!     Path::new("config.toml"),
 //!     Path::new("config.json"),
-//!     options,
+//!     &options,
 //!     &config_adapter,
 //!     &persist_adapter,
 //! )?;
 //! ```
 
-use crate::error::Result;
+use crate::domain::base16;
+use crate::error::{FernError, Result};
 use crate::ports::inbound::ConfigPort;
 use crate::ports::outbound::PersistPort;
-use std::path::Path;
+use crate::theme_to_json;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+
+/// Path that stands for stdin as an input and stdout as an output.
+pub const STDIO_PATH: &str = "-";
+
+/// Returns `true` if `path` is [`STDIO_PATH`].
+#[must_use]
+pub fn is_stdio(path: impl AsRef<Path>) -> bool {
+    path.as_ref() == Path::new(STDIO_PATH)
+}
+
+/// Names `path` in messages, using `stdio` when it is [`STDIO_PATH`].
+fn display_path<'a>(path: &'a Path, stdio: &'static str) -> Cow<'a, str> {
+    if is_stdio(path) {
+        Cow::Borrowed(stdio)
+    } else {
+        path.to_string_lossy()
+    }
+}
 
 /// Options for the convert command.
 ///
@@ -149,6 +232,8 @@ use std::path::Path;
 /// let options = ConvertOptions {
 ///     verbose: true,
 ///     pretty: true,
+///     strict: false,
+///     ..ConvertOptions::default()
 /// };
 /// ```
 #[derive(Debug, Clone)]
@@ -171,11 +256,21 @@ pub struct ConvertOptions {
     /// }
     /// ```
     ///
-    /// When `false`, output is compact (smaller file size):
+    /// When `false`, output is compact (smaller file size, faster for QML
+    /// to parse, harder to read):
     /// ```json
     /// {"variant":"dark","colors":{...}}
     /// ```
     pub pretty: bool,
+
+    /// Whether to treat warnings as errors.
+    ///
+    /// When enabled, any warning fails the conversion and no output is
+    /// written.
+    pub strict: bool,
+
+    /// The format to write.
+    pub format: OutputFormat,
 }
 
 impl Default for ConvertOptions {
@@ -183,6 +278,30 @@ impl Default for ConvertOptions {
         Self {
             verbose: false,
             pretty: true,
+            strict: false,
+            format: OutputFormat::Json,
+        }
+    }
+}
+
+/// Output format for conversion.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum OutputFormat {
+    /// Theme JSON for QuickShell.
+    #[default]
+    Json,
+    /// Base16 YAML scheme for terminal and editor templates.
+    Base16,
+}
+
+impl OutputFormat {
+    /// Returns the file extension for this format.
+    #[must_use]
+    pub const fn extension(&self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Base16 => "yaml",
         }
     }
 }
@@ -207,8 +326,8 @@ pub struct ConvertResult {
 ///
 /// # Arguments
 ///
-/// * `input` — Path to the input TOML file
-/// * `output` — Path where the JSON output will be written
+/// * `input` — Path to the input TOML file, or `-` for stdin
+/// * `output` — Path where the JSON output will be written, or `-` for stdout
 /// * `options` — Configuration for conversion behavior
 /// * `config_adapter` — Adapter for loading TOML configuration
 /// * `persist_adapter` — Adapter for writing JSON output
@@ -221,10 +340,10 @@ pub struct ConvertResult {
 /// # Errors
 ///
 /// Returns an error if:
-/// - The input file cannot be read
+/// - The input file (or stdin) cannot be read
 /// - TOML parsing fails
 /// - Validation fails
-/// - The output file cannot be written
+/// - The output file (or stdout) cannot be written
 ///
 /// # Example
 ///
@@ -239,7 +358,7 @@ pub struct ConvertResult {
 /// let result = run(
 ///     Path::new("~/.config/fern/config.toml"),
 ///     Path::new("~/.config/fern/config.json"),
-///     ConvertOptions::default(),
+///     &ConvertOptions::default(),
 ///     &config_adapter,
 ///     &persist_adapter,
 /// )?;
@@ -258,7 +377,7 @@ pub struct ConvertResult {
 pub fn run<P: AsRef<Path>, Q: AsRef<Path>>(
     input: P,
     output: Q,
-    options: ConvertOptions,
+    options: &ConvertOptions,
     config_adapter: &impl ConfigPort,
     persist_adapter: &impl PersistPort,
 ) -> Result<ConvertResult> {
@@ -266,15 +385,34 @@ pub fn run<P: AsRef<Path>, Q: AsRef<Path>>(
     let output = output.as_ref();
 
     if options.verbose {
-        eprintln!("Converting: {} -> {}", input.display(), output.display());
+        eprintln!(
+            "Converting: {} -> {}",
+            display_path(input, "<stdin>"),
+            display_path(output, "<stdout>")
+        );
     }
 
     // Load and validate
-    let raw = config_adapter.load_from_file(input)?;
-    let validated = raw.validate()?;
+    let raw = if is_stdio(input) {
+        let mut source = String::new();
+        std::io::stdin()
+            .read_to_string(&mut source)
+            .map_err(|e| FernError::io("reading configuration from <stdin>", e))?;
+        config_adapter.load(&source)?
+    } else {
+        config_adapter.load_from_file(input)?
+    };
+    let mut validated = raw.validate()?;
+    if options.strict {
+        validated = validated.deny_warnings()?;
+    }
 
     // Collect warnings before consuming validated
-    let warnings: Vec<String> = validated.warnings().iter().map(ToString::to_string).collect();
+    let warnings: Vec<String> = validated
+        .warnings()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
 
     // Print warnings if verbose
     if options.verbose {
@@ -286,16 +424,188 @@ pub fn run<P: AsRef<Path>, Q: AsRef<Path>>(
     // Convert to theme
     let theme = validated.into_theme();
 
-    // Persist to JSON
-    persist_adapter.save_theme(&theme, output)?;
+    let scheme = input
+        .file_stem()
+        .filter(|_| !is_stdio(input))
+        .map_or_else(|| "fern".into(), |stem| stem.to_string_lossy());
+
+    // Persist in the requested format
+    if is_stdio(output) {
+        let mut content = match options.format {
+            OutputFormat::Json => theme_to_json(&theme, options.pretty)?,
+            OutputFormat::Base16 => base16::to_yaml(&theme, &scheme),
+        };
+        if !content.ends_with('\n') {
+            content.push('\n');
+        }
+        std::io::stdout()
+            .write_all(content.as_bytes())
+            .map_err(|e| FernError::io("writing to <stdout>", e))?;
+    } else {
+        match options.format {
+            OutputFormat::Json if options.pretty => persist_adapter.save_theme(&theme, output)?,
+            OutputFormat::Json => persist_adapter.save_theme_compact(&theme, output)?,
+            OutputFormat::Base16 => persist_adapter.save_base16(&theme, &scheme, output)?,
+        }
+    }
 
     if options.verbose {
-        eprintln!("Wrote {}", output.display());
+        eprintln!("Wrote {}", display_path(output, "<stdout>"));
     }
 
     Ok(ConvertResult { warnings })
 }
 
+/// Result of converting a directory of configuration files.
+///
+/// Each file is converted independently; a failure is recorded and the
+/// batch carries on with the next file.
+#[derive(Debug, Default)]
+pub struct BatchResult {
+    /// Input files that converted successfully, with their results.
+    pub succeeded: Vec<(PathBuf, ConvertResult)>,
+
+    /// Input files that failed to convert, with the error for each.
+    pub failed: Vec<(PathBuf, FernError)>,
+}
+
+impl BatchResult {
+    /// Returns `true` if every file converted successfully.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Converts every `*.toml` file under a directory to a sibling output file.
+///
+/// Outputs are named with [`OutputFormat::extension`], e.g. `*.json`.
+/// Subdirectories are searched too; symlinked directories are not followed.
+///
+/// Files are processed in path order using [`run`], so each one gets the
+/// same validation and atomicity guarantees as a single conversion. A bad
+/// file does not abort the batch; check [`BatchResult::failed`].
+///
+/// # Errors
+///
+/// Returns an error only if a directory itself cannot be read.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let result = run_all("themes", &ConvertOptions::default(), &config_adapter, &persist_adapter)?;
+/// println!("{} converted, {} failed", result.succeeded.len(), result.failed.len());
+/// ```
+pub fn run_all<P: AsRef<Path>>(
+    dir: P,
+    options: &ConvertOptions,
+    config_adapter: &impl ConfigPort,
+    persist_adapter: &impl PersistPort,
+) -> Result<BatchResult> {
+    let jobs = find_inputs(dir.as_ref())?
+        .into_iter()
+        .map(|input| {
+            let output = derive_output_path_for(&input, options.format);
+            (input, output)
+        })
+        .collect();
+
+    Ok(run_batch(jobs, options, config_adapter, persist_adapter))
+}
+
+/// Converts every `*.toml` file under a directory into `output_dir`.
+///
+/// Like [`run_all`], but each output is named after its input's file name
+/// and written to `output_dir`, which is created if needed. The sources are
+/// left untouched, so `--minify` gives a clean bundle for distribution.
+///
+/// # Errors
+///
+/// Returns an error if a directory cannot be read, `output_dir` cannot be
+/// created, or two inputs in different subdirectories share a file name.
+/// A name collision is caught before anything is written.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let result = run_all_into("themes", "dist", &options, &config_adapter, &persist_adapter)?;
+/// ```
+pub fn run_all_into<P: AsRef<Path>, Q: AsRef<Path>>(
+    dir: P,
+    output_dir: Q,
+    options: &ConvertOptions,
+    config_adapter: &impl ConfigPort,
+    persist_adapter: &impl PersistPort,
+) -> Result<BatchResult> {
+    let output_dir = output_dir.as_ref();
+
+    let mut sources: HashMap<PathBuf, PathBuf> = HashMap::new();
+    let mut jobs = Vec::new();
+    for input in find_inputs(dir.as_ref())? {
+        let Some(name) = input.file_name() else {
+            continue;
+        };
+        let output = derive_output_path_for(output_dir.join(name), options.format);
+        if let Some(other) = sources.insert(output.clone(), input.clone()) {
+            return Err(FernError::io(
+                format!(
+                    "{} and {} would both be written to {}",
+                    other.display(),
+                    input.display(),
+                    output.display()
+                ),
+                std::io::Error::from(std::io::ErrorKind::AlreadyExists),
+            ));
+        }
+        jobs.push((input, output));
+    }
+
+    std::fs::create_dir_all(output_dir)
+        .map_err(|e| FernError::io(format!("creating {}", output_dir.display()), e))?;
+
+    Ok(run_batch(jobs, options, config_adapter, persist_adapter))
+}
+
+/// Collects every `*.toml` file under `dir`, in path order.
+fn find_inputs(dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut inputs = Vec::new();
+    let mut pending = vec![dir.to_path_buf()];
+
+    while let Some(dir) = pending.pop() {
+        let entries = std::fs::read_dir(&dir)
+            .map_err(|e| FernError::io(format!("reading directory {}", dir.display()), e))?;
+
+        for entry in entries.filter_map(std::result::Result::ok) {
+            let path = entry.path();
+            if entry.file_type().is_ok_and(|kind| kind.is_dir()) {
+                pending.push(path);
+            } else if path.is_file() && path.extension().is_some_and(|ext| ext == "toml") {
+                inputs.push(path);
+            }
+        }
+    }
+
+    inputs.sort();
+    Ok(inputs)
+}
+
+/// Converts each `(input, output)` pair, recording successes and failures.
+fn run_batch(
+    jobs: Vec<(PathBuf, PathBuf)>,
+    options: &ConvertOptions,
+    config_adapter: &impl ConfigPort,
+    persist_adapter: &impl PersistPort,
+) -> BatchResult {
+    let mut batch = BatchResult::default();
+    for (input, output) in jobs {
+        match run(&input, &output, options, config_adapter, persist_adapter) {
+            Ok(result) => batch.succeeded.push((input, result)),
+            Err(err) => batch.failed.push((input, err)),
+        }
+    }
+    batch
+}
+
 /// Derives the output path from an input path.
 ///
 /// Replaces the file extension with `.json`. If the input has no extension,
@@ -319,8 +629,32 @@ pub fn run<P: AsRef<Path>, Q: AsRef<Path>>(
 /// ```
 #[must_use]
 pub fn derive_output_path(input: impl AsRef<Path>) -> std::path::PathBuf {
+    derive_output_path_for(input, OutputFormat::Json)
+}
+
+/// Derives the output path for a format from an input path.
+///
+/// Input from stdin (`-`) goes to stdout (`-`).
+///
+/// # Examples
+///
+/// ```rust
+/// use fern_theme::commands::convert::{derive_output_path_for, OutputFormat};
+/// use std::path::PathBuf;
+///
+/// assert_eq!(
+///     derive_output_path_for("config.toml", OutputFormat::Base16),
+///     PathBuf::from("config.yaml")
+/// );
+/// assert_eq!(derive_output_path_for("-", OutputFormat::Json), PathBuf::from("-"));
+/// ```
+#[must_use]
+pub fn derive_output_path_for(input: impl AsRef<Path>, format: OutputFormat) -> std::path::PathBuf {
     let input = input.as_ref();
-    input.with_extension("json")
+    if is_stdio(input) {
+        return input.to_path_buf();
+    }
+    input.with_extension(format.extension())
 }
 
 #[cfg(test)]
@@ -330,7 +664,10 @@ mod tests {
 
     #[test]
     fn derive_output_replaces_extension() {
-        assert_eq!(derive_output_path("config.toml"), PathBuf::from("config.json"));
+        assert_eq!(
+            derive_output_path("config.toml"),
+            PathBuf::from("config.json")
+        );
     }
 
     #[test]
@@ -346,10 +683,152 @@ mod tests {
         );
     }
 
+    #[test]
+    fn derive_output_keeps_stdio() {
+        assert!(is_stdio(derive_output_path("-")));
+        assert!(!is_stdio(derive_output_path("config.toml")));
+        assert_eq!(display_path(Path::new("-"), "<stdin>"), "<stdin>");
+        assert_eq!(display_path(Path::new("a.toml"), "<stdin>"), "a.toml");
+    }
+
     #[test]
     fn convert_options_default() {
         let options = ConvertOptions::default();
         assert!(!options.verbose);
         assert!(options.pretty);
+        assert!(!options.strict);
+        assert_eq!(options.format, OutputFormat::Json);
+    }
+
+    #[test]
+    fn run_writes_base16_scheme() {
+        use crate::adapters::{InMemoryPersistAdapter, TomlConfigAdapter};
+
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("mocha.toml");
+        std::fs::write(&input, "[appearance]\naccent = \"#89b4fa\"\n").unwrap();
+        let persist = InMemoryPersistAdapter::new();
+
+        run(
+            &input,
+            "mocha.yaml",
+            &ConvertOptions {
+                format: OutputFormat::Base16,
+                ..ConvertOptions::default()
+            },
+            &TomlConfigAdapter::new(),
+            &persist,
+        )
+        .unwrap();
+
+        let yaml = &persist.written()[Path::new("mocha.yaml")];
+        assert!(yaml.starts_with("scheme: \"mocha\"\n"), "{yaml}");
+        assert!(yaml.contains("base0D: \"89b4fa\"\n"), "{yaml}");
+    }
+
+    #[test]
+    fn run_normalizes_color_spellings() {
+        use crate::adapters::{InMemoryPersistAdapter, TomlConfigAdapter};
+
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("config.toml");
+        let persist = InMemoryPersistAdapter::new();
+        let convert = |accent: &str| {
+            std::fs::write(&input, format!("[appearance]\naccent = \"{accent}\"\n")).unwrap();
+            run(
+                &input,
+                "config.json",
+                &ConvertOptions::default(),
+                &TomlConfigAdapter::new(),
+                &persist,
+            )
+            .unwrap();
+            let json: serde_json::Value =
+                serde_json::from_str(&persist.written()[Path::new("config.json")]).unwrap();
+            json["colors"]["accent"].as_str().unwrap().to_string()
+        };
+
+        assert_eq!(convert("#fff"), "#ffffff");
+        assert_eq!(convert("#FFFFFF"), "#ffffff");
+        assert_eq!(convert("rgb(255, 255, 255)"), "#ffffff");
+        assert_eq!(convert("#89b4fa80"), "#8089b4fa");
+        assert_eq!(convert("tomato"), "#ff6347");
+    }
+
+    #[test]
+    fn run_all_converts_each_toml_and_records_failures() {
+        use crate::adapters::{FileSystemAdapter, TomlConfigAdapter};
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("good.toml"), "[bar]\nheight = 32\n").unwrap();
+        std::fs::write(
+            dir.path().join("bad.toml"),
+            "[appearance]\naccent = \"#gg0000\"\n",
+        )
+        .unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let result = run_all(
+            dir.path(),
+            &ConvertOptions::default(),
+            &TomlConfigAdapter::new(),
+            &FileSystemAdapter::new(),
+        )
+        .unwrap();
+
+        assert_eq!(result.succeeded.len(), 1);
+        assert_eq!(result.failed.len(), 1);
+        assert!(!result.is_success());
+        assert!(dir.path().join("good.json").exists());
+        assert!(!dir.path().join("bad.json").exists());
+    }
+
+    #[test]
+    fn run_all_into_mirrors_names_into_output_dir() {
+        use crate::adapters::{FileSystemAdapter, TomlConfigAdapter};
+
+        let dir = tempfile::tempdir().unwrap();
+        let themes = dir.path().join("themes");
+        std::fs::create_dir_all(themes.join("extra")).unwrap();
+        std::fs::write(themes.join("mocha.toml"), "[bar]\nheight = 32\n").unwrap();
+        std::fs::write(themes.join("extra/latte.toml"), "[bar]\nheight = 36\n").unwrap();
+        let dist = dir.path().join("dist");
+
+        let result = run_all_into(
+            &themes,
+            &dist,
+            &ConvertOptions::default(),
+            &TomlConfigAdapter::new(),
+            &FileSystemAdapter::new(),
+        )
+        .unwrap();
+
+        assert!(result.is_success());
+        assert!(dist.join("mocha.json").is_file());
+        assert!(dist.join("latte.json").is_file());
+        assert!(!themes.join("mocha.json").exists());
+    }
+
+    #[test]
+    fn run_all_into_rejects_name_collisions() {
+        use crate::adapters::{FileSystemAdapter, TomlConfigAdapter};
+
+        let dir = tempfile::tempdir().unwrap();
+        for sub in ["a", "b"] {
+            std::fs::create_dir_all(dir.path().join(sub)).unwrap();
+            std::fs::write(dir.path().join(sub).join("theme.toml"), "").unwrap();
+        }
+        let dist = dir.path().join("dist");
+
+        let result = run_all_into(
+            dir.path(),
+            &dist,
+            &ConvertOptions::default(),
+            &TomlConfigAdapter::new(),
+            &FileSystemAdapter::new(),
+        );
+
+        assert!(result.is_err());
+        assert!(!dist.exists());
     }
 }
@@ -18,6 +18,7 @@
 //! | [`convert`] | `fernctl convert` | Convert TOML to JSON |
 //! | [`query`] | `fernctl query` | Query specific theme values |
 //! | [`watch`] | `fernctl watch` | Watch config and auto-convert on changes |
+//! | `schema` | `fernctl schema` | Emit a JSON Schema for the user config (`schema` feature) |
 //!
 //! ## Architecture
 //!
@@ -59,11 +60,11 @@
 //! // Options configure command behavior
 //! let options = watch::WatchOptions {
 //!     debounce_ms: 100,
-//!     notify_on_success: true,
+//!     notify: watch::Notify::All,
 //!     ..Default::default()
 //! };
 //!
-//! watch::run(&config_path, &json_path, options, &config_adapter, &persist_adapter)?;
+//! watch::run(&config_path, &json_path, &options, &config_adapter, &persist_adapter, &NullIpcPort)?;
 //! ```
 //!
 //! ## Error Handling
@@ -90,3 +91,7 @@ pub mod validate;
 #[cfg(feature = "watch")]
 #[cfg_attr(docsrs, doc(cfg(feature = "watch")))]
 pub mod watch;
+
+#[cfg(feature = "schema")]
+#[cfg_attr(docsrs, doc(cfg(feature = "schema")))]
+pub mod schema;
@@ -63,7 +63,9 @@
 //! | Path | Type | Description |
 //! |------|------|-------------|
 //! | `bar.height` | integer | Bar height in pixels |
-//! | `bar.position` | string | Bar position ("top" or "bottom") |
+//! | `bar.position` | string | Bar position ("top", "bottom", "left", or "right") |
+//! | `bar.opacity` | float | Bar background opacity (0.0–1.0) |
+//! | `bar.blur` | boolean | Whether the compositor blurs behind the bar |
 //!
 //! ### Typography
 //!
@@ -71,6 +73,28 @@
 //! |------|------|-------------|
 //! | `typography.family` | string | Primary font family |
 //! | `typography.mono` | string | Monospace font family |
+//! | `typography.line_height` | number | Line height multiplier |
+//! | `typography.letter_spacing` | number | Letter spacing (px) |
+//!
+//! ### Spacing
+//!
+//! | Path | Type | Description |
+//! |------|------|-------------|
+//! | `spacing.xs` … `spacing.xl` | integer | Spacing scale levels (px) |
+//!
+//! ### Components
+//!
+//! Padding derived from the spacing scale (see
+//! [`ComponentSpacing`](crate::domain::components::ComponentSpacing)).
+//!
+//! | Path | Type | Description |
+//! |------|------|-------------|
+//! | `components.button.horizontal` | integer | Button horizontal padding (px) |
+//! | `components.button.vertical` | integer | Button vertical padding (px) |
+//! | `components.module.horizontal` | integer | Bar module horizontal padding (px) |
+//! | `components.module.vertical` | integer | Bar module vertical padding (px) |
+//! | `components.bar.horizontal` | integer | Bar horizontal padding (px) |
+//! | `components.bar.vertical` | integer | Bar vertical padding (px) |
 //!
 //! ### Radius
 //!
@@ -118,12 +142,13 @@
 //! let adapter = TomlConfigAdapter::new();
 //! let options = QueryOptions { verbose: false };
 //!
-//! if let Some(value) = run(&config_path, "colors.accent", options, &adapter)? {
+//! if let Some(value) = run(&config_path, "colors.accent", &options, &adapter)? {
 //!     println!("Accent: {}", value);
 //! }
 //! ```
 
 use crate::error::Result;
+use crate::load_theme;
 use crate::ports::inbound::ConfigPort;
 use std::path::Path;
 
@@ -151,6 +176,8 @@ pub const AVAILABLE_PATHS: &[&str] = &[
     "variant",
     "bar.height",
     "bar.position",
+    "bar.opacity",
+    "bar.blur",
     "colors.background",
     "colors.foreground",
     "colors.accent",
@@ -161,6 +188,19 @@ pub const AVAILABLE_PATHS: &[&str] = &[
     "colors.info",
     "typography.family",
     "typography.mono",
+    "typography.line_height",
+    "typography.letter_spacing",
+    "spacing.xs",
+    "spacing.sm",
+    "spacing.md",
+    "spacing.lg",
+    "spacing.xl",
+    "components.button.horizontal",
+    "components.button.vertical",
+    "components.module.horizontal",
+    "components.module.vertical",
+    "components.bar.horizontal",
+    "components.bar.vertical",
     "radius.sm",
     "radius.md",
     "radius.lg",
@@ -193,7 +233,7 @@ pub const AVAILABLE_PATHS: &[&str] = &[
 /// let adapter = TomlConfigAdapter::new();
 /// let options = QueryOptions::default();
 ///
-/// match run(&config_path, "colors.accent", options, &adapter)? {
+/// match run(&config_path, "colors.accent", &options, &adapter)? {
 ///     Some(value) => println!("{}", value),
 ///     None => eprintln!("Unknown path"),
 /// }
@@ -201,7 +241,7 @@ pub const AVAILABLE_PATHS: &[&str] = &[
 pub fn run<P: AsRef<Path>>(
     config_path: P,
     query_path: &str,
-    options: QueryOptions,
+    options: &QueryOptions,
     adapter: &impl ConfigPort,
 ) -> Result<Option<String>> {
     let config_path = config_path.as_ref();
@@ -211,15 +251,15 @@ pub fn run<P: AsRef<Path>>(
     }
 
     // Load and validate configuration
-    let raw = adapter.load_from_file(config_path)?;
-    let validated = raw.validate()?;
-    let theme = validated.into_theme();
+    let theme = load_theme(config_path, adapter)?;
 
     // Extract value by path
     let value = match query_path {
         "variant" => Some(theme.variant.name().to_string()),
         "bar.height" => Some(theme.bar.height.to_string()),
         "bar.position" => Some(theme.bar.position.name().to_string()),
+        "bar.opacity" => Some(theme.bar.opacity.to_string()),
+        "bar.blur" => Some(theme.bar.blur.to_string()),
         "colors.background" => Some(theme.colors.background.to_hex()),
         "colors.foreground" => Some(theme.colors.foreground.to_hex()),
         "colors.accent" => Some(theme.colors.accent.to_hex()),
@@ -230,6 +270,19 @@ pub fn run<P: AsRef<Path>>(
         "colors.info" => Some(theme.colors.info.to_hex()),
         "typography.family" => Some(theme.typography.family.name().to_string()),
         "typography.mono" => Some(theme.typography.mono.name().to_string()),
+        "typography.line_height" => Some(theme.typography.line_height.to_string()),
+        "typography.letter_spacing" => Some(theme.typography.letter_spacing.to_string()),
+        "spacing.xs" => Some(theme.spacing.xs.to_string()),
+        "spacing.sm" => Some(theme.spacing.sm.to_string()),
+        "spacing.md" => Some(theme.spacing.md.to_string()),
+        "spacing.lg" => Some(theme.spacing.lg.to_string()),
+        "spacing.xl" => Some(theme.spacing.xl.to_string()),
+        "components.button.horizontal" => Some(theme.components().button_padding().0.to_string()),
+        "components.button.vertical" => Some(theme.components().button_padding().1.to_string()),
+        "components.module.horizontal" => Some(theme.components().module_padding().0.to_string()),
+        "components.module.vertical" => Some(theme.components().module_padding().1.to_string()),
+        "components.bar.horizontal" => Some(theme.components().bar_padding().0.to_string()),
+        "components.bar.vertical" => Some(theme.components().bar_padding().1.to_string()),
         "radius.sm" => Some(theme.radius.sm.to_string()),
         "radius.md" => Some(theme.radius.md.to_string()),
         "radius.lg" => Some(theme.radius.lg.to_string()),
@@ -263,9 +316,31 @@ pub fn run<P: AsRef<Path>>(
 /// ```
 #[must_use]
 pub fn find_similar_paths(unknown: &str) -> Vec<String> {
-    let mut candidates: Vec<(f64, &str)> = AVAILABLE_PATHS
-        .iter()
-        .map(|&path| (strsim::jaro_winkler(unknown, path), path))
+    find_similar(unknown, AVAILABLE_PATHS.iter().copied())
+}
+
+/// Finds the candidates most similar to `unknown`.
+///
+/// The general form of [`find_similar_paths`], for "did you mean?"
+/// suggestions over any set of names. Returns at most 3 candidates, most
+/// similar first.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::commands::query::find_similar;
+///
+/// let suggestions = find_similar("shel", ["obs", "shell", "theme-watcher"]);
+/// assert_eq!(suggestions, vec!["shell".to_string()]);
+/// ```
+#[must_use]
+pub fn find_similar<'a>(
+    unknown: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    let mut candidates: Vec<(f64, &str)> = candidates
+        .into_iter()
+        .map(|candidate| (strsim::jaro_winkler(unknown, candidate), candidate))
         .filter(|(score, _)| *score > 0.6)
         .collect();
 
@@ -274,7 +349,7 @@ pub fn find_similar_paths(unknown: &str) -> Vec<String> {
     candidates
         .into_iter()
         .take(3)
-        .map(|(_, path)| path.to_string())
+        .map(|(_, candidate)| candidate.to_string())
         .collect()
 }
 
@@ -285,12 +360,17 @@ pub fn find_similar_paths(unknown: &str) -> Vec<String> {
 pub fn print_available_paths() {
     eprintln!("\nAvailable paths:");
     eprintln!("  variant");
-    eprintln!("  bar.height, bar.position");
+    eprintln!("  bar.height, bar.position, bar.opacity, bar.blur");
     eprintln!(
         "  colors.background, colors.foreground, colors.accent, colors.surface"
     );
     eprintln!("  colors.error, colors.warning, colors.success, colors.info");
     eprintln!("  typography.family, typography.mono");
+    eprintln!("  typography.line_height, typography.letter_spacing");
+    eprintln!("  spacing.xs, spacing.sm, spacing.md, spacing.lg, spacing.xl");
+    eprintln!("  components.button.horizontal, components.button.vertical");
+    eprintln!("  components.module.horizontal, components.module.vertical");
+    eprintln!("  components.bar.horizontal, components.bar.vertical");
     eprintln!("  radius.sm, radius.md, radius.lg");
 }
 
@@ -0,0 +1,91 @@
+//! # JSON Schema Generation
+//!
+//! The `schema` command emits a [JSON Schema](https://json-schema.org/) that
+//! describes the user configuration file ([`UserConfig`]). Editors with
+//! schema-aware TOML support (e.g. VS Code with Even Better TOML) use it for
+//! inline validation and autocomplete before `fernctl validate` ever runs.
+//!
+//! ## Usage
+//!
+//! ```bash
+//! fernctl schema > ~/.config/fern/config.schema.json
+//! ```
+//!
+//! Then point the editor at it, for example with a directive at the top of
+//! `config.toml`:
+//!
+//! ```toml
+//! #:schema ./config.schema.json
+//! ```
+//!
+//! ## What the Schema Covers
+//!
+//! | Aspect | Source |
+//! |--------|--------|
+//! | Field types and defaults | Derived from [`UserConfig`] |
+//! | `appearance.theme` | Enum: `dark`, `light`, `auto` |
+//! | `bar.position` | Enum: `top`, `bottom` |
+//! | Color fields | Any string, with hex, `rgba()`, CSS name and `${VAR}` examples |
+//! | Integer fields | Ranges implied by their pixel types |
+//!
+//! This module requires the `schema` feature.
+
+use crate::domain::user_config::UserConfig;
+
+/// Returns the JSON Schema for the user configuration file.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::commands::schema::user_config_schema;
+///
+/// let schema = user_config_schema();
+/// assert!(schema["properties"]["appearance"].is_object());
+/// ```
+#[must_use]
+pub fn user_config_schema() -> serde_json::Value {
+    schemars::schema_for!(UserConfig).to_value()
+}
+
+/// Renders the user configuration schema as pretty-printed JSON.
+#[must_use]
+pub fn run() -> String {
+    // Serializing a `serde_json::Value` cannot fail.
+    serde_json::to_string_pretty(&user_config_schema()).unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn schema_describes_sections() {
+        let schema = user_config_schema();
+        assert!(schema["properties"]["appearance"].is_object());
+        assert!(schema["properties"]["bar"].is_object());
+    }
+
+    #[test]
+    fn schema_lists_theme_variants() {
+        let rendered = run();
+        assert!(rendered.contains("\"dark\""));
+        assert!(rendered.contains("\"light\""));
+        assert!(rendered.contains("\"bottom\""));
+    }
+
+    #[test]
+    fn schema_accepts_every_color_form() {
+        let schema = user_config_schema();
+        let defs = &schema["$defs"];
+        for color in [
+            &defs["AppearanceConfig"]["properties"]["accent"],
+            &defs["UserColorsConfig"]["properties"]["surface"],
+        ] {
+            assert!(color.get("pattern").is_none());
+            let examples = color["examples"].to_string();
+            assert!(examples.contains("rgba("));
+            assert!(examples.contains("tomato"));
+            assert!(examples.contains("${FERN_ACCENT}"));
+        }
+    }
+}
@@ -15,8 +15,24 @@
 //!
 //! # With verbose output
 //! fernctl validate -v
+//!
+//! # List only the values the config changes from the defaults
+//! fernctl validate --diff
+//! ```
+//!
+//! ## Diffing Against Defaults
+//!
+//! `--diff` compares the validated theme with the built-in theme for its
+//! variant ([`Theme::base_defaults`]) and prints each value that differs:
+//!
+//! ```text
+//! bar.height: 40 -> 32
+//! colors.accent: #89b4fa -> #ff6b6b
 //! ```
 //!
+//! Use it to audit a config for accidental overrides, or to trim one down
+//! before sharing it.
+//!
 //! ## Validation Stages
 //!
 //! Validation proceeds in three stages:
@@ -60,7 +76,7 @@
 //!
 //! - Color values are valid hex (`#RRGGBB` or `#RRGGBBAA`)
 //! - Numbers are within allowed ranges
-//! - Enum values are recognized (`"top"`, `"bottom"`, etc.)
+//! - Enum values are recognized (`"top"`, `"left"`, etc.)
 //!
 //! **Errors at this stage:**
 //! - `fern::config::invalid_color` — Malformed color value
@@ -95,7 +111,7 @@
 //! let adapter = TomlConfigAdapter::new();
 //! let options = ValidateOptions { verbose: true };
 //!
-//! match run(&path, options, &adapter) {
+//! match run(&path, &options, &adapter) {
 //!     Ok(result) => {
 //!         println!("Valid! {} warnings", result.warnings.len());
 //!     }
@@ -121,6 +137,7 @@ use std::path::Path;
 ///
 /// let options = ValidateOptions {
 ///     verbose: true,
+///     strict: false,
 /// };
 /// ```
 #[derive(Debug, Clone, Default)]
@@ -132,6 +149,12 @@ pub struct ValidateOptions {
     /// - Theme variant detected
     /// - Key configuration values
     pub verbose: bool,
+
+    /// Whether to treat warnings as errors.
+    ///
+    /// When enabled, any warning (unknown or deprecated keys, etc.) fails
+    /// validation with [`ConfigError::StrictWarnings`](crate::error::ConfigError::StrictWarnings).
+    pub strict: bool,
 }
 
 /// Result of a successful validation.
@@ -143,7 +166,7 @@ pub struct ValidateOptions {
 /// # Example
 ///
 /// ```rust,ignore
-/// let result = validate::run(&path, options, &adapter)?;
+/// let result = validate::run(&path, &options, &adapter)?;
 ///
 /// for warning in &result.warnings {
 ///     eprintln!("Warning: {}", warning);
@@ -199,7 +222,7 @@ pub struct ValidateResult {
 /// let adapter = TomlConfigAdapter::new();
 /// let options = ValidateOptions::default();
 ///
-/// match run(Path::new("config.toml"), options, &adapter) {
+/// match run(Path::new("config.toml"), &options, &adapter) {
 ///     Ok(result) => {
 ///         println!("Configuration is valid!");
 ///         println!("Theme variant: {}", result.theme.variant.name());
@@ -220,7 +243,7 @@ pub struct ValidateResult {
 /// ```
 pub fn run<P: AsRef<Path>>(
     path: P,
-    options: ValidateOptions,
+    options: &ValidateOptions,
     adapter: &impl ConfigPort,
 ) -> Result<ValidateResult> {
     let path = path.as_ref();
@@ -233,7 +256,10 @@ pub fn run<P: AsRef<Path>>(
     let raw = adapter.load_from_file(path)?;
 
     // Validate the parsed configuration
-    let validated = raw.validate()?;
+    let mut validated = raw.validate()?;
+    if options.strict {
+        validated = validated.deny_warnings()?;
+    }
 
     // Collect warnings
     let warnings: Vec<String> = validated.warnings().iter().map(ToString::to_string).collect();
@@ -258,5 +284,6 @@ mod tests {
     fn validate_options_default() {
         let options = ValidateOptions::default();
         assert!(!options.verbose);
+        assert!(!options.strict);
     }
 }
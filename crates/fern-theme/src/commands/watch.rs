@@ -22,6 +22,9 @@
 //!
 //! # Disable desktop notifications
 //! fernctl watch --quiet
+//!
+//! # Also signal QuickShell to reload after each conversion
+//! fernctl watch --reload
 //! ```
 //!
 //! ## How It Works
@@ -143,18 +146,31 @@
 //! No IPC is needed — file watching on both sides creates a clean
 //! decoupled architecture.
 //!
+//! The JSON is only rewritten when the theme actually changes. Edits that
+//! convert to the same theme as last time — a new comment, reordered keys —
+//! are reported as [`WatchEvent::Unchanged`] and leave the file, and the
+//! shell, alone.
+//!
+//! Some compositors make `FileView` watching unreliable, and QuickShell can
+//! miss a change when the file is rewritten rapidly. With `--reload`
+//! ([`WatchOptions::reload`]), each successful conversion is followed by an
+//! explicit reload through the [`IpcPort`], such as
+//! [`SignalIpcAdapter`](crate::adapters::SignalIpcAdapter) sending `SIGHUP`.
+//! Reloads are debounced along with conversions. A failed reload (say, the
+//! shell is not running) is reported like a warning and watching carries on.
+//!
 //! ## Exit Codes
 //!
 //! | Code | Meaning |
 //! |------|---------|
-//! | `0` | Normal exit (Ctrl+C) |
-//! | `1` | Initialization error (file not found, etc.) |
-//! | `2` | Watch system error |
+//! | `0` | Normal exit ([`StopToken`]; the binary sets it on Ctrl+C) |
+//! | `2` | Initialization error (file not found, etc.) |
+//! | `3` | Watch system error |
 //!
 //! ## Programmatic Usage
 //!
 //! ```rust,ignore
-//! use fern_theme::commands::watch::{run, WatchOptions, WatchEvent};
+//! use fern_theme::commands::watch::{run_with_handler, Notify, WatchOptions, WatchEvent};
 //! use fern_theme::adapters::{TomlConfigAdapter, FileSystemAdapter};
 //!
 //! let config_adapter = TomlConfigAdapter::new();
@@ -162,23 +178,25 @@
 //!
 //! let options = WatchOptions {
 //!     debounce_ms: 100,
-//!     notify_on_success: true,
-//!     notify_on_error: true,
+//!     notify: Notify::All,
 //!     verbose: true,
+//!     ..WatchOptions::default()
 //! };
 //!
 //! // Run the watch loop (blocks until interrupted)
-//! run(
+//! run_with_handler(
 //!     &config_path,
 //!     &json_path,
-//!     options,
+//!     &options,
 //!     &config_adapter,
 //!     &persist_adapter,
+//!     &NullIpcPort,
 //!     |event| {
 //!         // Custom event handler
 //!         match event {
-//!             WatchEvent::Reloaded { warnings } => { /* ... */ }
-//!             WatchEvent::Error { error } => { /* ... */ }
+//!             WatchEvent::Reloaded { warnings, .. } => { /* ... */ }
+//!             WatchEvent::Error { error, .. } => { /* ... */ }
+//!             _ => {}
 //!         }
 //!     },
 //! )?;
@@ -194,14 +212,61 @@
 //!
 //! It's safe to run indefinitely in the background.
 
-use crate::error::{FernError, Notification, Notifiable, Result, Severity};
+use crate::domain::theme::Theme;
+use crate::error::{FernError, Notifiable, Notification, Result, Severity};
 use crate::ports::inbound::ConfigPort;
-use crate::ports::outbound::PersistPort;
+use crate::ports::outbound::{IpcPort, PersistPort};
+use crate::theme_to_json;
 use notify_debouncer_mini::{new_debouncer, notify::RecursiveMode, DebounceEventResult};
 use std::path::Path;
-use std::sync::mpsc::{channel, Receiver};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{channel, Receiver, RecvTimeoutError};
+use std::sync::Arc;
 use std::time::Duration;
 
+/// How often the watch loop checks its [`StopToken`].
+const STOP_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Stops a running watch loop from elsewhere, such as another thread, a
+/// [`WatchEvent`] handler, or the caller's Ctrl+C handler.
+///
+/// Clones share the same flag. The loop notices within a tenth of a second
+/// and returns `Ok(())`. The library never installs a signal handler
+/// itself; the `fern-theme watch` binary stops the loop through this token.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::commands::watch::{StopToken, WatchOptions};
+///
+/// let options = WatchOptions::default();
+/// let stop = options.stop.clone();
+/// // ... run the loop on another thread, then:
+/// stop.stop();
+/// assert!(options.stop.is_stopped());
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct StopToken(Arc<AtomicBool>);
+
+impl StopToken {
+    /// Creates a token that has not been stopped.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Asks the watch loop to exit.
+    pub fn stop(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    /// Returns `true` once [`stop`](Self::stop) has been called.
+    #[must_use]
+    pub fn is_stopped(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
 /// Options for the watch command.
 ///
 /// These options control the behavior of file watching, notification delivery,
@@ -210,13 +275,14 @@ use std::time::Duration;
 /// # Example
 ///
 /// ```rust
-/// use fern_theme::commands::watch::WatchOptions;
+/// use fern_theme::commands::watch::{Notify, WatchOptions};
 ///
 /// let options = WatchOptions {
 ///     debounce_ms: 200,  // Wait 200ms for events to settle
-///     notify_on_success: true,
-///     notify_on_error: true,
+///     notify: Notify::All,
 ///     verbose: false,
+///     reload: false,
+///     ..WatchOptions::default()
 /// };
 /// ```
 ///
@@ -225,9 +291,9 @@ use std::time::Duration;
 /// | Option | Default | Rationale |
 /// |--------|---------|-----------|
 /// | `debounce_ms` | 100 | Fast enough to feel responsive, slow enough to debounce |
-/// | `notify_on_success` | true | User wants feedback that changes were applied |
-/// | `notify_on_error` | true | Errors should be visible |
+/// | `notify` | [`Notify::All`] | Feedback that changes were applied; errors should be visible |
 /// | `verbose` | false | Terminal output is opt-in |
+/// | `reload` | false | QuickShell normally picks up the file itself |
 #[derive(Debug, Clone)]
 pub struct WatchOptions {
     /// Debounce duration in milliseconds.
@@ -241,17 +307,12 @@ pub struct WatchOptions {
     /// - 100-200ms for slower editors or network filesystems
     pub debounce_ms: u64,
 
-    /// Whether to send a notification on successful reload.
-    ///
-    /// When `true`, a brief notification confirms each successful conversion.
-    /// Set to `false` for a quieter experience.
-    pub notify_on_success: bool,
-
-    /// Whether to send a notification on errors.
+    /// Which desktop notifications to send.
     ///
-    /// When `true`, parse errors and validation failures trigger notifications.
-    /// Strongly recommended to keep this enabled.
-    pub notify_on_error: bool,
+    /// By default each successful conversion is confirmed and parse errors
+    /// and validation failures are shown. [`Notify::Errors`] gives a quieter
+    /// experience; keeping errors on is strongly recommended.
+    pub notify: Notify,
 
     /// Whether to print events to the terminal.
     ///
@@ -261,19 +322,55 @@ pub struct WatchOptions {
     /// [14:32:15] Error: invalid color at line 5
     /// ```
     pub verbose: bool,
+
+    /// Whether to signal the shell to reload after each conversion.
+    ///
+    /// For when QuickShell's own file watching misses changes. The reload
+    /// goes through the `ipc_adapter` passed to [`run`].
+    pub reload: bool,
+
+    /// Stops the loop when triggered; see [`StopToken`].
+    pub stop: StopToken,
 }
 
 impl Default for WatchOptions {
     fn default() -> Self {
         Self {
             debounce_ms: 100,
-            notify_on_success: true,
-            notify_on_error: true,
+            notify: Notify::All,
             verbose: false,
+            reload: false,
+            stop: StopToken::new(),
         }
     }
 }
 
+/// Which desktop notifications the watch loop sends.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Notify {
+    /// Successful reloads as well as warnings and errors.
+    #[default]
+    All,
+    /// Only warnings and errors.
+    Errors,
+    /// No notifications (`--quiet`).
+    Off,
+}
+
+impl Notify {
+    /// Whether successful reloads are confirmed.
+    #[must_use]
+    pub const fn on_success(self) -> bool {
+        matches!(self, Self::All)
+    }
+
+    /// Whether warnings and errors are shown.
+    #[must_use]
+    pub const fn on_error(self) -> bool {
+        !matches!(self, Self::Off)
+    }
+}
+
 /// Events emitted during the watch loop.
 ///
 /// These events can be used to implement custom notification handling
@@ -309,7 +406,7 @@ pub enum WatchEvent {
     Reloaded {
         /// Warnings encountered during validation (non-fatal).
         warnings: Vec<String>,
-        /// Number of bytes written to the output file.
+        /// Size in bytes of the theme JSON written to the output file.
         bytes_written: usize,
     },
 
@@ -323,16 +420,27 @@ pub enum WatchEvent {
         notification: Notification,
     },
 
-    /// File system event received but debouncing.
+    /// A change to the watched file has settled and is about to be
+    /// converted.
     ///
-    /// Only emitted in verbose mode.
+    /// Followed by `Reloaded`, `Unchanged` or `Error` for the same change.
     Debouncing,
+
+    /// The config converted to the same theme as last time.
+    ///
+    /// Nothing was written and the shell was not reloaded, so edits that
+    /// only touch comments or formatting don't cause a reload.
+    Unchanged {
+        /// Warnings encountered during validation (non-fatal).
+        warnings: Vec<String>,
+    },
 }
 
 /// A callback function for watch events.
 ///
 /// Implement this to customize how events are handled during watching.
-pub type EventHandler = dyn Fn(WatchEvent);
+/// See [`run_with_handler`].
+pub type EventHandler = dyn FnMut(WatchEvent);
 
 /// Runs the configuration watch loop.
 ///
@@ -347,10 +455,11 @@ pub type EventHandler = dyn Fn(WatchEvent);
 /// * `options` — Watch configuration options
 /// * `config_adapter` — Adapter for parsing TOML
 /// * `persist_adapter` — Adapter for writing JSON
+/// * `ipc_adapter` — Adapter for reloading the shell when `options.reload` is set
 ///
 /// # Returns
 ///
-/// - `Ok(())` — Watch loop exited cleanly (interrupted)
+/// - `Ok(())` — Watch loop exited cleanly because `options.stop` was set
 /// - `Err(FernError)` — Unrecoverable error (e.g., watch system failure)
 ///
 /// # Errors
@@ -374,21 +483,23 @@ pub type EventHandler = dyn Fn(WatchEvent);
 /// let persist_adapter = FileSystemAdapter::new();
 /// let options = WatchOptions::default();
 ///
-/// // This blocks until Ctrl+C
+/// // This blocks until `options.stop` is set
 /// run(
 ///     Path::new("~/.config/fern/config.toml"),
 ///     Path::new("~/.config/fern/config.json"),
-///     options,
+///     &options,
 ///     &config_adapter,
 ///     &persist_adapter,
+///     &NullIpcPort,
 /// )?;
 /// ```
 ///
 /// # Graceful Shutdown
 ///
-/// The watch loop responds to SIGINT (Ctrl+C) by exiting cleanly. No special
-/// cleanup is required — in-progress writes are completed atomically before
-/// the function returns.
+/// The watch loop exits cleanly with `Ok(())` once [`WatchOptions::stop`]
+/// is set. To stop on Ctrl+C, stop the token from your own SIGINT handler.
+/// The token is checked between conversions, so an in-progress write is
+/// always completed before the function returns.
 ///
 /// # Thread Safety
 ///
@@ -397,15 +508,67 @@ pub type EventHandler = dyn Fn(WatchEvent);
 ///
 /// ```rust,ignore
 /// tokio::task::spawn_blocking(|| {
-///     watch::run(&input, &output, options, &config, &persist)
+///     watch::run(&input, &output, &options, &config, &persist, &ipc)
 /// }).await?;
 /// ```
 pub fn run<P, Q>(
     input: P,
     output: Q,
-    options: WatchOptions,
+    options: &WatchOptions,
+    config_adapter: &impl ConfigPort,
+    persist_adapter: &impl PersistPort,
+    ipc_adapter: &impl IpcPort,
+) -> Result<()>
+where
+    P: AsRef<Path>,
+    Q: AsRef<Path>,
+{
+    run_with_handler(
+        input,
+        output,
+        options,
+        config_adapter,
+        persist_adapter,
+        ipc_adapter,
+        |_| {},
+    )
+}
+
+/// Runs the configuration watch loop, passing each [`WatchEvent`] to
+/// `handler`.
+///
+/// Behaves exactly like [`run`] — terminal output and notifications still
+/// follow `options` — but lets library consumers drive their own UI or
+/// logging. The handler sees, in order:
+///
+/// 1. [`WatchEvent::Started`] once the watcher is set up
+/// 2. `Reloaded` or `Error` for the initial conversion
+/// 3. For each change: `Debouncing`, then `Reloaded`, `Unchanged` or `Error`
+///
+/// # Errors
+///
+/// Same as [`run`].
+///
+/// # Example
+///
+/// ```rust,ignore
+/// use fern_theme::commands::watch::{run_with_handler, Notify, WatchEvent, WatchOptions};
+///
+/// let options = WatchOptions { notify: Notify::Errors, ..WatchOptions::default() };
+/// run_with_handler(&input, &output, &options, &config, &persist, &ipc, |event| {
+///     if let WatchEvent::Error { notification, .. } = event {
+///         status_bar.show(notification);
+///     }
+/// })?;
+/// ```
+pub fn run_with_handler<P, Q>(
+    input: P,
+    output: Q,
+    options: &WatchOptions,
     config_adapter: &impl ConfigPort,
     persist_adapter: &impl PersistPort,
+    ipc_adapter: &impl IpcPort,
+    mut handler: impl FnMut(WatchEvent),
 ) -> Result<()>
 where
     P: AsRef<Path>,
@@ -435,25 +598,44 @@ where
 
     // Log startup
     if options.verbose {
-        eprintln!(
-            "Watching {} -> {}",
-            input.display(),
-            output.display()
-        );
-        eprintln!("Press Ctrl+C to stop");
+        eprintln!("Watching {} -> {}", input.display(), output.display());
     }
+    handler(WatchEvent::Started {
+        input: input.to_path_buf(),
+        output: output.to_path_buf(),
+    });
+
+    // The last theme written, so no-op edits can skip the write
+    let mut last_theme = None;
 
     // Initial conversion
-    if let Err(e) = convert_and_report(input, output, &options, config_adapter, persist_adapter) {
+    let result = convert_and_report(
+        input,
+        output,
+        &options,
+        config_adapter,
+        persist_adapter,
+        ipc_adapter,
+        &mut last_theme,
+    );
+    if let Err(e) = &result {
         if options.verbose {
             eprintln!("[initial] Error: {e}");
         }
         // Don't exit on initial error — start watching anyway
     }
+    handler(result.unwrap_or_else(error_event));
 
     // Watch loop
     loop {
-        match rx.recv() {
+        if options.stop.is_stopped() {
+            if options.verbose {
+                eprintln!("[{}] Stopped", timestamp());
+            }
+            return Ok(());
+        }
+
+        match rx.recv_timeout(STOP_POLL_INTERVAL) {
             Ok(Ok(events)) => {
                 // Check if any events are relevant to our file
                 let relevant = events.iter().any(|e| e.path == input);
@@ -464,15 +646,24 @@ where
                 if options.verbose {
                     eprintln!("[{}] File changed, converting...", timestamp());
                 }
-
-                if let Err(e) =
-                    convert_and_report(input, output, &options, config_adapter, persist_adapter)
-                {
+                handler(WatchEvent::Debouncing);
+
+                let result = convert_and_report(
+                    input,
+                    output,
+                    &options,
+                    config_adapter,
+                    persist_adapter,
+                    ipc_adapter,
+                    &mut last_theme,
+                );
+                if let Err(e) = &result {
                     if options.verbose {
                         eprintln!("[{}] Error: {e}", timestamp());
                     }
                     // Continue watching — don't exit on config errors
                 }
+                handler(result.unwrap_or_else(error_event));
             }
             Ok(Err(error)) => {
                 // Debouncer error (usually recoverable)
@@ -480,6 +671,7 @@ where
                     eprintln!("[{}] Watch warning: {error}", timestamp());
                 }
             }
+            Err(RecvTimeoutError::Timeout) => {}
             Err(e) => {
                 // Channel closed — watcher died
                 return Err(FernError::watch(format!("watcher channel closed: {e}")));
@@ -491,35 +683,67 @@ where
 /// Converts the config and reports the result.
 ///
 /// This is the core conversion logic extracted for reuse between initial
-/// conversion and watch-triggered conversions.
+/// conversion and watch-triggered conversions. If the theme equals
+/// `last_theme` and the output still exists, nothing is written and
+/// [`WatchEvent::Unchanged`] is returned; otherwise the theme is saved,
+/// remembered in `last_theme`, and [`WatchEvent::Reloaded`] is returned.
 fn convert_and_report(
     input: &Path,
     output: &Path,
     options: &WatchOptions,
     config_adapter: &impl ConfigPort,
     persist_adapter: &impl PersistPort,
-) -> Result<()> {
+    ipc_adapter: &impl IpcPort,
+    last_theme: &mut Option<Theme>,
+) -> Result<WatchEvent> {
     // Load and validate
     let raw = config_adapter.load_from_file(input)?;
     let validated = raw.validate()?;
 
     // Collect warnings
-    let warnings: Vec<String> = validated.warnings().iter().map(ToString::to_string).collect();
+    let warnings: Vec<String> = validated
+        .warnings()
+        .iter()
+        .map(ToString::to_string)
+        .collect();
 
     // Report warnings
     for warning in &warnings {
         if options.verbose {
             eprintln!("[{}] Warning: {}", timestamp(), warning);
         }
-        if options.notify_on_error {
+        if options.notify.on_error() {
             // Warnings are non-fatal but should be shown
             send_notification(&Notification::warning("Config Warning", warning));
         }
     }
 
-    // Convert and persist
+    // Skip the write when nothing the shell sees has changed
     let theme = validated.into_theme();
+    if last_theme.as_ref() == Some(&theme) && persist_adapter.exists(output) {
+        if options.verbose {
+            eprintln!("[{}] Theme unchanged, skipping write", timestamp());
+        }
+        return Ok(WatchEvent::Unchanged { warnings });
+    }
+
+    // Convert and persist
     persist_adapter.save_theme(&theme, output)?;
+    let bytes_written = theme_to_json(&theme, true).map_or(0, |json| json.len());
+
+    // The JSON is written; a failed reload is worth a warning, not an error
+    if options.reload {
+        if let Err(e) = ipc_adapter.reload_theme(&theme) {
+            if options.verbose {
+                eprintln!("[{}] Reload failed: {e}", timestamp());
+            }
+            if options.notify.on_error() {
+                send_notification(&Notification::warning("Reload Failed", e.to_string()));
+            }
+        } else if options.verbose {
+            eprintln!("[{}] Signalled shell to reload", timestamp());
+        }
+    }
 
     // Report success
     if options.verbose {
@@ -531,14 +755,27 @@ fn convert_and_report(
         );
     }
 
-    if options.notify_on_success {
+    if options.notify.on_success() {
         send_notification(&Notification::info(
             "Config Reloaded",
             format!("Updated {}", output.display()),
         ));
     }
 
-    Ok(())
+    *last_theme = Some(theme);
+    Ok(WatchEvent::Reloaded {
+        warnings,
+        bytes_written,
+    })
+}
+
+/// Turns a failed conversion into the event for it.
+fn error_event(error: FernError) -> WatchEvent {
+    let notification = error.to_notification();
+    WatchEvent::Error {
+        error,
+        notification,
+    }
 }
 
 /// Sends a desktop notification.
@@ -601,7 +838,7 @@ fn notify_on_error<T>(result: Result<T>, options: &WatchOptions) -> Result<T> {
     match result {
         Ok(v) => Ok(v),
         Err(e) => {
-            if options.notify_on_error {
+            if options.notify.on_error() {
                 send_notification(&e.to_notification());
             }
             Err(e)
@@ -617,9 +854,157 @@ mod tests {
     fn watch_options_default() {
         let opts = WatchOptions::default();
         assert_eq!(opts.debounce_ms, 100);
-        assert!(opts.notify_on_success);
-        assert!(opts.notify_on_error);
+        assert_eq!(opts.notify, Notify::All);
         assert!(!opts.verbose);
+        assert!(!opts.reload);
+        assert!(!opts.stop.is_stopped());
+    }
+
+    #[test]
+    fn stop_token_ends_the_loop() {
+        use crate::adapters::{InMemoryPersistAdapter, TomlConfigAdapter};
+        use crate::ports::outbound::NullIpcPort;
+
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("config.toml");
+        std::fs::write(&input, "[bar]\nheight = 32\n").unwrap();
+
+        let options = WatchOptions {
+            notify: Notify::Off,
+            ..WatchOptions::default()
+        };
+        let stop = options.stop.clone();
+        let mut events = Vec::new();
+
+        run_with_handler(
+            &input,
+            "config.json",
+            &options,
+            &TomlConfigAdapter::new(),
+            &InMemoryPersistAdapter::new(),
+            &NullIpcPort,
+            |event| {
+                if matches!(event, WatchEvent::Reloaded { .. }) {
+                    stop.stop();
+                }
+                events.push(event);
+            },
+        )
+        .unwrap();
+
+        assert!(matches!(events[0], WatchEvent::Started { .. }));
+        assert!(matches!(events[1], WatchEvent::Reloaded { .. }));
+    }
+
+    #[test]
+    fn reload_signals_shell_after_conversion() {
+        use crate::adapters::{InMemoryPersistAdapter, TomlConfigAdapter};
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        #[derive(Default)]
+        struct CountingIpc(AtomicUsize);
+
+        impl IpcPort for CountingIpc {
+            fn reload_theme(&self, _theme: &Theme) -> Result<()> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                Ok(())
+            }
+            fn send_command(&self, _command: &str) -> Result<()> {
+                Ok(())
+            }
+            fn is_shell_running(&self) -> bool {
+                true
+            }
+            fn shell_version(&self) -> Option<String> {
+                None
+            }
+            fn ping(&self) -> Result<u64> {
+                Ok(0)
+            }
+        }
+
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("config.toml");
+        let ipc = CountingIpc::default();
+        let mut options = WatchOptions {
+            notify: Notify::Off,
+            ..WatchOptions::default()
+        };
+        let convert = |options: &WatchOptions| {
+            convert_and_report(
+                &input,
+                Path::new("config.json"),
+                options,
+                &TomlConfigAdapter::new(),
+                &InMemoryPersistAdapter::new(),
+                &ipc,
+                &mut None,
+            )
+        };
+
+        std::fs::write(&input, "[bar]\nheight = 32\n").unwrap();
+        convert(&options).unwrap();
+        assert_eq!(ipc.0.load(Ordering::SeqCst), 0);
+
+        options.reload = true;
+        convert(&options).unwrap();
+        assert_eq!(ipc.0.load(Ordering::SeqCst), 1);
+
+        // No reload when the config does not convert
+        std::fs::write(&input, "[bar]\nheight = \"tall\"\n").unwrap();
+        assert!(convert(&options).is_err());
+        assert_eq!(ipc.0.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn error_event_carries_notification() {
+        assert!(matches!(
+            error_event(FernError::watch("gone")),
+            WatchEvent::Error { ref notification, .. } if !notification.title.is_empty()
+        ));
+    }
+
+    #[test]
+    fn unchanged_theme_is_not_rewritten() {
+        use crate::adapters::{InMemoryPersistAdapter, TomlConfigAdapter};
+        use crate::ports::outbound::NullIpcPort;
+
+        let dir = tempfile::tempdir().unwrap();
+        let input = dir.path().join("config.toml");
+        let output = Path::new("config.json");
+        let persist = InMemoryPersistAdapter::new();
+        let options = WatchOptions {
+            notify: Notify::Off,
+            ..WatchOptions::default()
+        };
+        let mut last_theme = None;
+        let mut convert = || {
+            convert_and_report(
+                &input,
+                output,
+                &options,
+                &TomlConfigAdapter::new(),
+                &persist,
+                &NullIpcPort,
+                &mut last_theme,
+            )
+            .unwrap()
+        };
+
+        std::fs::write(&input, "[bar]\nheight = 32\n").unwrap();
+        assert!(
+            matches!(convert(), WatchEvent::Reloaded { bytes_written, .. } if bytes_written > 0)
+        );
+
+        // A comment-only edit converts to the same theme
+        persist.insert(output, "sentinel");
+        std::fs::write(&input, "# taller later\n[bar]\nheight = 32\n").unwrap();
+        assert!(matches!(convert(), WatchEvent::Unchanged { .. }));
+        assert_eq!(persist.written()[output], "sentinel");
+
+        std::fs::write(&input, "[bar]\nheight = 40\n").unwrap();
+        assert!(matches!(convert(), WatchEvent::Reloaded { .. }));
+        assert_ne!(persist.written()[output], "sentinel");
     }
 
     #[test]
@@ -0,0 +1,348 @@
+//! # Base16 Export
+//!
+//! [Base16](https://github.com/chriskempson/base16) describes a color
+//! scheme as 16 slots, `base00`–`base0F`, and a large ecosystem of
+//! terminal, editor, and shell templates consume it. Exporting a Fern
+//! theme as a Base16 scheme lets one theme drive all of them.
+//!
+//! ## Slot Mapping
+//!
+//! Base16 has more slots than the Fern palette has colors, so some colors
+//! fill more than one slot:
+//!
+//! | Slot | Base16 meaning | Fern color |
+//! |------|----------------|------------|
+//! | `base00` | Default background | `background` |
+//! | `base01` | Lighter background (status bars) | `surface` |
+//! | `base02` | Selection background | `surface_hover` |
+//! | `base03` | Comments, invisibles | `foreground_dim` |
+//! | `base04` | Dark foreground (status bars) | `foreground_dim` |
+//! | `base05` | Default foreground | `foreground` |
+//! | `base06` | Light foreground | `foreground` |
+//! | `base07` | Light background | `foreground` |
+//! | `base08` | Red — variables, deleted | `error` |
+//! | `base09` | Orange — constants | `warning` |
+//! | `base0A` | Yellow — classes, search | `warning` |
+//! | `base0B` | Green — strings, inserted | `success` |
+//! | `base0C` | Cyan — escapes, regexes | `info` |
+//! | `base0D` | Blue — functions | `accent` |
+//! | `base0E` | Magenta — keywords | `accent` |
+//! | `base0F` | Brown — deprecated | `error` |
+//!
+//! Base16 colors are opaque, so alpha is dropped.
+//!
+//! ## Import
+//!
+//! [`Base16Scheme`] goes the other way, turning a scheme into a starting
+//! `config.toml`. Only some slots have a clear Fern role; the rest are
+//! best guesses and are flagged with a comment in the generated file:
+//!
+//! | Fern color | Slot | Guessed |
+//! |------------|------|---------|
+//! | `background` | `base00` | |
+//! | `surface` | `base01` | |
+//! | `surface_hover` | `base02` | ✓ |
+//! | `foreground` | `base05` | |
+//! | `foreground_dim` | `base04` | ✓ |
+//! | `accent` | `base0D` | |
+//! | `error` | `base08` | |
+//! | `warning` | `base0A` | ✓ |
+//! | `success` | `base0B` | ✓ |
+//! | `info` | `base0C` | ✓ |
+//!
+//! The theme variant is guessed too: dark if the background is darker
+//! than the foreground.
+//!
+//! ## Output
+//!
+//! ```yaml
+//! scheme: "Fern Dark"
+//! author: "fern-theme"
+//! base00: "1e1e2e"
+//! base01: "313244"
+//! ...
+//! ```
+
+use super::theme::Theme;
+use super::tokens::color::{Background, ColorRole, ColorToken};
+use crate::error::{ConfigError, FernError, Result};
+use std::fmt::Write;
+
+/// Slot names, in order.
+const SLOT_NAMES: [&str; 16] = [
+    "base00", "base01", "base02", "base03", "base04", "base05", "base06", "base07", "base08",
+    "base09", "base0A", "base0B", "base0C", "base0D", "base0E", "base0F",
+];
+
+/// Fern color roles read from a Base16 scheme: `(role, slot, guessed)`.
+const IMPORT_ROLES: [(&str, usize, bool); 10] = [
+    ("background", 0x00, false),
+    ("surface", 0x01, false),
+    ("surface_hover", 0x02, true),
+    ("foreground", 0x05, false),
+    ("foreground_dim", 0x04, true),
+    ("accent", 0x0D, false),
+    ("error", 0x08, false),
+    ("warning", 0x0A, true),
+    ("success", 0x0B, true),
+    ("info", 0x0C, true),
+];
+
+/// Returns the 16 Base16 slots for a theme, in order, as `RRGGBB` hex.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::domain::base16;
+/// use fern_theme::domain::theme::Theme;
+///
+/// let theme = Theme::dark();
+/// let slots = base16::slots(&theme);
+/// assert_eq!(slots[0], ("base00", theme.colors.background.to_hex()[1..].to_string()));
+/// ```
+#[must_use]
+pub fn slots(theme: &Theme) -> [(&'static str, String); 16] {
+    let c = &theme.colors;
+    [
+        ("base00", rgb(c.background)),
+        ("base01", rgb(c.surface)),
+        ("base02", rgb(c.surface_hover)),
+        ("base03", rgb(c.foreground_dim)),
+        ("base04", rgb(c.foreground_dim)),
+        ("base05", rgb(c.foreground)),
+        ("base06", rgb(c.foreground)),
+        ("base07", rgb(c.foreground)),
+        ("base08", rgb(c.error)),
+        ("base09", rgb(c.warning)),
+        ("base0A", rgb(c.warning)),
+        ("base0B", rgb(c.success)),
+        ("base0C", rgb(c.info)),
+        ("base0D", rgb(c.accent)),
+        ("base0E", rgb(c.accent)),
+        ("base0F", rgb(c.error)),
+    ]
+}
+
+/// Renders a theme as a Base16 YAML scheme named `scheme`.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::domain::base16;
+/// use fern_theme::domain::theme::Theme;
+///
+/// let yaml = base16::to_yaml(&Theme::dark(), "Fern Dark");
+/// assert!(yaml.starts_with("scheme: \"Fern Dark\"\n"));
+/// assert_eq!(yaml.lines().count(), 18);
+/// ```
+#[must_use]
+pub fn to_yaml(theme: &Theme, scheme: &str) -> String {
+    let mut yaml = format!("scheme: {scheme:?}\nauthor: \"fern-theme\"\n");
+    for (slot, hex) in slots(theme) {
+        // Writing to a String cannot fail
+        let _ = writeln!(yaml, "{slot}: \"{hex}\"");
+    }
+    yaml
+}
+
+/// A Base16 color scheme read from YAML.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Base16Scheme {
+    /// The scheme's name, if it has one.
+    pub name: Option<String>,
+    /// The 16 slot colors, `base00` first.
+    pub slots: [ColorToken<Background>; 16],
+}
+
+impl Base16Scheme {
+    /// Parses a Base16 YAML scheme.
+    ///
+    /// Accepts both the classic flat layout and the newer layout with the
+    /// colors under `palette:`. Colors may be written with or without a
+    /// leading `#`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::MissingField`] if a slot is missing, or
+    /// [`ConfigError::InvalidColor`] if a slot is not a hex color.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::base16::{to_yaml, Base16Scheme};
+    /// use fern_theme::domain::theme::Theme;
+    ///
+    /// let scheme = Base16Scheme::parse(&to_yaml(&Theme::dark(), "Fern Dark")).unwrap();
+    /// assert_eq!(scheme.name.as_deref(), Some("Fern Dark"));
+    /// assert_eq!(scheme.slots[0], Theme::dark().colors.background);
+    /// ```
+    pub fn parse(yaml: &str) -> Result<Self> {
+        let mut name = None;
+        let mut values: [Option<&str>; 16] = [None; 16];
+
+        for line in yaml.lines() {
+            let line = line.trim();
+            if line.starts_with('#') {
+                continue;
+            }
+            let Some((key, value)) = line.split_once(':') else {
+                continue;
+            };
+            let value = unquote(value);
+
+            let key = key.trim();
+            if key == "scheme" || key == "name" {
+                name = Some(value.to_string());
+            } else if let Some(slot) = SLOT_NAMES.iter().position(|s| s.eq_ignore_ascii_case(key)) {
+                values[slot] = Some(value);
+            }
+        }
+
+        let mut slots = [ColorToken::default(); 16];
+        for (slot, value) in values.iter().enumerate() {
+            let value = value.ok_or_else(|| {
+                FernError::Config(ConfigError::MissingField {
+                    key: SLOT_NAMES[slot].to_string(),
+                    expected_type: "hex color".to_string(),
+                })
+            })?;
+            let hex = value.strip_prefix('#').unwrap_or(value);
+            slots[slot] = ColorToken::from_hex(&format!("#{hex}")).map_err(|_| {
+                FernError::Config(ConfigError::InvalidColor {
+                    value: value.to_string(),
+                    similar: Vec::new(),
+                    span: None,
+                    source_code: None,
+                })
+            })?;
+        }
+
+        Ok(Self { name, slots })
+    }
+
+    /// Returns `true` if the background is darker than the foreground.
+    #[must_use]
+    pub fn is_dark(&self) -> bool {
+        self.slots[0x00].relative_luminance() < self.slots[0x05].relative_luminance()
+    }
+
+    /// Renders a starting `config.toml` that uses this scheme's colors.
+    ///
+    /// Roles without a direct Base16 slot are marked `# guessed` so they
+    /// can be reviewed by hand.
+    #[must_use]
+    pub fn to_user_config(&self) -> String {
+        let source = self.name.as_ref().map_or_else(
+            || "a Base16 scheme".to_string(),
+            |name| format!("the Base16 scheme {name:?}"),
+        );
+        let variant = if self.is_dark() { "dark" } else { "light" };
+
+        let mut toml = format!(
+            "# Imported from {source}.\n\
+             # Colors marked \"guessed\" have no exact Base16 slot; review them.\n\
+             \n\
+             [appearance]\n\
+             theme = \"{variant}\"  # guessed from base00/base05\n\
+             \n\
+             [appearance.colors]\n"
+        );
+        for (role, slot, guessed) in IMPORT_ROLES {
+            let entry = format!("{role} = \"{}\"", self.slots[slot].to_hex());
+            let note = if guessed { ", guessed" } else { "" };
+            // Writing to a String cannot fail
+            let _ = writeln!(toml, "{entry:<28}# {}{note}", SLOT_NAMES[slot]);
+        }
+        toml
+    }
+}
+
+/// Strips whitespace, a trailing comment, and quotes from a YAML value.
+fn unquote(value: &str) -> &str {
+    let value = value.trim();
+    for quote in ['"', '\''] {
+        if let Some(rest) = value.strip_prefix(quote) {
+            return rest.split(quote).next().unwrap_or(rest);
+        }
+    }
+    value.split(" #").next().unwrap_or(value).trim()
+}
+
+/// Formats a color as `RRGGBB`, the form Base16 uses.
+fn rgb<Role: ColorRole>(color: ColorToken<Role>) -> String {
+    let (r, g, b) = color.to_rgb_tuple();
+    format!("{r:02x}{g:02x}{b:02x}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{slots, to_yaml, Base16Scheme, ColorToken, ConfigError, FernError, Theme};
+    use std::fmt::Write;
+
+    #[test]
+    fn slots_follow_palette() {
+        let theme = Theme::dark();
+        let slots = slots(&theme);
+
+        assert_eq!(slots[0].1, theme.colors.background.to_hex()[1..]);
+        assert_eq!(slots[5].1, theme.colors.foreground.to_hex()[1..]);
+        assert_eq!(slots[8].1, theme.colors.error.to_hex()[1..]);
+        assert_eq!(slots[13].1, theme.colors.accent.to_hex()[1..]);
+        assert_eq!(slots[15].0, "base0F");
+    }
+
+    #[test]
+    fn parse_accepts_both_layouts() {
+        let classic = to_yaml(&Theme::light(), "Fern Light");
+        let scheme = Base16Scheme::parse(&classic).unwrap();
+        assert_eq!(
+            scheme.slots[0x0D].to_hex(),
+            Theme::light().colors.accent.to_hex()
+        );
+        assert!(!scheme.is_dark());
+
+        let mut palette = String::from("system: \"base16\"\nname: \"Mocha\"\npalette:\n");
+        for (slot, hex) in slots(&Theme::dark()) {
+            let _ = writeln!(palette, "  {slot}: \"#{hex}\"");
+        }
+        let scheme = Base16Scheme::parse(&palette).unwrap();
+        assert_eq!(scheme.name.as_deref(), Some("Mocha"));
+        assert!(scheme.is_dark());
+    }
+
+    #[test]
+    fn parse_rejects_missing_or_invalid_slots() {
+        let yaml = to_yaml(&Theme::dark(), "Fern");
+
+        let missing = yaml.replace("base0F", "base0G");
+        assert!(matches!(
+            Base16Scheme::parse(&missing),
+            Err(FernError::Config(ConfigError::MissingField { ref key, .. })) if key == "base0F"
+        ));
+
+        let invalid = yaml.replacen("base00: \"", "base00: \"zz", 1);
+        assert!(Base16Scheme::parse(&invalid).is_err());
+    }
+
+    #[test]
+    fn user_config_flags_guesses_and_loads() {
+        let scheme = Base16Scheme::parse(&to_yaml(&Theme::dark(), "Fern")).unwrap();
+        let toml = scheme.to_user_config();
+
+        assert!(toml.contains("# base0D\n"), "{toml}");
+        assert!(toml.contains("# base0C, guessed\n"), "{toml}");
+
+        let config: crate::domain::user_config::UserConfig = toml::from_str(&toml).unwrap();
+        let theme = config.into_theme().unwrap();
+        assert_eq!(theme.colors.accent, Theme::dark().colors.accent);
+        assert_eq!(theme.colors.background, Theme::dark().colors.background);
+    }
+
+    #[test]
+    fn alpha_is_dropped() {
+        let mut theme = Theme::dark();
+        theme.colors.surface = ColorToken::from_rgba(0x31, 0x32, 0x44, 0x80);
+
+        assert!(to_yaml(&theme, "Fern").contains("base01: \"313244\"\n"));
+    }
+}
@@ -0,0 +1,122 @@
+//! # Component Spacing
+//!
+//! Components should not pick their own padding. A button that uses 6px
+//! here and 8px there drifts out of rhythm with everything around it, and
+//! changing the spacing scale no longer changes the whole shell.
+//!
+//! [`ComponentSpacing`] derives each component's padding from the theme's
+//! [`SpacingScale`], so every component moves together when the scale is
+//! customized:
+//!
+//! | Component | Horizontal | Vertical |
+//! |-----------|------------|----------|
+//! | Button | `sm` | `xs` |
+//! | Module | `md` | `sm` |
+//! | Bar | `sm` | `xs` |
+//!
+//! ```rust
+//! use fern_theme::domain::components::ComponentSpacing;
+//! use fern_theme::domain::tokens::spacing::SpacingScale;
+//!
+//! let spacing = ComponentSpacing::new(SpacingScale::default());
+//! assert_eq!(spacing.button_padding(), (8, 4));
+//! ```
+//!
+//! The padding is written into `theme.json` under `components`, where QML
+//! reads it instead of hardcoding values per component.
+
+use super::tokens::spacing::SpacingScale;
+use serde::ser::SerializeStruct;
+use serde::Serialize;
+
+/// Padding for each component, derived from a [`SpacingScale`].
+///
+/// Every method returns a `(horizontal, vertical)` pair in pixels.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ComponentSpacing {
+    scale: SpacingScale,
+}
+
+impl ComponentSpacing {
+    /// Creates component spacing from a spacing scale.
+    #[must_use]
+    pub const fn new(scale: SpacingScale) -> Self {
+        Self { scale }
+    }
+
+    /// Returns button padding: `sm` horizontally, `xs` vertically.
+    #[must_use]
+    pub const fn button_padding(&self) -> (u16, u16) {
+        (self.scale.sm, self.scale.xs)
+    }
+
+    /// Returns bar module padding: `md` horizontally, `sm` vertically.
+    #[must_use]
+    pub const fn module_padding(&self) -> (u16, u16) {
+        (self.scale.md, self.scale.sm)
+    }
+
+    /// Returns padding inside the bar itself: `sm` horizontally, `xs`
+    /// vertically.
+    #[must_use]
+    pub const fn bar_padding(&self) -> (u16, u16) {
+        (self.scale.sm, self.scale.xs)
+    }
+}
+
+/// A `(horizontal, vertical)` pair with named fields for JSON.
+#[derive(Serialize)]
+struct Padding {
+    horizontal: u16,
+    vertical: u16,
+}
+
+impl From<(u16, u16)> for Padding {
+    fn from((horizontal, vertical): (u16, u16)) -> Self {
+        Self {
+            horizontal,
+            vertical,
+        }
+    }
+}
+
+// Serialization: one `{horizontal, vertical}` object per component
+impl Serialize for ComponentSpacing {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut state = serializer.serialize_struct("ComponentSpacing", 3)?;
+        state.serialize_field("button", &Padding::from(self.button_padding()))?;
+        state.serialize_field("module", &Padding::from(self.module_padding()))?;
+        state.serialize_field("bar", &Padding::from(self.bar_padding()))?;
+        state.end()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn padding_follows_scale() {
+        let spacing = ComponentSpacing::new(SpacingScale {
+            xs: 2,
+            sm: 6,
+            ..SpacingScale::default()
+        });
+
+        assert_eq!(spacing.button_padding(), (6, 2));
+        assert_eq!(spacing.module_padding(), (12, 6));
+        assert_eq!(spacing.bar_padding(), (6, 2));
+    }
+
+    #[test]
+    fn serializes_named_padding() {
+        let json = serde_json::to_value(ComponentSpacing::default()).unwrap();
+        assert_eq!(json["button"]["horizontal"], 8);
+        assert_eq!(json["button"]["vertical"], 4);
+        assert_eq!(json["module"]["horizontal"], 12);
+        assert_eq!(json["bar"]["vertical"], 4);
+    }
+}
@@ -0,0 +1,220 @@
+//! # Semantic Lint Rules
+//!
+//! Structural validation checks each value on its own: a color parses, a
+//! height is in range. Some problems only show up when fields are looked at
+//! together — light text on a light background, or a corner radius larger
+//! than the bar it rounds. This module holds those cross-field rules.
+//!
+//! Rules produce [`ConfigWarning`]s, never errors: the theme still loads,
+//! and `--strict` turns the warnings into failures for CI.
+//!
+//! ## Adding a Rule
+//!
+//! Write a `fn(&Theme) -> Vec<ConfigWarning>` and add it to [`RULES`]:
+//!
+//! ```rust,ignore
+//! fn check_something(theme: &Theme) -> Vec<ConfigWarning> {
+//!     // ...
+//! }
+//!
+//! pub const RULES: &[SemanticRule] = &[
+//!     // ...
+//!     SemanticRule { name: "something", check: check_something },
+//! ];
+//! ```
+
+use crate::domain::theme::Theme;
+use crate::ports::inbound::ConfigWarning;
+
+/// Minimum contrast for body text (WCAG AA).
+pub const MIN_TEXT_CONTRAST: f64 = 4.5;
+
+/// Minimum contrast for secondary text and the accent (WCAG AA, large text).
+pub const MIN_SECONDARY_CONTRAST: f64 = 3.0;
+
+/// Minimum contrast for status colors.
+///
+/// Status colors mark icons and indicators rather than running text, so they
+/// only need to stand out from the background.
+pub const MIN_STATUS_CONTRAST: f64 = 2.0;
+
+/// Bar opacity below which text legibility depends on the wallpaper.
+pub const MIN_UNBLURRED_OPACITY: f32 = 0.5;
+
+/// A named cross-field check.
+#[derive(Debug, Clone, Copy)]
+pub struct SemanticRule {
+    /// Short rule name, for documentation and debugging.
+    pub name: &'static str,
+    /// Returns a warning for each problem found.
+    pub check: fn(&Theme) -> Vec<ConfigWarning>,
+}
+
+/// Every semantic rule, in the order they run.
+pub const RULES: &[SemanticRule] = &[
+    SemanticRule {
+        name: "contrast",
+        check: check_contrast,
+    },
+    SemanticRule {
+        name: "radius",
+        check: check_radius,
+    },
+    SemanticRule {
+        name: "bar-translucency",
+        check: check_bar_translucency,
+    },
+];
+
+/// Runs every rule in [`RULES`] against a theme.
+#[must_use]
+pub fn run(theme: &Theme) -> Vec<ConfigWarning> {
+    RULES.iter().flat_map(|rule| (rule.check)(theme)).collect()
+}
+
+/// Checks that text, accent, and status colors stand out from the background.
+fn check_contrast(theme: &Theme) -> Vec<ConfigWarning> {
+    let colors = &theme.colors;
+    let bg = &colors.background;
+
+    [
+        (
+            "colors.foreground",
+            colors.foreground.contrast_ratio(bg),
+            MIN_TEXT_CONTRAST,
+        ),
+        (
+            "colors.foreground_dim",
+            colors.foreground_dim.contrast_ratio(bg),
+            MIN_SECONDARY_CONTRAST,
+        ),
+        (
+            "colors.accent",
+            colors.accent.contrast_ratio(bg),
+            MIN_SECONDARY_CONTRAST,
+        ),
+        (
+            "colors.error",
+            colors.error.contrast_ratio(bg),
+            MIN_STATUS_CONTRAST,
+        ),
+        (
+            "colors.warning",
+            colors.warning.contrast_ratio(bg),
+            MIN_STATUS_CONTRAST,
+        ),
+        (
+            "colors.success",
+            colors.success.contrast_ratio(bg),
+            MIN_STATUS_CONTRAST,
+        ),
+        (
+            "colors.info",
+            colors.info.contrast_ratio(bg),
+            MIN_STATUS_CONTRAST,
+        ),
+    ]
+    .into_iter()
+    .filter(|(_, ratio, min)| ratio < min)
+    .map(|(key, ratio, min)| {
+        ConfigWarning::new(format!(
+            "contrast against the background is {ratio:.1}:1, below {min}:1"
+        ))
+        .with_key(key)
+        .with_suggestion("Pick a color further from the background's lightness")
+    })
+    .collect()
+}
+
+/// Checks that the radius scale increases and fits the bar.
+fn check_radius(theme: &Theme) -> Vec<ConfigWarning> {
+    let radius = &theme.radius;
+    let mut warnings = Vec::new();
+
+    if !(radius.sm <= radius.md && radius.md <= radius.lg) {
+        warnings.push(
+            ConfigWarning::new(format!(
+                "radius scale is not increasing (sm = {}, md = {}, lg = {})",
+                radius.sm, radius.md, radius.lg
+            ))
+            .with_key("appearance.radius")
+            .with_suggestion("Keep sm <= md <= lg"),
+        );
+    }
+
+    let half_bar = theme.bar.height / 2;
+    if radius.lg > half_bar {
+        warnings.push(
+            ConfigWarning::new(format!(
+                "radius.lg ({}px) is more than half the bar height ({}px); corners will be clipped",
+                radius.lg, theme.bar.height
+            ))
+            .with_key("appearance.radius.lg")
+            .with_suggestion(format!("Use at most {half_bar}px, or raise bar.height")),
+        );
+    }
+
+    warnings
+}
+
+/// Checks that a see-through bar keeps its text legible.
+fn check_bar_translucency(theme: &Theme) -> Vec<ConfigWarning> {
+    if theme.bar.blur || theme.bar.opacity >= MIN_UNBLURRED_OPACITY {
+        return Vec::new();
+    }
+
+    vec![ConfigWarning::new(format!(
+        "bar opacity {} without blur leaves text contrast to the wallpaper",
+        theme.bar.opacity
+    ))
+    .with_key("bar.opacity")
+    .with_suggestion(format!(
+        "Set bar.blur = true or raise bar.opacity to at least {MIN_UNBLURRED_OPACITY}"
+    ))]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::tokens::color::ColorToken;
+
+    fn keys(warnings: &[ConfigWarning]) -> Vec<&str> {
+        warnings.iter().filter_map(|w| w.key.as_deref()).collect()
+    }
+
+    #[test]
+    fn default_themes_are_clean() {
+        assert!(run(&Theme::dark()).is_empty());
+        assert!(run(&Theme::light()).is_empty());
+    }
+
+    #[test]
+    fn low_contrast_foreground_is_flagged() {
+        let mut theme = Theme::dark();
+        theme.colors.foreground = ColorToken::from_hex("#2a2a3a").unwrap();
+
+        assert_eq!(keys(&run(&theme)), vec!["colors.foreground"]);
+    }
+
+    #[test]
+    fn radius_rules() {
+        let mut theme = Theme::dark();
+        theme.radius.md = 2;
+        theme.radius.lg = 30;
+
+        assert_eq!(
+            keys(&run(&theme)),
+            vec!["appearance.radius", "appearance.radius.lg"]
+        );
+    }
+
+    #[test]
+    fn translucent_bar_needs_blur() {
+        let mut theme = Theme::dark();
+        theme.bar.opacity = 0.3;
+        assert_eq!(keys(&run(&theme)), vec!["bar.opacity"]);
+
+        theme.bar.blur = true;
+        assert!(run(&theme).is_empty());
+    }
+}
@@ -14,11 +14,11 @@
 //! │   ├── radius      — Semantic border radius
 //! │   └── typography  — Font families and sizes
 //! │
-//! ├── components/     — Component specifications (future)
-//! │   ├── button      — Button visual spec
-//! │   └── module      — Bar module spec
+//! ├── components      — Component padding derived from the spacing scale
+//! ├── base16          — Base16 scheme export
 //! │
-//! └── theme           — Complete theme combining all tokens
+//! ├── theme           — Complete theme combining all tokens
+//! └── lint            — Cross-field rules run after validation
 //! ```
 //!
 //! ## Design Philosophy
@@ -57,16 +57,17 @@
 //! let spacing = SpacingMd::pixels(); // 12
 //! ```
 
+pub mod base16;
+pub mod components;
+pub mod lint;
 pub mod theme;
 pub mod tokens;
 pub mod user_config;
 
-// Future modules
-// pub mod components;
-
 /// Convenient re-exports of domain types.
 pub mod prelude {
-    pub use super::theme::Theme;
+    pub use super::components::ComponentSpacing;
+    pub use super::theme::{PartialTheme, Theme};
     pub use super::tokens::{
         // Color types
         ColorToken,
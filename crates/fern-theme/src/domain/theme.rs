@@ -35,13 +35,21 @@
 //! let radius = theme.radius.button();
 //! ```
 
-use super::tokens::{
-    color::*,
-    radius::RadiusScale,
-    spacing::SpacingValue,
-    typography::Typography,
-};
+use super::components::ComponentSpacing;
+use super::tokens::{color::*, radius::RadiusScale, spacing::SpacingScale, typography::Typography};
+use crate::ports::inbound::ConfigWarning;
 use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Current version of the serialized [`Theme`] schema.
+///
+/// Bump this whenever the JSON shape of `Theme` changes incompatibly and
+/// add a matching migration step in the persistence adapter.
+pub const THEME_SCHEMA_VERSION: u32 = 2;
+
+const fn current_schema_version() -> u32 {
+    THEME_SCHEMA_VERSION
+}
 
 /// Theme variant selection.
 ///
@@ -59,6 +67,9 @@ pub enum ThemeVariant {
 }
 
 impl ThemeVariant {
+    /// Every variant, in the order `fernctl theme list` prints them.
+    pub const ALL: [Self; 3] = [Self::Dark, Self::Light, Self::Auto];
+
     /// Returns the variant name.
     #[must_use]
     pub const fn name(&self) -> &'static str {
@@ -142,6 +153,42 @@ impl ColorPalette {
             info: ColorToken::from_hex("#04a5e5").expect("valid hex"),
         }
     }
+
+    /// Returns the palette as seen with a color vision deficiency.
+    ///
+    /// See [`ColorToken::simulate_cvd`].
+    #[must_use]
+    pub fn simulate_cvd(&self, kind: CvdType) -> Self {
+        Self {
+            background: self.background.simulate_cvd(kind),
+            surface: self.surface.simulate_cvd(kind),
+            surface_hover: self.surface_hover.simulate_cvd(kind),
+            foreground: self.foreground.simulate_cvd(kind),
+            foreground_dim: self.foreground_dim.simulate_cvd(kind),
+            accent: self.accent.simulate_cvd(kind),
+            error: self.error.simulate_cvd(kind),
+            warning: self.warning.simulate_cvd(kind),
+            success: self.success.simulate_cvd(kind),
+            info: self.info.simulate_cvd(kind),
+        }
+    }
+
+    /// Returns each color's role name and hex value, in declaration order.
+    #[must_use]
+    pub fn entries(&self) -> [(&'static str, String); 10] {
+        [
+            ("background", self.background.to_hex()),
+            ("surface", self.surface.to_hex()),
+            ("surface_hover", self.surface_hover.to_hex()),
+            ("foreground", self.foreground.to_hex()),
+            ("foreground_dim", self.foreground_dim.to_hex()),
+            ("accent", self.accent.to_hex()),
+            ("error", self.error.to_hex()),
+            ("warning", self.warning.to_hex()),
+            ("success", self.success.to_hex()),
+            ("info", self.info.to_hex()),
+        ]
+    }
 }
 
 impl Default for ColorPalette {
@@ -151,7 +198,7 @@ impl Default for ColorPalette {
 }
 
 /// Bar (panel) configuration.
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct BarConfig {
     /// Bar height in pixels.
     #[serde(default = "default_bar_height")]
@@ -162,18 +209,30 @@ pub struct BarConfig {
     /// Margin from screen edge.
     #[serde(default)]
     pub margin: u16,
+    /// Background opacity, from 0.0 (transparent) to 1.0 (opaque).
+    #[serde(default = "default_bar_opacity")]
+    pub opacity: f32,
+    /// Whether the compositor should blur behind the bar.
+    #[serde(default)]
+    pub blur: bool,
 }
 
 fn default_bar_height() -> u16 {
     40
 }
 
+const fn default_bar_opacity() -> f32 {
+    1.0
+}
+
 impl Default for BarConfig {
     fn default() -> Self {
         Self {
             height: 40,
             position: BarPosition::Top,
             margin: 0,
+            opacity: default_bar_opacity(),
+            blur: false,
         }
     }
 }
@@ -187,6 +246,10 @@ pub enum BarPosition {
     Top,
     /// Bar at bottom of screen.
     Bottom,
+    /// Vertical bar on the left edge.
+    Left,
+    /// Vertical bar on the right edge.
+    Right,
 }
 
 impl BarPosition {
@@ -196,8 +259,16 @@ impl BarPosition {
         match self {
             Self::Top => "top",
             Self::Bottom => "bottom",
+            Self::Left => "left",
+            Self::Right => "right",
         }
     }
+
+    /// Returns `true` for side bars, which lay their modules out vertically.
+    #[must_use]
+    pub const fn is_vertical(&self) -> bool {
+        matches!(self, Self::Left | Self::Right)
+    }
 }
 
 /// A complete theme combining all design tokens.
@@ -217,10 +288,16 @@ impl BarPosition {
 /// // Default light theme
 /// let theme = Theme::light();
 ///
+/// // From parts, with defaults for anything left unset
+/// let theme = Theme::builder().bar(Default::default()).build();
+///
 /// // From configuration (typically via adapters)
 /// // let theme = Theme::from_config(config)?;
 /// ```
 ///
+/// Code outside this crate should prefer [`Theme::builder`] to a struct
+/// literal: the builder keeps compiling when `Theme` gains new sections.
+///
 /// # Accessing Tokens
 ///
 /// ```rust
@@ -236,6 +313,9 @@ impl BarPosition {
 /// let font = &theme.typography.family;
 /// let mono = &theme.typography.mono;
 ///
+/// // Spacing
+/// let gap = theme.spacing.md;
+///
 /// // Radius
 /// let button_radius = theme.radius.button();
 /// let module_radius = theme.radius.module();
@@ -243,8 +323,11 @@ impl BarPosition {
 /// // Bar
 /// let bar_height = theme.bar.height;
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Theme {
+    /// Schema version of this theme (defaults to the current version).
+    #[serde(default = "current_schema_version")]
+    pub schema_version: u32,
     /// The theme variant (dark, light, auto).
     #[serde(default)]
     pub variant: ThemeVariant,
@@ -254,6 +337,9 @@ pub struct Theme {
     /// Typography settings.
     #[serde(default)]
     pub typography: Typography,
+    /// Spacing scale.
+    #[serde(default)]
+    pub spacing: SpacingScale,
     /// Radius scale.
     #[serde(default)]
     pub radius: RadiusScale,
@@ -278,9 +364,11 @@ impl Theme {
     #[must_use]
     pub fn dark() -> Self {
         Self {
+            schema_version: THEME_SCHEMA_VERSION,
             variant: ThemeVariant::Dark,
             colors: ColorPalette::dark(),
             typography: Typography::default(),
+            spacing: SpacingScale::default(),
             radius: RadiusScale::default(),
             bar: BarConfig::default(),
         }
@@ -299,26 +387,135 @@ impl Theme {
     #[must_use]
     pub fn light() -> Self {
         Self {
+            schema_version: THEME_SCHEMA_VERSION,
             variant: ThemeVariant::Light,
             colors: ColorPalette::light(),
             typography: Typography::default(),
+            spacing: SpacingScale::default(),
             radius: RadiusScale::default(),
             bar: BarConfig::default(),
         }
     }
 
-    /// Returns the default spacing value for modules.
+    /// Starts building a theme from parts.
+    ///
+    /// See [`ThemeBuilder`].
+    pub fn builder() -> ThemeBuilder {
+        ThemeBuilder::new()
+    }
+
+    /// Applies a partial theme on top of this one.
+    ///
+    /// Only the sections set to `Some` in `partial` replace the
+    /// corresponding sections of `self`; everything else is kept.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::theme::{BarConfig, PartialTheme, Theme};
+    ///
+    /// let theme = Theme::dark().with_overrides(PartialTheme {
+    ///     bar: Some(BarConfig { height: 32, ..BarConfig::default() }),
+    ///     ..PartialTheme::default()
+    /// });
+    /// assert_eq!(theme.bar.height, 32);
+    /// assert_eq!(theme.variant.name(), "dark");
+    /// ```
+    #[must_use]
+    pub fn with_overrides(self, partial: PartialTheme) -> Self {
+        Self {
+            schema_version: self.schema_version,
+            variant: partial.variant.unwrap_or(self.variant),
+            colors: partial.colors.unwrap_or(self.colors),
+            typography: partial.typography.unwrap_or(self.typography),
+            spacing: partial.spacing.unwrap_or(self.spacing),
+            radius: partial.radius.unwrap_or(self.radius),
+            bar: partial.bar.unwrap_or(self.bar),
+        }
+    }
+
+    /// Runs the cross-field lint rules in [`lint::RULES`](super::lint::RULES).
+    ///
+    /// These catch combinations that are individually valid but look wrong
+    /// together, such as low text contrast. Problems are returned as
+    /// warnings; an empty list means the theme passed every rule.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::theme::Theme;
+    ///
+    /// assert!(Theme::dark().validate_semantic().is_empty());
+    /// ```
+    #[must_use]
+    pub fn validate_semantic(&self) -> Vec<ConfigWarning> {
+        super::lint::run(self)
+    }
+
+    /// Returns the spacing value for modules (the scale's `lg` level).
     ///
     /// This is a convenience method for common usage.
     #[must_use]
     pub const fn module_spacing(&self) -> u16 {
-        SpacingValue::Lg.pixels()
+        self.spacing.lg
     }
 
-    /// Returns the default spacing value for buttons.
+    /// Returns the spacing value for buttons (the scale's `sm` level).
     #[must_use]
     pub const fn button_spacing(&self) -> u16 {
-        SpacingValue::Sm.pixels()
+        self.spacing.sm
+    }
+
+    /// Returns the built-in theme this one is based on.
+    ///
+    /// That is [`Theme::light`] for light themes and [`Theme::dark`]
+    /// otherwise, matching how a config picks its starting palette.
+    #[must_use]
+    pub fn base_defaults(&self) -> Self {
+        match self.variant {
+            ThemeVariant::Light => Self::light(),
+            ThemeVariant::Dark | ThemeVariant::Auto => Self::dark(),
+        }
+    }
+
+    /// Returns every value that differs from `base`, sorted by path.
+    ///
+    /// Values are compared as they appear in the theme JSON, so paths match
+    /// `fernctl query` paths (e.g. `colors.accent`).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::theme::Theme;
+    ///
+    /// let mut theme = Theme::dark();
+    /// theme.bar.height = 32;
+    ///
+    /// let diff = theme.diff(&theme.base_defaults());
+    /// assert_eq!(diff.len(), 1);
+    /// assert_eq!(diff[0].to_string(), "bar.height: 40 -> 32");
+    /// ```
+    #[must_use]
+    pub fn diff(&self, base: &Self) -> Vec<ThemeDifference> {
+        let mut differences = Vec::new();
+        diff_values("", &to_json(base), &to_json(self), &mut differences);
+        differences.sort_by(|a, b| a.path.cmp(&b.path));
+        differences
+    }
+
+    /// Returns component padding derived from this theme's spacing scale.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::theme::Theme;
+    ///
+    /// let theme = Theme::dark();
+    /// assert_eq!(theme.components().button_padding(), (8, 4));
+    /// ```
+    #[must_use]
+    pub const fn components(&self) -> ComponentSpacing {
+        ComponentSpacing::new(self.spacing)
     }
 }
 
@@ -328,6 +525,190 @@ impl Default for Theme {
     }
 }
 
+/// Builds a [`Theme`] one section at a time.
+///
+/// Sections that are never set come from the built-in theme for the
+/// variant — [`Theme::light`] for light themes, [`Theme::dark`] otherwise.
+/// Unlike a struct literal, code using the builder keeps compiling as
+/// `Theme` gains new sections; they simply take their defaults.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::domain::theme::{BarConfig, Theme, ThemeVariant};
+///
+/// let theme = Theme::builder()
+///     .variant(ThemeVariant::Light)
+///     .bar(BarConfig { height: 32, ..BarConfig::default() })
+///     .build();
+///
+/// assert_eq!(theme.bar.height, 32);
+/// assert_eq!(theme.colors, Theme::light().colors);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+#[must_use]
+pub struct ThemeBuilder {
+    parts: PartialTheme,
+}
+
+impl ThemeBuilder {
+    /// Creates a builder with every section unset.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the theme variant, which also picks the defaults for unset
+    /// sections.
+    pub const fn variant(mut self, variant: ThemeVariant) -> Self {
+        self.parts.variant = Some(variant);
+        self
+    }
+
+    /// Sets the color palette.
+    pub const fn colors(mut self, colors: ColorPalette) -> Self {
+        self.parts.colors = Some(colors);
+        self
+    }
+
+    /// Sets the typography.
+    pub fn typography(mut self, typography: Typography) -> Self {
+        self.parts.typography = Some(typography);
+        self
+    }
+
+    /// Sets the spacing scale.
+    pub const fn spacing(mut self, spacing: SpacingScale) -> Self {
+        self.parts.spacing = Some(spacing);
+        self
+    }
+
+    /// Sets the radius scale.
+    pub const fn radius(mut self, radius: RadiusScale) -> Self {
+        self.parts.radius = Some(radius);
+        self
+    }
+
+    /// Sets the bar configuration.
+    pub const fn bar(mut self, bar: BarConfig) -> Self {
+        self.parts.bar = Some(bar);
+        self
+    }
+
+    /// Builds the theme, filling unset sections with defaults.
+    #[must_use]
+    pub fn build(self) -> Theme {
+        let base = match self.parts.variant {
+            Some(ThemeVariant::Light) => Theme::light(),
+            _ => Theme::dark(),
+        };
+        base.with_overrides(self.parts)
+    }
+}
+
+impl From<PartialTheme> for ThemeBuilder {
+    fn from(parts: PartialTheme) -> Self {
+        Self { parts }
+    }
+}
+
+/// One value that differs between two themes.
+///
+/// Displays as `path: default -> value`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThemeDifference {
+    /// Dotted path to the value, e.g. `colors.accent`.
+    pub path: String,
+    /// The value in the base theme.
+    pub default: serde_json::Value,
+    /// The value in the compared theme.
+    pub value: serde_json::Value,
+}
+
+impl fmt::Display for ThemeDifference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}: {} -> {}",
+            self.path,
+            display_value(&self.default),
+            display_value(&self.value)
+        )
+    }
+}
+
+/// Serializes a theme for comparison.
+///
+/// Goes through a JSON string rather than `serde_json::to_value` so `f32`
+/// fields keep their short form (`0.85`, not `0.8500000238418579`).
+fn to_json(theme: &Theme) -> serde_json::Value {
+    serde_json::to_string(theme)
+        .ok()
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Collects the leaves that differ between two JSON values.
+fn diff_values(
+    path: &str,
+    base: &serde_json::Value,
+    value: &serde_json::Value,
+    out: &mut Vec<ThemeDifference>,
+) {
+    match (base, value) {
+        (serde_json::Value::Object(base), serde_json::Value::Object(value)) => {
+            for (key, child) in value {
+                let child_path = if path.is_empty() {
+                    key.clone()
+                } else {
+                    format!("{path}.{key}")
+                };
+                let base_child = base.get(key).unwrap_or(&serde_json::Value::Null);
+                diff_values(&child_path, base_child, child, out);
+            }
+        }
+        _ if base != value => out.push(ThemeDifference {
+            path: path.to_string(),
+            default: base.clone(),
+            value: value.clone(),
+        }),
+        _ => {}
+    }
+}
+
+/// Formats a JSON leaf without quotes around strings.
+fn display_value(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// A theme with every section optional.
+///
+/// Used with [`Theme::with_overrides`] to layer a set of changes onto a
+/// base theme. Sections left as `None` keep the base theme's values.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PartialTheme {
+    /// Theme variant override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub variant: Option<ThemeVariant>,
+    /// Color palette override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub colors: Option<ColorPalette>,
+    /// Typography override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typography: Option<Typography>,
+    /// Spacing scale override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub spacing: Option<SpacingScale>,
+    /// Radius scale override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub radius: Option<RadiusScale>,
+    /// Bar configuration override.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bar: Option<BarConfig>,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -339,6 +720,21 @@ mod tests {
         assert_eq!(theme.bar.height, 40);
     }
 
+    #[test]
+    fn diff_lists_only_changed_values() {
+        let mut theme = Theme::light();
+        assert!(theme.diff(&theme.base_defaults()).is_empty());
+
+        theme.bar.opacity = 0.85;
+        theme.spacing.sm = 6;
+        let diff: Vec<String> = theme
+            .diff(&theme.base_defaults())
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(diff, ["bar.opacity: 1.0 -> 0.85", "spacing.sm: 8 -> 6"]);
+    }
+
     #[test]
     fn theme_light() {
         let theme = Theme::light();
@@ -354,6 +750,69 @@ mod tests {
         assert_eq!(original.bar.height, restored.bar.height);
     }
 
+    #[test]
+    fn with_overrides_applies_only_some_fields() {
+        let theme = Theme::dark().with_overrides(PartialTheme {
+            colors: Some(ColorPalette::light()),
+            ..PartialTheme::default()
+        });
+        assert_eq!(theme.variant, ThemeVariant::Dark);
+        assert_eq!(theme.colors, ColorPalette::light());
+        assert_eq!(theme.bar, BarConfig::default());
+    }
+
+    #[test]
+    fn with_empty_overrides_is_identity() {
+        let theme = Theme::light().with_overrides(PartialTheme::default());
+        assert_eq!(theme, Theme::light());
+    }
+
+    #[test]
+    fn theme_full_roundtrip_is_lossless() {
+        for original in [Theme::dark(), Theme::light()] {
+            let json = serde_json::to_string(&original).unwrap();
+            let restored: Theme = serde_json::from_str(&json).unwrap();
+            assert_eq!(original, restored);
+        }
+    }
+
+    #[test]
+    fn serialized_theme_includes_every_scale() {
+        let json = serde_json::to_value(Theme::dark()).unwrap();
+        for key in ["colors", "spacing", "radius", "typography", "bar"] {
+            assert!(json.get(key).is_some(), "missing {key}");
+        }
+        assert_eq!(json["spacing"]["md"], 12);
+    }
+
+    #[test]
+    fn schema_version_defaults_when_absent() {
+        let theme: Theme = serde_json::from_str(r#"{"variant": "light"}"#).unwrap();
+        assert_eq!(theme.schema_version, THEME_SCHEMA_VERSION);
+        assert_eq!(theme.variant, ThemeVariant::Light);
+    }
+
+    #[test]
+    fn builder_fills_unset_sections_from_variant() {
+        assert_eq!(Theme::builder().build(), Theme::dark());
+        assert_eq!(
+            Theme::builder().variant(ThemeVariant::Light).build(),
+            Theme::light()
+        );
+
+        let theme = Theme::builder()
+            .variant(ThemeVariant::Auto)
+            .radius(RadiusScale {
+                md: 10,
+                ..RadiusScale::default()
+            })
+            .build();
+        assert_eq!(theme.variant, ThemeVariant::Auto);
+        assert_eq!(theme.colors, ColorPalette::dark());
+        assert_eq!(theme.radius.md, 10);
+        assert_eq!(theme.schema_version, THEME_SCHEMA_VERSION);
+    }
+
     #[test]
     fn theme_variant_from_name() {
         assert_eq!(ThemeVariant::from_name("dark"), Some(ThemeVariant::Dark));
@@ -445,6 +445,8 @@ impl StatusRole for Info {}
 /// assert_eq!(color.to_hex(), "#313244");
 /// assert_eq!(color.to_rgb_tuple(), (0x31, 0x32, 0x44));
 /// assert_eq!(color.to_css_rgb(), "rgb(49, 50, 68)");
+/// assert_eq!(color.to_qml_color(), "#313244");
+/// assert_eq!(color.to_argb_u32(), 0xFF31_3244);
 /// ```
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ColorToken<Role: ColorRole> {
@@ -553,11 +555,131 @@ impl<Role: ColorRole> ColorToken<Role> {
     pub fn from_hex(hex: &str) -> Result<Self, ConfigError> {
         parse_hex_color(hex).ok_or_else(|| ConfigError::InvalidColor {
             value: hex.to_string(),
+            similar: Vec::new(),
             span: None,
             source_code: None,
         })
     }
 
+    /// Creates a color token from a hex string in Qt's ordering.
+    ///
+    /// Identical to [`from_hex`](Self::from_hex) except that 8-digit values
+    /// are read as `#AARRGGBB`, the form produced by
+    /// [`to_qml_color`](Self::to_qml_color).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidColor`] under the same conditions as
+    /// [`from_hex`](Self::from_hex).
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    ///
+    /// let color = ColorToken::<Surface>::from_qml_color("#80313244").unwrap();
+    /// assert_eq!(color.alpha(), 0x80);
+    /// assert_eq!(color.to_hex(), "#31324480");
+    /// ```
+    pub fn from_qml_color(hex: &str) -> Result<Self, ConfigError> {
+        let parsed = match hex.strip_prefix('#') {
+            Some(digits) if digits.len() == 8 => u32::from_str_radix(digits, 16).ok().map(|argb| {
+                let [a, r, g, b] = argb.to_be_bytes();
+                Self::from_rgba(r, g, b, a)
+            }),
+            _ => parse_hex_color(hex),
+        };
+
+        parsed.ok_or_else(|| ConfigError::InvalidColor {
+            value: hex.to_string(),
+            similar: Vec::new(),
+            span: None,
+            source_code: None,
+        })
+    }
+
+    /// Creates a color token from a CSS color string.
+    ///
+    /// Accepts everything [`from_hex`](Self::from_hex) does, the
+    /// functional forms `rgb(r, g, b)` and `rgba(r, g, b, a)` that design
+    /// tools copy out, and the color keywords accepted by
+    /// [`from_name`](Self::from_name). Channels are 0-255 and alpha is 0-1,
+    /// as written by [`to_css_rgb`](Self::to_css_rgb). Surrounding
+    /// whitespace and case are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidColor`] if the value is not a valid hex
+    /// color, a well-formed `rgb()`/`rgba()` with in-range values, or a
+    /// known color keyword.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    ///
+    /// let color = ColorToken::<Accent>::from_css("rgb(137, 180, 250)").unwrap();
+    /// assert_eq!(color.to_hex(), "#89b4fa");
+    ///
+    /// let glass = ColorToken::<Surface>::from_css("rgba(49, 50, 68, 0.5)").unwrap();
+    /// assert_eq!(glass.alpha(), 128);
+    ///
+    /// let tomato = ColorToken::<Accent>::from_css("tomato").unwrap();
+    /// assert_eq!(tomato.to_hex(), "#ff6347");
+    ///
+    /// assert!(ColorToken::<Surface>::from_css("rgb(256, 0, 0)").is_err());
+    /// ```
+    pub fn from_css(value: &str) -> Result<Self, ConfigError> {
+        let trimmed = value.trim();
+        if trimmed.starts_with('#') {
+            return Self::from_hex(trimmed);
+        }
+        if trimmed.contains('(') {
+            return parse_css_rgb(trimmed).ok_or_else(|| ConfigError::InvalidColor {
+                value: value.to_string(),
+                similar: Vec::new(),
+                span: None,
+                source_code: None,
+            });
+        }
+
+        Self::from_name(value)
+    }
+
+    /// Creates a color token from a CSS color keyword such as `tomato` or
+    /// `rebeccapurple`.
+    ///
+    /// Names are matched case-insensitively against the table in
+    /// [`named`](super::named). `transparent` gives fully transparent black.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::InvalidColor`] for an unknown name, with the
+    /// closest keywords in `similar` for a "did you mean?" suggestion.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    /// use fern_theme::error::ConfigError;
+    ///
+    /// let color = ColorToken::<Surface>::from_name("RebeccaPurple").unwrap();
+    /// assert_eq!(color.to_hex(), "#663399");
+    ///
+    /// let err = ColorToken::<Accent>::from_name("tomatoe").unwrap_err();
+    /// assert!(matches!(err, ConfigError::InvalidColor { similar, .. } if similar[0] == "tomato"));
+    /// ```
+    pub fn from_name(name: &str) -> Result<Self, ConfigError> {
+        super::named::lookup(name)
+            .map(|(r, g, b, a)| Self::from_rgba(r, g, b, a))
+            .ok_or_else(|| ConfigError::InvalidColor {
+                value: name.to_string(),
+                similar: super::named::find_similar_names(name),
+                span: None,
+                source_code: None,
+            })
+    }
+
     /// Returns the red component (0-255).
     #[must_use]
     pub const fn red(&self) -> u8 {
@@ -642,6 +764,49 @@ impl<Role: ColorRole> ColorToken<Role> {
         }
     }
 
+    /// Returns the color packed as `0xAARRGGBB`.
+    ///
+    /// This is the layout Qt's `QColor::fromRgba` and `QRgb` expect.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    ///
+    /// let color = ColorToken::<Surface>::from_rgba(0x31, 0x32, 0x44, 0x80);
+    /// assert_eq!(color.to_argb_u32(), 0x8031_3244);
+    /// ```
+    #[must_use]
+    pub const fn to_argb_u32(&self) -> u32 {
+        u32::from_be_bytes([self.a, self.r, self.g, self.b])
+    }
+
+    /// Returns the color as a hex string in Qt's ordering.
+    ///
+    /// QML's `color` type reads 8-digit hex as `#AARRGGBB`, unlike CSS which
+    /// reads `#RRGGBBAA`. Opaque colors use the 6-digit form, which both
+    /// agree on.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    ///
+    /// let opaque = ColorToken::<Surface>::from_hex("#313244").unwrap();
+    /// assert_eq!(opaque.to_qml_color(), "#313244");
+    ///
+    /// let transparent = ColorToken::<Surface>::from_rgba(0x31, 0x32, 0x44, 0x80);
+    /// assert_eq!(transparent.to_qml_color(), "#80313244");
+    /// ```
+    #[must_use]
+    pub fn to_qml_color(&self) -> String {
+        if self.is_opaque() {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            format!("#{:08x}", self.to_argb_u32())
+        }
+    }
+
     /// Returns the color as a CSS `rgb()` or `rgba()` function.
     ///
     /// # Example
@@ -701,6 +866,116 @@ impl<Role: ColorRole> ColorToken<Role> {
             _role: PhantomData,
         }
     }
+
+    /// Returns the WCAG relative luminance, from 0.0 (black) to 1.0 (white).
+    ///
+    /// Alpha is ignored.
+    #[must_use]
+    pub fn relative_luminance(&self) -> f64 {
+        let r = linear_channel(self.r);
+        let g = linear_channel(self.g);
+        let b = linear_channel(self.b);
+        0.0722_f64.mul_add(b, 0.2126_f64.mul_add(r, 0.7152 * g))
+    }
+
+    /// Returns the WCAG contrast ratio against another color, from 1.0 to 21.0.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    ///
+    /// let black = ColorToken::<Background>::from_hex("#000000").unwrap();
+    /// let white = ColorToken::<Foreground>::from_hex("#ffffff").unwrap();
+    /// assert!((white.contrast_ratio(&black) - 21.0).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn contrast_ratio<Other: ColorRole>(&self, other: &ColorToken<Other>) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Returns black or white, whichever contrasts more with this color.
+    ///
+    /// Use this for text drawn on a user-chosen color, such as a label on
+    /// the accent, where neither palette foreground is guaranteed to be
+    /// legible.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    ///
+    /// let accent = ColorToken::<Accent>::from_hex("#1e66f5").unwrap();
+    /// assert_eq!(accent.readable_foreground().to_hex(), "#ffffff");
+    /// ```
+    #[must_use]
+    pub fn readable_foreground(&self) -> ColorToken<Foreground> {
+        let black = ColorToken::from_rgb(0, 0, 0);
+        let white = ColorToken::from_rgb(255, 255, 255);
+        if self.contrast_ratio(&black) > self.contrast_ratio(&white) {
+            black
+        } else {
+            white
+        }
+    }
+
+    /// Returns this color as seen with a color vision deficiency.
+    ///
+    /// Uses the Machado et al. (2009) simulation matrices at full severity,
+    /// applied in linear RGB. Alpha is preserved.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::color::*;
+    ///
+    /// let red = ColorToken::<Error>::from_hex("#ff0000").unwrap();
+    /// let seen = red.simulate_cvd(CvdType::Deuteranopia);
+    ///
+    /// // Red shifts toward olive: the green channel catches up with red
+    /// assert!(seen.green() > 100);
+    ///
+    /// // Grays are unaffected
+    /// let gray = ColorToken::<Surface>::from_hex("#808080").unwrap();
+    /// assert_eq!(gray.simulate_cvd(CvdType::Protanopia), gray);
+    /// ```
+    #[must_use]
+    pub fn simulate_cvd(&self, kind: CvdType) -> Self {
+        let rgb = [
+            linear_channel(self.r),
+            linear_channel(self.g),
+            linear_channel(self.b),
+        ];
+        let [r, g, b] = kind
+            .matrix()
+            .map(|row| row[2].mul_add(rgb[2], row[0].mul_add(rgb[0], row[1] * rgb[1])));
+
+        Self::from_rgba(srgb_channel(r), srgb_channel(g), srgb_channel(b), self.a)
+    }
+}
+
+/// Converts an sRGB channel to linear light for luminance calculations.
+fn linear_channel(channel: u8) -> f64 {
+    let c = f64::from(channel) / 255.0;
+    if c <= 0.040_45 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts linear light back to an sRGB channel, clamping out-of-gamut values.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // clamped to 0..=255
+fn srgb_channel(linear: f64) -> u8 {
+    let c = linear.clamp(0.0, 1.0);
+    let c = if c <= 0.003_130_8 {
+        c * 12.92
+    } else {
+        1.055_f64.mul_add(c.powf(1.0 / 2.4), -0.055)
+    };
+    (c * 255.0).round().clamp(0.0, 255.0) as u8
 }
 
 impl<Role: ColorRole> fmt::Debug for ColorToken<Role> {
@@ -725,13 +1000,15 @@ impl<Role: ColorRole> Default for ColorToken<Role> {
     }
 }
 
-// Serialization: Colors serialize to hex strings
+// Serialization: Colors serialize to hex strings in Qt's `#AARRGGBB` order,
+// since the serialized theme is what QML reads. User-authored TOML is parsed
+// with `from_hex` and keeps CSS `#RRGGBBAA` order.
 impl<Role: ColorRole> Serialize for ColorToken<Role> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        self.to_hex().serialize(serializer)
+        self.to_qml_color().serialize(serializer)
     }
 }
 
@@ -741,7 +1018,76 @@ impl<'de, Role: ColorRole> Deserialize<'de> for ColorToken<Role> {
         D: serde::Deserializer<'de>,
     {
         let hex = String::deserialize(deserializer)?;
-        Self::from_hex(&hex).map_err(serde::de::Error::custom)
+        Self::from_qml_color(&hex).map_err(serde::de::Error::custom)
+    }
+}
+
+// ============================================================================
+// Color Vision Deficiency
+// ============================================================================
+
+/// A type of color vision deficiency, for [`ColorToken::simulate_cvd`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CvdType {
+    /// No functioning red cones (red-green confusion, reds look dark).
+    Protanopia,
+    /// No functioning green cones (the most common red-green confusion).
+    Deuteranopia,
+    /// No functioning blue cones (blue-yellow confusion).
+    Tritanopia,
+}
+
+impl CvdType {
+    /// Returns every deficiency type.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Protanopia, Self::Deuteranopia, Self::Tritanopia]
+    }
+
+    /// Returns the deficiency name.
+    #[must_use]
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Protanopia => "protanopia",
+            Self::Deuteranopia => "deuteranopia",
+            Self::Tritanopia => "tritanopia",
+        }
+    }
+
+    /// Parses a deficiency name, case-insensitively.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        Self::all()
+            .iter()
+            .copied()
+            .find(|kind| kind.name().eq_ignore_ascii_case(name))
+    }
+
+    /// Returns the linear-RGB simulation matrix (Machado et al. 2009, severity 1.0).
+    const fn matrix(self) -> [[f64; 3]; 3] {
+        match self {
+            Self::Protanopia => [
+                [0.152_286, 1.052_583, -0.204_868],
+                [0.114_503, 0.786_281, 0.099_216],
+                [-0.003_882, -0.048_116, 1.051_998],
+            ],
+            Self::Deuteranopia => [
+                [0.367_322, 0.860_646, -0.227_968],
+                [0.280_085, 0.672_501, 0.047_413],
+                [-0.011_820, 0.042_940, 0.968_881],
+            ],
+            Self::Tritanopia => [
+                [1.255_528, -0.076_749, -0.178_779],
+                [-0.078_411, 0.930_809, 0.147_602],
+                [0.004_733, 0.691_367, 0.303_900],
+            ],
+        }
+    }
+}
+
+impl fmt::Display for CvdType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
     }
 }
 
@@ -783,6 +1129,37 @@ fn parse_hex_color<Role: ColorRole>(hex: &str) -> Option<ColorToken<Role>> {
     }
 }
 
+/// Parses `rgb(r, g, b)` or `rgba(r, g, b, a)` with 0-255 channels and
+/// 0-1 alpha.
+fn parse_css_rgb<Role: ColorRole>(value: &str) -> Option<ColorToken<Role>> {
+    let (name, args) = value.strip_suffix(')')?.split_once('(')?;
+    let args: Vec<&str> = args.split(',').map(str::trim).collect();
+
+    match (name.trim().to_ascii_lowercase().as_str(), args.as_slice()) {
+        ("rgb", [r, g, b]) => Some(ColorToken::from_rgb(
+            r.parse().ok()?,
+            g.parse().ok()?,
+            b.parse().ok()?,
+        )),
+        ("rgba", [r, g, b, a]) => Some(ColorToken::from_rgba(
+            r.parse().ok()?,
+            g.parse().ok()?,
+            b.parse().ok()?,
+            parse_css_alpha(a)?,
+        )),
+        _ => None,
+    }
+}
+
+/// Parses a CSS alpha value in 0-1 to 0-255.
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)] // checked to 0..=1
+fn parse_css_alpha(value: &str) -> Option<u8> {
+    let alpha: f64 = value.parse().ok()?;
+    (0.0..=1.0)
+        .contains(&alpha)
+        .then(|| (alpha * 255.0).round() as u8)
+}
+
 /// Parses a single hex digit.
 fn parse_hex_digit(byte: &u8) -> Option<u8> {
     match byte {
@@ -857,6 +1234,55 @@ mod tests {
         assert!(ColorToken::<Surface>::from_hex("#12345").is_err()); // Wrong length
     }
 
+    #[test]
+    fn from_css_functional_forms() {
+        let color = ColorToken::<Accent>::from_css("rgb(137, 180, 250)").unwrap();
+        assert_eq!(color.to_hex(), "#89b4fa");
+
+        let color = ColorToken::<Surface>::from_css(" RGBA(49,50,68,0) ").unwrap();
+        assert_eq!(color.to_rgba_tuple(), (49, 50, 68, 0));
+
+        let hex = ColorToken::<Surface>::from_css("#313244").unwrap();
+        assert_eq!(hex, ColorToken::<Surface>::from_hex("#313244").unwrap());
+    }
+
+    #[test]
+    fn from_css_roundtrips_to_css_rgb() {
+        let color = ColorToken::<Surface>::from_rgba(0x31, 0x32, 0x44, 0x80);
+        let restored = ColorToken::<Surface>::from_css(&color.to_css_rgb()).unwrap();
+        assert_eq!(restored, color);
+    }
+
+    #[test]
+    fn from_css_invalid() {
+        for value in [
+            "rgb(256, 0, 0)",
+            "rgb(1, 2)",
+            "rgba(1, 2, 3)",
+            "rgba(1, 2, 3, 1.5)",
+            "rgb(1, 2, 3",
+            "hsl(0, 0%, 0%)",
+            "313244",
+        ] {
+            assert!(ColorToken::<Surface>::from_css(value).is_err(), "{value}");
+        }
+    }
+
+    #[test]
+    fn from_css_named_colors() {
+        let color = ColorToken::<Accent>::from_css("Tomato").unwrap();
+        assert_eq!(color.to_hex(), "#ff6347");
+
+        let clear = ColorToken::<Surface>::from_css("transparent").unwrap();
+        assert_eq!(clear.to_rgba_tuple(), (0, 0, 0, 0));
+
+        let err = ColorToken::<Accent>::from_css("rebeccapurpel").unwrap_err();
+        assert!(matches!(
+            err,
+            ConfigError::InvalidColor { ref similar, .. } if similar[0] == "rebeccapurple"
+        ));
+    }
+
     #[test]
     fn to_hex_roundtrip() {
         let original = "#89b4fa";
@@ -874,6 +1300,87 @@ mod tests {
         assert_eq!(color, restored);
     }
 
+    #[test]
+    fn to_argb_u32_packs_alpha_first() {
+        let color = ColorToken::<Surface>::from_rgba(0x12, 0x34, 0x56, 0x78);
+        assert_eq!(color.to_argb_u32(), 0x7812_3456);
+
+        let opaque = ColorToken::<Surface>::from_rgb(0x12, 0x34, 0x56);
+        assert_eq!(opaque.to_argb_u32(), 0xFF12_3456);
+    }
+
+    #[test]
+    fn to_qml_color_puts_alpha_first() {
+        let color = ColorToken::<Surface>::from_rgba(0x12, 0x34, 0x56, 0x78);
+        assert_eq!(color.to_hex(), "#12345678");
+        assert_eq!(color.to_qml_color(), "#78123456");
+    }
+
+    #[test]
+    fn semi_transparent_surface_maps_to_qt_order() {
+        let surface = ColorToken::<Surface>::from_hex("#31324480").unwrap();
+        assert_eq!(surface.to_qml_color(), "#80313244");
+        assert_eq!(surface.to_argb_u32(), 0x8031_3244);
+
+        let json = serde_json::to_string(&surface).unwrap();
+        assert_eq!(json, "\"#80313244\"");
+    }
+
+    #[test]
+    fn from_qml_color_roundtrip() {
+        let color = ColorToken::<Surface>::from_rgba(0x12, 0x34, 0x56, 0x78);
+        let restored = ColorToken::<Surface>::from_qml_color(&color.to_qml_color()).unwrap();
+        assert_eq!(color, restored);
+        assert!(ColorToken::<Surface>::from_qml_color("#zz313244").is_err());
+    }
+
+    #[test]
+    fn contrast_ratio_extremes() {
+        let black = ColorToken::<Background>::from_rgb(0, 0, 0);
+        let white = ColorToken::<Foreground>::from_rgb(255, 255, 255);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 0.01);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 0.01);
+        assert!((white.contrast_ratio(&white) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn readable_foreground_picks_higher_contrast() {
+        let dark = ColorToken::<Accent>::from_hex("#313244").unwrap();
+        assert_eq!(dark.readable_foreground().to_hex(), "#ffffff");
+
+        let light = ColorToken::<Accent>::from_hex("#f9e2af").unwrap();
+        assert_eq!(light.readable_foreground().to_hex(), "#000000");
+
+        // Mid gray sits near the crossover (luminance ~0.18), past which
+        // black wins
+        let above = ColorToken::<Accent>::from_rgb(0x80, 0x80, 0x80);
+        assert_eq!(above.readable_foreground().to_hex(), "#000000");
+        let below = ColorToken::<Accent>::from_rgb(0x70, 0x70, 0x70);
+        assert_eq!(below.readable_foreground().to_hex(), "#ffffff");
+    }
+
+    #[test]
+    fn simulate_cvd() {
+        let red = ColorToken::<Error>::from_hex("#ff000080").unwrap();
+        let seen = red.simulate_cvd(CvdType::Deuteranopia);
+        assert_eq!(seen.to_hex(), "#a3900080");
+
+        for kind in CvdType::all() {
+            let gray = ColorToken::<Surface>::from_hex("#808080").unwrap();
+            assert_eq!(gray.simulate_cvd(*kind), gray, "{kind}");
+        }
+    }
+
+    #[test]
+    fn cvd_type_from_name() {
+        assert_eq!(
+            CvdType::from_name("Deuteranopia"),
+            Some(CvdType::Deuteranopia)
+        );
+        assert_eq!(CvdType::from_name("tritanopia"), Some(CvdType::Tritanopia));
+        assert_eq!(CvdType::from_name("achromatopsia"), None);
+    }
+
     #[test]
     fn role_name() {
         let surface = ColorToken::<Surface>::from_rgb(0, 0, 0);
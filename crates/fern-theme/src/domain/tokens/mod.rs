@@ -30,6 +30,7 @@
 //! | Category | Module | Description |
 //! |----------|--------|-------------|
 //! | Color | [`color`] | RGBA colors with semantic roles |
+//! | Named colors | [`named`] | CSS color keywords |
 //! | Spacing | [`spacing`] | Scale-based spacing values |
 //! | Radius | [`radius`] | Border radius with semantic names |
 //! | Typography | [`typography`] | Font families and size scales |
@@ -45,6 +46,7 @@
 //! See each submodule for detailed examples.
 
 pub mod color;
+pub mod named;
 pub mod radius;
 pub mod spacing;
 pub mod typography;
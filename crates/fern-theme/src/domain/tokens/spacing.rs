@@ -65,11 +65,30 @@
 //!
 //! // There's no way to express "7px" — it's not a type!
 //! ```
+//!
+//! ## Customizable Values
+//!
+//! The scale levels are fixed, but [`SpacingScale`] lets configuration
+//! override the pixel value of each level:
+//!
+//! ```toml
+//! [appearance.spacing]
+//! sm = 6    # Tighter small spacing
+//! lg = 20
+//! ```
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::marker::PhantomData;
 
+// ============================================================================
+// Sealed Trait Pattern
+// ============================================================================
+
+mod private {
+    pub trait Sealed {}
+}
+
 /// The base unit for spacing in pixels.
 ///
 /// All spacing values are multiples of this base unit, creating a consistent
@@ -217,6 +236,28 @@ impl<const N: u8> fmt::Display for Spacing<N> {
     }
 }
 
+/// Marker trait for all spacing levels.
+///
+/// Implemented for every [`Spacing<N>`], allowing APIs like
+/// [`SpacingScale::get`] to accept a spacing level as a type parameter.
+/// It is sealed, meaning only types in this crate can implement it.
+pub trait SpacingSemantic: private::Sealed + fmt::Debug + Clone + Copy + 'static {
+    /// The default pixel value for this spacing level.
+    const DEFAULT_PIXELS: u16;
+
+    /// Returns the semantic name of this spacing level, if it has one.
+    fn level_name() -> Option<&'static str>;
+}
+
+impl<const N: u8> private::Sealed for Spacing<N> {}
+impl<const N: u8> SpacingSemantic for Spacing<N> {
+    const DEFAULT_PIXELS: u16 = Self::pixels();
+
+    fn level_name() -> Option<&'static str> {
+        Self::name()
+    }
+}
+
 // Serialization: Spacing serializes to pixel value
 impl<const N: u8> Serialize for Spacing<N> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -310,6 +351,135 @@ pub type SpacingLg = Spacing<4>;
 /// ```
 pub type SpacingXl = Spacing<6>;
 
+// ============================================================================
+// Runtime Spacing Scale
+// ============================================================================
+
+/// A complete spacing scale with customizable pixel values.
+///
+/// While the spacing types ([`SpacingSm`], [`SpacingMd`], etc.) provide
+/// compile-time type safety, `SpacingScale` provides runtime-configurable
+/// values for the actual pixels.
+///
+/// # Default Values
+///
+/// ```rust
+/// use fern_theme::domain::tokens::spacing::SpacingScale;
+///
+/// let scale = SpacingScale::default();
+/// assert_eq!(scale.xs, 4);
+/// assert_eq!(scale.sm, 8);
+/// assert_eq!(scale.md, 12);
+/// assert_eq!(scale.lg, 16);
+/// assert_eq!(scale.xl, 24);
+/// ```
+///
+/// # Customization
+///
+/// Users can customize the pixel values via configuration:
+///
+/// ```toml
+/// [appearance.spacing]
+/// sm = 6
+/// md = 10
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct SpacingScale {
+    /// Extra-small spacing (default 4px).
+    #[serde(default = "default_xs")]
+    pub xs: u16,
+    /// Small spacing (default 8px).
+    #[serde(default = "default_sm")]
+    pub sm: u16,
+    /// Medium spacing (default 12px).
+    #[serde(default = "default_md")]
+    pub md: u16,
+    /// Large spacing (default 16px).
+    #[serde(default = "default_lg")]
+    pub lg: u16,
+    /// Extra-large spacing (default 24px).
+    #[serde(default = "default_xl")]
+    pub xl: u16,
+}
+
+fn default_xs() -> u16 {
+    SpacingXs::pixels()
+}
+fn default_sm() -> u16 {
+    SpacingSm::pixels()
+}
+fn default_md() -> u16 {
+    SpacingMd::pixels()
+}
+fn default_lg() -> u16 {
+    SpacingLg::pixels()
+}
+fn default_xl() -> u16 {
+    SpacingXl::pixels()
+}
+
+impl SpacingScale {
+    /// Creates a new spacing scale with default values.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            xs: SpacingXs::pixels(),
+            sm: SpacingSm::pixels(),
+            md: SpacingMd::pixels(),
+            lg: SpacingLg::pixels(),
+            xl: SpacingXl::pixels(),
+        }
+    }
+
+    /// Returns the pixel value for a given spacing level.
+    ///
+    /// Levels without a semantic name (e.g., `Spacing<7>`) are not part of
+    /// the scale and resolve to their fixed default pixels.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::spacing::*;
+    ///
+    /// let scale = SpacingScale::default();
+    /// assert_eq!(scale.get::<SpacingMd>(), 12);
+    /// ```
+    #[must_use]
+    pub fn get<S: SpacingSemantic>(&self) -> u16 {
+        match S::level_name().and_then(SpacingValue::from_name) {
+            Some(value) => self.value(value),
+            None => S::DEFAULT_PIXELS,
+        }
+    }
+
+    /// Returns the pixel value for a runtime spacing level.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use fern_theme::domain::tokens::spacing::*;
+    ///
+    /// let scale = SpacingScale { sm: 6, ..SpacingScale::default() };
+    /// assert_eq!(scale.value(SpacingValue::Sm), 6);
+    /// ```
+    #[must_use]
+    pub const fn value(&self, value: SpacingValue) -> u16 {
+        match value {
+            SpacingValue::Xs => self.xs,
+            SpacingValue::Sm => self.sm,
+            SpacingValue::Md => self.md,
+            SpacingValue::Lg => self.lg,
+            SpacingValue::Xl => self.xl,
+        }
+    }
+}
+
+impl Default for SpacingScale {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// A runtime spacing value for when const generics aren't suitable.
 ///
 /// While `Spacing<N>` is preferred for compile-time safety, `SpacingValue`
@@ -475,6 +645,51 @@ mod tests {
         assert_eq!(SpacingValue::from_name("invalid"), None);
     }
 
+    #[test]
+    fn spacing_scale_defaults() {
+        let scale = SpacingScale::default();
+        for value in SpacingValue::all() {
+            assert_eq!(scale.value(*value), value.pixels());
+        }
+    }
+
+    #[test]
+    fn spacing_scale_get() {
+        let scale = SpacingScale::default();
+        assert_eq!(scale.get::<SpacingSm>(), 8);
+        assert_eq!(scale.get::<SpacingMd>(), 12);
+
+        let custom = SpacingScale {
+            md: 10,
+            ..SpacingScale::default()
+        };
+        assert_eq!(custom.get::<SpacingMd>(), 10);
+        // Off-scale levels ignore customization
+        assert_eq!(custom.get::<Spacing<7>>(), 28);
+    }
+
+    #[test]
+    fn spacing_scale_serialization() {
+        let scale = SpacingScale::default();
+        let json = serde_json::to_string(&scale).unwrap();
+        let restored: SpacingScale = serde_json::from_str(&json).unwrap();
+        assert_eq!(scale, restored);
+    }
+
+    #[test]
+    fn spacing_scale_partial_deserialization() {
+        let scale: SpacingScale = serde_json::from_str(r#"{"sm": 6}"#).unwrap();
+        assert_eq!(scale.sm, 6);
+        assert_eq!(scale.md, 12);
+    }
+
+    #[test]
+    fn spacing_value_name_roundtrip() {
+        for value in SpacingValue::all() {
+            assert_eq!(SpacingValue::from_name(value.name()), Some(*value));
+        }
+    }
+
     #[test]
     fn spacing_serialization() {
         let sm = SpacingSm::new();
@@ -25,6 +25,12 @@
 //! | `Lg` | 16px | Subheadings |
 //! | `Xl` | 20px | Headings |
 //! | `Xxl` | 24px | Large headings |
+//!
+//! ## Vertical Rhythm
+//!
+//! [`Typography`] also carries a `line_height` multiplier (default `1.2`)
+//! and a `letter_spacing` offset in pixels (default `0.0`). QML and CSS
+//! consumers apply both so text sits on the same rhythm everywhere.
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
@@ -380,8 +386,9 @@ impl Default for FontSizeScale {
 /// let typography = Typography::default();
 /// assert_eq!(typography.family.name(), "Inter");
 /// assert_eq!(typography.size.md, 14);
+/// assert!((typography.line_height - 1.2).abs() < f32::EPSILON);
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct Typography {
     /// Primary (UI) font family.
     #[serde(default = "FontFamily::default_primary")]
@@ -395,6 +402,25 @@ pub struct Typography {
     /// Font size scale.
     #[serde(default)]
     pub size: FontSizeScale,
+    /// Line height as a multiple of the font size.
+    #[serde(default = "default_line_height")]
+    pub line_height: f32,
+    /// Extra spacing between letters, in pixels.
+    #[serde(default = "default_letter_spacing")]
+    pub letter_spacing: f32,
+}
+
+/// Default line height multiplier.
+pub const DEFAULT_LINE_HEIGHT: f32 = 1.2;
+
+/// Default letter spacing in pixels.
+pub const DEFAULT_LETTER_SPACING: f32 = 0.0;
+
+const fn default_line_height() -> f32 {
+    DEFAULT_LINE_HEIGHT
+}
+const fn default_letter_spacing() -> f32 {
+    DEFAULT_LETTER_SPACING
 }
 
 impl Typography {
@@ -406,6 +432,8 @@ impl Typography {
             mono: FontFamily::default_mono(),
             icon: FontFamily::default_icon(),
             size: FontSizeScale::default(),
+            line_height: DEFAULT_LINE_HEIGHT,
+            letter_spacing: DEFAULT_LETTER_SPACING,
         }
     }
 }
@@ -467,4 +495,19 @@ mod tests {
         let restored: Typography = serde_json::from_str(&json).unwrap();
         assert_eq!(typography.family, restored.family);
     }
+
+    #[test]
+    fn typography_rhythm_defaults() {
+        let typography = Typography::default();
+        assert!((typography.line_height - DEFAULT_LINE_HEIGHT).abs() < f32::EPSILON);
+        assert!(typography.letter_spacing.abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn typography_rhythm_defaults_when_missing() {
+        let json = r#"{"family": "Inter"}"#;
+        let typography: Typography = serde_json::from_str(json).unwrap();
+        assert!((typography.line_height - DEFAULT_LINE_HEIGHT).abs() < f32::EPSILON);
+        assert!(typography.letter_spacing.abs() < f32::EPSILON);
+    }
 }
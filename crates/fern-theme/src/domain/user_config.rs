@@ -14,6 +14,13 @@
 //! accent = "#ff6b6b"
 //! font_family = "Inter"
 //!
+//! [appearance.typography]
+//! line_height = 1.4
+//! letter_spacing = 0.5
+//!
+//! [appearance.colors]
+//! background = "#1e1e2e"
+//!
 //! [bar]
 //! height = 40
 //! position = "top"
@@ -29,17 +36,24 @@
 //!     ↓
 //! [parse to UserConfig]
 //!     ↓
+//! UserConfig::interpolate_env()   (expands ${VAR} and ${VAR:-default})
+//!     ↓
 //! UserConfig::into_theme()
 //!     ↓
 //! Theme (validated, ready for use)
 //! ```
 
 use crate::domain::theme::{BarConfig, BarPosition, ColorPalette, Theme, ThemeVariant};
-use crate::domain::tokens::color::{Accent, ColorToken};
+use crate::domain::tokens::color::{ColorRole, ColorToken};
 use crate::domain::tokens::radius::RadiusScale;
-use crate::domain::tokens::typography::{FontFamily, FontSizeScale, Typography};
+use crate::domain::tokens::spacing::SpacingScale;
+use crate::domain::tokens::typography::{
+    FontFamily, FontSizeScale, Typography, DEFAULT_LETTER_SPACING, DEFAULT_LINE_HEIGHT,
+};
 use crate::error::{ConfigError, FernError, Result};
+use crate::ports::inbound::ConfigWarning;
 use serde::Deserialize;
+use std::ops::RangeInclusive;
 
 // ============================================================================
 // UserConfig — Top-level configuration
@@ -66,6 +80,7 @@ use serde::Deserialize;
 /// let theme = user_config.into_theme()?;
 /// ```
 #[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UserConfig {
     /// Appearance settings (colors, fonts, etc.)
     #[serde(default)]
@@ -85,7 +100,7 @@ impl UserConfig {
     ///
     /// This method:
     /// 1. Determines the base theme variant (dark/light)
-    /// 2. Applies any color overrides (e.g., accent)
+    /// 2. Applies any color overrides (`[appearance.colors]`, then accent)
     /// 3. Applies typography overrides
     /// 4. Applies radius overrides
     /// 5. Builds the bar configuration
@@ -95,6 +110,8 @@ impl UserConfig {
     /// Returns an error if:
     /// - An invalid color value is provided (e.g., "#gg0000")
     /// - An invalid theme variant is specified
+    /// - A bar dimension is out of range (see [`UserBarConfig::validate`])
+    /// - The bar position is not one of top, bottom, left, or right
     ///
     /// # Example
     ///
@@ -114,18 +131,16 @@ impl UserConfig {
             ThemeVariant::Auto => ColorPalette::dark(), // Default to dark for auto
         };
 
-        // Apply accent color override if provided
+        // Apply palette overrides, then the accent shorthand
+        if let Some(overrides) = self.appearance.colors {
+            overrides.apply(&mut colors)?;
+        }
         if let Some(ref accent) = self.appearance.accent {
-            colors.accent = ColorToken::<Accent>::from_hex(accent).map_err(|_| {
-                FernError::Config(ConfigError::InvalidColor {
-                    value: accent.clone(),
-                    span: None,
-                    source_code: None,
-                })
-            })?;
+            colors.accent = parse_color(accent)?;
         }
 
         // Build typography with overrides
+        let rhythm = self.appearance.typography.unwrap_or_default();
         let typography = Typography {
             family: self
                 .appearance
@@ -143,8 +158,17 @@ impl UserConfig {
                 .map(FontFamily::new)
                 .unwrap_or_else(FontFamily::default_icon),
             size: FontSizeScale::default(),
+            line_height: rhythm.line_height.unwrap_or(DEFAULT_LINE_HEIGHT),
+            letter_spacing: rhythm.letter_spacing.unwrap_or(DEFAULT_LETTER_SPACING),
         };
 
+        // Build spacing scale with overrides
+        let spacing = self
+            .appearance
+            .spacing
+            .map(UserSpacingConfig::into_scale)
+            .unwrap_or_default();
+
         // Build radius scale with overrides
         let radius = self
             .appearance
@@ -153,22 +177,176 @@ impl UserConfig {
             .unwrap_or_default();
 
         // Build bar configuration
+        self.bar.validate()?;
+        let position = BarPosition::from_name(&self.bar.position).ok_or_else(|| {
+            FernError::Config(ConfigError::InvalidPosition {
+                value: self.bar.position.clone(),
+                span: None,
+                source_code: None,
+            })
+        })?;
         let bar = BarConfig {
             height: self.bar.height,
-            position: BarPosition::from_name(&self.bar.position).unwrap_or_default(),
+            position,
             margin: self.bar.margin,
+            opacity: self.bar.opacity,
+            blur: self.bar.blur,
+        };
+
+        Ok(Theme::builder()
+            .variant(variant)
+            .colors(colors)
+            .typography(typography)
+            .spacing(spacing)
+            .radius(radius)
+            .bar(bar)
+            .build())
+    }
+
+    /// Expands environment variable references in every string field.
+    ///
+    /// See [`expand_env`] for the syntax. Called while loading, before the
+    /// values are validated; the `[modules]` passthrough is left as written.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::UndefinedEnvVar`] for a reference to an unset
+    /// variable that has no default.
+    pub fn interpolate_env(self) -> Result<Self> {
+        self.interpolate_with(|name| std::env::var(name).ok())
+    }
+
+    /// Like [`interpolate_env`](Self::interpolate_env), reading variables
+    /// through `lookup` instead of the process environment.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::UndefinedEnvVar`] for a reference `lookup`
+    /// cannot resolve that has no default.
+    pub fn interpolate_with(mut self, lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let expand = |key: &str, value: &mut String| -> Result<()> {
+            *value = expand_env(value, key, &lookup)?;
+            Ok(())
         };
 
-        Ok(Theme {
-            variant,
-            colors,
-            typography,
-            radius,
-            bar,
-        })
+        let appearance = &mut self.appearance;
+        expand("appearance.theme", &mut appearance.theme)?;
+        let optional = [
+            ("appearance.accent", &mut appearance.accent),
+            ("appearance.font_family", &mut appearance.font_family),
+            ("appearance.font_mono", &mut appearance.font_mono),
+            ("appearance.font_icon", &mut appearance.font_icon),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                expand(key, value)?;
+            }
+        }
+        if let Some(colors) = &mut appearance.colors {
+            for (name, value) in colors.fields_mut() {
+                if let Some(value) = value {
+                    expand(&format!("appearance.colors.{name}"), value)?;
+                }
+            }
+        }
+
+        let bar = &mut self.bar;
+        expand("bar.position", &mut bar.position)?;
+        let modules = [
+            ("bar.modules_left", &mut bar.modules_left),
+            ("bar.modules_center", &mut bar.modules_center),
+            ("bar.modules_right", &mut bar.modules_right),
+        ];
+        for (key, list) in modules {
+            for value in list {
+                expand(key, value)?;
+            }
+        }
+
+        Ok(self)
     }
 }
 
+// ============================================================================
+// Environment interpolation
+// ============================================================================
+
+/// Expands `${NAME}` and `${NAME:-default}` references in `value`.
+///
+/// `NAME` is letters, digits, and underscores, not starting with a digit.
+/// The default is used when the variable is unset or empty, as in POSIX
+/// shells. Text that isn't a well-formed reference, such as a lone `$` or
+/// an unclosed `${`, is kept as written. `key` names the config value for
+/// error messages.
+///
+/// # Errors
+///
+/// Returns [`ConfigError::UndefinedEnvVar`] for an unset variable with no
+/// default.
+///
+/// # Example
+///
+/// ```rust
+/// use fern_theme::domain::user_config::expand_env;
+///
+/// let lookup = |name: &str| (name == "FERN_ACCENT").then(|| "#ff6b6b".to_string());
+///
+/// assert_eq!(expand_env("${FERN_ACCENT}", "accent", lookup).unwrap(), "#ff6b6b");
+/// assert_eq!(expand_env("${FERN_FONT:-Inter}", "font", lookup).unwrap(), "Inter");
+/// assert!(expand_env("${FERN_FONT}", "font", lookup).is_err());
+/// ```
+pub fn expand_env(
+    value: &str,
+    key: &str,
+    lookup: impl Fn(&str) -> Option<String>,
+) -> Result<String> {
+    let mut expanded = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find("${") {
+        expanded.push_str(&rest[..start]);
+        let after = &rest[start + 2..];
+
+        let Some(end) = after.find('}') else {
+            rest = &rest[start..];
+            break;
+        };
+        let reference = &after[..end];
+        let (name, default) = match reference.split_once(":-") {
+            Some((name, default)) => (name, Some(default)),
+            None => (reference, None),
+        };
+
+        if is_env_name(name) {
+            match (lookup(name).filter(|v| !v.is_empty()), default) {
+                (Some(found), _) => expanded.push_str(&found),
+                (None, Some(default)) => expanded.push_str(default),
+                (None, None) => {
+                    return Err(FernError::Config(ConfigError::UndefinedEnvVar {
+                        key: key.to_string(),
+                        name: name.to_string(),
+                    }))
+                }
+            }
+        } else {
+            expanded.push_str(&rest[start..start + 2 + end + 1]);
+        }
+        rest = &after[end + 1..];
+    }
+
+    expanded.push_str(rest);
+    Ok(expanded)
+}
+
+/// Returns `true` for a valid environment variable name.
+fn is_env_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
 // ============================================================================
 // AppearanceConfig — Visual styling options
 // ============================================================================
@@ -177,13 +355,17 @@ impl UserConfig {
 ///
 /// Contains all visual styling options: theme variant, colors, fonts, and radii.
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct AppearanceConfig {
     /// Theme variant: "dark", "light", or "auto"
     #[serde(default = "default_theme")]
+    #[cfg_attr(feature = "schema", schemars(extend("enum" = ["dark", "light", "auto"])))]
     pub theme: String,
 
-    /// Accent color override (hex format, e.g., "#ff6b6b")
+    /// Accent color override: hex ("#ff6b6b"), `rgb()`/`rgba()`, a CSS
+    /// color name, or a `${VAR}` reference
     #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
     pub accent: Option<String>,
 
     /// Primary font family name
@@ -198,9 +380,21 @@ pub struct AppearanceConfig {
     #[serde(default)]
     pub font_icon: Option<String>,
 
+    /// Spacing scale overrides (`[appearance.spacing]`)
+    #[serde(default)]
+    pub spacing: Option<UserSpacingConfig>,
+
     /// Border radius overrides
     #[serde(default)]
     pub radius: Option<UserRadiusConfig>,
+
+    /// Typography rhythm overrides (`[appearance.typography]`)
+    #[serde(default)]
+    pub typography: Option<UserTypographyConfig>,
+
+    /// Palette overrides (`[appearance.colors]`)
+    #[serde(default)]
+    pub colors: Option<UserColorsConfig>,
 }
 
 fn default_theme() -> String {
@@ -215,7 +409,10 @@ impl Default for AppearanceConfig {
             font_family: None,
             font_mono: None,
             font_icon: None,
+            spacing: None,
             radius: None,
+            typography: None,
+            colors: None,
         }
     }
 }
@@ -228,20 +425,37 @@ impl Default for AppearanceConfig {
 ///
 /// Note: `modules_left`, `modules_center`, and `modules_right` are parsed
 /// but not included in the Theme — they're passed through to QuickShell.
+/// [`validate_modules`](Self::validate_modules) still checks them.
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UserBarConfig {
-    /// Bar height in pixels
+    /// Bar height in pixels (16–200)
     #[serde(default = "default_bar_height")]
+    #[cfg_attr(feature = "schema", schemars(range(min = 16, max = 200)))]
     pub height: u16,
 
-    /// Bar position: "top" or "bottom"
+    /// Bar position: "top", "bottom", "left", or "right"
     #[serde(default = "default_bar_position")]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(extend("enum" = ["top", "bottom", "left", "right"]))
+    )]
     pub position: String,
 
-    /// Margin from screen edge
+    /// Margin from screen edge in pixels (0–500)
     #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(range(max = 500)))]
     pub margin: u16,
 
+    /// Background opacity (0.0–1.0); below 1.0 the bar is translucent
+    #[serde(default = "default_bar_opacity")]
+    #[cfg_attr(feature = "schema", schemars(range(min = 0.0, max = 1.0)))]
+    pub opacity: f32,
+
+    /// Ask the compositor to blur behind the bar
+    #[serde(default)]
+    pub blur: bool,
+
     /// Modules on the left (passed through to QuickShell)
     #[serde(default)]
     pub modules_left: Vec<String>,
@@ -263,12 +477,141 @@ fn default_bar_position() -> String {
     "top".to_string()
 }
 
+const fn default_bar_opacity() -> f32 {
+    1.0
+}
+
+/// Allowed bar heights in pixels.
+///
+/// Below 16px text and icons no longer fit; above 200px the bar takes over
+/// a large part of a laptop screen.
+pub const BAR_HEIGHT_RANGE: RangeInclusive<u16> = 16..=200;
+
+/// Allowed bar margins in pixels.
+///
+/// Large enough for floating-bar setups on 4K screens, small enough to
+/// catch typos like `margin = 5000`.
+pub const BAR_MARGIN_RANGE: RangeInclusive<u16> = 0..=500;
+
+/// Allowed bar opacities, from fully transparent to opaque.
+pub const BAR_OPACITY_RANGE: RangeInclusive<f32> = 0.0..=1.0;
+
+/// Bar modules the shell can draw.
+///
+/// Every module belongs in one place, except [`SPACER_MODULE`].
+pub const KNOWN_MODULES: &[&str] = &["workspaces", "clock", "obs", "power", "tray", "spacer"];
+
+/// Filler module that may appear any number of times, in any region.
+pub const SPACER_MODULE: &str = "spacer";
+
+impl UserBarConfig {
+    /// Checks that the bar settings are within their allowed ranges.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::OutOfRange`] if `height` is outside
+    /// [`BAR_HEIGHT_RANGE`], `margin` is outside [`BAR_MARGIN_RANGE`], or
+    /// `opacity` is outside [`BAR_OPACITY_RANGE`].
+    pub fn validate(&self) -> Result<()> {
+        check_range("bar.height", self.height, &BAR_HEIGHT_RANGE)?;
+        check_range("bar.margin", self.margin, &BAR_MARGIN_RANGE)?;
+        check_range("bar.opacity", self.opacity, &BAR_OPACITY_RANGE)
+    }
+
+    /// Checks the module lists for unknown and misplaced modules.
+    ///
+    /// Warns about each name missing from [`KNOWN_MODULES`], with "did you
+    /// mean?" suggestions, and about each module listed a second time,
+    /// whether in another region (`clock` in both `modules_center` and
+    /// `modules_right`) or the same one.
+    #[must_use]
+    pub fn validate_modules(&self) -> Vec<ConfigWarning> {
+        let regions = [
+            ("bar.modules_left", &self.modules_left),
+            ("bar.modules_center", &self.modules_center),
+            ("bar.modules_right", &self.modules_right),
+        ];
+        let mut warnings = Vec::new();
+        let mut placed: Vec<(&str, &str)> = Vec::new();
+
+        for (key, modules) in regions {
+            for module in modules.iter().map(String::as_str) {
+                if module == SPACER_MODULE {
+                    continue;
+                }
+                if !KNOWN_MODULES.contains(&module) {
+                    warnings.push(unknown_module(key, module));
+                }
+
+                let Some(&(_, first)) = placed.iter().find(|(name, _)| *name == module) else {
+                    placed.push((module, key));
+                    continue;
+                };
+                let warning = if first == key {
+                    ConfigWarning::new(format!("bar module \"{module}\" is listed twice"))
+                        .with_suggestion(format!("Remove one \"{module}\" from {key}"))
+                } else {
+                    ConfigWarning::new(format!(
+                        "bar module \"{module}\" is already placed in {first}"
+                    ))
+                    .with_suggestion(format!("Keep \"{module}\" in either {first} or {key}"))
+                };
+                warnings.push(warning.with_key(key));
+            }
+        }
+
+        warnings
+    }
+}
+
+/// Warns about a module name that isn't in [`KNOWN_MODULES`].
+fn unknown_module(key: &str, module: &str) -> ConfigWarning {
+    let name = module.to_lowercase();
+    let mut similar: Vec<(f64, &str)> = KNOWN_MODULES
+        .iter()
+        .map(|&known| (strsim::jaro_winkler(&name, known), known))
+        .filter(|(score, _)| *score > 0.7)
+        .collect();
+    similar.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    let suggestion = match similar.first() {
+        Some((_, known)) => format!("Did you mean: {known}?"),
+        None => format!("Known modules: {}", KNOWN_MODULES.join(", ")),
+    };
+    ConfigWarning::new(format!("unknown bar module \"{module}\""))
+        .with_key(key)
+        .with_suggestion(suggestion)
+}
+
+/// Returns [`ConfigError::OutOfRange`] if `value` is outside `range`.
+///
+/// NaN is never in range.
+fn check_range<T>(key: &str, value: T, range: &RangeInclusive<T>) -> Result<()>
+where
+    T: Copy + PartialOrd + Into<f64>,
+{
+    if range.contains(&value) {
+        return Ok(());
+    }
+
+    Err(FernError::Config(ConfigError::OutOfRange {
+        key: key.to_string(),
+        value: value.into(),
+        min: (*range.start()).into(),
+        max: (*range.end()).into(),
+        span: None,
+        source_code: None,
+    }))
+}
+
 impl Default for UserBarConfig {
     fn default() -> Self {
         Self {
             height: default_bar_height(),
             position: default_bar_position(),
             margin: 0,
+            opacity: default_bar_opacity(),
+            blur: false,
             modules_left: vec!["workspaces".to_string()],
             modules_center: vec!["clock".to_string()],
             modules_right: vec!["tray".to_string()],
@@ -276,6 +619,51 @@ impl Default for UserBarConfig {
     }
 }
 
+// ============================================================================
+// UserSpacingConfig — Spacing overrides
+// ============================================================================
+
+/// Spacing configuration section (`[appearance.spacing]`).
+///
+/// Allows users to customize the pixel value of each spacing level.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UserSpacingConfig {
+    /// Extra-small spacing (default 4px)
+    #[serde(default)]
+    pub xs: Option<u16>,
+
+    /// Small spacing (default 8px)
+    #[serde(default)]
+    pub sm: Option<u16>,
+
+    /// Medium spacing (default 12px)
+    #[serde(default)]
+    pub md: Option<u16>,
+
+    /// Large spacing (default 16px)
+    #[serde(default)]
+    pub lg: Option<u16>,
+
+    /// Extra-large spacing (default 24px)
+    #[serde(default)]
+    pub xl: Option<u16>,
+}
+
+impl UserSpacingConfig {
+    /// Converts user spacing config into a [`SpacingScale`].
+    fn into_scale(self) -> SpacingScale {
+        let default = SpacingScale::default();
+        SpacingScale {
+            xs: self.xs.unwrap_or(default.xs),
+            sm: self.sm.unwrap_or(default.sm),
+            md: self.md.unwrap_or(default.md),
+            lg: self.lg.unwrap_or(default.lg),
+            xl: self.xl.unwrap_or(default.xl),
+        }
+    }
+}
+
 // ============================================================================
 // UserRadiusConfig — Radius overrides
 // ============================================================================
@@ -284,6 +672,7 @@ impl Default for UserBarConfig {
 ///
 /// Allows users to customize the radius scale values.
 #[derive(Debug, Clone, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 pub struct UserRadiusConfig {
     /// No radius (typically 0)
     #[serde(default)]
@@ -332,6 +721,148 @@ impl Default for UserRadiusConfig {
     }
 }
 
+// ============================================================================
+// UserTypographyConfig — Typography rhythm overrides
+// ============================================================================
+
+/// Typography configuration section (`[appearance.typography]`).
+///
+/// Font families stay on `[appearance]` for compatibility; this section
+/// holds the vertical rhythm settings.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UserTypographyConfig {
+    /// Line height multiplier (default 1.2)
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(range(min = 0.0)))]
+    pub line_height: Option<f32>,
+
+    /// Letter spacing in pixels (default 0.0)
+    #[serde(default)]
+    pub letter_spacing: Option<f32>,
+}
+
+// ============================================================================
+// UserColorsConfig — Palette overrides
+// ============================================================================
+
+/// Color configuration section (`[appearance.colors]`).
+///
+/// Overrides individual palette colors on top of the variant's defaults.
+/// Each takes the same forms as `appearance.accent`: hex, `rgb()`/`rgba()`,
+/// a CSS color name, or a `${VAR}` reference. `appearance.accent`, if set,
+/// takes precedence over `accent` here.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct UserColorsConfig {
+    /// Main background color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub background: Option<String>,
+
+    /// Raised surface color (module backgrounds)
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub surface: Option<String>,
+
+    /// Surface color under the pointer
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub surface_hover: Option<String>,
+
+    /// Primary text color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub foreground: Option<String>,
+
+    /// Secondary text color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub foreground_dim: Option<String>,
+
+    /// Accent color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub accent: Option<String>,
+
+    /// Error status color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub error: Option<String>,
+
+    /// Warning status color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub warning: Option<String>,
+
+    /// Success status color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub success: Option<String>,
+
+    /// Info status color
+    #[serde(default)]
+    #[cfg_attr(feature = "schema", schemars(schema_with = "color_schema"))]
+    pub info: Option<String>,
+}
+
+impl UserColorsConfig {
+    /// Returns each override with its key, for interpolation.
+    const fn fields_mut(&mut self) -> [(&'static str, &mut Option<String>); 10] {
+        [
+            ("background", &mut self.background),
+            ("surface", &mut self.surface),
+            ("surface_hover", &mut self.surface_hover),
+            ("foreground", &mut self.foreground),
+            ("foreground_dim", &mut self.foreground_dim),
+            ("accent", &mut self.accent),
+            ("error", &mut self.error),
+            ("warning", &mut self.warning),
+            ("success", &mut self.success),
+            ("info", &mut self.info),
+        ]
+    }
+
+    /// Applies the overrides that are set onto `palette`.
+    fn apply(self, palette: &mut ColorPalette) -> Result<()> {
+        fn set<Role: ColorRole>(token: &mut ColorToken<Role>, value: Option<String>) -> Result<()> {
+            if let Some(value) = value {
+                *token = parse_color(&value)?;
+            }
+            Ok(())
+        }
+
+        set(&mut palette.background, self.background)?;
+        set(&mut palette.surface, self.surface)?;
+        set(&mut palette.surface_hover, self.surface_hover)?;
+        set(&mut palette.foreground, self.foreground)?;
+        set(&mut palette.foreground_dim, self.foreground_dim)?;
+        set(&mut palette.accent, self.accent)?;
+        set(&mut palette.error, self.error)?;
+        set(&mut palette.warning, self.warning)?;
+        set(&mut palette.success, self.success)?;
+        set(&mut palette.info, self.info)
+    }
+}
+
+/// Schema for an optional color: any string, with an example of each
+/// form [`parse_color`] and [`expand_env`] accept.
+///
+/// A pattern would have to track every color syntax, and unknown values
+/// already fail validation with a suggestion.
+#[cfg(feature = "schema")]
+fn color_schema(_: &mut schemars::SchemaGenerator) -> schemars::Schema {
+    schemars::json_schema!({
+        "type": ["string", "null"],
+        "examples": ["#ff6b6b", "rgba(203, 166, 247, 0.8)", "tomato", "${FERN_ACCENT}"],
+    })
+}
+
+/// Parses a user-supplied color: hex, `rgb()`, `rgba()`, or a CSS keyword.
+fn parse_color<Role: ColorRole>(value: &str) -> Result<ColorToken<Role>> {
+    ColorToken::from_css(value).map_err(FernError::Config)
+}
+
 // ============================================================================
 // BarPosition helper
 // ============================================================================
@@ -343,6 +874,8 @@ impl BarPosition {
         match name.to_lowercase().as_str() {
             "top" => Some(Self::Top),
             "bottom" => Some(Self::Bottom),
+            "left" => Some(Self::Left),
+            "right" => Some(Self::Right),
             _ => None,
         }
     }
@@ -402,6 +935,164 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn user_config_colors_override() {
+        let config: UserConfig = toml::from_str(
+            r##"
+[appearance]
+accent = "#ff6b6b"
+
+[appearance.colors]
+background = "#000000"
+accent = "#00ff00"
+"##,
+        )
+        .unwrap();
+
+        let theme = config.into_theme().unwrap();
+        assert_eq!(theme.colors.background.to_hex(), "#000000");
+        assert_eq!(theme.colors.accent.to_hex(), "#ff6b6b");
+        assert_eq!(theme.colors.surface, ColorPalette::dark().surface);
+    }
+
+    #[test]
+    fn user_config_accepts_css_colors() {
+        let config: UserConfig = toml::from_str(
+            r#"
+[appearance]
+accent = "rgb(203, 166, 247)"
+
+[appearance.colors]
+surface = "rgba(49, 50, 68, 0.5)"
+"#,
+        )
+        .unwrap();
+
+        let theme = config.into_theme().unwrap();
+        assert_eq!(theme.colors.accent.to_hex(), "#cba6f7");
+        assert_eq!(theme.colors.surface.to_hex(), "#31324480");
+    }
+
+    #[test]
+    fn user_config_accepts_color_names() {
+        let config: UserConfig = toml::from_str(
+            r#"
+[appearance]
+accent = "tomato"
+
+[appearance.colors]
+background = "rebeccapurple"
+"#,
+        )
+        .unwrap();
+
+        let theme = config.into_theme().unwrap();
+        assert_eq!(theme.colors.accent.to_hex(), "#ff6347");
+        assert_eq!(theme.colors.background.to_hex(), "#663399");
+    }
+
+    #[test]
+    fn user_config_unknown_color_name_suggests() {
+        use crate::error::Notifiable;
+
+        let config: UserConfig = toml::from_str("[appearance]\naccent = \"tomatoe\"").unwrap();
+
+        let err = config.into_theme().unwrap_err();
+        assert!(matches!(
+            &err,
+            FernError::Config(ConfigError::InvalidColor { value, .. }) if value == "tomatoe"
+        ));
+        assert_eq!(err.suggestion(), Some("Did you mean: tomato?".to_string()));
+    }
+
+    fn fern_env(name: &str) -> Option<String> {
+        match name {
+            "FERN_ACCENT" => Some("#ff6b6b".to_string()),
+            "FERN_EMPTY" => Some(String::new()),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn expand_env_defined_variable() {
+        assert_eq!(
+            expand_env("${FERN_ACCENT}", "accent", fern_env).unwrap(),
+            "#ff6b6b"
+        );
+        assert_eq!(
+            expand_env("a ${FERN_ACCENT} b", "accent", fern_env).unwrap(),
+            "a #ff6b6b b"
+        );
+    }
+
+    #[test]
+    fn expand_env_undefined_variable() {
+        let err = expand_env("${FERN_MISSING}", "appearance.accent", fern_env).unwrap_err();
+        assert!(matches!(
+            err,
+            FernError::Config(ConfigError::UndefinedEnvVar { ref key, ref name })
+                if key == "appearance.accent" && name == "FERN_MISSING"
+        ));
+    }
+
+    #[test]
+    fn expand_env_defaulted_variable() {
+        assert_eq!(
+            expand_env("${FERN_MISSING:-#89b4fa}", "accent", fern_env).unwrap(),
+            "#89b4fa"
+        );
+        assert_eq!(
+            expand_env("${FERN_EMPTY:-Inter}", "font", fern_env).unwrap(),
+            "Inter"
+        );
+        assert_eq!(
+            expand_env("${FERN_ACCENT:-#89b4fa}", "accent", fern_env).unwrap(),
+            "#ff6b6b"
+        );
+    }
+
+    #[test]
+    fn expand_env_keeps_non_references() {
+        for literal in ["$5", "${not closed", "${1BAD}", "no refs"] {
+            assert_eq!(expand_env(literal, "key", fern_env).unwrap(), literal);
+        }
+    }
+
+    #[test]
+    fn user_config_interpolates_string_fields() {
+        let config: UserConfig = toml::from_str(
+            r#"
+[appearance]
+accent = "${FERN_ACCENT}"
+font_family = "${FERN_FONT:-Fira Sans}"
+
+[appearance.colors]
+background = "${FERN_BG:-#000000}"
+
+[bar]
+position = "${FERN_BAR:-bottom}"
+"#,
+        )
+        .unwrap();
+
+        let theme = config
+            .interpolate_with(fern_env)
+            .unwrap()
+            .into_theme()
+            .unwrap();
+        assert_eq!(theme.colors.accent.to_hex(), "#ff6b6b");
+        assert_eq!(theme.colors.background.to_hex(), "#000000");
+        assert_eq!(theme.typography.family.name(), "Fira Sans");
+        assert_eq!(theme.bar.position, BarPosition::Bottom);
+
+        let config: UserConfig = toml::from_str("[bar]\nposition = \"${FERN_BAR}\"").unwrap();
+        let err = config.interpolate_with(fern_env).unwrap_err();
+        assert!(matches!(
+            err,
+            FernError::Config(ConfigError::UndefinedEnvVar { ref key, .. }) if key == "bar.position"
+        ));
+    }
+
     #[test]
     fn user_config_light_theme() {
         let config = UserConfig {
@@ -452,6 +1143,43 @@ mod tests {
         assert_eq!(theme.radius.lg, 12); // Default unchanged
     }
 
+    #[test]
+    fn user_config_spacing_override() {
+        let config = UserConfig {
+            appearance: AppearanceConfig {
+                spacing: Some(UserSpacingConfig {
+                    sm: Some(6),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let theme = config.into_theme().unwrap();
+        assert_eq!(theme.spacing.sm, 6);
+        assert_eq!(theme.button_spacing(), 6);
+        assert_eq!(theme.spacing.md, 12); // Default unchanged
+    }
+
+    #[test]
+    fn user_config_typography_override() {
+        let config = UserConfig {
+            appearance: AppearanceConfig {
+                typography: Some(UserTypographyConfig {
+                    line_height: Some(1.5),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let theme = config.into_theme().unwrap();
+        assert!((theme.typography.line_height - 1.5).abs() < f32::EPSILON);
+        assert!(theme.typography.letter_spacing.abs() < f32::EPSILON); // Default unchanged
+    }
+
     #[test]
     fn user_config_bar_position() {
         let config = UserConfig {
@@ -466,10 +1194,129 @@ mod tests {
         assert_eq!(theme.bar.position, BarPosition::Bottom);
     }
 
+    #[test]
+    fn user_config_vertical_bar_position() {
+        let config = UserConfig {
+            bar: UserBarConfig {
+                position: "left".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let theme = config.into_theme().unwrap();
+        assert_eq!(theme.bar.position, BarPosition::Left);
+        assert!(theme.bar.position.is_vertical());
+        assert!(!BarPosition::Top.is_vertical());
+    }
+
+    #[test]
+    fn user_config_invalid_bar_position() {
+        let config = UserConfig {
+            bar: UserBarConfig {
+                position: "middle".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        assert!(matches!(
+            config.into_theme(),
+            Err(FernError::Config(ConfigError::InvalidPosition { value, .. })) if value == "middle"
+        ));
+    }
+
+    #[test]
+    fn user_config_bar_out_of_range() {
+        for (height, margin, key) in [
+            (0, 0, "bar.height"),
+            (201, 0, "bar.height"),
+            (40, 5000, "bar.margin"),
+        ] {
+            let config = UserConfig {
+                bar: UserBarConfig {
+                    height,
+                    margin,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            let err = config.into_theme().unwrap_err();
+            assert!(
+                matches!(&err, FernError::Config(ConfigError::OutOfRange { key: k, .. }) if k == key),
+                "unexpected error: {err:?}"
+            );
+        }
+
+        let edges = UserBarConfig {
+            height: *BAR_HEIGHT_RANGE.start(),
+            margin: *BAR_MARGIN_RANGE.end(),
+            ..Default::default()
+        };
+        assert!(edges.validate().is_ok());
+    }
+
+    #[test]
+    fn user_config_bar_effects() {
+        let config = UserConfig {
+            bar: UserBarConfig {
+                opacity: 0.85,
+                blur: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let theme = config.into_theme().unwrap();
+        assert!((theme.bar.opacity - 0.85).abs() < f32::EPSILON);
+        assert!(theme.bar.blur);
+
+        for opacity in [-0.1, 1.5, f32::NAN] {
+            let bar = UserBarConfig {
+                opacity,
+                ..Default::default()
+            };
+            assert!(matches!(
+                bar.validate(),
+                Err(FernError::Config(ConfigError::OutOfRange { key, .. })) if key == "bar.opacity"
+            ));
+        }
+    }
+
+    #[test]
+    fn bar_module_warnings() {
+        assert!(UserBarConfig::default().validate_modules().is_empty());
+
+        let modules =
+            |names: &[&str]| -> Vec<String> { names.iter().map(ToString::to_string).collect() };
+        let bar = UserBarConfig {
+            modules_left: modules(&["workspaces", "spacer", "clok"]),
+            modules_center: modules(&["clock", "spacer"]),
+            modules_right: modules(&["tray", "clock", "tray"]),
+            ..Default::default()
+        };
+        let warnings: Vec<String> = bar
+            .validate_modules()
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        assert_eq!(
+            warnings,
+            [
+                r#"[bar.modules_left] unknown bar module "clok" (Did you mean: clock?)"#,
+                r#"[bar.modules_right] bar module "clock" is already placed in bar.modules_center (Keep "clock" in either bar.modules_center or bar.modules_right)"#,
+                r#"[bar.modules_right] bar module "tray" is listed twice (Remove one "tray" from bar.modules_right)"#,
+            ]
+        );
+    }
+
     #[test]
     fn bar_position_from_name() {
         assert_eq!(BarPosition::from_name("top"), Some(BarPosition::Top));
         assert_eq!(BarPosition::from_name("BOTTOM"), Some(BarPosition::Bottom));
+        assert_eq!(BarPosition::from_name("Left"), Some(BarPosition::Left));
+        assert_eq!(BarPosition::from_name("right"), Some(BarPosition::Right));
         assert_eq!(BarPosition::from_name("invalid"), None);
     }
 
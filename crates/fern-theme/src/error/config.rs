@@ -138,6 +138,7 @@ impl ConfigLocation {
 ///
 /// let error = ConfigError::InvalidColor {
 ///     value: "#gg0000".to_string(),
+///     similar: vec![],
 ///     span: None,
 ///     location: None,
 /// };
@@ -150,14 +151,16 @@ impl ConfigLocation {
 pub enum ConfigError {
     /// Invalid color format.
     ///
-    /// The provided value is not a valid hex color. Colors must be in the
-    /// format `#RRGGBB` or `#RRGGBBAA` where each character is a hex digit.
+    /// The provided value is not a valid color. Colors are written as hex
+    /// (`#RRGGBB` or `#RRGGBBAA`); user config also accepts the CSS forms
+    /// `rgb(r, g, b)`, `rgba(r, g, b, a)`, and color keywords like `tomato`.
     ///
     /// # Valid Examples
     ///
     /// - `#89b4fa` — 6-digit hex (opaque)
     /// - `#89b4fa80` — 8-digit hex (with alpha)
     /// - `#fff` — 3-digit shorthand (expands to #ffffff)
+    /// - `rebeccapurple` — CSS color keyword (user config only)
     ///
     /// # Invalid Examples
     ///
@@ -169,8 +172,10 @@ pub enum ConfigError {
     InvalidColor {
         /// The invalid color value.
         value: String,
+        /// Color keywords close to `value` (for "did you mean?" suggestions).
+        similar: Vec<String>,
         /// Source span for highlighting.
-        #[label("this color value is not valid hex")]
+        #[label("this color value is not valid")]
         span: Option<SourceSpan>,
         /// Location in config file.
         #[source_code]
@@ -187,11 +192,11 @@ pub enum ConfigError {
         /// The configuration key.
         key: String,
         /// The invalid value.
-        value: i64,
+        value: f64,
         /// Minimum allowed value.
-        min: i64,
+        min: f64,
         /// Maximum allowed value.
-        max: i64,
+        max: f64,
         /// Source span for highlighting.
         #[label("value out of range")]
         span: Option<SourceSpan>,
@@ -293,7 +298,7 @@ pub enum ConfigError {
 
     /// Invalid bar position.
     ///
-    /// The bar position must be "top" or "bottom".
+    /// The bar position must be "top", "bottom", "left", or "right".
     #[error("invalid bar position: {value}")]
     #[diagnostic(code(fern::config::invalid_position))]
     InvalidPosition {
@@ -337,6 +342,64 @@ pub enum ConfigError {
         /// The font family that was not found.
         family: String,
     },
+
+    /// Theme file uses a newer schema than this build understands.
+    ///
+    /// Produced when loading a saved theme whose `schema_version` is
+    /// greater than [`THEME_SCHEMA_VERSION`](crate::domain::theme::THEME_SCHEMA_VERSION).
+    #[error("theme schema version {found} is newer than supported version {supported}")]
+    #[diagnostic(code(fern::config::unsupported_version))]
+    UnsupportedVersion {
+        /// Version found in the file.
+        found: u32,
+        /// Highest version this build supports.
+        supported: u32,
+    },
+
+    /// A value references an environment variable that is not set.
+    ///
+    /// String values may contain `${NAME}`; a reference without a
+    /// `${NAME:-default}` fallback must resolve.
+    #[error("environment variable {name} in {key} is not set")]
+    #[diagnostic(code(fern::config::undefined_env_var))]
+    UndefinedEnvVar {
+        /// The configuration key containing the reference.
+        key: String,
+        /// The variable name.
+        name: String,
+    },
+
+    /// A file listed in `include` does not exist.
+    ///
+    /// Include paths are resolved relative to the directory of the file
+    /// that lists them.
+    #[error("included file {path} not found (included from {from})")]
+    #[diagnostic(code(fern::config::include_not_found))]
+    IncludeNotFound {
+        /// The resolved path of the missing file.
+        path: String,
+        /// The file whose `include` listed it.
+        from: String,
+    },
+
+    /// Config files include each other in a loop.
+    #[error("include cycle: {}", .chain.join(" -> "))]
+    #[diagnostic(code(fern::config::include_cycle))]
+    IncludeCycle {
+        /// The files in the loop, ending with the one included twice.
+        chain: Vec<String>,
+    },
+
+    /// Warnings were reported while running in strict mode.
+    ///
+    /// With `--strict`, every [`ConfigWarning`](crate::ports::inbound::ConfigWarning)
+    /// is elevated to this error so CI can reject configs that merely warn.
+    #[error("{} warning(s) treated as errors in strict mode", .warnings.len())]
+    #[diagnostic(code(fern::config::strict))]
+    StrictWarnings {
+        /// The rendered warnings.
+        warnings: Vec<String>,
+    },
 }
 
 impl ConfigError {
@@ -366,6 +429,11 @@ impl ConfigError {
             Self::InvalidPosition { .. } => "fern::config::invalid_position",
             Self::DeprecatedKey { .. } => "fern::config::deprecated",
             Self::FontNotFound { .. } => "fern::config::font_not_found",
+            Self::UnsupportedVersion { .. } => "fern::config::unsupported_version",
+            Self::UndefinedEnvVar { .. } => "fern::config::undefined_env_var",
+            Self::IncludeNotFound { .. } => "fern::config::include_not_found",
+            Self::IncludeCycle { .. } => "fern::config::include_cycle",
+            Self::StrictWarnings { .. } => "fern::config::strict",
         }
     }
 
@@ -383,6 +451,11 @@ impl ConfigError {
             Self::InvalidPosition { .. } => "Invalid Position".to_string(),
             Self::DeprecatedKey { key, .. } => format!("Deprecated: {key}"),
             Self::FontNotFound { family, .. } => format!("Font Not Found: {family}"),
+            Self::UnsupportedVersion { .. } => "Unsupported Theme Version".to_string(),
+            Self::UndefinedEnvVar { name, .. } => format!("Undefined Variable: {name}"),
+            Self::IncludeNotFound { .. } => "Included File Not Found".to_string(),
+            Self::IncludeCycle { .. } => "Include Cycle".to_string(),
+            Self::StrictWarnings { .. } => "Strict Mode".to_string(),
         }
     }
 }
@@ -402,8 +475,11 @@ impl Notifiable for ConfigError {
 
     fn suggestion(&self) -> Option<String> {
         match self {
+            Self::InvalidColor { similar, .. } if !similar.is_empty() => {
+                Some(format!("Did you mean: {}?", similar.join(", ")))
+            }
             Self::InvalidColor { .. } => {
-                Some("Use hex format: #RRGGBB or #RRGGBBAA".to_string())
+                Some("Use #RRGGBB, #RRGGBBAA, rgb(r, g, b), or rgba(r, g, b, a)".to_string())
             }
             Self::OutOfRange { min, max, .. } => {
                 Some(format!("Value must be between {min} and {max}"))
@@ -421,7 +497,7 @@ impl Notifiable for ConfigError {
                 Some("Theme must be one of: dark, light, auto".to_string())
             }
             Self::InvalidPosition { .. } => {
-                Some("Position must be one of: top, bottom".to_string())
+                Some("Position must be one of: top, bottom, left, right".to_string())
             }
             Self::DeprecatedKey { replacement, removed_in, .. } => {
                 Some(format!("Use `{replacement}` instead (removing in {removed_in})"))
@@ -429,6 +505,19 @@ impl Notifiable for ConfigError {
             Self::FontNotFound { .. } => {
                 Some("Install the font or use a different family".to_string())
             }
+            Self::UnsupportedVersion { .. } => {
+                Some("Upgrade fern, or regenerate the theme with `fernctl convert`".to_string())
+            }
+            Self::UndefinedEnvVar { name, .. } => Some(format!(
+                "Set {name}, or give a fallback: ${{{name}:-value}}"
+            )),
+            Self::IncludeNotFound { .. } => {
+                Some("Include paths are relative to the file that lists them".to_string())
+            }
+            Self::IncludeCycle { .. } => {
+                Some("Remove one of the includes to break the loop".to_string())
+            }
+            Self::StrictWarnings { warnings } => Some(warnings.join("; ")),
             _ => None,
         }
     }
@@ -446,6 +535,7 @@ mod tests {
     fn invalid_color_has_correct_severity() {
         let err = ConfigError::InvalidColor {
             value: "#gg0000".to_string(),
+            similar: vec![],
             span: None,
             source_code: None,
         };
@@ -0,0 +1,175 @@
+//! Process exit codes for the CLIs.
+//!
+//! Scripts branch on these, so each code is tied to an error kind rather
+//! than left to the default `main` error path (which always exits with 1).
+//! `fernctl` maps its own errors onto the same codes.
+
+use super::{ConfigError, FernError};
+use std::process::ExitCode;
+
+/// Exit status of a `fern-theme` or `fernctl` command.
+///
+/// | Code | Variant | Meaning |
+/// |------|---------|---------|
+/// | `0` | [`Success`](Self::Success) | Success |
+/// | `1` | [`Config`](Self::Config) | Configuration error (parse, validation, strict warnings) |
+/// | `2` | [`Io`](Self::Io) | I/O error (file not found, permissions) |
+/// | `3` | [`Watch`](Self::Watch) | Watch system error |
+/// | `4` | [`Ipc`](Self::Ipc) | QuickShell could not be reached over D-Bus |
+/// | `5` | [`Service`](Self::Service) | A service could not be controlled (fernctl) |
+///
+/// The code follows the error's kind, not its [`Severity`](super::Severity):
+/// a warning-level error that reaches `main` still failed the command.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum FernExitCode {
+    /// The command succeeded.
+    Success = 0,
+    /// The configuration could not be parsed or failed validation.
+    Config = 1,
+    /// A file could not be read or written.
+    Io = 2,
+    /// The file watcher failed.
+    Watch = 3,
+    /// Communication with QuickShell failed.
+    Ipc = 4,
+    /// A service could not be started, stopped, or signalled.
+    Service = 5,
+}
+
+impl FernExitCode {
+    /// Returns the numeric exit status.
+    #[must_use]
+    pub const fn code(self) -> u8 {
+        self as u8
+    }
+
+    /// Returns the exit code for an error.
+    #[must_use]
+    pub const fn for_error(error: &FernError) -> Self {
+        match error {
+            FernError::Config(error) => Self::for_config_error(error),
+            FernError::Io { .. } => Self::Io,
+            FernError::Watch { .. } => Self::Watch,
+            FernError::Ipc { .. } => Self::Ipc,
+        }
+    }
+
+    /// Returns the exit code for a configuration error.
+    ///
+    /// Every [`ConfigError`] is a configuration problem, whatever its
+    /// severity; the match keeps new variants from slipping through
+    /// unmapped.
+    #[must_use]
+    pub const fn for_config_error(error: &ConfigError) -> Self {
+        match error {
+            ConfigError::ParseError { .. }
+            | ConfigError::InvalidColor { .. }
+            | ConfigError::OutOfRange { .. }
+            | ConfigError::UnknownKey { .. }
+            | ConfigError::MissingField { .. }
+            | ConfigError::TypeMismatch { .. }
+            | ConfigError::InvalidTheme { .. }
+            | ConfigError::InvalidPosition { .. }
+            | ConfigError::DeprecatedKey { .. }
+            | ConfigError::FontNotFound { .. }
+            | ConfigError::UnsupportedVersion { .. }
+            | ConfigError::UndefinedEnvVar { .. }
+            | ConfigError::IncludeNotFound { .. }
+            | ConfigError::IncludeCycle { .. }
+            | ConfigError::StrictWarnings { .. } => Self::Config,
+        }
+    }
+}
+
+impl From<FernExitCode> for ExitCode {
+    fn from(code: FernExitCode) -> Self {
+        Self::from(code.code())
+    }
+}
+
+impl From<&FernError> for FernExitCode {
+    fn from(error: &FernError) -> Self {
+        Self::for_error(error)
+    }
+}
+
+/// Reports an error on stderr and returns the matching exit code.
+///
+/// The error is rendered through miette, so source snippets and help text
+/// show up when the `fancy-errors` feature is enabled. Any error type with
+/// a `From<&E>` mapping to [`FernExitCode`] can be passed.
+///
+/// ```rust,ignore
+/// fn main() -> ExitCode {
+///     match run() {
+///         Ok(()) => FernExitCode::Success.into(),
+///         Err(e) => exit_with(e),
+///     }
+/// }
+/// ```
+#[must_use]
+pub fn exit_with<E>(error: E) -> ExitCode
+where
+    E: miette::Diagnostic + Send + Sync + 'static,
+    for<'a> FernExitCode: From<&'a E>,
+{
+    let code = FernExitCode::from(&error);
+    eprintln!("{:?}", miette::Report::new(error));
+    code.into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config_error() -> FernError {
+        ConfigError::MissingField {
+            key: "appearance.accent".into(),
+            expected_type: "color".into(),
+        }
+        .into()
+    }
+
+    #[test]
+    fn maps_each_kind_to_its_code() {
+        let io = FernError::io("reading config", std::io::Error::other("denied"));
+
+        assert_eq!(
+            FernExitCode::for_error(&config_error()),
+            FernExitCode::Config
+        );
+        assert_eq!(FernExitCode::for_error(&io), FernExitCode::Io);
+        assert_eq!(
+            FernExitCode::for_error(&FernError::watch("inotify")),
+            FernExitCode::Watch
+        );
+        assert_eq!(
+            FernExitCode::for_error(&FernError::ipc("no reply")),
+            FernExitCode::Ipc
+        );
+    }
+
+    #[test]
+    fn warning_severity_config_errors_still_fail_as_config() {
+        let error: FernError = ConfigError::UnknownKey {
+            key: "appearance.colour".into(),
+            similar: vec!["appearance.accent".into()],
+            span: None,
+            source_code: None,
+        }
+        .into();
+        assert_eq!(error.severity(), crate::error::Severity::Warning);
+        assert_eq!(FernExitCode::for_error(&error), FernExitCode::Config);
+    }
+
+    #[test]
+    fn codes_match_documented_values() {
+        assert_eq!(FernExitCode::Success.code(), 0);
+        assert_eq!(FernExitCode::Config.code(), 1);
+        assert_eq!(FernExitCode::Io.code(), 2);
+        assert_eq!(FernExitCode::Watch.code(), 3);
+        assert_eq!(FernExitCode::Ipc.code(), 4);
+        assert_eq!(FernExitCode::Service.code(), 5);
+    }
+}
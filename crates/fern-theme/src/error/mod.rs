@@ -55,10 +55,12 @@
 //! ```
 
 mod config;
+mod exit;
 mod notify;
 mod severity;
 
 pub use config::ConfigError;
+pub use exit::{exit_with, FernExitCode};
 pub use notify::{Notifiable, Notification};
 pub use severity::Severity;
 
@@ -34,6 +34,7 @@
 //!
 //! let error = ConfigError::InvalidColor {
 //!     value: "#gg0000".to_string(),
+//!     similar: vec![],
 //!     span: None,
 //!     source_code: None,
 //! };
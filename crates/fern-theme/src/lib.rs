@@ -104,6 +104,21 @@
 //! let theme = validated.into_theme();              // Theme
 //! ```
 //!
+//! ### Embedding the Pipeline
+//!
+//! Tools that just want a theme out of a config file don't need the adapters
+//! or the type-state steps above. [`load_theme_from_path`] runs the whole
+//! TOML → [`Theme`](domain::theme::Theme) pipeline, and [`theme_to_json`]
+//! renders the JSON that QuickShell reads:
+//!
+//! ```rust,ignore
+//! let theme = fern_theme::load_theme_from_path("config.toml")?;
+//! let json = fern_theme::theme_to_json(&theme, true)?;
+//! ```
+//!
+//! These are the same steps `fern-theme convert` runs, so the output matches
+//! byte for byte.
+//!
 //! ### Using Design Tokens
 //!
 //! ```rust,ignore
@@ -127,6 +142,7 @@
 //! | `fancy-errors` | ✓ | Enables colorful miette error output |
 //! | `watch` | ✗ | Enables file watching for live reload |
 //! | `dbus` | ✗ | Enables D-Bus IPC with QuickShell |
+//! | `schema` | ✗ | Enables JSON Schema generation for the user config |
 //!
 //! ## Modules
 //!
@@ -168,6 +184,60 @@ pub mod adapters;
 #[cfg_attr(docsrs, doc(cfg(feature = "cli")))]
 pub mod commands;
 
+/// Loads a TOML configuration file and converts it to a [`Theme`].
+///
+/// This parses the file (following any `include` directives), validates it,
+/// and builds the theme — the same pipeline `fern-theme convert` runs.
+/// Validation warnings are not fatal and are dropped; use
+/// [`ConfigPort`](ports::ConfigPort) directly to inspect them.
+///
+/// [`Theme`]: domain::theme::Theme
+///
+/// # Errors
+///
+/// Returns an error if the file cannot be read, is not valid TOML, or fails
+/// validation.
+///
+/// # Example
+///
+/// ```rust,ignore
+/// let theme = fern_theme::load_theme_from_path("~/.config/fern/config.toml")?;
+/// println!("Variant: {}", theme.variant.name());
+/// ```
+#[cfg(feature = "cli")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cli")))]
+pub fn load_theme_from_path(
+    path: impl AsRef<std::path::Path>,
+) -> error::Result<domain::theme::Theme> {
+    load_theme(path, &adapters::TomlConfigAdapter::new())
+}
+
+/// Renders a [`Theme`] as the JSON QuickShell reads.
+///
+/// `pretty` selects indented output, as `fern-theme convert` writes by
+/// default; otherwise the JSON is compact.
+///
+/// [`Theme`]: domain::theme::Theme
+///
+/// # Errors
+///
+/// Returns [`FernError::Io`](error::FernError::Io) if the theme cannot be
+/// serialized.
+#[cfg(feature = "cli")]
+#[cfg_attr(docsrs, doc(cfg(feature = "cli")))]
+pub fn theme_to_json(theme: &domain::theme::Theme, pretty: bool) -> error::Result<String> {
+    adapters::render_theme(theme, pretty)
+}
+
+/// Loads and validates `path` with `adapter`, discarding warnings.
+#[cfg(feature = "cli")]
+pub(crate) fn load_theme(
+    path: impl AsRef<std::path::Path>,
+    adapter: &impl ports::ConfigPort,
+) -> error::Result<domain::theme::Theme> {
+    Ok(adapter.load_from_file(path)?.validate()?.into_theme())
+}
+
 /// Convenient re-exports for common usage patterns.
 ///
 /// Import this module to get started quickly:
@@ -177,7 +247,7 @@ pub mod commands;
 /// ```
 pub mod prelude {
     // Domain types
-    pub use crate::domain::theme::Theme;
+    pub use crate::domain::theme::{PartialTheme, Theme, ThemeBuilder};
     pub use crate::domain::tokens::{
         ColorToken,
         BackgroundRole,
@@ -199,3 +269,27 @@ pub mod prelude {
 
 /// Crate version from Cargo.toml.
 pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+#[cfg(all(test, feature = "cli"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_theme_from_path_round_trips_to_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("config.toml");
+        std::fs::write(&path, "[bar]\nheight = 40\n").unwrap();
+
+        let theme = load_theme_from_path(&path).unwrap();
+        assert_eq!(theme.bar.height, 40);
+
+        let json = theme_to_json(&theme, false).unwrap();
+        assert!(!json.contains('\n'));
+        assert_eq!(json, adapters::render_theme(&theme, false).unwrap());
+    }
+
+    #[test]
+    fn load_theme_from_path_reports_missing_file() {
+        assert!(load_theme_from_path("/does/not/exist.toml").is_err());
+    }
+}
@@ -25,12 +25,36 @@
 //! # Convert TOML to JSON
 //! fern-theme convert config.toml -o config.json
 //!
+//! # Convert to compact JSON
+//! fern-theme convert config.toml --minify
+//!
+//! # Convert TOML from stdin to JSON on stdout
+//! generate-config | fern-theme convert - > config.json
+//!
+//! # Convert a whole directory of themes
+//! fern-theme convert --all themes/
+//!
+//! # Bundle them, minified, into a separate directory
+//! fern-theme convert --all themes/ --output-dir dist/ --minify
+//!
+//! # Export a Base16 scheme for terminal and editor templates
+//! fern-theme convert config.toml --format base16
+//!
+//! # Fail on warnings (CI, pre-commit hooks)
+//! fern-theme validate --strict
+//!
+//! # Show what the config changes from the defaults
+//! fern-theme validate --diff
+//!
 //! # Query theme values
 //! fern-theme query colors.background
 //!
 //! # Watch for changes and auto-convert
 //! fern-theme watch
 //!
+//! # ...and signal QuickShell to reload after each conversion
+//! fern-theme watch --reload
+//!
 //! # Show default dark theme
 //! fern-theme defaults dark
 //! ```
@@ -39,11 +63,17 @@
 //!
 //! By default, `fern-theme` looks for configuration at:
 //!
-//! - **Linux**: `~/.config/fern/config.toml`
+//! - **Linux**: `~/.config/fern/config.toml` (`$XDG_CONFIG_HOME`)
 //! - **macOS**: `~/Library/Application Support/fern/config.toml`
 //! - **Windows**: `%APPDATA%\fern\config.toml`
 //!
-//! Override with the `--config` flag on any command.
+//! If that file does not exist, the first `fern/config.toml` under
+//! `$XDG_CONFIG_DIRS` (default `/etc/xdg`) is used instead, so a distro can
+//! ship a system-wide default. With `--merge-system`, the system configs are
+//! merged beneath the user's config rather than replaced by it.
+//!
+//! Override with the `--config` flag on any command; `--verbose` prints the
+//! file that was picked.
 //!
 //! ## Exit Codes
 //!
@@ -53,19 +83,30 @@
 //! | `1` | Configuration error (parse, validation) |
 //! | `2` | I/O error (file not found, permissions) |
 //! | `3` | Watch system error |
+//! | `4` | IPC error (QuickShell unreachable) |
+//!
+//! See [`FernExitCode`](fern_theme::error::FernExitCode) for the mapping
+//! from errors. `convert --all` exits with the code of its first failure.
 //!
 //! ## Environment Variables
 //!
 //! | Variable | Description |
 //! |----------|-------------|
 //! | `FERN_CONFIG` | Override default config file path |
+//! | `XDG_CONFIG_DIRS` | System config directories searched after the user's |
 //! | `NO_COLOR` | Disable colored output |
 //! | `RUST_LOG` | Set log level (e.g., `debug`, `trace`) |
 
 use clap::{Parser, Subcommand};
-use fern_theme::adapters::{FileSystemAdapter, TomlConfigAdapter};
-use fern_theme::error::Result;
-use std::path::PathBuf;
+use fern_theme::adapters::{
+    find_config, system_config_paths, user_config_path, FileSystemAdapter, TomlConfigAdapter,
+};
+use fern_theme::error::{exit_with, FernExitCode, Result};
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+#[cfg(feature = "watch")]
+use fern_theme::adapters::SignalIpcAdapter;
 
 mod commands_impl {
     //! Command implementations.
@@ -99,6 +140,13 @@ struct Cli {
     /// Enable verbose output
     #[arg(short, long, global = true)]
     verbose: bool,
+
+    /// Merge system configs from $XDG_CONFIG_DIRS beneath the config.
+    ///
+    /// Settings the config leaves out are filled in from
+    /// /etc/xdg/fern/config.toml (and any other XDG_CONFIG_DIRS entry).
+    #[arg(long, global = true)]
+    merge_system: bool,
 }
 
 /// Available commands
@@ -114,22 +162,70 @@ enum Commands {
         /// Defaults to ~/.config/fern/config.toml
         #[arg(short, long, env = "FERN_CONFIG")]
         config: Option<PathBuf>,
+
+        /// Treat warnings (unknown or deprecated keys) as errors.
+        #[arg(long)]
+        strict: bool,
+
+        /// Print only the values that differ from the default theme.
+        #[arg(long)]
+        diff: bool,
     },
 
     /// Convert TOML configuration to JSON.
     ///
     /// Validates the input file and writes a JSON file suitable for
-    /// consumption by QuickShell. The conversion is atomic — if validation
-    /// fails, no output is written.
+    /// consumption by QuickShell, or a Base16 scheme with --format base16.
+    /// The conversion is atomic — if validation fails, no output is written.
     Convert {
-        /// Input TOML file to convert.
-        input: PathBuf,
+        /// Input TOML file to convert, or `-` to read stdin.
+        #[arg(required_unless_present = "all")]
+        input: Option<PathBuf>,
 
-        /// Output JSON file path.
+        /// Convert every *.toml file in this directory (and its
+        /// subdirectories) to a sibling *.json (or *.yaml for base16).
         ///
-        /// Defaults to the input filename with .json extension.
+        /// Reports a summary and exits non-zero if any file failed.
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["input", "output"])]
+        all: Option<PathBuf>,
+
+        /// With --all, write every output into this directory instead,
+        /// named after its input file. The directory is created if needed.
+        ///
+        /// Fails before writing anything if two inputs share a file name.
+        #[arg(long, value_name = "DIR", requires = "all")]
+        output_dir: Option<PathBuf>,
+
+        /// Output file path, or `-` to write stdout.
+        ///
+        /// Defaults to the input filename with the format's extension
+        /// (.json, or .yaml for base16), or stdout when reading stdin.
         #[arg(short, long)]
         output: Option<PathBuf>,
+
+        /// Output format.
+        ///
+        /// `base16` writes a Base16 YAML scheme for terminal and editor
+        /// templates instead of theme JSON.
+        #[arg(long, value_enum, default_value = "json")]
+        format: commands_impl::convert::OutputFormat,
+
+        /// Pretty-print the JSON output (default).
+        #[arg(long, conflicts_with = "minify")]
+        pretty: bool,
+
+        /// Write compact JSON without whitespace.
+        ///
+        /// Smaller and faster for QML to parse at startup, but harder to
+        /// read. Mutually exclusive with --pretty.
+        #[arg(long)]
+        minify: bool,
+
+        /// Treat warnings (unknown or deprecated keys) as errors.
+        ///
+        /// No output is written for a file that has warnings.
+        #[arg(long)]
+        strict: bool,
     },
 
     /// Query a specific theme value by path.
@@ -186,24 +282,68 @@ enum Commands {
         /// By default, notifications are sent on success and error.
         #[arg(short, long)]
         quiet: bool,
+
+        /// Signal QuickShell (SIGHUP) to reload after each conversion.
+        ///
+        /// For compositors where QuickShell's own file watching misses
+        /// changes. Reloads share the conversion debounce.
+        #[arg(long)]
+        reload: bool,
     },
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(code) => code.into(),
+        Err(e) => exit_with(e),
+    }
+}
+
+/// Runs the selected command, returning the exit code on success.
+fn run(cli: Cli) -> Result<FernExitCode> {
+    let adapter = if cli.merge_system {
+        TomlConfigAdapter::new().with_base_layers(system_config_paths())
+    } else {
+        TomlConfigAdapter::new()
+    };
 
     match cli.command {
-        Commands::Validate { config } => {
-            let path = config.unwrap_or_else(default_config_path);
-            cmd_validate(&path, cli.verbose)?;
+        Commands::Validate {
+            config,
+            strict,
+            diff,
+        } => {
+            let path = resolve_config(config, &adapter, cli.verbose);
+            cmd_validate(&path, strict, diff, cli.verbose, &adapter)?;
         }
-        Commands::Convert { input, output } => {
-            let output = output.unwrap_or_else(|| commands_impl::convert::derive_output_path(&input));
-            cmd_convert(&input, &output, cli.verbose)?;
+        Commands::Convert {
+            input,
+            output,
+            all,
+            output_dir,
+            format,
+            pretty: _,
+            minify,
+            strict,
+        } => {
+            let options = commands_impl::convert::ConvertOptions {
+                verbose: cli.verbose,
+                pretty: !minify,
+                strict,
+                format,
+            };
+            if let Some(dir) = all {
+                return cmd_convert_all(&dir, output_dir.as_deref(), &options, &adapter);
+            } else if let Some(input) = input {
+                let output = output.unwrap_or_else(|| {
+                    commands_impl::convert::derive_output_path_for(&input, format)
+                });
+                cmd_convert(&input, &output, &options, &adapter)?;
+            }
         }
         Commands::Query { path, config } => {
-            let config_path = config.unwrap_or_else(default_config_path);
-            cmd_query(&config_path, &path, cli.verbose)?;
+            let config_path = resolve_config(config, &adapter, cli.verbose);
+            cmd_query(&config_path, &path, cli.verbose, &adapter)?;
         }
         Commands::Defaults { variant } => {
             cmd_defaults(&variant)?;
@@ -214,67 +354,160 @@ fn main() -> Result<()> {
             output,
             debounce,
             quiet,
+            reload,
         } => {
-            let config_path = config.unwrap_or_else(default_config_path);
+            let config_path = resolve_config(config, &adapter, cli.verbose);
             let output_path =
                 output.unwrap_or_else(|| commands_impl::convert::derive_output_path(&config_path));
-            cmd_watch(&config_path, &output_path, debounce, quiet, cli.verbose)?;
+            cmd_watch(
+                &config_path,
+                &output_path,
+                debounce,
+                quiet,
+                reload,
+                cli.verbose,
+                &adapter,
+            )?;
         }
     }
 
-    Ok(())
+    Ok(FernExitCode::Success)
 }
 
 /// Returns the default configuration file path.
 ///
 /// Uses the XDG config directory on Linux, Application Support on macOS,
-/// and %APPDATA% on Windows.
+/// and %APPDATA% on Windows, falling back to the first system config under
+/// `$XDG_CONFIG_DIRS`. With no config anywhere, the user's path is returned
+/// so the error names the file to create.
 fn default_config_path() -> PathBuf {
-    dirs::config_dir()
-        .map(|p| p.join("fern").join("config.toml"))
+    find_config()
+        .or_else(user_config_path)
         .unwrap_or_else(|| PathBuf::from("config.toml"))
 }
 
+/// Picks the config file, reporting it (and any merged layers) when verbose.
+fn resolve_config(config: Option<PathBuf>, adapter: &TomlConfigAdapter, verbose: bool) -> PathBuf {
+    let path = config.unwrap_or_else(default_config_path);
+    if verbose {
+        eprintln!("Config file: {}", path.display());
+        for layer in adapter.base_layers().iter().filter(|layer| layer.is_file()) {
+            eprintln!("Merging beneath it: {}", layer.display());
+        }
+    }
+    path
+}
+
 /// Validates a configuration file.
-fn cmd_validate(path: &PathBuf, verbose: bool) -> Result<()> {
-    let adapter = TomlConfigAdapter::new();
-    let options = commands_impl::validate::ValidateOptions { verbose };
+fn cmd_validate(
+    path: &PathBuf,
+    strict: bool,
+    diff: bool,
+    verbose: bool,
+    adapter: &TomlConfigAdapter,
+) -> Result<()> {
+    let options = commands_impl::validate::ValidateOptions { verbose, strict };
 
-    let result = commands_impl::validate::run(path, options, &adapter)?;
+    let result = commands_impl::validate::run(path, &options, adapter)?;
 
     for warning in &result.warnings {
         eprintln!("Warning: {warning}");
     }
 
     println!("Configuration is valid.");
+
+    if diff {
+        let differences = result.theme.diff(&result.theme.base_defaults());
+        if differences.is_empty() {
+            println!(
+                "No differences from the default {} theme.",
+                result.theme.variant.name()
+            );
+        }
+        for difference in &differences {
+            println!("  {difference}");
+        }
+    }
     Ok(())
 }
 
 /// Converts a TOML configuration to JSON.
-fn cmd_convert(input: &PathBuf, output: &PathBuf, verbose: bool) -> Result<()> {
-    let config_adapter = TomlConfigAdapter::new();
+fn cmd_convert(
+    input: &PathBuf,
+    output: &PathBuf,
+    options: &commands_impl::convert::ConvertOptions,
+    config_adapter: &TomlConfigAdapter,
+) -> Result<()> {
     let persist_adapter = FileSystemAdapter::new();
-    let options = commands_impl::convert::ConvertOptions {
-        verbose,
-        pretty: true,
-    };
 
-    let result = commands_impl::convert::run(input, output, options, &config_adapter, &persist_adapter)?;
+    let result =
+        commands_impl::convert::run(input, output, options, config_adapter, &persist_adapter)?;
 
     for warning in &result.warnings {
         eprintln!("Warning: {warning}");
     }
 
-    println!("Converted to: {}", output.display());
+    // Keep stdout clean when it carries the converted theme
+    if !commands_impl::convert::is_stdio(output) {
+        println!("Converted to: {}", output.display());
+    }
     Ok(())
 }
 
+/// Converts every TOML file in a directory, printing a summary.
+///
+/// Outputs go next to their inputs, or into `output_dir` if given.
+/// Returns the exit code of the first failed file, if any.
+fn cmd_convert_all(
+    dir: &PathBuf,
+    output_dir: Option<&Path>,
+    options: &commands_impl::convert::ConvertOptions,
+    config_adapter: &TomlConfigAdapter,
+) -> Result<FernExitCode> {
+    let persist_adapter = FileSystemAdapter::new();
+
+    let batch = match output_dir {
+        Some(output_dir) => commands_impl::convert::run_all_into(
+            dir,
+            output_dir,
+            options,
+            config_adapter,
+            &persist_adapter,
+        )?,
+        None => commands_impl::convert::run_all(dir, options, config_adapter, &persist_adapter)?,
+    };
+
+    for (input, result) in &batch.succeeded {
+        for warning in &result.warnings {
+            eprintln!("Warning ({}): {warning}", input.display());
+        }
+    }
+    for (input, err) in &batch.failed {
+        eprintln!("Failed: {}: {err}", input.display());
+    }
+
+    println!(
+        "Converted {} file(s), {} failed.",
+        batch.succeeded.len(),
+        batch.failed.len()
+    );
+
+    Ok(batch
+        .failed
+        .first()
+        .map_or(FernExitCode::Success, |(_, err)| err.into()))
+}
+
 /// Queries a theme value by path.
-fn cmd_query(config_path: &PathBuf, query_path: &str, verbose: bool) -> Result<()> {
-    let adapter = TomlConfigAdapter::new();
+fn cmd_query(
+    config_path: &PathBuf,
+    query_path: &str,
+    verbose: bool,
+    adapter: &TomlConfigAdapter,
+) -> Result<()> {
     let options = commands_impl::query::QueryOptions { verbose };
 
-    match commands_impl::query::run(config_path, query_path, options, &adapter)? {
+    match commands_impl::query::run(config_path, query_path, &options, adapter)? {
         Some(value) => {
             println!("{value}");
         }
@@ -323,21 +556,42 @@ fn cmd_watch(
     output_path: &PathBuf,
     debounce_ms: u64,
     quiet: bool,
+    reload: bool,
     verbose: bool,
+    config_adapter: &TomlConfigAdapter,
 ) -> Result<()> {
-    let config_adapter = TomlConfigAdapter::new();
     let persist_adapter = FileSystemAdapter::new();
 
     let options = commands_impl::watch::WatchOptions {
         debounce_ms,
-        notify_on_success: !quiet,
-        notify_on_error: !quiet,
+        notify: if quiet {
+            commands_impl::watch::Notify::Off
+        } else {
+            commands_impl::watch::Notify::All
+        },
         verbose,
+        reload,
+        ..commands_impl::watch::WatchOptions::default()
     };
 
     println!("Watching {} for changes...", config_path.display());
     println!("Output: {}", output_path.display());
+    if reload {
+        println!("QuickShell will be signalled to reload after each conversion.");
+    }
     println!("Press Ctrl+C to stop.\n");
 
-    commands_impl::watch::run(config_path, output_path, options, &config_adapter, &persist_adapter)
+    let stop = options.stop.clone();
+    ctrlc::set_handler(move || stop.stop()).map_err(|e| {
+        fern_theme::error::FernError::watch(format!("failed to install Ctrl+C handler: {e}"))
+    })?;
+
+    commands_impl::watch::run(
+        config_path,
+        output_path,
+        &options,
+        config_adapter,
+        &persist_adapter,
+        &SignalIpcAdapter::new(),
+    )
 }
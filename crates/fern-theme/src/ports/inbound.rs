@@ -88,6 +88,7 @@
 
 use crate::domain::theme::Theme;
 use crate::error::{ConfigError, FernError, Notification, Result, Severity};
+use miette::SourceSpan;
 use std::path::Path;
 
 // ============================================================================
@@ -137,6 +138,8 @@ pub struct RawConfig {
     inner: serde_json::Value,
     /// The source format this config was parsed from.
     source_format: SourceFormat,
+    /// The original file content, used to point errors at the offending value.
+    source: Option<String>,
 }
 
 /// The format a configuration was parsed from.
@@ -174,9 +177,21 @@ impl RawConfig {
         Self {
             inner: value,
             source_format,
+            source: None,
         }
     }
 
+    /// Attaches the original file content.
+    ///
+    /// With the source attached, validation errors such as
+    /// [`ConfigError::OutOfRange`] carry a span pointing at the offending
+    /// value. Only TOML sources are located.
+    #[must_use]
+    pub fn with_source(mut self, source: impl Into<String>) -> Self {
+        self.source = Some(source.into());
+        self
+    }
+
     /// Returns the source format this configuration was parsed from.
     #[must_use]
     pub fn source_format(&self) -> SourceFormat {
@@ -190,6 +205,10 @@ impl RawConfig {
     /// - Color values are valid hex format
     /// - Numeric values are within valid ranges
     /// - Unknown keys trigger warnings (not errors)
+    /// - Cross-field rules ([`Theme::validate_semantic`]) trigger warnings
+    /// - Unknown or repeated bar modules
+    ///   ([`UserBarConfig::validate_modules`](crate::domain::user_config::UserBarConfig::validate_modules))
+    ///   trigger warnings
     ///
     /// # Errors
     ///
@@ -213,13 +232,63 @@ impl RawConfig {
                 })
             })?;
 
-        // Transform UserConfig to Theme, applying overrides
-        let theme = user_config.into_theme()?;
+        // Expand ${VAR} references, then transform UserConfig to Theme
+        let user_config = user_config
+            .interpolate_env()
+            .map_err(|e| self.with_span(e))?;
+        let module_warnings = user_config.bar.validate_modules();
+        let theme = user_config.into_theme().map_err(|e| self.with_span(e))?;
 
-        Ok(ValidatedConfig {
-            theme,
-            warnings: Vec::new(), // Future: collect warnings about unknown keys
-        })
+        // Cross-field checks (future: also warn about unknown keys)
+        let mut warnings = theme.validate_semantic();
+        warnings.extend(module_warnings);
+
+        Ok(ValidatedConfig { theme, warnings })
+    }
+
+    /// Points a bar value error at its value in the source, if known.
+    ///
+    /// The source is only the top-level file. A value merged in from an
+    /// include or a base layer is not in it, so its error is left without
+    /// a span rather than showing unrelated text.
+    fn with_span(&self, error: FernError) -> FernError {
+        let (Some(source), SourceFormat::Toml) = (&self.source, self.source_format) else {
+            return error;
+        };
+
+        match error {
+            FernError::Config(ConfigError::InvalidPosition {
+                value,
+                span: None,
+                source_code: None,
+            }) => {
+                let span = locate_toml_value(source, "bar.position");
+                FernError::Config(ConfigError::InvalidPosition {
+                    value,
+                    source_code: span.map(|_| source.clone()),
+                    span,
+                })
+            }
+            FernError::Config(ConfigError::OutOfRange {
+                key,
+                value,
+                min,
+                max,
+                span: None,
+                source_code: None,
+            }) => {
+                let span = locate_toml_value(source, &key);
+                FernError::Config(ConfigError::OutOfRange {
+                    source_code: span.map(|_| source.clone()),
+                    span,
+                    key,
+                    value,
+                    min,
+                    max,
+                })
+            }
+            other => other,
+        }
     }
 
     /// Returns the inner JSON value for inspection.
@@ -231,6 +300,16 @@ impl RawConfig {
     }
 }
 
+/// Finds the span of the value at a dotted key path in TOML source.
+fn locate_toml_value(source: &str, key_path: &str) -> Option<SourceSpan> {
+    let doc = toml_edit::ImDocument::parse(source).ok()?;
+    let mut item = doc.as_item();
+    for key in key_path.split('.') {
+        item = item.get(key)?;
+    }
+    item.span().map(SourceSpan::from)
+}
+
 /// Configuration that has passed validation.
 ///
 /// `ValidatedConfig` represents a configuration that has been checked for
@@ -288,6 +367,27 @@ impl ValidatedConfig {
         !self.warnings.is_empty()
     }
 
+    /// Fails if validation produced any warnings (strict mode).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ConfigError::StrictWarnings`] listing every warning.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let validated = raw.validate()?.deny_warnings()?;
+    /// ```
+    pub fn deny_warnings(self) -> Result<Self> {
+        if self.warnings.is_empty() {
+            return Ok(self);
+        }
+
+        Err(FernError::Config(ConfigError::StrictWarnings {
+            warnings: self.warnings.iter().map(ToString::to_string).collect(),
+        }))
+    }
+
     /// Consumes this validated configuration and returns the theme.
     ///
     /// After calling this method, the `ValidatedConfig` is consumed and the
@@ -717,6 +817,22 @@ mod tests {
         assert_eq!(raw.source_format(), SourceFormat::Toml);
     }
 
+    #[test]
+    fn out_of_range_error_points_at_value() {
+        let source = "[bar]\nheight = 40\nmargin = 5000\n";
+        let value = serde_json::json!({ "bar": { "height": 40, "margin": 5000 } });
+        let raw = RawConfig::new(value, SourceFormat::Toml).with_source(source);
+
+        let (key, span) = match raw.validate() {
+            Err(FernError::Config(ConfigError::OutOfRange { key, span, .. })) => (key, span),
+            _ => (String::new(), None),
+        };
+        assert_eq!(key, "bar.margin");
+
+        let span = span.unwrap();
+        assert_eq!(&source[span.offset()..span.offset() + span.len()], "5000");
+    }
+
     #[test]
     fn config_warning_display() {
         let warning = ConfigWarning::new("unknown key 'colour'")
@@ -736,4 +852,27 @@ mod tests {
         assert_eq!(notification.severity, Severity::Warning);
         assert!(notification.suggestion.is_some());
     }
+
+    #[test]
+    fn deny_warnings_passes_clean_config() {
+        let validated = ValidatedConfig {
+            theme: Theme::dark(),
+            warnings: Vec::new(),
+        };
+        assert!(validated.deny_warnings().is_ok());
+    }
+
+    #[test]
+    fn deny_warnings_elevates_warnings() {
+        let validated = ValidatedConfig {
+            theme: Theme::dark(),
+            warnings: vec![ConfigWarning::new("unknown key 'colour'").with_key("colour")],
+        };
+
+        let err = validated.deny_warnings().unwrap_err();
+        assert!(matches!(
+            err,
+            FernError::Config(ConfigError::StrictWarnings { ref warnings }) if warnings.len() == 1
+        ));
+    }
 }
@@ -168,6 +168,30 @@ pub trait PersistPort: Send + Sync {
     /// ```
     fn save_theme(&self, theme: &Theme, path: impl AsRef<Path>) -> Result<()>;
 
+    /// Saves a theme to the specified path without pretty-printing.
+    ///
+    /// Compact output is smaller and faster for QML to parse at startup, at
+    /// the cost of readability. Adapters whose storage has no notion of
+    /// formatting can rely on the default, which delegates to
+    /// [`save_theme`](Self::save_theme).
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FernError::Io`] if the file cannot be written.
+    fn save_theme_compact(&self, theme: &Theme, path: impl AsRef<Path>) -> Result<()> {
+        self.save_theme(theme, path)
+    }
+
+    /// Saves a theme as a Base16 YAML scheme named `scheme`.
+    ///
+    /// See [`base16`](crate::domain::base16) for how the palette maps onto
+    /// the Base16 slots.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`FernError::Io`] if the file cannot be written.
+    fn save_base16(&self, theme: &Theme, scheme: &str, path: impl AsRef<Path>) -> Result<()>;
+
     /// Loads a theme from the specified path.
     ///
     /// # Arguments
@@ -0,0 +1,98 @@
+//! End-to-end tests for the TOML → JSON → Theme pipeline.
+//!
+//! Each fixture `tests/fixtures/<name>.toml` is converted with the real
+//! `convert` command, adapters included, into a temp dir. The output must
+//! match `tests/fixtures/<name>.json` and load back into the same theme.
+//!
+//! When a change to the theme JSON is intentional, update the golden files
+//! alongside it.
+
+#![cfg(feature = "cli")]
+
+use fern_theme::adapters::{FileSystemAdapter, TomlConfigAdapter};
+use fern_theme::commands::convert::{self, ConvertOptions};
+use fern_theme::ports::inbound::ConfigPort;
+use fern_theme::ports::outbound::PersistPort;
+use std::path::{Path, PathBuf};
+
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/fixtures")
+        .join(name)
+}
+
+fn read_json(path: &Path) -> serde_json::Value {
+    serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap()
+}
+
+/// Converts `<name>.toml` and checks the result against `<name>.json`.
+fn assert_roundtrip(name: &str) {
+    let input = fixture(&format!("{name}.toml"));
+    let golden = fixture(&format!("{name}.json"));
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join(format!("{name}.json"));
+
+    convert::run(
+        &input,
+        &output,
+        &ConvertOptions::default(),
+        &TomlConfigAdapter::new(),
+        &FileSystemAdapter::new(),
+    )
+    .unwrap();
+
+    // TOML → JSON matches the golden file
+    assert_eq!(
+        read_json(&output),
+        read_json(&golden),
+        "{name}.json differs"
+    );
+
+    // JSON → Theme gives back the theme the TOML describes
+    let expected = TomlConfigAdapter::new()
+        .load_from_file(&input)
+        .unwrap()
+        .validate()
+        .unwrap()
+        .into_theme();
+    let persist = FileSystemAdapter::new();
+    assert_eq!(persist.load_theme(&output).unwrap(), expected);
+    assert_eq!(persist.load_theme(&golden).unwrap(), expected);
+}
+
+#[test]
+fn dark_config_roundtrips() {
+    assert_roundtrip("dark");
+}
+
+#[test]
+fn light_config_roundtrips() {
+    assert_roundtrip("light");
+}
+
+#[test]
+fn overridden_config_roundtrips() {
+    assert_roundtrip("overridden");
+}
+
+#[test]
+fn minified_output_matches_pretty() {
+    let input = fixture("overridden.toml");
+    let dir = tempfile::tempdir().unwrap();
+    let output = dir.path().join("overridden.json");
+
+    convert::run(
+        &input,
+        &output,
+        &ConvertOptions {
+            pretty: false,
+            ..ConvertOptions::default()
+        },
+        &TomlConfigAdapter::new(),
+        &FileSystemAdapter::new(),
+    )
+    .unwrap();
+
+    assert!(!std::fs::read_to_string(&output).unwrap().contains('\n'));
+    assert_eq!(read_json(&output), read_json(&fixture("overridden.json")));
+}
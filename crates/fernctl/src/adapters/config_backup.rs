@@ -0,0 +1,239 @@
+//! # Config Backups
+//!
+//! Keeps copies of `config.json` so a theme change that breaks the shell
+//! can be undone with `fernctl theme rollback`.
+//!
+//! Before `config.json` is overwritten, [`ConfigBackups::backup`] copies it
+//! to `config.json.bak` and to a timestamped file in `backups/`:
+//!
+//! ```text
+//! ~/.config/fern/
+//! ├── config.json
+//! ├── config.json.bak                        # restored by default
+//! └── backups/
+//!     ├── config-20260114-093012.481.json
+//!     └── config-20260115-201544.007.json    # newest
+//! ```
+//!
+//! Only the newest [`keep`](ConfigBackups::with_keep) timestamped backups
+//! are kept; older ones are deleted after each backup.
+
+use crate::error::{FernctlError, Result};
+use fern_core::FernPaths;
+use std::path::{Path, PathBuf};
+
+/// Number of timestamped backups kept by default.
+pub const DEFAULT_KEEP_BACKUPS: usize = 10;
+
+/// Backups of the generated `config.json`.
+#[derive(Debug, Clone)]
+pub struct ConfigBackups {
+    json: PathBuf,
+    latest: PathBuf,
+    dir: PathBuf,
+    keep: usize,
+}
+
+impl ConfigBackups {
+    /// Creates backups for the user's `config.json`.
+    #[must_use]
+    pub fn new(paths: &FernPaths) -> Self {
+        Self::at(
+            paths.config_json(),
+            paths.config_json_backup(),
+            paths.config_backups_dir(),
+        )
+    }
+
+    /// Creates backups for `json`, with the latest copy at `latest` and the
+    /// timestamped ones in `dir`.
+    #[must_use]
+    pub const fn at(json: PathBuf, latest: PathBuf, dir: PathBuf) -> Self {
+        Self {
+            json,
+            latest,
+            dir,
+            keep: DEFAULT_KEEP_BACKUPS,
+        }
+    }
+
+    /// Sets how many timestamped backups to keep (at least one).
+    #[must_use]
+    pub fn with_keep(mut self, keep: usize) -> Self {
+        self.keep = keep.max(1);
+        self
+    }
+
+    /// Returns the path restored when no backup is named.
+    #[must_use]
+    pub fn latest(&self) -> &Path {
+        &self.latest
+    }
+
+    /// Copies the current `config.json` to the backups.
+    ///
+    /// Returns the timestamped backup, or `None` if there is no
+    /// `config.json` yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a copy cannot be written or old backups cannot
+    /// be removed.
+    pub fn backup(&self) -> Result<Option<PathBuf>> {
+        if !self.json.exists() {
+            return Ok(None);
+        }
+
+        std::fs::copy(&self.json, &self.latest)
+            .map_err(|e| FernctlError::io(format!("writing {}", self.latest.display()), e))?;
+
+        std::fs::create_dir_all(&self.dir)
+            .map_err(|e| FernctlError::io("creating backups directory", e))?;
+        let stamp = chrono::Local::now().format("%Y%m%d-%H%M%S%.3f");
+        let stamped = self.dir.join(format!("config-{stamp}.json"));
+        std::fs::copy(&self.json, &stamped)
+            .map_err(|e| FernctlError::io(format!("writing {}", stamped.display()), e))?;
+
+        for old in self.list()?.into_iter().skip(self.keep) {
+            std::fs::remove_file(&old)
+                .map_err(|e| FernctlError::io(format!("removing {}", old.display()), e))?;
+        }
+
+        Ok(Some(stamped))
+    }
+
+    /// Lists the timestamped backups, newest first.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backups directory exists but cannot be read.
+    pub fn list(&self) -> Result<Vec<PathBuf>> {
+        if !self.dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = std::fs::read_dir(&self.dir)
+            .map_err(|e| FernctlError::io("reading backups directory", e))?;
+        let mut backups: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| {
+                let is_json = path
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+                let is_backup = path
+                    .file_name()
+                    .and_then(|name| name.to_str())
+                    .is_some_and(|name| name.starts_with("config-"));
+                is_json && is_backup
+            })
+            .collect();
+
+        // Timestamps sort lexically, so reverse name order is newest first
+        backups.sort_unstable_by(|a, b| b.cmp(a));
+        Ok(backups)
+    }
+
+    /// Resolves a backup name to a path.
+    ///
+    /// `None` means `config.json.bak`. A bare file name is looked up in the
+    /// backups directory; anything else is used as a path.
+    #[must_use]
+    pub fn resolve(&self, name: Option<&str>) -> PathBuf {
+        match name {
+            None => self.latest.clone(),
+            Some(name) if !name.contains('/') => self.dir.join(name),
+            Some(path) => PathBuf::from(path),
+        }
+    }
+
+    /// Replaces `config.json` with `backup`.
+    ///
+    /// The backup is copied to a temporary file first and renamed into
+    /// place, so `config.json` is never left half-written.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the backup does not exist or cannot be copied.
+    pub fn restore(&self, backup: &Path) -> Result<()> {
+        if !backup.exists() {
+            return Err(FernctlError::config(format!(
+                "Backup not found: {}",
+                backup.display()
+            )));
+        }
+
+        let mut temp = self.json.as_os_str().to_owned();
+        temp.push(".tmp");
+        let temp = PathBuf::from(temp);
+
+        std::fs::copy(backup, &temp)
+            .map_err(|e| FernctlError::io(format!("reading {}", backup.display()), e))?;
+        std::fs::rename(&temp, &self.json).map_err(|e| FernctlError::io("replacing config JSON", e))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn backups_in(dir: &Path) -> ConfigBackups {
+        ConfigBackups::at(
+            dir.join("config.json"),
+            dir.join("config.json.bak"),
+            dir.join("backups"),
+        )
+    }
+
+    #[test]
+    fn backup_without_config_does_nothing() {
+        let dir = tempfile::tempdir().unwrap();
+        let backups = backups_in(dir.path());
+
+        assert!(backups.backup().unwrap().is_none());
+        assert!(!backups.latest().exists());
+    }
+
+    #[test]
+    fn backup_then_restore_roundtrips() {
+        let dir = tempfile::tempdir().unwrap();
+        let backups = backups_in(dir.path());
+        let json = dir.path().join("config.json");
+
+        std::fs::write(&json, "good").unwrap();
+        let stamped = backups.backup().unwrap().unwrap();
+        std::fs::write(&json, "broken").unwrap();
+
+        backups.restore(&backups.resolve(None)).unwrap();
+        assert_eq!(std::fs::read_to_string(&json).unwrap(), "good");
+
+        let name = stamped.file_name().unwrap().to_str().unwrap();
+        assert_eq!(backups.resolve(Some(name)), stamped);
+    }
+
+    #[test]
+    fn keeps_only_newest_backups() {
+        let dir = tempfile::tempdir().unwrap();
+        let backups = backups_in(dir.path()).with_keep(2);
+        let stale = dir.path().join("backups");
+        std::fs::create_dir_all(&stale).unwrap();
+        for stamp in ["20200101-000000.000", "20200102-000000.000"] {
+            std::fs::write(stale.join(format!("config-{stamp}.json")), "old").unwrap();
+        }
+        std::fs::write(dir.path().join("config.json"), "current").unwrap();
+
+        let newest = backups.backup().unwrap().unwrap();
+
+        let kept = backups.list().unwrap();
+        assert_eq!(kept.len(), 2);
+        assert_eq!(kept[0], newest);
+        assert!(kept[1].ends_with("config-20200102-000000.000.json"));
+    }
+
+    #[test]
+    fn restore_missing_backup_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let backups = backups_in(dir.path());
+
+        assert!(backups.restore(&backups.resolve(None)).is_err());
+    }
+}
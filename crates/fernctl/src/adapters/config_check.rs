@@ -0,0 +1,46 @@
+//! # Config Check
+//!
+//! Validates the user configuration before the shell is asked to reload it.
+//!
+//! Reuses `fern_theme`'s validate command so the TUI reports exactly what
+//! `fernctl theme validate` would.
+
+use crate::domain::action::ConfigProblem;
+use fern_theme::commands::validate;
+use fern_theme::error::Notifiable;
+use fern_theme::ports::ConfigPort;
+use std::path::Path;
+
+/// Validates the configuration at `path`.
+///
+/// Returns `None` when the configuration is valid, or a [`ConfigProblem`]
+/// describing the first error (with its suggestion) otherwise.
+pub fn check_config(path: &Path, adapter: &impl ConfigPort) -> Option<ConfigProblem> {
+    validate::run(path, &validate::ValidateOptions::default(), adapter)
+        .err()
+        .map(|e| ConfigProblem {
+            title: e.title(),
+            message: e.to_string(),
+            suggestion: e.suggestion(),
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fern_theme::adapters::InMemoryConfigAdapter;
+
+    #[test]
+    fn valid_config_has_no_problem() {
+        let adapter = InMemoryConfigAdapter::new("[appearance]\ntheme = \"dark\"\n");
+        assert!(check_config(Path::new("config.toml"), &adapter).is_none());
+    }
+
+    #[test]
+    fn invalid_config_reports_problem() {
+        let adapter = InMemoryConfigAdapter::new("[appearance\n");
+        let problem = check_config(Path::new("config.toml"), &adapter).unwrap();
+        assert!(!problem.title.is_empty());
+        assert!(!problem.message.is_empty());
+    }
+}
@@ -7,14 +7,22 @@
 //!
 //! ## Modules
 //!
+//! - [`config_backup`] - `config.json` backups for `theme rollback`
+//! - [`config_check`] - Config validation before reload
 //! - [`service_control`] - Process start/stop/restart
 //! - [`state_watcher`] - File watching with notify
 //! - [`shell_ipc`] - QuickShell reload via SIGHUP
 
+pub mod config_backup;
+pub mod config_check;
 pub mod service_control;
 pub mod shell_ipc;
 pub mod state_watcher;
 
-pub use service_control::ServiceController;
-pub use shell_ipc::{find_quickshell_pid, is_shell_running, reload_shell, shell_uptime};
+pub use config_backup::{ConfigBackups, DEFAULT_KEEP_BACKUPS};
+pub use config_check::check_config;
+pub use service_control::{probe_health, ServiceController};
+pub use shell_ipc::{
+    find_quickshell_pid, is_shell_running, process_uptime, reload_shell, shell_uptime,
+};
 pub use state_watcher::{StateChange, StateWatcher};
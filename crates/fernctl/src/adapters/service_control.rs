@@ -2,59 +2,116 @@
 //!
 //! Controls service lifecycle (start, stop, restart).
 
-use crate::domain::KnownService;
+use crate::domain::{HealthProbe, KnownService, ServiceCatalog, ServiceDescriptor};
 use crate::error::{FernctlError, Result};
 use fern_core::FernPaths;
 use std::collections::HashMap;
 use std::process::{Child, Command, Stdio};
 
-/// Controls the lifecycle of known services.
+/// Controls the lifecycle of registered services.
+///
+/// Every operation works on a [`ServiceDescriptor`]; the [`KnownService`]
+/// methods look the descriptor up in the controller's [`ServiceCatalog`],
+/// so user overrides in `services.toml` apply to built-ins too.
 pub struct ServiceController {
     /// Fern paths configuration.
     paths: FernPaths,
+    /// Registered services.
+    catalog: ServiceCatalog,
     /// Map of service name to managed child process.
     managed: HashMap<String, Child>,
 }
 
 impl ServiceController {
-    /// Creates a new service controller.
+    /// Creates a new service controller using the user's service catalog.
+    ///
+    /// Falls back to the built-in services if `services.toml` is invalid.
     #[must_use]
     pub fn new(paths: FernPaths) -> Self {
+        let catalog = ServiceCatalog::load(&paths).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring services.toml: {e}");
+            ServiceCatalog::builtin()
+        });
+        Self::with_catalog(paths, catalog)
+    }
+
+    /// Creates a service controller with an explicit catalog.
+    #[must_use]
+    pub fn with_catalog(paths: FernPaths, catalog: ServiceCatalog) -> Self {
         Self {
             paths,
+            catalog,
             managed: HashMap::new(),
         }
     }
 
-    /// Starts a service.
+    /// Returns the service catalog.
+    #[must_use]
+    pub fn catalog(&self) -> &ServiceCatalog {
+        &self.catalog
+    }
+
+    /// Starts a built-in service.
     ///
     /// # Errors
     ///
     /// Returns an error if the service cannot be started.
     pub fn start(&mut self, service: KnownService) -> Result<()> {
-        if self.is_running(service) {
-            tracing::info!("{} is already running", service.display_name());
-            return Ok(());
-        }
+        let descriptor = self.descriptor(service);
+        self.start_service(&descriptor)
+    }
 
-        tracing::info!("Starting {}...", service.display_name());
+    /// Stops a built-in service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service cannot be stopped.
+    pub fn stop(&mut self, service: KnownService) -> Result<()> {
+        let descriptor = self.descriptor(service);
+        self.stop_service(&descriptor)
+    }
 
-        let mut cmd = Command::new(service.binary());
-        for arg in service.start_args() {
-            cmd.arg(arg);
-        }
+    /// Restarts a built-in service.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service cannot be restarted.
+    pub fn restart(&mut self, service: KnownService) -> Result<()> {
+        let descriptor = self.descriptor(service);
+        self.restart_service(&descriptor)
+    }
 
-        // Detach from terminal
-        cmd.stdin(Stdio::null());
-        cmd.stdout(Stdio::null());
-        cmd.stderr(Stdio::null());
+    /// Checks if a built-in service is running.
+    #[must_use]
+    pub fn is_running(&self, service: KnownService) -> bool {
+        self.is_service_running(&self.descriptor(service))
+    }
 
-        let child = cmd
-            .spawn()
-            .map_err(|e| FernctlError::process_io(format!("starting {}", service.binary()), e))?;
+    /// Gets the PID of a running built-in service, if any.
+    #[must_use]
+    pub fn get_pid(&self, service: KnownService) -> Option<u32> {
+        self.service_pid(&self.descriptor(service))
+    }
 
-        tracing::info!("{} started (PID {})", service.display_name(), child.id());
-        self.managed.insert(service.name().to_string(), child);
+    /// Starts a service and keeps track of its process.
+    ///
+    /// Managed processes are killed when the controller is dropped; use
+    /// [`spawn`](Self::spawn) to start a service that outlives fernctl.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the service cannot be started.
+    pub fn start_service(&mut self, descriptor: &ServiceDescriptor) -> Result<()> {
+        if self.is_service_running(descriptor) {
+            tracing::info!("{} is already running", descriptor.display_name());
+            return Ok(());
+        }
+
+        tracing::info!("Starting {}...", descriptor.display_name());
+        let child = Self::spawn(descriptor)?;
+
+        tracing::info!("{} started (PID {})", descriptor.display_name(), child.id());
+        self.managed.insert(descriptor.name.clone(), child);
 
         Ok(())
     }
@@ -64,28 +121,21 @@ impl ServiceController {
     /// # Errors
     ///
     /// Returns an error if the service cannot be stopped.
-    pub fn stop(&mut self, service: KnownService) -> Result<()> {
-        tracing::info!("Stopping {}...", service.display_name());
+    pub fn stop_service(&mut self, descriptor: &ServiceDescriptor) -> Result<()> {
+        tracing::info!("Stopping {}...", descriptor.display_name());
 
         // First try to stop our managed child
-        if let Some(mut child) = self.managed.remove(service.name()) {
+        if let Some(mut child) = self.managed.remove(&descriptor.name) {
             let _ = child.kill();
             let _ = child.wait();
-            tracing::info!("{} stopped (managed)", service.display_name());
+            tracing::info!("{} stopped (managed)", descriptor.display_name());
             return Ok(());
         }
 
-        // Fall back to pkill for externally started processes
-        let status = Command::new("pkill")
-            .arg("-f")
-            .arg(format!("^{}", service.binary()))
-            .status()
-            .map_err(|e| FernctlError::process_io("running pkill", e))?;
-
-        if status.success() {
-            tracing::info!("{} stopped", service.display_name());
+        if Self::stop_external(descriptor)? {
+            tracing::info!("{} stopped", descriptor.display_name());
         } else {
-            tracing::debug!("{} was not running", service.display_name());
+            tracing::debug!("{} was not running", descriptor.display_name());
         }
 
         Ok(())
@@ -96,51 +146,32 @@ impl ServiceController {
     /// # Errors
     ///
     /// Returns an error if the service cannot be restarted.
-    pub fn restart(&mut self, service: KnownService) -> Result<()> {
-        self.stop(service)?;
+    pub fn restart_service(&mut self, descriptor: &ServiceDescriptor) -> Result<()> {
+        self.stop_service(descriptor)?;
         std::thread::sleep(std::time::Duration::from_millis(500));
-        self.start(service)
+        self.start_service(descriptor)
     }
 
     /// Checks if a service is running.
     #[must_use]
-    pub fn is_running(&self, service: KnownService) -> bool {
-        // Check managed processes first
-        if let Some(child) = self.managed.get(service.name()) {
-            // Check if process is still alive
-            let pid = child.id();
-            let proc_path = format!("/proc/{}", pid);
-            if std::path::Path::new(&proc_path).exists() {
-                return true;
-            }
-        }
-
-        // Check via pgrep
-        Command::new("pgrep")
-            .arg("-x")
-            .arg(service.binary())
-            .stdout(Stdio::null())
-            .status()
-            .map(|s| s.success())
-            .unwrap_or(false)
+    pub fn is_service_running(&self, descriptor: &ServiceDescriptor) -> bool {
+        self.managed_pid(descriptor).is_some() || probe_health(descriptor)
     }
 
     /// Gets the PID of a running service, if any.
     #[must_use]
-    pub fn get_pid(&self, service: KnownService) -> Option<u32> {
-        // Check managed processes first
-        if let Some(child) = self.managed.get(service.name()) {
-            let pid = child.id();
-            let proc_path = format!("/proc/{}", pid);
-            if std::path::Path::new(&proc_path).exists() {
-                return Some(pid);
-            }
+    pub fn service_pid(&self, descriptor: &ServiceDescriptor) -> Option<u32> {
+        if let Some(pid) = self.managed_pid(descriptor) {
+            return Some(pid);
+        }
+        if let Some(pid) = self.pid_file_pid(descriptor) {
+            return Some(pid);
         }
 
         // Check via pgrep
         let output = Command::new("pgrep")
             .arg("-x")
-            .arg(service.binary())
+            .arg(descriptor.binary())
             .output()
             .ok()?;
 
@@ -152,6 +183,65 @@ impl ServiceController {
         }
     }
 
+    /// Spawns a service's start command, detached from the terminal.
+    ///
+    /// The returned child is not tracked; dropping it leaves the process
+    /// running.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the command cannot be spawned.
+    pub fn spawn(descriptor: &ServiceDescriptor) -> Result<Child> {
+        let (program, args) = descriptor
+            .start_cmd
+            .split_first()
+            .ok_or_else(|| FernctlError::service(&descriptor.name, "start_cmd is empty"))?;
+
+        let mut cmd = Command::new(program);
+        cmd.args(args);
+
+        // Detach from terminal
+        cmd.stdin(Stdio::null());
+        cmd.stdout(Stdio::null());
+        cmd.stderr(Stdio::null());
+
+        cmd.spawn()
+            .map_err(|e| FernctlError::process_io(format!("starting {program}"), e))
+    }
+
+    /// Stops a service this controller did not start.
+    ///
+    /// Runs the descriptor's `stop_cmd`, or kills processes whose command
+    /// line starts with the start command. Returns `true` if anything was
+    /// stopped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the stop command cannot be run.
+    pub fn stop_external(descriptor: &ServiceDescriptor) -> Result<bool> {
+        let status = if let Some((program, args)) = descriptor
+            .stop_cmd
+            .as_ref()
+            .and_then(|cmd| cmd.split_first())
+        {
+            Command::new(program)
+                .args(args)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .status()
+                .map_err(|e| FernctlError::process_io(format!("running {program}"), e))?
+        } else {
+            let program = descriptor.start_cmd.first().map_or("", String::as_str);
+            Command::new("pkill")
+                .arg("-f")
+                .arg(format!("^{program}"))
+                .status()
+                .map_err(|e| FernctlError::process_io("running pkill", e))?
+        };
+
+        Ok(status.success())
+    }
+
     /// Cleans up terminated child processes.
     pub fn reap_children(&mut self) {
         self.managed.retain(|name, child| {
@@ -174,6 +264,61 @@ impl ServiceController {
     pub fn paths(&self) -> &FernPaths {
         &self.paths
     }
+
+    /// Returns the catalog descriptor for a built-in service.
+    fn descriptor(&self, service: KnownService) -> ServiceDescriptor {
+        self.catalog
+            .get(service.name())
+            .cloned()
+            .unwrap_or_else(|| ServiceDescriptor::builtin(service))
+    }
+
+    /// Returns the PID a daemon wrote to its PID file, if it is still alive.
+    ///
+    /// Daemons that take a single-instance lock (like fern-obs) keep their
+    /// PID in `~/.local/state/fern/<service>.pid`.
+    fn pid_file_pid(&self, descriptor: &ServiceDescriptor) -> Option<u32> {
+        let content = std::fs::read_to_string(self.paths.service_pid(&descriptor.name)).ok()?;
+        let pid: u32 = content.trim().parse().ok()?;
+        std::path::Path::new(&format!("/proc/{pid}"))
+            .exists()
+            .then_some(pid)
+    }
+
+    /// Returns the PID of a managed child that is still alive.
+    fn managed_pid(&self, descriptor: &ServiceDescriptor) -> Option<u32> {
+        let pid = self.managed.get(&descriptor.name)?.id();
+        std::path::Path::new(&format!("/proc/{pid}"))
+            .exists()
+            .then_some(pid)
+    }
+}
+
+/// Runs a descriptor's health probe.
+///
+/// Returns `true` if the service looks healthy.
+#[must_use]
+pub fn probe_health(descriptor: &ServiceDescriptor) -> bool {
+    match &descriptor.health {
+        HealthProbe::Process => Command::new("pgrep")
+            .arg("-x")
+            .arg(descriptor.binary())
+            .stdout(Stdio::null())
+            .status()
+            .is_ok_and(|s| s.success()),
+        HealthProbe::Command(cmd) => {
+            let Some((program, args)) = cmd.split_first() else {
+                return false;
+            };
+            Command::new(program)
+                .args(args)
+                .stdin(Stdio::null())
+                .stdout(Stdio::null())
+                .stderr(Stdio::null())
+                .status()
+                .is_ok_and(|s| s.success())
+        }
+    }
 }
 
 impl Drop for ServiceController {
@@ -197,4 +342,26 @@ mod tests {
         let controller = ServiceController::new(paths);
         assert!(controller.managed.is_empty());
     }
+
+    #[test]
+    fn known_services_resolve_through_catalog() {
+        let catalog = ServiceCatalog::from_toml(
+            "[[service]]\nname = \"obs\"\nstart_cmd = [\"my-obs\", \"daemon\"]\n",
+        )
+        .unwrap();
+        let controller = ServiceController::with_catalog(FernPaths::new(), catalog);
+
+        assert_eq!(controller.descriptor(KnownService::Obs).binary(), "my-obs");
+        assert_eq!(
+            controller.descriptor(KnownService::Shell).binary(),
+            "quickshell"
+        );
+    }
+
+    #[test]
+    fn spawn_rejects_empty_start_cmd() {
+        let mut descriptor = ServiceDescriptor::builtin(KnownService::Obs);
+        descriptor.start_cmd.clear();
+        assert!(ServiceController::spawn(&descriptor).is_err());
+    }
 }
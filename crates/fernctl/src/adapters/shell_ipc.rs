@@ -62,7 +62,14 @@ pub fn is_shell_running() -> bool {
 #[must_use]
 pub fn shell_uptime() -> Option<u64> {
     let pid = find_quickshell_pid().ok()?;
+    process_uptime(u32::try_from(pid).ok()?)
+}
 
+/// Gets a process's uptime in seconds from `/proc`.
+///
+/// Returns `None` if the process does not exist.
+#[must_use]
+pub fn process_uptime(pid: u32) -> Option<u64> {
     // Read process start time from /proc
     let stat_path = format!("/proc/{}/stat", pid);
     let stat = std::fs::read_to_string(stat_path).ok()?;
@@ -3,7 +3,7 @@
 //! Watches state files for changes and emits actions.
 
 use crate::domain::action::ConfigSummary;
-use crate::domain::{Action, LogEntry};
+use crate::domain::{parse_log_file, Action, KnownService, LogEntry, StateRead};
 use crate::error::{FernctlError, Result};
 use fern_core::FernPaths;
 use notify::{RecommendedWatcher, RecursiveMode};
@@ -17,6 +17,8 @@ use std::time::Duration;
 pub enum StateChange {
     /// OBS state file changed.
     ObsState(serde_json::Value),
+    /// OBS state file exists but is unreadable or corrupt.
+    ObsStateUnreadable(String),
     /// Shell log file changed.
     ShellLogs(Vec<LogEntry>),
     /// Config file changed.
@@ -123,18 +125,19 @@ impl StateWatcher {
         let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
 
         match file_name {
-            "obs-state.json" => {
-                if let Ok(content) = std::fs::read_to_string(path) {
-                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                        let _ = tx.send(StateChange::ObsState(json));
-                    }
+            "obs-state.json" => match StateRead::read(path) {
+                StateRead::Valid(json) => {
+                    let _ = tx.send(StateChange::ObsState(json));
                 }
-            }
+                StateRead::Unreadable(e) | StateRead::Corrupt(e) => {
+                    let _ = tx.send(StateChange::ObsStateUnreadable(e));
+                }
+                StateRead::Missing => {}
+            },
             "shell-log.json" => {
                 if let Ok(content) = std::fs::read_to_string(path) {
-                    if let Ok(logs) = parse_shell_logs(&content) {
-                        let _ = tx.send(StateChange::ShellLogs(logs));
-                    }
+                    let logs = parse_log_file(&content, KnownService::Shell.name());
+                    let _ = tx.send(StateChange::ShellLogs(logs));
                 }
             }
             "config.json" => {
@@ -166,6 +169,13 @@ impl StateWatcher {
                     info,
                 })
             }
+            StateChange::ObsStateUnreadable(error) => {
+                let status = fern_core::ServiceStatus::Failed(format!("state unreadable: {error}"));
+                Some(Action::ServiceStateChanged {
+                    name: "obs".to_string(),
+                    info: fern_core::ServiceInfo::new("obs", status),
+                })
+            }
             StateChange::ShellLogs(logs) => {
                 // Sync all logs from the file
                 Some(Action::LogsSync(logs))
@@ -176,27 +186,6 @@ impl StateWatcher {
     }
 }
 
-/// Parses shell logs from JSON content.
-fn parse_shell_logs(content: &str) -> std::result::Result<Vec<LogEntry>, serde_json::Error> {
-    // Try parsing as array first
-    if let Ok(entries) = serde_json::from_str::<Vec<LogEntry>>(content) {
-        return Ok(entries);
-    }
-
-    // Try parsing as object with entries field
-    if let Ok(json) = serde_json::from_str::<serde_json::Value>(content) {
-        if let Some(entries) = json.get("entries").and_then(|e| e.as_array()) {
-            let parsed: Vec<LogEntry> = entries
-                .iter()
-                .filter_map(|e| serde_json::from_value(e.clone()).ok())
-                .collect();
-            return Ok(parsed);
-        }
-    }
-
-    Ok(vec![])
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,204 @@
+//! # Init Command
+//!
+//! Scaffolds a configuration for new users: creates `~/.config/fern/`,
+//! writes a commented starter `config.toml` with every setting at its
+//! default, and optionally converts it to the `config.json` the shell
+//! reads.
+//!
+//! An existing `config.toml` is left alone unless `--force` is given.
+
+use crate::error::{FernctlError, Result};
+use fern_theme::adapters::{FileSystemAdapter, TomlConfigAdapter};
+use fern_theme::commands::convert;
+use fern_theme::ports::PersistPort;
+use std::path::{Path, PathBuf};
+
+/// Starter `config.toml`, annotated with what each setting does.
+///
+/// Every value is the built-in default, so the file loads to the same theme
+/// as having no config at all.
+pub const STARTER_CONFIG: &str = r##"# Fern Shell Configuration
+#
+# Every value below is the default. Change what you like, then run
+# `fernctl reload` to apply it. `fernctl theme validate` checks this file.
+
+[appearance]
+# Color scheme: "dark", "light", or "auto"
+theme = "dark"
+
+# Accent color for highlights, focus states, and active elements.
+# Colors can be hex ("#89b4fa"), rgb()/rgba(), or CSS names ("tomato").
+accent = "#89b4fa"
+
+# Font families
+font_family = "Inter"
+font_mono = "JetBrainsMono Nerd Font"
+font_icon = "Material Symbols Rounded"
+
+# Override individual palette colors on top of the theme
+# [appearance.colors]
+# background = "#1e1e2e"
+# surface = "#313244"
+
+# Spacing scale (pixels)
+[appearance.spacing]
+xs = 4
+sm = 8
+md = 12
+lg = 16
+xl = 24
+
+# Border radius scale (pixels)
+# Components use these by role: sm for buttons, md for modules, and so on.
+[appearance.radius]
+none = 0
+sm = 4
+md = 8
+lg = 12
+full = 9999
+
+# Text rhythm
+[appearance.typography]
+line_height = 1.2
+letter_spacing = 0.0
+
+[bar]
+# Bar height in pixels (16-200)
+height = 40
+
+# Position: "top", "bottom", "left", or "right"
+position = "top"
+
+# Margin from the screen edge in pixels (0-500)
+margin = 0
+
+# Background opacity (0.0-1.0); below 1.0 the bar is translucent
+opacity = 1.0
+
+# Ask the compositor to blur behind the bar
+blur = false
+
+# Module layout
+modules_left = ["workspaces"]
+modules_center = ["clock"]
+modules_right = ["tray"]
+"##;
+
+/// Options for the init command.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct InitOptions {
+    /// Overwrite an existing `config.toml`.
+    pub force: bool,
+    /// Also generate `config.json` from the new `config.toml`.
+    pub convert: bool,
+}
+
+/// Runs the init command.
+///
+/// # Errors
+///
+/// Returns an error if the config directory cannot be created, a
+/// `config.toml` already exists and `force` is off, or a file cannot be
+/// written.
+pub fn run(options: InitOptions) -> Result<()> {
+    let persist = FileSystemAdapter::new();
+    let existed = persist.config_dir().is_some_and(|dir| dir.is_dir());
+    let dir = persist.ensure_config_dir()?;
+    if !existed {
+        println!("Created {}", dir.display());
+    }
+
+    for path in scaffold(&dir, options, &persist)? {
+        println!("Created {}", path.display());
+    }
+
+    if !options.convert {
+        println!("Run `fernctl reload` after editing to apply the config.");
+    }
+    Ok(())
+}
+
+/// Writes the starter files into `dir`, returning the paths written.
+fn scaffold(dir: &Path, options: InitOptions, persist: &impl PersistPort) -> Result<Vec<PathBuf>> {
+    let config_toml = dir.join("config.toml");
+    if persist.exists(&config_toml) && !options.force {
+        return Err(FernctlError::config(format!(
+            "{} already exists (use --force to overwrite it)",
+            config_toml.display()
+        )));
+    }
+
+    std::fs::write(&config_toml, STARTER_CONFIG)
+        .map_err(|e| FernctlError::io(format!("writing {}", config_toml.display()), e))?;
+
+    if !options.convert {
+        return Ok(vec![config_toml]);
+    }
+
+    let config_json = dir.join("config.json");
+    convert::run(
+        &config_toml,
+        &config_json,
+        &convert::ConvertOptions::default(),
+        &TomlConfigAdapter::new(),
+        persist,
+    )?;
+    Ok(vec![config_toml, config_json])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fern_theme::domain::theme::Theme;
+    use fern_theme::domain::user_config::UserConfig;
+
+    #[test]
+    fn starter_config_matches_defaults() {
+        let config: UserConfig = toml::from_str(STARTER_CONFIG).unwrap();
+        let theme = config.into_theme().unwrap();
+        assert!(theme.diff(&Theme::dark()).is_empty());
+    }
+
+    #[test]
+    fn scaffold_writes_config_and_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let options = InitOptions {
+            force: false,
+            convert: true,
+        };
+
+        let created = scaffold(dir.path(), options, &FileSystemAdapter::new()).unwrap();
+        assert_eq!(
+            created,
+            [
+                dir.path().join("config.toml"),
+                dir.path().join("config.json")
+            ]
+        );
+        assert!(dir.path().join("config.json").is_file());
+    }
+
+    #[test]
+    fn scaffold_refuses_to_overwrite_without_force() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_toml = dir.path().join("config.toml");
+        std::fs::write(&config_toml, "[bar]\nheight = 32\n").unwrap();
+
+        let persist = FileSystemAdapter::new();
+        assert!(scaffold(dir.path(), InitOptions::default(), &persist).is_err());
+        assert_eq!(
+            std::fs::read_to_string(&config_toml).unwrap(),
+            "[bar]\nheight = 32\n"
+        );
+
+        let force = InitOptions {
+            force: true,
+            convert: false,
+        };
+        scaffold(dir.path(), force, &persist).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(&config_toml).unwrap(),
+            STARTER_CONFIG
+        );
+    }
+}
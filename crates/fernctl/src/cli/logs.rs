@@ -2,10 +2,18 @@
 //!
 //! View and follow aggregated logs from Fern Shell services.
 
-use crate::domain::{LogBuffer, LogEntry};
+use super::status::OutputFormat;
+use crate::domain::{
+    match_service_name, parse_log_file, service_from_log_path, LogBuffer, LogEntry, ServiceCatalog,
+    LOG_FILES,
+};
 use crate::error::{FernctlError, Result};
 use fern_core::FernPaths;
+use notify::{RecursiveMode, Watcher};
 use std::fs;
+use std::io::IsTerminal;
+use std::sync::mpsc;
+use std::time::Duration;
 
 /// Options for the logs command.
 #[derive(Debug, Clone)]
@@ -18,6 +26,10 @@ pub struct LogsOptions {
     pub lines: usize,
     /// Filter by log level.
     pub level: Option<String>,
+    /// Only show entries logged at or after this time.
+    pub since: Option<chrono::DateTime<chrono::Utc>>,
+    /// Output format.
+    pub format: OutputFormat,
 }
 
 impl Default for LogsOptions {
@@ -27,6 +39,8 @@ impl Default for LogsOptions {
             service: None,
             lines: 50,
             level: None,
+            since: None,
+            format: OutputFormat::Text,
         }
     }
 }
@@ -35,65 +49,152 @@ impl Default for LogsOptions {
 ///
 /// # Errors
 ///
-/// Returns an error if log files cannot be read.
+/// Returns an error if log files cannot be read or `--service` matches no
+/// service.
 pub fn run(options: LogsOptions) -> Result<()> {
     let paths = FernPaths::new();
-    let mut buffer = LogBuffer::with_default_capacity();
-
-    // Load shell logs
-    let shell_log_path = paths.service_state("shell-log");
-    if shell_log_path.exists() {
-        load_log_file(&shell_log_path, &mut buffer)?;
-    }
-
-    // Apply service filter
-    if let Some(ref service) = options.service {
-        buffer.set_filter(service);
-    }
+    let options = LogsOptions {
+        service: options
+            .service
+            .as_deref()
+            .map(|name| resolve_service(&paths, name))
+            .transpose()?,
+        ..options
+    };
+    let buffer = load_all_logs(&paths, &options)?;
 
     // Display logs
     let entries: Vec<_> = buffer.filtered_entries().collect();
     let start = entries.len().saturating_sub(options.lines);
 
+    let style = EntryStyle::detect(options.format);
     for entry in entries.iter().skip(start) {
-        println!("{}", entry.format_colored());
+        print_entry(entry, style);
     }
 
     if options.follow {
-        println!("\n\x1b[90m--- Following logs (Ctrl+C to exit) ---\x1b[0m\n");
-        follow_logs(&paths, &options)?;
+        if style == EntryStyle::Colored {
+            println!("\n\x1b[90m--- Following logs (Ctrl+C to exit) ---\x1b[0m\n");
+        } else if style == EntryStyle::Plain {
+            println!("\n--- Following logs (Ctrl+C to exit) ---\n");
+        }
+        let last_seen = buffer.entries().back().map(LogEntry::time);
+        follow_logs(&paths, &options, last_seen)?;
     }
 
     Ok(())
 }
 
-fn load_log_file(path: &std::path::Path, buffer: &mut LogBuffer) -> Result<()> {
-    let content = fs::read_to_string(path).map_err(|e| FernctlError::state_io("reading logs", e))?;
+/// How log entries are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum EntryStyle {
+    /// Text with ANSI level colors.
+    Colored,
+    /// Text without escape codes.
+    Plain,
+    /// One JSON object per line.
+    Json,
+}
 
-    // Try parsing as JSON array of log entries
-    if let Ok(entries) = serde_json::from_str::<Vec<LogEntry>>(&content) {
-        for entry in entries {
-            buffer.push(entry);
-        }
-    } else if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-        // Try parsing as object with entries array
-        if let Some(entries) = json.get("entries").and_then(|e| e.as_array()) {
-            for entry_json in entries {
-                if let Ok(entry) = serde_json::from_value::<LogEntry>(entry_json.clone()) {
-                    buffer.push(entry);
+impl EntryStyle {
+    /// Chooses a style for the output format and current terminal.
+    ///
+    /// Text output is only colored when stdout is a TTY and `NO_COLOR`
+    /// is unset or empty.
+    fn detect(format: OutputFormat) -> Self {
+        match format {
+            OutputFormat::Json => Self::Json,
+            OutputFormat::Text => {
+                let no_color = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+                if !no_color && std::io::stdout().is_terminal() {
+                    Self::Colored
+                } else {
+                    Self::Plain
                 }
             }
         }
     }
+}
 
-    Ok(())
+fn print_entry(entry: &LogEntry, style: EntryStyle) {
+    match style {
+        EntryStyle::Colored => println!("{}", entry.format_colored()),
+        EntryStyle::Plain => println!("{}", entry.format_cli()),
+        EntryStyle::Json => {
+            if let Ok(json) = serde_json::to_string(entry) {
+                println!("{json}");
+            }
+        }
+    }
 }
 
-fn follow_logs(paths: &FernPaths, options: &LogsOptions) -> Result<()> {
-    use notify::{RecursiveMode, Watcher};
-    use std::sync::mpsc;
-    use std::time::Duration;
+/// Resolves a `--service` value to the name its log entries carry.
+///
+/// Accepts registered services and the services that write log files,
+/// ignoring case and allowing unique prefixes.
+fn resolve_service(paths: &FernPaths, name: &str) -> Result<String> {
+    let catalog = ServiceCatalog::load(paths)?;
+    if let Some(descriptor) = catalog.get(name) {
+        return Ok(descriptor.name.clone());
+    }
+
+    let log_services: Vec<String> = LOG_FILES
+        .iter()
+        .map(|file| service_from_log_path(std::path::Path::new(file)))
+        .collect();
+    let mut candidates: Vec<&str> = catalog.names().collect();
+    for service in &log_services {
+        if !candidates.contains(&service.as_str()) {
+            candidates.push(service);
+        }
+    }
 
+    match_service_name(name, candidates)
+        .map(str::to_string)
+        .map_err(|message| FernctlError::service(name, message))
+}
+
+/// Loads every known log file into a single buffer, oldest entry first.
+fn load_all_logs(paths: &FernPaths, options: &LogsOptions) -> Result<LogBuffer> {
+    let mut entries = Vec::new();
+    for name in LOG_FILES {
+        let path = paths.state_dir().join(name);
+        if path.exists() {
+            entries.extend(load_log_file(&path)?);
+        }
+    }
+    entries.sort_by_key(LogEntry::time);
+
+    // Apply service filter
+    if let Some(ref service) = options.service {
+        entries.retain(|e| e.matches_service(service));
+    }
+
+    // Apply time filter
+    if let Some(since) = options.since {
+        entries.retain(|e| e.is_since(since));
+    }
+
+    let mut buffer = LogBuffer::with_default_capacity();
+    for entry in entries {
+        buffer.push(entry);
+    }
+
+    Ok(buffer)
+}
+
+fn load_log_file(path: &std::path::Path) -> Result<Vec<LogEntry>> {
+    let content =
+        fs::read_to_string(path).map_err(|e| FernctlError::state_io("reading logs", e))?;
+    Ok(parse_log_file(&content, &service_from_log_path(path)))
+}
+
+fn follow_logs(
+    paths: &FernPaths,
+    options: &LogsOptions,
+    mut last_seen: Option<std::time::SystemTime>,
+) -> Result<()> {
+    let style = EntryStyle::detect(options.format);
     let (tx, rx) = mpsc::channel();
 
     let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
@@ -108,36 +209,23 @@ fn follow_logs(paths: &FernPaths, options: &LogsOptions) -> Result<()> {
         .watch(state_dir, RecursiveMode::NonRecursive)
         .map_err(|e| FernctlError::watch_notify("watching state directory", e))?;
 
-    let shell_log_path = paths.service_state("shell-log");
-    let mut last_len = shell_log_path
-        .metadata()
-        .map(|m| m.len())
-        .unwrap_or(0);
-
     loop {
         match rx.recv_timeout(Duration::from_millis(100)) {
             Ok(event) => {
-                if event
-                    .paths
-                    .iter()
-                    .any(|p| p.file_name().map_or(false, |n| n == "shell-log.json"))
-                {
-                    // Check if file grew
-                    if let Ok(metadata) = shell_log_path.metadata() {
-                        if metadata.len() > last_len {
-                            // Reload and show new entries
-                            let mut buffer = LogBuffer::with_default_capacity();
-                            if load_log_file(&shell_log_path, &mut buffer).is_ok() {
-                                if let Some(ref service) = options.service {
-                                    buffer.set_filter(service);
-                                }
-                                // Show only new entries (rough approximation)
-                                let entries: Vec<_> = buffer.filtered_entries().collect();
-                                if let Some(entry) = entries.last() {
-                                    println!("{}", entry.format_colored());
-                                }
-                            }
-                            last_len = metadata.len();
+                let is_log_file = event.paths.iter().any(|p| {
+                    p.file_name()
+                        .is_some_and(|n| LOG_FILES.iter().any(|name| n == *name))
+                });
+                if !is_log_file {
+                    continue;
+                }
+
+                // Reload and show entries newer than the last one printed
+                if let Ok(buffer) = load_all_logs(paths, options) {
+                    for entry in buffer.filtered_entries() {
+                        if last_seen.is_none_or(|last| entry.time() > last) {
+                            print_entry(entry, style);
+                            last_seen = Some(entry.time());
                         }
                     }
                 }
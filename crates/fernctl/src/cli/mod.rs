@@ -4,14 +4,23 @@
 //!
 //! ## Available Commands
 //!
+//! - `init` - Create a starter config
 //! - `status` - Show service status
 //! - `logs` - View aggregated logs
 //! - `reload` - Reload QuickShell configuration
-//! - `obs` - OBS daemon control
+//! - `service` - Start/stop/restart any registered service
+//! - `obs` - OBS daemon control (alias for `service obs`)
 //! - `theme` - Theme management
+//! - `preview` - Theme swatch images (requires the `preview` feature)
+//! - `watch-services` - Supervise services and restart them on crash
 
+pub mod init;
 pub mod logs;
 pub mod obs;
+#[cfg(feature = "preview")]
+pub mod preview;
 pub mod reload;
+pub mod service;
 pub mod status;
+pub mod supervise;
 pub mod theme;
@@ -0,0 +1,353 @@
+//! # Theme Preview Image
+//!
+//! Renders a theme to a PNG swatch sheet for READMEs and for comparing
+//! themes side by side. Requires the `preview` feature.
+//!
+//! ```text
+//! ┌──────────────────────────────────────────────┐
+//! │ ▌ 1 2 3 ▐            12:34            ● ● ●  │  mock bar
+//! ├──────────────────────────────────────────────┤
+//! │ ┌──────┐ ┌──────┐ ┌──────┐ ┌──────┐ ┌──────┐ │
+//! │ │      │ │      │ │      │ │      │ │      │ │  palette swatches
+//! │ │#1e1e2│ │#3132…│ │      │ │      │ │      │ │  with hex labels
+//! │ └──────┘ └──────┘ └──────┘ └──────┘ └──────┘ │
+//! │ ┌──────┐ ┌──────┐ ┌──────┐ ┌──────┐ ┌──────┐ │
+//! │ └──────┘ └──────┘ └──────┘ └──────┘ └──────┘ │
+//! │ 11.3   7.9   [5.2]                           │  text contrast
+//! └──────────────────────────────────────────────┘
+//! ```
+//!
+//! Text is drawn with a built-in 3×5 pixel font covering hex digits and a
+//! few symbols, so no font files are needed.
+
+use crate::error::{FernctlError, Result};
+use fern_theme::domain::theme::Theme;
+use fern_theme::domain::tokens::color::{ColorRole, ColorToken, Surface};
+use std::fs::File;
+use std::io::BufWriter;
+use std::path::Path;
+
+/// Image width in pixels.
+const WIDTH: u32 = 640;
+
+/// Outer margin and gap between swatches.
+const GAP: u32 = 16;
+
+/// Swatch size.
+const SWATCH_WIDTH: u32 = 108;
+const SWATCH_HEIGHT: u32 = 72;
+
+/// Swatches per row.
+const COLUMNS: u32 = 5;
+
+/// Text scale: each font pixel is drawn as a `TEXT_SCALE` square.
+const TEXT_SCALE: u32 = 2;
+
+/// An RGBA pixel buffer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Canvas {
+    width: u32,
+    height: u32,
+    pixels: Vec<u8>,
+}
+
+impl Canvas {
+    /// Creates a transparent canvas.
+    #[must_use]
+    pub fn new(width: u32, height: u32) -> Self {
+        Self {
+            width,
+            height,
+            pixels: vec![0; (width * height * 4) as usize],
+        }
+    }
+
+    /// Returns the image width.
+    #[must_use]
+    pub const fn width(&self) -> u32 {
+        self.width
+    }
+
+    /// Returns the image height.
+    #[must_use]
+    pub const fn height(&self) -> u32 {
+        self.height
+    }
+
+    /// Returns the RGBA value at a pixel.
+    #[must_use]
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        let i = ((y * self.width + x) * 4) as usize;
+        [
+            self.pixels[i],
+            self.pixels[i + 1],
+            self.pixels[i + 2],
+            self.pixels[i + 3],
+        ]
+    }
+
+    /// Fills a rectangle, blending translucent colors over what is below.
+    ///
+    /// Parts outside the canvas are clipped.
+    pub fn fill_rect<Role: ColorRole>(
+        &mut self,
+        x: u32,
+        y: u32,
+        width: u32,
+        height: u32,
+        color: &ColorToken<Role>,
+    ) {
+        let (red, green, blue, alpha) = color.to_rgba_tuple();
+        let weight = u32::from(alpha);
+        let blend = |below: u8, above: u8| {
+            let mixed = (u32::from(above) * weight + u32::from(below) * (255 - weight)) / 255;
+            u8::try_from(mixed).unwrap_or(u8::MAX)
+        };
+
+        for row in y..(y + height).min(self.height) {
+            for col in x..(x + width).min(self.width) {
+                let i = ((row * self.width + col) * 4) as usize;
+                self.pixels[i] = blend(self.pixels[i], red);
+                self.pixels[i + 1] = blend(self.pixels[i + 1], green);
+                self.pixels[i + 2] = blend(self.pixels[i + 2], blue);
+                self.pixels[i + 3] = self.pixels[i + 3].max(alpha);
+            }
+        }
+    }
+
+    /// Draws text with the built-in font. Unsupported characters are
+    /// skipped as blanks.
+    pub fn draw_text<Role: ColorRole>(
+        &mut self,
+        x: u32,
+        y: u32,
+        text: &str,
+        color: &ColorToken<Role>,
+    ) {
+        let mut cursor = x;
+        for c in text.chars() {
+            let rows = glyph(c.to_ascii_lowercase());
+            for (row, bits) in (0..).zip(rows) {
+                for col in 0..3 {
+                    if bits & (0b100 >> col) != 0 {
+                        self.fill_rect(
+                            cursor + col * TEXT_SCALE,
+                            y + row * TEXT_SCALE,
+                            TEXT_SCALE,
+                            TEXT_SCALE,
+                            color,
+                        );
+                    }
+                }
+            }
+            cursor += 4 * TEXT_SCALE;
+        }
+    }
+
+    /// Writes the canvas as a PNG file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be created or encoded.
+    pub fn write_png(&self, path: &Path) -> Result<()> {
+        let file = File::create(path)
+            .map_err(|e| FernctlError::io(format!("creating {}", path.display()), e))?;
+
+        let mut encoder = png::Encoder::new(BufWriter::new(file), self.width, self.height);
+        encoder.set_color(png::ColorType::Rgba);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()
+            .and_then(|mut writer| writer.write_image_data(&self.pixels))
+            .map_err(|e| FernctlError::io("encoding PNG", std::io::Error::other(e)))
+    }
+}
+
+/// Returns the width in pixels of `text` drawn with [`Canvas::draw_text`].
+fn text_width(text: &str) -> u32 {
+    u32::try_from(text.chars().count()).unwrap_or(0) * 4 * TEXT_SCALE
+}
+
+/// Height in pixels of one line of text.
+const TEXT_HEIGHT: u32 = 5 * TEXT_SCALE;
+
+/// Renders a theme's preview sheet.
+#[must_use]
+pub fn render(theme: &Theme) -> Canvas {
+    let colors = &theme.colors;
+    let bar_height = u32::from(theme.bar.height).clamp(24, 64);
+    let entries = colors.entries();
+    let rows = u32::try_from(entries.len()).unwrap_or(0).div_ceil(COLUMNS);
+
+    let swatches_top = bar_height + GAP;
+    let contrast_top = swatches_top + rows * (SWATCH_HEIGHT + GAP) + GAP / 2;
+    let height = contrast_top + TEXT_HEIGHT + GAP * 2;
+
+    let mut canvas = Canvas::new(WIDTH, height);
+    canvas.fill_rect(0, 0, WIDTH, height, &colors.background);
+    draw_bar(&mut canvas, theme, bar_height);
+
+    // Palette swatches, each labeled with its hex value in whichever of
+    // black or white reads best on it
+    for (i, (_, hex)) in (0..).zip(entries.iter()) {
+        let x = GAP + (i % COLUMNS) * (SWATCH_WIDTH + GAP);
+        let y = swatches_top + (i / COLUMNS) * (SWATCH_HEIGHT + GAP);
+        let Ok(color) = ColorToken::<Surface>::from_hex(hex) else {
+            continue;
+        };
+
+        canvas.fill_rect(x, y, SWATCH_WIDTH, SWATCH_HEIGHT, &color);
+        let label_y = y + SWATCH_HEIGHT - TEXT_HEIGHT - 6;
+        canvas.draw_text(x + 6, label_y, hex, &color.readable_foreground());
+    }
+
+    // Contrast ratios, each drawn in the text color it measures: primary
+    // and dim text on the background, then text on the accent
+    let primary = format!(
+        "{:.1}",
+        colors.foreground.contrast_ratio(&colors.background)
+    );
+    let dim = format!(
+        "{:.1}",
+        colors.foreground_dim.contrast_ratio(&colors.background)
+    );
+    let on_accent = colors.accent.readable_foreground();
+    let accent = format!("{:.1}", on_accent.contrast_ratio(&colors.accent));
+
+    let mut x = GAP;
+    canvas.draw_text(x, contrast_top, &primary, &colors.foreground);
+    x += text_width(&primary) + GAP * 2;
+    canvas.draw_text(x, contrast_top, &dim, &colors.foreground_dim);
+    x += text_width(&dim) + GAP * 2;
+    canvas.fill_rect(
+        x - GAP / 2,
+        contrast_top - GAP / 2,
+        text_width(&accent) + GAP,
+        TEXT_HEIGHT + GAP,
+        &colors.accent,
+    );
+    canvas.draw_text(x, contrast_top, &accent, &on_accent);
+
+    canvas
+}
+
+/// Draws a mock bar: workspaces on the left, a clock in the middle, and
+/// status dots on the right.
+fn draw_bar(canvas: &mut Canvas, theme: &Theme, height: u32) {
+    let colors = &theme.colors;
+    let padding = u32::from(theme.components().bar_padding().1);
+    let module_height = height.saturating_sub(padding * 2);
+    let text_y = padding + module_height.saturating_sub(TEXT_HEIGHT) / 2;
+
+    canvas.fill_rect(0, 0, WIDTH, height, &colors.surface);
+
+    // Workspaces, the first one active
+    let mut x = GAP;
+    for (i, workspace) in ["1", "2", "3"].iter().enumerate() {
+        let width = module_height.max(text_width(workspace) + GAP);
+        if i == 0 {
+            canvas.fill_rect(x, padding, width, module_height, &colors.accent);
+            canvas.draw_text(
+                x + GAP / 2,
+                text_y,
+                workspace,
+                &colors.accent.readable_foreground(),
+            );
+        } else {
+            canvas.fill_rect(x, padding, width, module_height, &colors.surface_hover);
+            canvas.draw_text(x + GAP / 2, text_y, workspace, &colors.foreground_dim);
+        }
+        x += width + padding;
+    }
+
+    // Clock
+    let clock = "12:34";
+    canvas.draw_text(
+        (WIDTH - text_width(clock)) / 2,
+        text_y,
+        clock,
+        &colors.foreground,
+    );
+
+    // Status dots
+    let dot = TEXT_HEIGHT;
+    let mut x = WIDTH - GAP - dot;
+    let dots = [
+        colors.error.to_rgba_tuple(),
+        colors.warning.to_rgba_tuple(),
+        colors.success.to_rgba_tuple(),
+    ];
+    for (red, green, blue, alpha) in dots {
+        let color = ColorToken::<Surface>::from_rgba(red, green, blue, alpha);
+        canvas.fill_rect(
+            x,
+            padding + module_height.saturating_sub(dot) / 2,
+            dot,
+            dot,
+            &color,
+        );
+        x = x.saturating_sub(dot + padding.max(4));
+    }
+}
+
+/// Returns the 3×5 bitmap for a character, one `u8` per row with the
+/// leftmost pixel in bit 2.
+const fn glyph(c: char) -> [u8; 5] {
+    match c {
+        '0' => [0b111, 0b101, 0b101, 0b101, 0b111],
+        '1' => [0b010, 0b110, 0b010, 0b010, 0b111],
+        '2' => [0b111, 0b001, 0b111, 0b100, 0b111],
+        '3' => [0b111, 0b001, 0b111, 0b001, 0b111],
+        '4' => [0b101, 0b101, 0b111, 0b001, 0b001],
+        '5' => [0b111, 0b100, 0b111, 0b001, 0b111],
+        '6' => [0b111, 0b100, 0b111, 0b101, 0b111],
+        '7' => [0b111, 0b001, 0b001, 0b001, 0b001],
+        '8' => [0b111, 0b101, 0b111, 0b101, 0b111],
+        '9' => [0b111, 0b101, 0b111, 0b001, 0b111],
+        'a' => [0b010, 0b101, 0b111, 0b101, 0b101],
+        'b' => [0b110, 0b101, 0b110, 0b101, 0b110],
+        'c' => [0b011, 0b100, 0b100, 0b100, 0b011],
+        'd' => [0b110, 0b101, 0b101, 0b101, 0b110],
+        'e' => [0b111, 0b100, 0b110, 0b100, 0b111],
+        'f' => [0b111, 0b100, 0b110, 0b100, 0b100],
+        '#' => [0b101, 0b111, 0b101, 0b111, 0b101],
+        '.' => [0b000, 0b000, 0b000, 0b000, 0b010],
+        ':' => [0b000, 0b010, 0b000, 0b010, 0b000],
+        _ => [0; 5],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_draws_bar_and_swatches() {
+        let theme = Theme::dark();
+        let canvas = render(&theme);
+        assert_eq!(canvas.width(), WIDTH);
+
+        let (r, g, b, _) = theme.colors.surface.to_rgba_tuple();
+        assert_eq!(canvas.pixel(WIDTH / 2, 1), [r, g, b, 255]);
+
+        // First swatch is the background color, away from its label
+        let top = u32::from(theme.bar.height).clamp(24, 64) + GAP;
+        let (r, g, b) = theme.colors.background.to_rgb_tuple();
+        assert_eq!(
+            canvas.pixel(GAP + SWATCH_WIDTH - 4, top + SWATCH_HEIGHT - 4),
+            [r, g, b, 255]
+        );
+    }
+
+    #[test]
+    fn write_png_creates_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("theme.png");
+
+        render(&Theme::light()).write_png(&path).unwrap();
+
+        let bytes = std::fs::read(&path).unwrap();
+        assert!(bytes.starts_with(b"\x89PNG"));
+    }
+}
@@ -1,29 +1,60 @@
 //! # Reload Command
 //!
 //! Reload QuickShell configuration.
+//!
+//! With `--theme <name>`, the theme is switched first: the config is
+//! validated with the new theme, `config.json` is backed up, both files are
+//! replaced atomically (restoring the old JSON if the TOML write fails),
+//! and only then is QuickShell signalled.
 
+use crate::adapters::ConfigBackups;
+use crate::cli::theme::switch_theme;
 use crate::error::{FernctlError, Result};
+use fern_core::FernPaths;
 use nix::sys::signal::{self, Signal};
 use nix::unistd::Pid;
 use std::process::Command;
+use std::time::Duration;
+
+/// How long QuickShell gets to handle SIGHUP before it is checked on.
+const RELOAD_CONFIRM_DELAY: Duration = Duration::from_millis(250);
 
-/// Runs the reload command.
+/// Runs the reload command, switching to `theme` first if given.
 ///
-/// Finds the QuickShell process and sends SIGHUP to trigger a config reload.
+/// Finds the QuickShell process and sends SIGHUP to trigger a config reload,
+/// then confirms it is still running. QuickShell is looked up before the
+/// theme is switched, so nothing is written when the shell is not running.
 ///
 /// # Errors
 ///
-/// Returns an error if QuickShell is not running or cannot be signaled.
-pub fn run() -> Result<()> {
+/// Returns an error if QuickShell is not running or cannot be signaled,
+/// exits during the reload, or if the theme cannot be switched.
+pub fn run(theme: Option<&str>) -> Result<()> {
     println!("Reloading Fern Shell configuration...");
 
     // Find QuickShell PID
-    let pid = find_quickshell_pid()?;
+    let pid = Pid::from_raw(find_quickshell_pid()?);
+
+    if let Some(name) = theme {
+        let paths = FernPaths::new();
+        switch_theme(
+            name,
+            &paths.config_toml(),
+            &paths.config_json(),
+            &ConfigBackups::new(&paths),
+        )?;
+        println!("Theme applied: {name}");
+    }
 
     // Send SIGHUP
-    signal::kill(Pid::from_raw(pid), Signal::SIGHUP)
+    signal::kill(pid, Signal::SIGHUP)
         .map_err(|e| FernctlError::process(format!("sending SIGHUP: {e}")))?;
 
+    // Confirm the shell survived the reload
+    std::thread::sleep(RELOAD_CONFIRM_DELAY);
+    signal::kill(pid, None)
+        .map_err(|_| FernctlError::process("QuickShell exited while reloading"))?;
+
     println!("Reload signal sent to QuickShell (PID {pid})");
     Ok(())
 }
@@ -40,9 +71,10 @@ fn find_quickshell_pid() -> Result<i32> {
     }
 
     let stdout = String::from_utf8_lossy(&output.stdout);
-    let pid_str = stdout.lines().next().ok_or_else(|| {
-        FernctlError::process("QuickShell is not running")
-    })?;
+    let pid_str = stdout
+        .lines()
+        .next()
+        .ok_or_else(|| FernctlError::process("QuickShell is not running"))?;
 
     pid_str
         .trim()
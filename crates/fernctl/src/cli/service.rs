@@ -0,0 +1,82 @@
+//! # Service Command
+//!
+//! Start, stop, restart, or inspect any registered service: the built-ins
+//! or user-defined services from `services.toml`.
+
+use crate::adapters::{probe_health, ServiceController};
+use crate::cli::status::{self, OutputFormat, StatusOptions};
+use crate::domain::{ServiceCatalog, ServiceDescriptor};
+use crate::error::Result;
+use fern_core::FernPaths;
+
+/// Service subcommand action.
+#[derive(Debug, Clone, Copy)]
+pub enum ServiceAction {
+    /// Start the service.
+    Start,
+    /// Stop the service.
+    Stop,
+    /// Restart the service.
+    Restart,
+    /// Show service status.
+    Status,
+}
+
+/// Runs the service command for the named service.
+///
+/// # Errors
+///
+/// Returns an error if the service is unknown or cannot be controlled.
+pub fn run(name: &str, action: ServiceAction) -> Result<()> {
+    let paths = FernPaths::new();
+    let catalog = ServiceCatalog::load(&paths)?;
+
+    let descriptor = catalog.resolve(name)?;
+
+    match action {
+        ServiceAction::Start => start(descriptor),
+        ServiceAction::Stop => stop(descriptor),
+        ServiceAction::Restart => {
+            stop(descriptor)?;
+            std::thread::sleep(std::time::Duration::from_millis(500));
+            start(descriptor)
+        }
+        ServiceAction::Status => status::run(StatusOptions {
+            service: Some(descriptor.name.clone()),
+            format: OutputFormat::Text,
+            verbose: true,
+            repair: false,
+        }),
+    }
+}
+
+fn start(descriptor: &ServiceDescriptor) -> Result<()> {
+    if probe_health(descriptor) {
+        println!("{} is already running.", descriptor.display_name());
+        return Ok(());
+    }
+
+    println!("Starting {}...", descriptor.display_name());
+
+    // Spawned untracked so the daemon outlives this command
+    let child = ServiceController::spawn(descriptor)?;
+
+    println!(
+        "{} started (PID {}).",
+        descriptor.display_name(),
+        child.id()
+    );
+    Ok(())
+}
+
+fn stop(descriptor: &ServiceDescriptor) -> Result<()> {
+    println!("Stopping {}...", descriptor.display_name());
+
+    if ServiceController::stop_external(descriptor)? {
+        println!("{} stopped.", descriptor.display_name());
+    } else {
+        println!("{} was not running.", descriptor.display_name());
+    }
+
+    Ok(())
+}
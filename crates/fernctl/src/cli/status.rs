@@ -1,12 +1,39 @@
 //! # Status Command
 //!
 //! Shows the status of all services or a specific service.
+//!
+//! A state file that cannot be parsed (a partial write, or a bad manual
+//! edit) shows its service as "unknown" instead of failing the command.
+//! `--repair` replaces such files with the service's disconnected default.
+//!
+//! `fernctl status --output json` prints one array with the same fields
+//! for every service, for waybar or polybar custom modules:
+//!
+//! ```json
+//! [
+//!   {
+//!     "name": "obs",
+//!     "running": true,
+//!     "healthy": true,
+//!     "pid": 4242,
+//!     "uptime_secs": 3600,
+//!     "status": "recording (00:12:34)",
+//!     "summary": { "connected": true, "recording": true, "streaming": false,
+//!                  "scene": "Gaming", "warning": null }
+//!   },
+//!   { "name": "shell", "running": false, "healthy": false, "pid": null,
+//!     "uptime_secs": null, "status": "stopped" }
+//! ]
+//! ```
 
-use crate::domain::KnownService;
-use crate::error::Result;
+use crate::adapters::{probe_health, process_uptime, ServiceController};
+use crate::domain::{KnownService, ServiceCatalog, ServiceDescriptor, StateRead};
+use crate::error::{FernctlError, Result};
 use fern_core::state::ServiceRegistry;
 use fern_core::FernPaths;
+use serde::Serialize;
 use std::fs;
+use std::path::Path;
 
 /// Options for the status command.
 #[derive(Debug, Clone)]
@@ -17,6 +44,8 @@ pub struct StatusOptions {
     pub format: OutputFormat,
     /// Show verbose output.
     pub verbose: bool,
+    /// Replace corrupt state files before showing status.
+    pub repair: bool,
 }
 
 /// Output format for commands.
@@ -33,51 +62,65 @@ pub enum OutputFormat {
 ///
 /// # Errors
 ///
-/// Returns an error if state files cannot be read.
+/// Returns an error if the service catalog cannot be loaded, the service
+/// name matches no service, or a corrupt state file cannot be repaired.
 pub fn run(options: StatusOptions) -> Result<()> {
     let paths = FernPaths::new();
+    let catalog = ServiceCatalog::load(&paths)?;
+    let target = options
+        .service
+        .as_deref()
+        .map(|name| catalog.resolve(name))
+        .transpose()?;
+
+    if options.repair {
+        let services: Vec<KnownService> = match target {
+            Some(descriptor) => descriptor.known().into_iter().collect(),
+            None => KnownService::all().to_vec(),
+        };
+        for service in services {
+            repair_state(paths.state_dir(), service)?;
+        }
+    }
 
-    if let Some(service_name) = &options.service {
+    if let Some(descriptor) = target {
         // Show specific service
-        if let Some(service) = KnownService::from_name(service_name) {
+        if let Some(service) = descriptor.known() {
             show_service_status(&paths, service, &options)?;
         } else {
-            eprintln!("Unknown service: {service_name}");
-            eprintln!("Available services: obs, shell, theme-watcher");
+            show_custom_status(&paths, descriptor, &options);
         }
     } else {
         // Show all services
-        show_all_status(&paths, &options)?;
+        show_all_status(&paths, &catalog, &options)?;
     }
 
     Ok(())
 }
 
 fn show_service_status(paths: &FernPaths, service: KnownService, options: &StatusOptions) -> Result<()> {
-    let state_path = paths.state_dir().join(service.state_file());
+    let state = StateRead::read(&paths.state_dir().join(service.state_file()));
 
-    let status = if state_path.exists() {
-        match fs::read_to_string(&state_path) {
-            Ok(content) => {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                    format_service_json(&json, service, options.verbose)
-                } else {
-                    format!("{}: state file corrupt", service.display_name())
-                }
-            }
-            Err(_) => format!("{}: cannot read state", service.display_name()),
+    let status = match &state {
+        StateRead::Valid(json) => format_service_json(json, service, options.verbose),
+        StateRead::Missing => format!("{}: stopped (no state file)", service.display_name()),
+        StateRead::Unreadable(e) => {
+            format!(
+                "{}: unknown (cannot read state: {e})",
+                service.display_name()
+            )
         }
-    } else {
-        format!("{}: stopped (no state file)", service.display_name())
+        StateRead::Corrupt(e) => format!(
+            "{}: unknown (state file corrupt: {e}; run `fernctl status --repair`)",
+            service.display_name()
+        ),
     };
 
     match options.format {
         OutputFormat::Text => println!("{status}"),
         OutputFormat::Json => {
-            if let Ok(content) = fs::read_to_string(&state_path) {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                    println!("{}", serde_json::to_string_pretty(&json).unwrap_or_default());
-                }
+            if let Some(json) = state_json(&state) {
+                println!("{}", serde_json::to_string_pretty(&json).unwrap_or_default());
             }
         }
     }
@@ -85,7 +128,41 @@ fn show_service_status(paths: &FernPaths, service: KnownService, options: &Statu
     Ok(())
 }
 
-fn show_all_status(paths: &FernPaths, options: &StatusOptions) -> Result<()> {
+/// Shows a user-defined service from `services.toml`.
+fn show_custom_status(paths: &FernPaths, descriptor: &ServiceDescriptor, options: &StatusOptions) {
+    match options.format {
+        OutputFormat::Text => println!(
+            "{}: {}",
+            descriptor.display_name(),
+            if probe_health(descriptor) {
+                "running"
+            } else {
+                "stopped"
+            }
+        ),
+        OutputFormat::Json => println!(
+            "{}",
+            serde_json::to_string_pretty(&custom_status_json(paths, descriptor))
+                .unwrap_or_default()
+        ),
+    }
+}
+
+/// Returns a user-defined service's state file contents, or its health.
+fn custom_status_json(paths: &FernPaths, descriptor: &ServiceDescriptor) -> serde_json::Value {
+    descriptor
+        .state_file
+        .as_ref()
+        .and_then(|file| fs::read_to_string(paths.state_dir().join(file)).ok())
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_else(|| serde_json::json!({ "running": probe_health(descriptor) }))
+}
+
+fn show_all_status(
+    paths: &FernPaths,
+    catalog: &ServiceCatalog,
+    options: &StatusOptions,
+) -> Result<()> {
     // Try to read the services registry first
     let registry_path = paths.services_registry();
     let registry: Option<ServiceRegistry> = if registry_path.exists() {
@@ -102,27 +179,30 @@ fn show_all_status(paths: &FernPaths, options: &StatusOptions) -> Result<()> {
             println!("-------------------");
 
             for service in KnownService::all() {
-                let state_path = paths.state_dir().join(service.state_file());
-                let status = get_service_status_line(service, &state_path, &registry);
+                let state = StateRead::read(&paths.state_dir().join(service.state_file()));
+                let status = get_service_status_line(service, &state, &registry);
                 println!("{status}");
             }
-        }
-        OutputFormat::Json => {
-            let mut output = serde_json::Map::new();
 
-            for service in KnownService::all() {
-                let state_path = paths.state_dir().join(service.state_file());
-                if let Ok(content) = fs::read_to_string(&state_path) {
-                    if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                        output.insert(service.name().to_string(), json);
-                    }
-                }
+            for descriptor in catalog.iter().filter(|d| d.known().is_none()) {
+                let (indicator, text) = if probe_health(descriptor) {
+                    ("\x1b[32m●\x1b[0m", "running")
+                } else {
+                    ("\x1b[90m○\x1b[0m", "stopped")
+                };
+                println!("{} {:14} {}", indicator, descriptor.display_name(), text);
             }
+        }
+        OutputFormat::Json => {
+            let controller = ServiceController::with_catalog(paths.clone(), catalog.clone());
+            let entries: Vec<StatusEntry> = catalog
+                .iter()
+                .map(|descriptor| status_entry(paths, &controller, descriptor))
+                .collect();
 
             println!(
                 "{}",
-                serde_json::to_string_pretty(&serde_json::Value::Object(output))
-                    .unwrap_or_default()
+                serde_json::to_string_pretty(&entries).unwrap_or_default()
             );
         }
     }
@@ -130,66 +210,191 @@ fn show_all_status(paths: &FernPaths, options: &StatusOptions) -> Result<()> {
     Ok(())
 }
 
+/// One service in `fernctl status --output json`.
+///
+/// The array of these is meant for status bar modules (waybar, polybar),
+/// so every service has the same fields.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+struct StatusEntry {
+    /// Service name.
+    name: String,
+    /// Whether the service process is running.
+    running: bool,
+    /// Whether it is running with a readable state file.
+    healthy: bool,
+    /// Process ID, if running.
+    pid: Option<u32>,
+    /// Seconds since the process started, if running.
+    uptime_secs: Option<u64>,
+    /// One-line status, as in the text output.
+    status: String,
+    /// Service-specific fields (e.g. whether OBS is recording).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    summary: Option<serde_json::Value>,
+}
+
+/// Builds the status entry for one service.
+fn status_entry(
+    paths: &FernPaths,
+    controller: &ServiceController,
+    descriptor: &ServiceDescriptor,
+) -> StatusEntry {
+    let running = controller.is_service_running(descriptor);
+    let pid = running
+        .then(|| controller.service_pid(descriptor))
+        .flatten();
+    let uptime_secs = pid.and_then(process_uptime);
+
+    let (status, summary, state_ok) = match descriptor.known() {
+        Some(service) => {
+            let state = StateRead::read(&paths.state_dir().join(service.state_file()));
+            let status = match &state {
+                StateRead::Valid(json) => get_status_text_from_json(json, &service),
+                StateRead::Unreadable(_) => "unknown (state unreadable)".to_string(),
+                StateRead::Corrupt(_) => "unknown (state corrupt)".to_string(),
+                StateRead::Missing if running => "running".to_string(),
+                StateRead::Missing => "stopped".to_string(),
+            };
+            let summary = match &state {
+                StateRead::Valid(json) => service_summary(json, service),
+                _ => None,
+            };
+            let state_ok = !matches!(state, StateRead::Unreadable(_) | StateRead::Corrupt(_));
+            (status, summary, state_ok)
+        }
+        None => {
+            let status = if running { "running" } else { "stopped" };
+            let summary = descriptor
+                .state_file
+                .as_ref()
+                .map(|_| custom_status_json(paths, descriptor));
+            (status.to_string(), summary, true)
+        }
+    };
+
+    StatusEntry {
+        name: descriptor.name.clone(),
+        running,
+        healthy: running && state_ok,
+        pid,
+        uptime_secs,
+        status,
+        summary,
+    }
+}
+
+/// Picks the fields of a built-in service's state worth showing in a bar.
+fn service_summary(json: &serde_json::Value, service: KnownService) -> Option<serde_json::Value> {
+    let flag = |pointer: &str| {
+        json.pointer(pointer)
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or(false)
+    };
+
+    match service {
+        KnownService::Obs => Some(serde_json::json!({
+            "connected": flag("/connected"),
+            "recording": flag("/recording/active"),
+            "streaming": flag("/streaming/active"),
+            "scene": json.get("current_scene"),
+            "warning": json.get("warning"),
+        })),
+        KnownService::ThemeWatcher => Some(serde_json::json!({
+            "watching": flag("/watching"),
+        })),
+        KnownService::Shell => None,
+    }
+}
+
+/// Returns the JSON shown for a state file, or `None` when there is none.
+///
+/// A broken file is reported as `{"status": "unknown", "error": ...}`.
+fn state_json(state: &StateRead) -> Option<serde_json::Value> {
+    match state {
+        StateRead::Valid(json) => Some(json.clone()),
+        StateRead::Missing => None,
+        StateRead::Unreadable(e) | StateRead::Corrupt(e) => {
+            Some(serde_json::json!({ "status": "unknown", "error": e }))
+        }
+    }
+}
+
+/// Replaces a corrupt state file with the service's disconnected default.
+///
+/// Missing, unreadable, and valid files are left alone.
+fn repair_state(state_dir: &Path, service: KnownService) -> Result<()> {
+    let path = state_dir.join(service.state_file());
+    if !matches!(StateRead::read(&path), StateRead::Corrupt(_)) {
+        return Ok(());
+    }
+
+    write_state(&path, &service.default_state()).map_err(|e| {
+        FernctlError::service(
+            service.name(),
+            format!("could not repair {}: {e}", path.display()),
+        )
+    })?;
+    println!("Repaired {}", path.display());
+    Ok(())
+}
+
+/// Writes a state file atomically (temp file, then rename).
+fn write_state(path: &Path, state: &serde_json::Value) -> std::io::Result<()> {
+    let json = serde_json::to_string_pretty(state)?;
+    let temp_path = path.with_extension("json.tmp");
+    fs::write(&temp_path, json)?;
+    fs::rename(&temp_path, path)
+}
+
 fn get_service_status_line(
     service: &KnownService,
-    state_path: &std::path::Path,
+    state: &StateRead,
     registry: &Option<ServiceRegistry>,
 ) -> String {
-    let indicator = if state_path.exists() {
-        if let Ok(content) = fs::read_to_string(state_path) {
-            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                let connected = json.get("connected").and_then(|v| v.as_bool()).unwrap_or(false);
-                let active = json
-                    .get("recording")
-                    .and_then(|r| r.get("active"))
+    let indicator = match state {
+        StateRead::Valid(json) => {
+            let connected = json.get("connected").and_then(|v| v.as_bool()).unwrap_or(false);
+            let active = json
+                .get("recording")
+                .and_then(|r| r.get("active"))
+                .and_then(|v| v.as_bool())
+                .unwrap_or(false)
+                || json
+                    .get("streaming")
+                    .and_then(|s| s.get("active"))
                     .and_then(|v| v.as_bool())
-                    .unwrap_or(false)
-                    || json
-                        .get("streaming")
-                        .and_then(|s| s.get("active"))
-                        .and_then(|v| v.as_bool())
-                        .unwrap_or(false);
-
-                if connected || active {
-                    "\x1b[32m●\x1b[0m" // Green dot
-                } else {
-                    "\x1b[33m○\x1b[0m" // Yellow empty dot
-                }
+                    .unwrap_or(false);
+
+            if connected || active {
+                "\x1b[32m●\x1b[0m" // Green dot
             } else {
-                "\x1b[31m✗\x1b[0m" // Red X
+                "\x1b[33m○\x1b[0m" // Yellow empty dot
             }
-        } else {
-            "\x1b[31m✗\x1b[0m"
         }
-    } else {
-        // Check registry for status
-        if let Some(reg) = registry {
-            if let Some(info) = reg.find(service.name()) {
-                if info.is_running() {
-                    "\x1b[32m●\x1b[0m"
+        StateRead::Unreadable(_) | StateRead::Corrupt(_) => "\x1b[31m✗\x1b[0m", // Red X
+        StateRead::Missing => {
+            // Check registry for status
+            if let Some(reg) = registry {
+                if let Some(info) = reg.find(service.name()) {
+                    if info.is_running() {
+                        "\x1b[32m●\x1b[0m"
+                    } else {
+                        "\x1b[90m○\x1b[0m" // Gray dot
+                    }
                 } else {
-                    "\x1b[90m○\x1b[0m" // Gray dot
+                    "\x1b[90m○\x1b[0m"
                 }
             } else {
                 "\x1b[90m○\x1b[0m"
             }
-        } else {
-            "\x1b[90m○\x1b[0m"
         }
     };
 
-    let status_text = if state_path.exists() {
-        if let Ok(content) = fs::read_to_string(state_path) {
-            if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                get_status_text_from_json(&json, service)
-            } else {
-                "corrupt".to_string()
-            }
-        } else {
-            "unreadable".to_string()
-        }
-    } else {
-        "stopped".to_string()
+    let status_text = match state {
+        StateRead::Valid(json) => get_status_text_from_json(json, service),
+        StateRead::Unreadable(_) => "unknown (state unreadable)".to_string(),
+        StateRead::Corrupt(_) => "unknown (state corrupt)".to_string(),
+        StateRead::Missing => "stopped".to_string(),
     };
 
     format!(
@@ -262,3 +467,56 @@ fn get_status_text_from_json(json: &serde_json::Value, service: &KnownService) -
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn repair_replaces_only_corrupt_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let obs = dir.path().join(KnownService::Obs.state_file());
+        let theme = dir.path().join(KnownService::ThemeWatcher.state_file());
+        fs::write(&obs, r#"{"connected": tr"#).unwrap();
+        fs::write(&theme, r#"{"watching": true}"#).unwrap();
+
+        repair_state(dir.path(), KnownService::Obs).unwrap();
+        repair_state(dir.path(), KnownService::ThemeWatcher).unwrap();
+        repair_state(dir.path(), KnownService::Shell).unwrap();
+
+        assert_eq!(
+            StateRead::read(&obs),
+            StateRead::Valid(KnownService::Obs.default_state())
+        );
+        assert_eq!(
+            StateRead::read(&theme),
+            StateRead::Valid(serde_json::json!({ "watching": true }))
+        );
+        assert!(!dir.path().join(KnownService::Shell.state_file()).exists());
+    }
+
+    #[test]
+    fn obs_summary_has_bar_fields() {
+        let json = serde_json::json!({
+            "connected": true,
+            "recording": { "active": true, "paused": false },
+            "streaming": { "active": false },
+            "current_scene": "Gaming",
+        });
+
+        let summary = service_summary(&json, KnownService::Obs).unwrap();
+        assert_eq!(summary["connected"], true);
+        assert_eq!(summary["recording"], true);
+        assert_eq!(summary["streaming"], false);
+        assert_eq!(summary["scene"], "Gaming");
+        assert!(summary["warning"].is_null());
+        assert!(service_summary(&json, KnownService::Shell).is_none());
+    }
+
+    #[test]
+    fn broken_state_is_reported_as_unknown() {
+        let json = state_json(&StateRead::Corrupt("EOF".to_string())).unwrap();
+        assert_eq!(json["status"], "unknown");
+        assert!(state_json(&StateRead::Missing).is_none());
+    }
+}
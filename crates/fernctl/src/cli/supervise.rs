@@ -0,0 +1,334 @@
+//! # Watch-Services Command
+//!
+//! A small process supervisor for users without systemd. Starts each
+//! registered service, watches for exits, and restarts crashed services
+//! with capped exponential backoff (see [`RestartPolicy`]).
+//!
+//! Every start, exit, and restart is appended to `supervisor-log.json` in
+//! the state directory, so it shows up in `fernctl logs` and the TUI. When
+//! a service exceeds `--max-restarts` or crash-loops, the supervisor stops
+//! restarting it and sends a desktop notification.
+//!
+//! Services start in dependency order (see `depends_on` in
+//! [`ServiceCatalog`]): a service waits until every supervised dependency
+//! passes its health check, so the shell only starts once the OBS bridge
+//! and theme watcher are up. A dependency that is given up on takes its
+//! dependents with it.
+//!
+//! Services that are already running when the supervisor starts are left
+//! alone: it can only watch processes it spawned itself.
+
+use crate::adapters::{probe_health, ServiceController};
+use crate::domain::supervisor::{RestartDecision, RestartPolicy, RestartTracker};
+use crate::domain::{LogEntry, ServiceCatalog, ServiceDescriptor};
+use crate::error::{FernctlError, Result};
+use fern_core::log::{JsonRingLogger, LogLimits};
+use fern_core::FernPaths;
+use std::path::PathBuf;
+use std::process::Child;
+use std::time::{Duration, Instant};
+
+/// How often children are polled for exit.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Options for the watch-services command.
+#[derive(Debug, Clone, Default)]
+pub struct SupervisorOptions {
+    /// Services to supervise. Empty means every registered service.
+    pub services: Vec<String>,
+    /// Total restarts allowed per service, or `None` for no limit.
+    pub max_restarts: Option<u32>,
+}
+
+/// A supervised service and its current process.
+struct Supervised {
+    descriptor: ServiceDescriptor,
+    /// Positions of the supervised services this one waits for.
+    depends_on: Vec<usize>,
+    tracker: RestartTracker,
+    child: Option<Child>,
+    started_at: Instant,
+    restart_at: Option<Instant>,
+    /// Whether the current process has passed its health check.
+    healthy: bool,
+    gave_up: bool,
+}
+
+/// Runs the supervisor until every supervised service has been given up on.
+///
+/// # Errors
+///
+/// Returns an error if the service catalog cannot be loaded, a requested
+/// service is unknown, the services depend on each other in a cycle, or
+/// there is nothing to supervise.
+pub fn run(options: &SupervisorOptions) -> Result<()> {
+    let paths = FernPaths::new();
+    let catalog = ServiceCatalog::load(&paths)?;
+    let mut log = SupervisorLog::new(paths.service_log("supervisor"));
+    let policy = RestartPolicy {
+        max_restarts: options.max_restarts,
+        ..RestartPolicy::default()
+    };
+
+    let selected = catalog.start_order(&select_services(&catalog, &options.services)?)?;
+    let mut descriptors = Vec::new();
+    for descriptor in selected {
+        if probe_health(descriptor) {
+            println!(
+                "{} is already running; not supervising it.",
+                descriptor.display_name()
+            );
+            continue;
+        }
+        descriptors.push(descriptor);
+    }
+
+    let dependencies = catalog.dependency_indices(&descriptors);
+    let mut supervised: Vec<_> = descriptors
+        .into_iter()
+        .zip(dependencies)
+        .map(|(descriptor, depends_on)| Supervised {
+            descriptor: descriptor.clone(),
+            depends_on,
+            tracker: RestartTracker::new(policy),
+            child: None,
+            started_at: Instant::now(),
+            restart_at: Some(Instant::now()),
+            healthy: false,
+            gave_up: false,
+        })
+        .collect();
+
+    if supervised.is_empty() {
+        return Err(FernctlError::service(
+            "supervisor",
+            "no services to supervise",
+        ));
+    }
+
+    println!(
+        "Supervising {} (Ctrl-C to stop)",
+        supervised
+            .iter()
+            .map(|s| s.descriptor.name.as_str())
+            .collect::<Vec<_>>()
+            .join(", ")
+    );
+
+    while supervised.iter().any(|s| !s.gave_up) {
+        let now = Instant::now();
+        for i in 0..supervised.len() {
+            if supervised[i].gave_up {
+                continue;
+            }
+            check_exit(&mut supervised[i], now, &mut log);
+
+            match dependencies_ready(&supervised, i) {
+                Ok(true) => start_if_due(&mut supervised[i], now, &mut log),
+                Ok(false) => {}
+                Err(dependency) => {
+                    let reason = format!("dependency {dependency} was given up on");
+                    give_up(&mut supervised[i], &reason, &mut log);
+                }
+            }
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+
+    Err(FernctlError::service(
+        "supervisor",
+        "gave up on every supervised service",
+    ))
+}
+
+/// Resolves the requested service names, or returns every service.
+fn select_services<'a>(
+    catalog: &'a ServiceCatalog,
+    names: &[String],
+) -> Result<Vec<&'a ServiceDescriptor>> {
+    if names.is_empty() {
+        return Ok(catalog.iter().collect());
+    }
+
+    names.iter().map(|name| catalog.resolve(name)).collect()
+}
+
+/// Returns whether every dependency of `supervised[index]` is healthy.
+///
+/// Health is probed once per process: a dependency stays ready until it
+/// exits. Returns the name of a dependency that was given up on as the
+/// error.
+fn dependencies_ready(
+    supervised: &[Supervised],
+    index: usize,
+) -> std::result::Result<bool, String> {
+    let mut ready = true;
+    for &dependency in &supervised[index].depends_on {
+        let dependency = &supervised[dependency];
+        if dependency.gave_up {
+            return Err(dependency.descriptor.name.clone());
+        }
+        ready &= dependency.healthy;
+    }
+    Ok(ready)
+}
+
+/// Handles a child that has exited since the last poll.
+fn check_exit(service: &mut Supervised, now: Instant, log: &mut SupervisorLog) {
+    let Some(child) = service.child.as_mut() else {
+        return;
+    };
+
+    let status = match child.try_wait() {
+        Ok(Some(status)) => status.to_string(),
+        Ok(None) => {
+            if !service.healthy && probe_health(&service.descriptor) {
+                service.healthy = true;
+            }
+            return;
+        }
+        Err(e) => format!("unknown status ({e})"),
+    };
+    service.child = None;
+    service.healthy = false;
+
+    let name = service.descriptor.name.clone();
+    log.write(LogEntry::warn("supervisor", format!("{name} exited: {status}")).with_service(&name));
+
+    match service
+        .tracker
+        .on_exit(now, now.duration_since(service.started_at))
+    {
+        RestartDecision::Restart { delay, attempt } => {
+            log.write(
+                LogEntry::info(
+                    "supervisor",
+                    format!(
+                        "restarting {name} in {}s (attempt {attempt})",
+                        delay.as_secs_f32()
+                    ),
+                )
+                .with_service(&name),
+            );
+            service.restart_at = Some(now + delay);
+        }
+        RestartDecision::GiveUp(reason) => give_up(service, &reason.to_string(), log),
+    }
+}
+
+/// Starts a service whose restart delay has elapsed.
+fn start_if_due(service: &mut Supervised, now: Instant, log: &mut SupervisorLog) {
+    if service.restart_at.is_none_or(|at| now < at) {
+        return;
+    }
+    service.restart_at = None;
+
+    let name = service.descriptor.name.clone();
+    match ServiceController::spawn(&service.descriptor) {
+        Ok(child) => {
+            log.write(
+                LogEntry::info("supervisor", format!("started {name} (PID {})", child.id()))
+                    .with_service(&name),
+            );
+            service.child = Some(child);
+            service.started_at = now;
+        }
+        Err(e) => {
+            // A failed spawn counts as an immediate exit
+            log.write(
+                LogEntry::error("supervisor", format!("failed to start {name}: {e}"))
+                    .with_service(&name),
+            );
+            service.started_at = now;
+            service.child = None;
+            match service.tracker.on_exit(now, Duration::ZERO) {
+                RestartDecision::Restart { delay, .. } => service.restart_at = Some(now + delay),
+                RestartDecision::GiveUp(reason) => give_up(service, &reason.to_string(), log),
+            }
+        }
+    }
+}
+
+/// Stops supervising a service and tells the user why.
+fn give_up(service: &mut Supervised, reason: &str, log: &mut SupervisorLog) {
+    let name = &service.descriptor.name;
+    let message = format!("giving up on {name}: {reason}");
+    log.write(LogEntry::error("supervisor", &message).with_service(name));
+    notify(service.descriptor.display_name(), &message);
+    service.gave_up = true;
+}
+
+/// Sends a desktop notification, ignoring failures.
+fn notify(title: &str, body: &str) {
+    let _ = std::process::Command::new("notify-send")
+        .arg("--app-name=fernctl")
+        .arg("--urgency=critical")
+        .arg(title)
+        .arg(body)
+        .spawn();
+}
+
+/// Bounded JSON log file shared with `fernctl logs`.
+struct SupervisorLog {
+    logger: JsonRingLogger,
+}
+
+impl SupervisorLog {
+    fn new(path: PathBuf) -> Self {
+        Self::with_limits(path, LogLimits::default())
+    }
+
+    fn with_limits(path: PathBuf, limits: LogLimits) -> Self {
+        Self {
+            logger: JsonRingLogger::open(path, limits),
+        }
+    }
+
+    /// Prints an entry and appends it to the log file.
+    ///
+    /// Write failures are reported on stderr but never stop supervision.
+    fn write(&mut self, entry: LogEntry) {
+        println!("{}", entry.format_cli());
+        if let Err(e) = self.logger.append(&entry) {
+            eprintln!("Warning: {e}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::domain::parse_log_file;
+
+    #[test]
+    fn supervisor_log_is_bounded() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("supervisor-log.json");
+        let mut log = SupervisorLog::with_limits(path.clone(), LogLimits::entries(3));
+
+        for i in 0..5 {
+            log.write(LogEntry::info("supervisor", format!("event {i}")));
+        }
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        let entries = parse_log_file(&content, "supervisor");
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].message, "event 2");
+        assert_eq!(entries[2].message, "event 4");
+    }
+
+    #[test]
+    fn select_services_defaults_to_all() {
+        let catalog = ServiceCatalog::builtin();
+        assert_eq!(
+            select_services(&catalog, &[]).unwrap().len(),
+            catalog.iter().count()
+        );
+        assert!(select_services(&catalog, &["nope".to_string()]).is_err());
+        assert_eq!(
+            select_services(&catalog, &["fern-obs".to_string()]).unwrap()[0].name,
+            "obs"
+        );
+    }
+}
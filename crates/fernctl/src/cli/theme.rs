@@ -4,15 +4,26 @@
 //!
 //! ## Available Commands
 //!
-//! - `theme apply <name>` - Apply a theme preset
+//! - `theme apply <name>` - Apply a theme preset, backing up `config.json`
+//! - `theme rollback [backup]` - Restore a `config.json` backup and reload
 //! - `theme validate` - Validate current configuration
 //! - `theme current` - Show current theme info
+//! - `theme list` - List theme names accepted by `theme apply`
 //! - `theme query <path>` - Query specific theme values
+//! - `theme simulate [--type <kind>]` - Preview the palette with color blindness
+//! - `theme import [--format base16] <file>` - Bootstrap a config from a Base16 scheme
+//! - `theme preview --out <file>` - Render a PNG swatch sheet (`preview` feature)
 
+use crate::adapters::{is_shell_running, reload_shell, ConfigBackups};
 use crate::error::{FernctlError, Result};
 use fern_core::FernPaths;
 use fern_theme::adapters::{FileSystemAdapter, TomlConfigAdapter};
-use fern_theme::commands::{convert, query, validate};
+use fern_theme::commands::{query, validate};
+use fern_theme::domain::base16::Base16Scheme;
+use fern_theme::domain::theme::{ColorPalette, ThemeVariant};
+use fern_theme::domain::tokens::CvdType;
+use fern_theme::ports::PersistPort;
+use std::path::{Path, PathBuf};
 
 /// Theme action to perform.
 #[derive(Debug, Clone)]
@@ -21,16 +32,47 @@ pub enum ThemeAction {
     Apply {
         /// Theme name to apply.
         name: String,
+        /// Number of timestamped `config.json` backups to keep.
+        keep_backups: usize,
+    },
+    /// Restore `config.json` from a backup and reload the shell.
+    Rollback {
+        /// Backup to restore. `None` restores `config.json.bak`.
+        backup: Option<String>,
+        /// List the timestamped backups instead of restoring.
+        list: bool,
     },
     /// Validate the current configuration.
     Validate,
     /// Show current theme information.
     Current,
+    /// List the theme names `Apply` accepts.
+    List,
     /// Query a specific theme value.
     Query {
         /// Path to query (e.g., colors.background).
         path: String,
     },
+    /// Show the palette as seen with a color vision deficiency.
+    Simulate {
+        /// Deficiency to simulate (e.g., deuteranopia). `None` shows all.
+        kind: Option<String>,
+    },
+    /// Generate a config from another tool's color scheme.
+    Import {
+        /// Scheme file to read.
+        path: PathBuf,
+        /// Scheme format (currently only `base16`).
+        format: String,
+        /// Where to write the config. `None` prints it to stdout.
+        output: Option<PathBuf>,
+    },
+    /// Render the current theme to a PNG swatch sheet.
+    #[cfg(feature = "preview")]
+    Preview {
+        /// PNG file to write.
+        out: PathBuf,
+    },
 }
 
 /// Options for theme commands.
@@ -51,15 +93,27 @@ pub struct ThemeOptions {
 /// Returns an error if the theme operation fails.
 pub fn run(options: ThemeOptions) -> Result<()> {
     match options.action {
-        ThemeAction::Apply { name } => run_apply(&name, options.verbose),
+        ThemeAction::Apply { name, keep_backups } => {
+            run_apply(&name, keep_backups, options.verbose)
+        }
+        ThemeAction::Rollback { backup, list } => run_rollback(backup.as_deref(), list),
         ThemeAction::Validate => run_validate(options.verbose),
         ThemeAction::Current => run_current(options.json),
+        ThemeAction::List => run_list(options.json),
         ThemeAction::Query { path } => run_query(&path, options.verbose),
+        ThemeAction::Simulate { kind } => run_simulate(kind.as_deref(), options.json),
+        ThemeAction::Import {
+            path,
+            format,
+            output,
+        } => run_import(&path, &format, output.as_deref()),
+        #[cfg(feature = "preview")]
+        ThemeAction::Preview { out } => run_preview(&out),
     }
 }
 
 /// Applies a theme by name.
-fn run_apply(name: &str, verbose: bool) -> Result<()> {
+fn run_apply(name: &str, keep_backups: usize, verbose: bool) -> Result<()> {
     let paths = FernPaths::new();
     let config_toml = paths.config_toml();
     let config_json = paths.config_json();
@@ -69,7 +123,46 @@ fn run_apply(name: &str, verbose: bool) -> Result<()> {
         eprintln!("Config JSON: {}", config_json.display());
     }
 
-    // Check if config file exists
+    // Keep the JSON the shell is using now, for `theme rollback`
+    let backups = ConfigBackups::new(&paths).with_keep(keep_backups);
+    let backup = switch_theme(name, &config_toml, &config_json, &backups)?;
+
+    if verbose {
+        eprintln!("Updated config with theme: {name}");
+        if let Some(backup) = backup {
+            eprintln!("Backed up previous config to: {}", backup.display());
+        }
+    }
+
+    println!("Theme applied: {name}");
+    println!("Config written to: {}", config_json.display());
+
+    Ok(())
+}
+
+/// Switches `config_toml` to the named theme and regenerates `config_json`,
+/// returning the backup of the previous JSON, if there was one.
+///
+/// Nothing is written unless the updated config validates. The current
+/// JSON is then backed up, each file is replaced by renaming a temporary
+/// file over it, and the previous JSON is restored if the TOML cannot be
+/// written after it.
+///
+/// Used by `fernctl theme apply` and `fernctl reload --theme`.
+pub(crate) fn switch_theme(
+    name: &str,
+    config_toml: &Path,
+    config_json: &Path,
+    backups: &ConfigBackups,
+) -> Result<Option<PathBuf>> {
+    if ThemeVariant::from_name(name).is_none() {
+        let names: Vec<&str> = ThemeVariant::ALL.iter().map(ThemeVariant::name).collect();
+        return Err(FernctlError::config(format!(
+            "Unknown theme: {name}. Available: {}",
+            names.join(", ")
+        )));
+    }
+
     if !config_toml.exists() {
         return Err(FernctlError::config(format!(
             "Config file not found: {}. Create a config.toml first.",
@@ -77,42 +170,57 @@ fn run_apply(name: &str, verbose: bool) -> Result<()> {
         )));
     }
 
-    // Read the current config
-    let content = std::fs::read_to_string(&config_toml)
-        .map_err(|e| FernctlError::io("reading config", e))?;
-
-    // Update the theme in the config
+    let content =
+        std::fs::read_to_string(config_toml).map_err(|e| FernctlError::io("reading config", e))?;
     let updated = update_theme_in_toml(&content, name)?;
 
-    // Write back the config
-    std::fs::write(&config_toml, &updated)
-        .map_err(|e| FernctlError::io("writing config", e))?;
-
-    if verbose {
-        eprintln!("Updated config with theme: {}", name);
+    // Validate before touching either file, resolving includes next to
+    // config.toml as a later load of the written file would
+    let theme = TomlConfigAdapter::new()
+        .load_at(config_toml, &updated)?
+        .validate()?
+        .into_theme();
+
+    let previous_json = std::fs::read(config_json).ok();
+    let backup = backups.backup()?;
+    let json_temp = temp_path(config_json);
+    FileSystemAdapter::new().save_theme(&theme, &json_temp)?;
+    std::fs::rename(&json_temp, config_json)
+        .map_err(|e| FernctlError::io("replacing config JSON", e))?;
+
+    if let Err(e) = write_atomically(config_toml, updated.as_bytes()) {
+        // Best effort: the write error is what the user needs to see
+        let _ = previous_json.map_or_else(
+            || std::fs::remove_file(config_json).ok(),
+            |previous| write_atomically(config_json, &previous).ok(),
+        );
+        return Err(e);
     }
 
-    // Convert to JSON
-    let config_adapter = TomlConfigAdapter::new();
-    let persist_adapter = FileSystemAdapter::new();
-    let convert_options = convert::ConvertOptions {
-        verbose,
-        pretty: true,
-    };
-
-    convert::run(&config_toml, &config_json, convert_options, &config_adapter, &persist_adapter)
-        .map_err(|e| FernctlError::config(format!("Failed to convert config: {}", e)))?;
+    Ok(backup)
+}
 
-    println!("Theme applied: {}", name);
-    println!("Config written to: {}", config_json.display());
+/// Returns the temporary path written before renaming over `path`.
+fn temp_path(path: &Path) -> PathBuf {
+    let mut temp = path.as_os_str().to_owned();
+    temp.push(".tmp");
+    PathBuf::from(temp)
+}
 
-    Ok(())
+/// Replaces `path` with `content` by writing a temporary file and renaming it.
+fn write_atomically(path: &Path, content: &[u8]) -> Result<()> {
+    let temp = temp_path(path);
+    std::fs::write(&temp, content)
+        .map_err(|e| FernctlError::io(format!("writing {}", temp.display()), e))?;
+    std::fs::rename(&temp, path)
+        .map_err(|e| FernctlError::io(format!("replacing {}", path.display()), e))
 }
 
 /// Updates the theme value in a TOML config string.
 fn update_theme_in_toml(content: &str, theme: &str) -> Result<String> {
     // Parse the TOML
-    let mut doc = content.parse::<toml_edit::DocumentMut>()
+    let mut doc = content
+        .parse::<toml_edit::DocumentMut>()
         .map_err(|e| FernctlError::parse("config TOML", e.to_string()))?;
 
     // Ensure appearance section exists
@@ -139,9 +247,12 @@ fn run_validate(verbose: bool) -> Result<()> {
     }
 
     let adapter = TomlConfigAdapter::new();
-    let options = validate::ValidateOptions { verbose };
+    let options = validate::ValidateOptions {
+        verbose,
+        ..validate::ValidateOptions::default()
+    };
 
-    match validate::run(&config_toml, options, &adapter) {
+    match validate::run(&config_toml, &options, &adapter) {
         Ok(result) => {
             for warning in &result.warnings {
                 eprintln!("\x1b[33mWarning:\x1b[0m {}", warning);
@@ -161,6 +272,59 @@ fn run_validate(verbose: bool) -> Result<()> {
     }
 }
 
+/// Restores `config.json` from a backup and reloads the shell.
+///
+/// `config.toml` is left alone, so the next `theme apply` or watcher
+/// conversion regenerates the JSON from it.
+fn run_rollback(backup: Option<&str>, list: bool) -> Result<()> {
+    let backups = ConfigBackups::new(&FernPaths::new());
+
+    if list {
+        let all = backups.list()?;
+        if all.is_empty() {
+            println!("No backups yet. `fernctl theme apply` creates one.");
+        }
+        for path in all {
+            println!("{}", path.file_name().unwrap_or_default().to_string_lossy());
+        }
+        return Ok(());
+    }
+
+    let source = backups.resolve(backup);
+    backups.restore(&source)?;
+    println!("Restored config.json from: {}", source.display());
+
+    if is_shell_running() {
+        reload_shell()?;
+        println!("Shell reloaded.");
+    } else {
+        println!("QuickShell is not running; the restored config applies on next start.");
+    }
+    println!("config.toml is unchanged. Apply a theme to make the rollback permanent.");
+    Ok(())
+}
+
+/// Lists theme names, one per line.
+///
+/// Shell completions for `theme apply` read this output, so it prints
+/// nothing but the names.
+fn run_list(json: bool) -> Result<()> {
+    let names: Vec<&str> = ThemeVariant::ALL.iter().map(ThemeVariant::name).collect();
+
+    if json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&names)
+                .map_err(|e| FernctlError::parse("theme list", e.to_string()))?
+        );
+    } else {
+        for name in names {
+            println!("{name}");
+        }
+    }
+    Ok(())
+}
+
 /// Shows the current theme information.
 fn run_current(json: bool) -> Result<()> {
     let paths = FernPaths::new();
@@ -241,7 +405,7 @@ fn run_query(path: &str, verbose: bool) -> Result<()> {
     let adapter = TomlConfigAdapter::new();
     let options = query::QueryOptions { verbose };
 
-    match query::run(&config_toml, path, options, &adapter)? {
+    match query::run(&config_toml, path, &options, &adapter)? {
         Some(value) => {
             println!("{}", value);
             Ok(())
@@ -259,6 +423,146 @@ fn run_query(path: &str, verbose: bool) -> Result<()> {
     }
 }
 
+/// Prints each palette color as seen with color vision deficiencies.
+fn run_simulate(kind: Option<&str>, json: bool) -> Result<()> {
+    let kinds = match kind {
+        Some(name) => vec![CvdType::from_name(name).ok_or_else(|| {
+            FernctlError::config(format!(
+                "Unknown deficiency '{name}'. Expected one of: {}",
+                CvdType::all()
+                    .iter()
+                    .map(CvdType::name)
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ))
+        })?],
+        None => CvdType::all().to_vec(),
+    };
+
+    let paths = FernPaths::new();
+    let config_toml = paths.config_toml();
+    if !config_toml.exists() {
+        return Err(FernctlError::config(format!(
+            "Config file not found: {}",
+            config_toml.display()
+        )));
+    }
+
+    let adapter = TomlConfigAdapter::new();
+    let palette = validate::run(
+        &config_toml,
+        &validate::ValidateOptions::default(),
+        &adapter,
+    )
+    .map_err(|e| FernctlError::config(e.to_string()))?
+    .theme
+    .colors;
+
+    if json {
+        let simulated: serde_json::Map<String, serde_json::Value> = kinds
+            .iter()
+            .map(|kind| {
+                let colors = palette
+                    .simulate_cvd(*kind)
+                    .entries()
+                    .into_iter()
+                    .map(|(role, hex)| (role.to_string(), serde_json::Value::String(hex)))
+                    .collect();
+                (kind.name().to_string(), serde_json::Value::Object(colors))
+            })
+            .collect();
+        println!("{}", serde_json::Value::Object(simulated));
+        return Ok(());
+    }
+
+    print!("{}", simulation_table(&palette, &kinds));
+    Ok(())
+}
+
+/// Converts a color scheme file into a starting `config.toml`.
+fn run_import(path: &Path, format: &str, output: Option<&Path>) -> Result<()> {
+    if format != "base16" {
+        return Err(FernctlError::config(format!(
+            "Unknown scheme format '{format}'. Expected: base16"
+        )));
+    }
+
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| FernctlError::io(format!("reading {}", path.display()), e))?;
+    let scheme = Base16Scheme::parse(&content)
+        .map_err(|e| FernctlError::config(format!("Invalid Base16 scheme: {e}")))?;
+    let config = scheme.to_user_config();
+
+    let Some(output) = output else {
+        print!("{config}");
+        return Ok(());
+    };
+
+    // Never clobber an existing config; the import is only a starting point
+    if output.exists() {
+        return Err(FernctlError::config(format!(
+            "{} already exists. Choose another --output or move it aside.",
+            output.display()
+        )));
+    }
+    std::fs::write(output, config)
+        .map_err(|e| FernctlError::io(format!("writing {}", output.display()), e))?;
+
+    println!("Config written to: {}", output.display());
+    println!("Review the colors marked \"guessed\" before applying it.");
+    Ok(())
+}
+
+/// Renders the current theme to a PNG.
+#[cfg(feature = "preview")]
+fn run_preview(out: &Path) -> Result<()> {
+    let paths = FernPaths::new();
+    let config_toml = paths.config_toml();
+    if !config_toml.exists() {
+        return Err(FernctlError::config(format!(
+            "Config file not found: {}",
+            config_toml.display()
+        )));
+    }
+
+    let adapter = TomlConfigAdapter::new();
+    let theme = validate::run(
+        &config_toml,
+        &validate::ValidateOptions::default(),
+        &adapter,
+    )
+    .map_err(|e| FernctlError::config(e.to_string()))?
+    .theme;
+
+    super::preview::render(&theme).write_png(out)?;
+    println!("Preview written to: {}", out.display());
+    Ok(())
+}
+
+/// Formats the original and simulated palette side by side.
+fn simulation_table(palette: &ColorPalette, kinds: &[CvdType]) -> String {
+    let simulated: Vec<_> = kinds
+        .iter()
+        .map(|k| palette.simulate_cvd(*k).entries())
+        .collect();
+
+    let mut out = format!("{:<16}{:<12}", "Color", "Original");
+    for kind in kinds {
+        out.push_str(&format!("{:<14}", kind.name()));
+    }
+    out.push('\n');
+
+    for (i, (role, hex)) in palette.entries().into_iter().enumerate() {
+        out.push_str(&format!("{role:<16}{hex:<12}"));
+        for entries in &simulated {
+            out.push_str(&format!("{:<14}", entries[i].1));
+        }
+        out.push('\n');
+    }
+
+    out
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -282,4 +586,110 @@ variant = "mocha"
         assert!(result.contains("theme = \"catppuccin\""));
         assert!(result.contains("variant = \"mocha\""));
     }
+
+    #[test]
+    fn test_import_refuses_to_overwrite() {
+        let dir = tempfile::tempdir().unwrap();
+        let scheme = dir.path().join("scheme.yaml");
+        std::fs::write(
+            &scheme,
+            fern_theme::domain::base16::to_yaml(&fern_theme::domain::theme::Theme::dark(), "Fern"),
+        )
+        .unwrap();
+        let output = dir.path().join("config.toml");
+
+        run_import(&scheme, "base16", Some(&output)).unwrap();
+        let config = std::fs::read_to_string(&output).unwrap();
+        assert!(config.contains("[appearance.colors]"));
+
+        assert!(run_import(&scheme, "base16", Some(&output)).is_err());
+        assert!(run_import(&scheme, "kitty", None).is_err());
+    }
+
+    fn backups_in(dir: &Path) -> ConfigBackups {
+        ConfigBackups::at(
+            dir.join("config.json"),
+            dir.join("config.json.bak"),
+            dir.join("backups"),
+        )
+    }
+
+    #[test]
+    fn test_switch_theme_writes_both_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let toml = dir.path().join("config.toml");
+        let json = dir.path().join("config.json");
+        let backups = backups_in(dir.path());
+        std::fs::write(&toml, "[appearance]\ntheme = \"dark\"\n").unwrap();
+        std::fs::write(&json, "previous").unwrap();
+
+        switch_theme("light", &toml, &json, &backups).unwrap();
+
+        let written_toml = std::fs::read_to_string(&toml).unwrap();
+        let written_json = std::fs::read_to_string(&json).unwrap();
+        assert!(written_toml.contains("theme = \"light\""));
+        assert!(written_json.contains("\"light\""));
+        assert!(!temp_path(&json).exists());
+        assert_eq!(
+            std::fs::read_to_string(backups.latest()).unwrap(),
+            "previous"
+        );
+    }
+
+    #[test]
+    fn test_switch_theme_rejects_unknown_name_without_writing() {
+        let dir = tempfile::tempdir().unwrap();
+        let toml = dir.path().join("config.toml");
+        let json = dir.path().join("config.json");
+        let backups = backups_in(dir.path());
+        std::fs::write(&toml, "[appearance]\ntheme = \"dark\"\n").unwrap();
+
+        assert!(switch_theme("catppuccin", &toml, &json, &backups).is_err());
+        assert!(!json.exists());
+        let unchanged = std::fs::read_to_string(&toml).unwrap();
+        assert!(unchanged.contains("theme = \"dark\""));
+    }
+
+    #[test]
+    fn test_switch_theme_restores_json_when_toml_write_fails() {
+        let dir = tempfile::tempdir().unwrap();
+        let toml = dir.path().join("config.toml");
+        let json = dir.path().join("config.json");
+        std::fs::write(&toml, "[appearance]\ntheme = \"dark\"\n").unwrap();
+        std::fs::write(&json, "previous").unwrap();
+        // A directory where the TOML temp file goes makes its write fail
+        std::fs::create_dir(temp_path(&toml)).unwrap();
+
+        assert!(switch_theme("light", &toml, &json, &backups_in(dir.path())).is_err());
+        assert_eq!(std::fs::read_to_string(&json).unwrap(), "previous");
+        let unchanged = std::fs::read_to_string(&toml).unwrap();
+        assert!(unchanged.contains("theme = \"dark\""));
+    }
+
+    #[test]
+    fn test_switch_theme_resolves_includes_next_to_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let toml = dir.path().join("config.toml");
+        let json = dir.path().join("config.json");
+        std::fs::write(&toml, "include = \"bar.toml\"\n").unwrap();
+        std::fs::write(dir.path().join("bar.toml"), "[bar]\nheight = 48\n").unwrap();
+
+        switch_theme("light", &toml, &json, &backups_in(dir.path())).unwrap();
+
+        let written_json = std::fs::read_to_string(&json).unwrap();
+        assert!(written_json.contains("\"light\""));
+        assert!(written_json.contains("\"height\": 48"));
+    }
+
+    #[test]
+    fn test_simulation_table() {
+        let palette = ColorPalette::dark();
+        let table = simulation_table(&palette, &[CvdType::Deuteranopia]);
+
+        let lines: Vec<_> = table.lines().collect();
+        assert_eq!(lines.len(), 11);
+        assert!(lines[0].contains("deuteranopia"));
+        assert!(lines[1].starts_with("background"));
+        assert!(lines[1].contains("#1e1e2e"));
+    }
 }
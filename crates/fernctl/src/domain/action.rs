@@ -4,6 +4,7 @@
 //! This follows the Elm architecture pattern where all state changes
 //! flow through a central update function.
 
+use super::app::PanelFocus;
 use super::log::LogEntry;
 use super::service::KnownService;
 use fern_core::ServiceInfo;
@@ -34,6 +35,14 @@ pub enum Action {
     /// Request to restart a service.
     RestartService(KnownService),
 
+    /// Starting, stopping, or restarting a service failed.
+    ServiceCommandFailed {
+        /// The service the command was for.
+        service: KnownService,
+        /// Why it failed.
+        message: String,
+    },
+
     // === Log Actions ===
     /// A new log entry was received.
     LogReceived(LogEntry),
@@ -47,6 +56,9 @@ pub enum Action {
     /// Clear all logs.
     ClearLogs,
 
+    /// Toggle whether the logs view follows the newest entry.
+    ToggleFollow,
+
     /// Set the log filter string.
     SetLogFilter(String),
 
@@ -57,6 +69,12 @@ pub enum Action {
     /// Request to reload the QuickShell configuration.
     ReloadShell,
 
+    /// Validation failed, so the reload was not sent.
+    ConfigInvalid(ConfigProblem),
+
+    /// Dismiss the config error dialog.
+    DismissConfigError,
+
     /// Request to apply a theme by name.
     ApplyTheme {
         /// Theme name to apply.
@@ -70,6 +88,9 @@ pub enum Action {
     /// Move focus to the previous panel.
     FocusPrev,
 
+    /// Move focus to a specific panel (e.g. on mouse click).
+    FocusPanel(PanelFocus),
+
     /// Toggle the help panel visibility.
     ToggleHelp,
 
@@ -90,6 +111,38 @@ pub enum Action {
     Quit,
 }
 
+/// Side effects requested by [`AppState::update`](super::AppState::update).
+///
+/// `update` only changes state. Anything that touches processes or the
+/// shell comes back as a command for the runtime to perform, which reports
+/// the outcome by dispatching another action. This keeps `update` free of
+/// I/O, so it can be tested by feeding actions and checking the state.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    /// Start a service.
+    StartService(KnownService),
+
+    /// Stop a service.
+    StopService(KnownService),
+
+    /// Restart a service.
+    RestartService(KnownService),
+
+    /// Validate the config and reload QuickShell if it is valid.
+    ReloadShell,
+}
+
+/// A configuration error to show the user instead of reloading.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConfigProblem {
+    /// Short title (e.g. "Invalid Color").
+    pub title: String,
+    /// Full error message.
+    pub message: String,
+    /// How to fix it, if known.
+    pub suggestion: Option<String>,
+}
+
 /// Summary of the current configuration for display.
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ConfigSummary {
@@ -5,14 +5,16 @@
 //! The application state is immutable and updates are performed through
 //! the `update` function which returns a new state.
 
-use super::action::{Action, ConfigSummary};
+use super::action::{Action, Command, ConfigProblem, ConfigSummary};
 use super::log::LogBuffer;
 use super::service::KnownService;
 use fern_core::state::{ServiceInfo, ServiceRegistry, ServiceStatus};
 use fern_core::FernPaths;
+use serde::{Deserialize, Serialize};
 
 /// Which panel has focus in the TUI.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 pub enum PanelFocus {
     /// Services panel (top-left).
     #[default]
@@ -75,6 +77,9 @@ pub struct AppState {
     /// Last error message, if any.
     pub last_error: Option<String>,
 
+    /// Config validation error blocking a reload, shown as a dialog.
+    pub config_error: Option<ConfigProblem>,
+
     /// Whether the application should quit.
     pub should_quit: bool,
 }
@@ -99,6 +104,7 @@ impl AppState {
             selected_service: 0,
             show_help: false,
             last_error: None,
+            config_error: None,
             should_quit: false,
         }
     }
@@ -114,8 +120,9 @@ impl AppState {
     /// Updates the state based on an action.
     ///
     /// This is the central update function following the Elm architecture.
-    /// All state changes should flow through this method.
-    pub fn update(&mut self, action: Action) {
+    /// All state changes should flow through this method. Actions that need
+    /// a side effect return the [`Command`] for the runtime to perform.
+    pub fn update(&mut self, action: Action) -> Option<Command> {
         match action {
             Action::ServiceStateChanged { name: _, info } => {
                 self.services.upsert(info);
@@ -126,18 +133,27 @@ impl AppState {
                 // Mark service as starting
                 let info = ServiceInfo::new(service.name(), ServiceStatus::Starting);
                 self.services.upsert(info);
+                return Some(Command::StartService(service));
             }
 
             Action::StopService(service) => {
                 // Mark service as stopping
                 let info = ServiceInfo::new(service.name(), ServiceStatus::Stopping);
                 self.services.upsert(info);
+                return Some(Command::StopService(service));
             }
 
             Action::RestartService(service) => {
                 // Mark service as stopping (will be started after)
                 let info = ServiceInfo::new(service.name(), ServiceStatus::Stopping);
                 self.services.upsert(info);
+                return Some(Command::RestartService(service));
+            }
+
+            Action::ServiceCommandFailed { service, message } => {
+                let info = ServiceInfo::new(service.name(), ServiceStatus::Failed(message.clone()));
+                self.services.upsert(info);
+                self.last_error = Some(message);
             }
 
             Action::LogReceived(entry) => {
@@ -157,6 +173,10 @@ impl AppState {
                 self.logs.clear();
             }
 
+            Action::ToggleFollow => {
+                self.logs.set_follow(!self.logs.follow());
+            }
+
             Action::SetLogFilter(filter) => {
                 self.logs.set_filter(filter);
             }
@@ -166,7 +186,17 @@ impl AppState {
             }
 
             Action::ReloadShell => {
-                // This action is handled by the adapter, state doesn't change
+                // Validation runs with the reload; a failure reopens the dialog
+                self.config_error = None;
+                return Some(Command::ReloadShell);
+            }
+
+            Action::ConfigInvalid(problem) => {
+                self.config_error = Some(problem);
+            }
+
+            Action::DismissConfigError => {
+                self.config_error = None;
             }
 
             Action::ApplyTheme { .. } => {
@@ -181,46 +211,17 @@ impl AppState {
                 self.focus = self.focus.prev();
             }
 
+            Action::FocusPanel(panel) => {
+                self.focus = panel;
+            }
+
             Action::ToggleHelp => {
                 self.show_help = !self.show_help;
             }
 
-            Action::SelectNext => {
-                match self.focus {
-                    PanelFocus::Services => {
-                        let count = KnownService::all().len();
-                        if count > 0 {
-                            self.selected_service = (self.selected_service + 1) % count;
-                        }
-                    }
-                    PanelFocus::Logs => {
-                        self.logs.select_next();
-                    }
-                    PanelFocus::Config => {
-                        // Config panel doesn't have selection
-                    }
-                }
-            }
+            Action::SelectNext => self.select_next(),
 
-            Action::SelectPrev => {
-                match self.focus {
-                    PanelFocus::Services => {
-                        let count = KnownService::all().len();
-                        if count > 0 {
-                            self.selected_service = self
-                                .selected_service
-                                .checked_sub(1)
-                                .unwrap_or(count.saturating_sub(1));
-                        }
-                    }
-                    PanelFocus::Logs => {
-                        self.logs.select_prev();
-                    }
-                    PanelFocus::Config => {
-                        // Config panel doesn't have selection
-                    }
-                }
-            }
+            Action::SelectPrev => self.select_prev(),
 
             Action::Tick => {
                 // Tick actions could update uptime counters, etc.
@@ -234,6 +235,47 @@ impl AppState {
                 self.should_quit = true;
             }
         }
+
+        None
+    }
+
+    /// Moves the selection down in the focused panel.
+    fn select_next(&mut self) {
+        match self.focus {
+            PanelFocus::Services => {
+                let count = KnownService::all().len();
+                if count > 0 {
+                    self.selected_service = (self.selected_service + 1) % count;
+                }
+            }
+            PanelFocus::Logs => {
+                self.logs.select_next();
+            }
+            PanelFocus::Config => {
+                // Config panel doesn't have selection
+            }
+        }
+    }
+
+    /// Moves the selection up in the focused panel.
+    fn select_prev(&mut self) {
+        match self.focus {
+            PanelFocus::Services => {
+                let count = KnownService::all().len();
+                if count > 0 {
+                    self.selected_service = self
+                        .selected_service
+                        .checked_sub(1)
+                        .unwrap_or(count.saturating_sub(1));
+                }
+            }
+            PanelFocus::Logs => {
+                self.logs.select_prev();
+            }
+            PanelFocus::Config => {
+                // Config panel doesn't have selection
+            }
+        }
     }
 
     /// Returns the currently selected service.
@@ -307,6 +349,83 @@ mod tests {
 
         state.update(Action::FocusPrev);
         assert_eq!(state.focus, PanelFocus::Services);
+
+        state.update(Action::FocusPanel(PanelFocus::Config));
+        assert_eq!(state.focus, PanelFocus::Config);
+    }
+
+    #[test]
+    fn focus_cycles_through_update() {
+        let mut state = AppState::new();
+
+        for expected in [PanelFocus::Logs, PanelFocus::Config, PanelFocus::Services] {
+            assert_eq!(state.update(Action::FocusNext), None);
+            assert_eq!(state.focus, expected);
+        }
+
+        assert_eq!(state.update(Action::FocusPrev), None);
+        assert_eq!(state.focus, PanelFocus::Config);
+    }
+
+    #[test]
+    fn scroll_actions_move_log_selection() {
+        let mut state = AppState::new();
+        for i in 0..20 {
+            state.update(Action::LogReceived(LogEntry::info(
+                "test",
+                format!("line {i}"),
+            )));
+        }
+
+        assert_eq!(state.update(Action::ScrollLogs(i32::MIN)), None);
+        assert_eq!(state.logs.selected(), Some(0));
+
+        state.update(Action::ScrollLogs(10));
+        assert_eq!(state.logs.selected(), Some(10));
+
+        state.update(Action::ScrollLogs(-3));
+        assert_eq!(state.logs.selected(), Some(7));
+
+        state.update(Action::ScrollLogs(i32::MAX));
+        assert_eq!(state.logs.selected(), Some(19));
+    }
+
+    #[test]
+    fn service_actions_return_commands() {
+        let mut state = AppState::new();
+
+        let command = state.update(Action::StartService(KnownService::Obs));
+        assert_eq!(command, Some(Command::StartService(KnownService::Obs)));
+        assert_eq!(
+            state.service_info(KnownService::Obs).map(|s| &s.status),
+            Some(&ServiceStatus::Starting)
+        );
+
+        state.update(Action::ServiceCommandFailed {
+            service: KnownService::Obs,
+            message: "no binary".to_string(),
+        });
+        assert!(matches!(
+            state.service_info(KnownService::Obs).map(|s| &s.status),
+            Some(ServiceStatus::Failed(_))
+        ));
+        assert_eq!(state.last_error.as_deref(), Some("no binary"));
+    }
+
+    #[test]
+    fn reload_closes_dialog_and_returns_command() {
+        let mut state = AppState::new();
+        state.update(Action::ConfigInvalid(ConfigProblem {
+            title: "Invalid Color".to_string(),
+            message: "bad hex".to_string(),
+            suggestion: None,
+        }));
+
+        assert_eq!(
+            state.update(Action::ReloadShell),
+            Some(Command::ReloadShell)
+        );
+        assert!(state.config_error.is_none());
     }
 
     #[test]
@@ -320,6 +439,9 @@ mod tests {
 
         state.update(Action::ClearLogs);
         assert!(state.logs.is_empty());
+
+        state.update(Action::ToggleFollow);
+        assert!(state.logs.follow());
     }
 
     #[test]
@@ -344,4 +466,20 @@ mod tests {
         state.update(Action::ToggleHelp);
         assert!(!state.show_help);
     }
+
+    #[test]
+    fn app_state_config_error_dialog() {
+        let mut state = AppState::new();
+        let problem = ConfigProblem {
+            title: "Invalid Color".to_string(),
+            message: "invalid color 'nope'".to_string(),
+            suggestion: Some("Use hex format: #RRGGBB".to_string()),
+        };
+
+        state.update(Action::ConfigInvalid(problem.clone()));
+        assert_eq!(state.config_error, Some(problem));
+
+        state.update(Action::DismissConfigError);
+        assert!(state.config_error.is_none());
+    }
 }
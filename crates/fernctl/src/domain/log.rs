@@ -2,9 +2,12 @@
 //!
 //! Types for log entries and log buffering.
 
+use super::service::KnownService;
 use chrono::{DateTime, Local, Utc};
 use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
+use std::path::Path;
+use std::time::SystemTime;
 
 /// Log severity level.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
@@ -23,18 +26,26 @@ pub enum LogLevel {
 }
 
 impl LogLevel {
-    /// Returns the ANSI color code for this log level.
+    /// Returns the display tone for this log level.
+    ///
+    /// This is the single source of truth for level coloring; the CLI maps
+    /// it to ANSI codes and the TUI maps it to ratatui styles.
     #[must_use]
-    pub const fn color(&self) -> &'static str {
+    pub const fn tone(&self) -> LevelTone {
         match self {
-            Self::Trace => "\x1b[90m",   // Gray
-            Self::Debug => "\x1b[36m",   // Cyan
-            Self::Info => "\x1b[32m",    // Green
-            Self::Warn => "\x1b[33m",    // Yellow
-            Self::Error => "\x1b[31m",   // Red
+            Self::Trace | Self::Debug => LevelTone::Dim,
+            Self::Info => LevelTone::Normal,
+            Self::Warn => LevelTone::Warning,
+            Self::Error => LevelTone::Error,
         }
     }
 
+    /// Returns the ANSI color code for this log level.
+    #[must_use]
+    pub const fn color(&self) -> &'static str {
+        self.tone().ansi()
+    }
+
     /// Returns the short label for this log level.
     #[must_use]
     pub const fn label(&self) -> &'static str {
@@ -48,6 +59,32 @@ impl LogLevel {
     }
 }
 
+/// How prominently a log level should be rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LevelTone {
+    /// De-emphasized (debug and trace).
+    Dim,
+    /// Regular informational output.
+    Normal,
+    /// Highlighted as a warning (yellow).
+    Warning,
+    /// Highlighted as an error (red).
+    Error,
+}
+
+impl LevelTone {
+    /// Returns the ANSI escape sequence for this tone.
+    #[must_use]
+    pub const fn ansi(&self) -> &'static str {
+        match self {
+            Self::Dim => "\x1b[2m",
+            Self::Normal => "\x1b[32m",
+            Self::Warning => "\x1b[33m",
+            Self::Error => "\x1b[31m",
+        }
+    }
+}
+
 impl std::fmt::Display for LogLevel {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{}", self.label())
@@ -64,15 +101,30 @@ impl Default for LogLevel {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LogEntry {
     /// Timestamp when the log was created.
-    #[serde(with = "chrono::serde::ts_milliseconds")]
+    ///
+    /// Serialized as milliseconds since the Unix epoch. When reading, RFC
+    /// 3339 strings (as written by the QML shell and `tracing`), offset-less
+    /// `YYYY-MM-DD HH:MM:SS` times (taken as UTC), and millisecond counts
+    /// written as strings are normalized to the same value.
+    #[serde(with = "timestamp_format")]
     pub timestamp: DateTime<Utc>,
 
     /// Log severity level.
     pub level: LogLevel,
 
     /// Source component (e.g., "Obs", "ConfigLoader").
+    ///
+    /// The QML shell writes this as `module`.
+    #[serde(alias = "module")]
     pub source: String,
 
+    /// Service that produced the entry (e.g., "obs", "shell").
+    ///
+    /// Empty when the writer didn't record one; [`parse_log_file`] fills it
+    /// in from the log file name.
+    #[serde(default)]
+    pub service: String,
+
     /// Log message.
     pub message: String,
 
@@ -89,6 +141,7 @@ impl LogEntry {
             timestamp: Utc::now(),
             level,
             source: source.into(),
+            service: String::new(),
             message: message.into(),
             data: None,
         }
@@ -112,6 +165,13 @@ impl LogEntry {
         Self::new(LogLevel::Error, source, message)
     }
 
+    /// Sets the service that produced the log entry.
+    #[must_use]
+    pub fn with_service(mut self, service: impl Into<String>) -> Self {
+        self.service = service.into();
+        self
+    }
+
     /// Adds structured data to the log entry.
     #[must_use]
     pub fn with_data(mut self, data: serde_json::Value) -> Self {
@@ -119,6 +179,27 @@ impl LogEntry {
         self
     }
 
+    /// Returns the timestamp as seconds since the Unix epoch.
+    #[must_use]
+    pub fn timestamp_secs(&self) -> i64 {
+        self.timestamp.timestamp()
+    }
+
+    /// Returns when the entry was logged.
+    ///
+    /// This is the ordering key for entries merged from several log files;
+    /// sort and filter on it rather than on any formatted form.
+    #[must_use]
+    pub fn time(&self) -> SystemTime {
+        self.timestamp.into()
+    }
+
+    /// Returns `true` if the entry was logged at or after `since`.
+    #[must_use]
+    pub fn is_since(&self, since: DateTime<Utc>) -> bool {
+        self.time() >= SystemTime::from(since)
+    }
+
     /// Returns the timestamp formatted for display.
     #[must_use]
     pub fn formatted_time(&self) -> String {
@@ -160,10 +241,164 @@ impl LogEntry {
             return true;
         }
         let filter_lower = filter.to_lowercase();
-        self.source.to_lowercase().contains(&filter_lower)
+        self.service.to_lowercase().contains(&filter_lower)
+            || self.source.to_lowercase().contains(&filter_lower)
             || self.message.to_lowercase().contains(&filter_lower)
             || self.level.label().to_lowercase().contains(&filter_lower)
     }
+
+    /// Checks if this entry was produced by the given service.
+    ///
+    /// Service aliases (e.g., `fern-obs`, `quickshell`) are normalized
+    /// through [`KnownService::from_name`] before comparing.
+    #[must_use]
+    pub fn matches_service(&self, service: &str) -> bool {
+        normalize_service(&self.service) == normalize_service(service)
+    }
+}
+
+/// Maps a service name or alias to its canonical lowercase name.
+fn normalize_service(name: &str) -> String {
+    KnownService::from_name(name).map_or_else(|| name.to_lowercase(), |s| s.name().to_string())
+}
+
+/// Serde helpers for [`LogEntry::timestamp`].
+mod timestamp_format {
+    use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    /// Either encoding found in log files.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum RawTimestamp {
+        Millis(i64),
+        Text(String),
+    }
+
+    pub fn serialize<S: Serializer>(
+        value: &DateTime<Utc>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i64(value.timestamp_millis())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<DateTime<Utc>, D::Error> {
+        match RawTimestamp::deserialize(deserializer)? {
+            RawTimestamp::Millis(millis) => {
+                Utc.timestamp_millis_opt(millis).single().ok_or_else(|| {
+                    serde::de::Error::custom(format!("timestamp out of range: {millis}"))
+                })
+            }
+            RawTimestamp::Text(text) => parse_text(&text)
+                .ok_or_else(|| serde::de::Error::custom(format!("invalid timestamp '{text}'"))),
+        }
+    }
+
+    /// Normalizes the string forms written by different services.
+    fn parse_text(text: &str) -> Option<DateTime<Utc>> {
+        let text = text.trim();
+
+        if let Ok(dt) = DateTime::parse_from_rfc3339(text) {
+            return Some(dt.with_timezone(&Utc));
+        }
+
+        for format in ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f"] {
+            if let Ok(naive) = NaiveDateTime::parse_from_str(text, format) {
+                return Some(naive.and_utc());
+            }
+        }
+
+        text.parse::<i64>()
+            .ok()
+            .and_then(|millis| Utc.timestamp_millis_opt(millis).single())
+    }
+}
+
+/// Parses a `--since` value into an absolute cutoff time.
+///
+/// Accepts relative durations (`30s`, `5m`, `1h`, `2d`) measured back from
+/// `now`, or absolute RFC 3339 timestamps (`2024-05-01T12:00:00Z`).
+///
+/// # Errors
+///
+/// Returns a description of the problem if the value is neither form.
+pub fn parse_since(input: &str, now: DateTime<Utc>) -> std::result::Result<DateTime<Utc>, String> {
+    let input = input.trim();
+
+    if let Ok(absolute) = DateTime::parse_from_rfc3339(input) {
+        return Ok(absolute.with_timezone(&Utc));
+    }
+
+    let split = input
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(input.len());
+    let (amount, unit) = input.split_at(split);
+    let amount: i64 = amount.parse().map_err(|_| {
+        format!("invalid --since value '{input}' (expected e.g. 5m, 1h, or an RFC 3339 time)")
+    })?;
+
+    let duration = match unit {
+        "s" => chrono::Duration::try_seconds(amount),
+        "m" => chrono::Duration::try_minutes(amount),
+        "h" => chrono::Duration::try_hours(amount),
+        "d" => chrono::Duration::try_days(amount),
+        _ => {
+            return Err(format!(
+                "unknown duration unit '{unit}' in '{input}' (use s, m, h, or d)"
+            ))
+        }
+    }
+    .ok_or_else(|| format!("duration '{input}' is too large"))?;
+
+    Ok(now - duration)
+}
+
+/// Log files aggregated by `fernctl logs` and the dashboard.
+///
+/// Each entry is a file name in the state directory.
+pub const LOG_FILES: &[&str] = &["shell-log.json", "obs-log.json", "supervisor-log.json"];
+
+/// Derives a service name from a log file path.
+///
+/// `obs-log.json` becomes `obs` and `shell-log.json` becomes `shell`.
+#[must_use]
+pub fn service_from_log_path(path: &Path) -> String {
+    let file_name = path.file_name().and_then(|n| n.to_str()).unwrap_or("");
+    file_name
+        .strip_suffix("-log.json")
+        .or_else(|| file_name.strip_suffix(".json"))
+        .unwrap_or(file_name)
+        .to_string()
+}
+
+/// Parses the contents of a log file.
+///
+/// Accepts either a JSON array of entries or an object with an `entries`
+/// array. Entries that fail to parse are skipped, and entries without a
+/// `service` are attributed to `default_service`.
+#[must_use]
+pub fn parse_log_file(content: &str, default_service: &str) -> Vec<LogEntry> {
+    let values = match serde_json::from_str::<serde_json::Value>(content) {
+        Ok(serde_json::Value::Array(values)) => values,
+        Ok(serde_json::Value::Object(mut object)) => match object.remove("entries") {
+            Some(serde_json::Value::Array(values)) => values,
+            _ => return Vec::new(),
+        },
+        _ => return Vec::new(),
+    };
+
+    values
+        .into_iter()
+        .filter_map(|value| serde_json::from_value::<LogEntry>(value).ok())
+        .map(|mut entry| {
+            if entry.service.is_empty() {
+                entry.service = default_service.to_string();
+            }
+            entry
+        })
+        .collect()
 }
 
 /// Ring buffer for log entries.
@@ -180,6 +415,9 @@ pub struct LogBuffer {
 
     /// Current filter string.
     filter: String,
+
+    /// Whether the selection tracks the newest entry as logs arrive.
+    follow: bool,
 }
 
 impl LogBuffer {
@@ -194,6 +432,7 @@ impl LogBuffer {
             capacity,
             selected: None,
             filter: String::new(),
+            follow: false,
         }
     }
 
@@ -207,6 +446,12 @@ impl LogBuffer {
     ///
     /// If the buffer is at capacity, the oldest entry is removed.
     pub fn push(&mut self, entry: LogEntry) {
+        self.push_entry(entry);
+        self.follow_tail();
+    }
+
+    /// Adds an entry without moving the selection in follow mode.
+    fn push_entry(&mut self, entry: LogEntry) {
         if self.entries.len() >= self.capacity {
             self.entries.pop_front();
             // Adjust selected index if it was pointing to the removed entry
@@ -217,6 +462,46 @@ impl LogBuffer {
         self.entries.push_back(entry);
     }
 
+    /// Returns the maximum number of entries kept.
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Changes the maximum number of entries kept.
+    ///
+    /// Shrinking drops the oldest entries. The capacity is at least 1.
+    pub fn set_capacity(&mut self, capacity: usize) {
+        self.capacity = capacity.max(1);
+        let excess = self.entries.len().saturating_sub(self.capacity);
+        if excess > 0 {
+            self.entries.drain(..excess);
+            self.selected = self.selected.and_then(|idx| idx.checked_sub(excess));
+            self.follow_tail();
+        }
+    }
+
+    /// Returns `true` if the selection follows the newest entry.
+    #[must_use]
+    pub fn follow(&self) -> bool {
+        self.follow
+    }
+
+    /// Turns follow mode on or off.
+    ///
+    /// Turning it on jumps to the newest entry.
+    pub fn set_follow(&mut self, follow: bool) {
+        self.follow = follow;
+        self.follow_tail();
+    }
+
+    /// Jumps to the newest entry if follow mode is on.
+    fn follow_tail(&mut self) {
+        if self.follow {
+            self.jump_to_end();
+        }
+    }
+
     /// Clears all entries from the buffer.
     pub fn clear(&mut self) {
         self.entries.clear();
@@ -281,10 +566,14 @@ impl LogBuffer {
         }
 
         let current = self.selected.unwrap_or(count.saturating_sub(1));
+        // i32::MIN jumps to the top, so it cannot be negated
+        let distance = offset.unsigned_abs() as usize;
         let new_idx = if offset > 0 {
-            (current + offset as usize).min(count.saturating_sub(1))
+            current
+                .saturating_add(distance)
+                .min(count.saturating_sub(1))
         } else {
-            current.saturating_sub((-offset) as usize)
+            current.saturating_sub(distance)
         };
         self.selected = Some(new_idx);
     }
@@ -324,19 +613,20 @@ impl LogBuffer {
     /// This allows streaming new logs without duplicating existing ones.
     pub fn sync(&mut self, entries: Vec<LogEntry>) {
         // Get the timestamp of the newest entry we have
-        let last_timestamp = self.entries.back().map(|e| e.timestamp);
+        let last_timestamp = self.entries.back().map(LogEntry::time);
 
         // Add only entries newer than our last one
         for entry in entries {
             let is_new = match last_timestamp {
-                Some(last) => entry.timestamp > last,
+                Some(last) => entry.time() > last,
                 None => true, // Buffer is empty, add all
             };
 
             if is_new {
-                self.push(entry);
+                self.push_entry(entry);
             }
         }
+        self.follow_tail();
     }
 }
 
@@ -365,6 +655,38 @@ mod tests {
         assert_eq!(buffer.entries[0].message, "message 2");
     }
 
+    #[test]
+    fn set_capacity_drops_oldest() {
+        let mut buffer = LogBuffer::new(5);
+        for i in 0..5 {
+            buffer.push(LogEntry::info("test", format!("message {i}")));
+        }
+        buffer.jump_to_end();
+
+        buffer.set_capacity(2);
+        assert_eq!(buffer.capacity(), 2);
+        assert_eq!(buffer.len(), 2);
+        assert_eq!(buffer.entries[0].message, "message 3");
+        assert_eq!(buffer.selected(), Some(1));
+    }
+
+    #[test]
+    fn follow_tracks_newest_entry() {
+        let mut buffer = LogBuffer::new(10);
+        buffer.push(LogEntry::info("test", "message 1"));
+        assert_eq!(buffer.selected(), None);
+
+        buffer.set_follow(true);
+        assert_eq!(buffer.selected(), Some(0));
+
+        buffer.push(LogEntry::info("test", "message 2"));
+        assert_eq!(buffer.selected(), Some(1));
+
+        buffer.set_follow(false);
+        buffer.push(LogEntry::info("test", "message 3"));
+        assert_eq!(buffer.selected(), Some(1));
+    }
+
     #[test]
     fn log_buffer_filter() {
         let mut buffer = LogBuffer::new(10);
@@ -385,6 +707,149 @@ mod tests {
         assert_eq!(buffer.filtered_count(), 3);
     }
 
+    #[test]
+    fn log_entry_matches_service_aliases() {
+        let entry = LogEntry::info("daemon", "connected").with_service("obs");
+
+        assert!(entry.matches_service("obs"));
+        assert!(entry.matches_service("fern-obs"));
+        assert!(entry.matches_service("OBS"));
+        assert!(!entry.matches_service("shell"));
+    }
+
+    #[test]
+    fn service_from_log_path_strips_suffix() {
+        assert_eq!(service_from_log_path(Path::new("/tmp/obs-log.json")), "obs");
+        assert_eq!(service_from_log_path(Path::new("shell-log.json")), "shell");
+    }
+
+    #[test]
+    fn parse_log_file_defaults_service() {
+        let content = r#"[
+            {"timestamp": 1000, "level": "info", "source": "Bar", "message": "a"},
+            {"timestamp": 2000, "level": "warn", "source": "daemon", "service": "obs", "message": "b"}
+        ]"#;
+
+        let entries = parse_log_file(content, "shell");
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].service, "shell");
+        assert_eq!(entries[1].service, "obs");
+    }
+
+    #[test]
+    fn parse_log_file_accepts_entries_object_and_module_alias() {
+        let content = r#"{"entries": [
+            {"timestamp": 1000, "level": "error", "module": "Clock", "message": "boom"}
+        ]}"#;
+
+        let entries = parse_log_file(content, "shell");
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].source, "Clock");
+        assert_eq!(entries[0].level, LogLevel::Error);
+    }
+
+    #[test]
+    fn log_level_tones() {
+        assert_eq!(LogLevel::Trace.tone(), LevelTone::Dim);
+        assert_eq!(LogLevel::Debug.tone(), LevelTone::Dim);
+        assert_eq!(LogLevel::Warn.color(), "\x1b[33m");
+        assert_eq!(LogLevel::Error.color(), "\x1b[31m");
+    }
+
+    #[test]
+    fn timestamp_accepts_millis_and_rfc3339() {
+        let millis: LogEntry = serde_json::from_str(
+            r#"{"timestamp": 1700000000000, "level": "info", "source": "a", "message": "m"}"#,
+        )
+        .expect("millis timestamp should parse");
+        assert_eq!(millis.timestamp_secs(), 1_700_000_000);
+
+        let text: LogEntry = serde_json::from_str(
+            r#"{"timestamp": "2023-11-14T22:13:20.000Z", "level": "info", "module": "a", "message": "m"}"#,
+        )
+        .expect("RFC 3339 timestamp should parse");
+        assert_eq!(text.timestamp_secs(), 1_700_000_000);
+    }
+
+    #[test]
+    fn timestamp_normalizes_legacy_strings() {
+        for raw in [
+            "2023-11-14T22:13:20+00:00",
+            "2023-11-14T23:13:20+01:00",
+            "2023-11-14T22:13:20",
+            "2023-11-14 22:13:20.000",
+            "1700000000000",
+        ] {
+            let json = format!(
+                r#"{{"timestamp": "{raw}", "level": "info", "source": "a", "message": "m"}}"#
+            );
+            let entry: LogEntry = serde_json::from_str(&json).unwrap();
+            assert_eq!(entry.timestamp_secs(), 1_700_000_000, "{raw}");
+        }
+
+        let bad = r#"{"timestamp": "yesterday", "level": "info", "source": "a", "message": "m"}"#;
+        assert!(serde_json::from_str::<LogEntry>(bad).is_err());
+    }
+
+    #[test]
+    fn time_orders_entries_from_mixed_sources() {
+        let content = r#"[
+            {"timestamp": "2023-11-14T22:13:21Z", "level": "info", "source": "Bar", "message": "second"},
+            {"timestamp": 1700000000000, "level": "info", "source": "daemon", "message": "first"},
+            {"timestamp": "2023-11-14 22:13:22", "level": "info", "source": "Bar", "message": "third"}
+        ]"#;
+
+        let mut entries = parse_log_file(content, "shell");
+        entries.sort_by_key(LogEntry::time);
+        let messages: Vec<_> = entries.iter().map(|e| e.message.as_str()).collect();
+        assert_eq!(messages, ["first", "second", "third"]);
+
+        let cutoff = DateTime::parse_from_rfc3339("2023-11-14T22:13:21Z")
+            .unwrap()
+            .with_timezone(&Utc);
+        assert!(!entries[0].is_since(cutoff));
+        assert!(entries[1].is_since(cutoff));
+        assert_eq!(
+            entries[0].time(),
+            SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(1_700_000_000)
+        );
+    }
+
+    #[test]
+    fn timestamp_serializes_as_millis() {
+        let entry: LogEntry = serde_json::from_str(
+            r#"{"timestamp": "2023-11-14T22:13:20Z", "level": "info", "source": "a", "message": "m"}"#,
+        )
+        .expect("entry should parse");
+        let json = serde_json::to_string(&entry).expect("entry should serialize");
+        assert!(json.contains("\"timestamp\":1700000000000"));
+    }
+
+    #[test]
+    fn parse_since_relative_durations() {
+        let now = Utc::now();
+        assert_eq!(
+            parse_since("30s", now),
+            Ok(now - chrono::Duration::seconds(30))
+        );
+        assert_eq!(
+            parse_since("5m", now),
+            Ok(now - chrono::Duration::minutes(5))
+        );
+        assert_eq!(parse_since("1h", now), Ok(now - chrono::Duration::hours(1)));
+        assert_eq!(parse_since("2d", now), Ok(now - chrono::Duration::days(2)));
+    }
+
+    #[test]
+    fn parse_since_absolute_and_invalid() {
+        let now = Utc::now();
+        let parsed = parse_since("2023-11-14T22:13:20Z", now).expect("RFC 3339 should parse");
+        assert_eq!(parsed.timestamp(), 1_700_000_000);
+
+        assert!(parse_since("5y", now).is_err());
+        assert!(parse_since("soon", now).is_err());
+    }
+
     #[test]
     fn log_entry_matches_filter() {
         let entry = LogEntry::info("ConfigLoader", "Loaded config successfully");
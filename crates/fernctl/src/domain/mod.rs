@@ -5,16 +5,28 @@
 //! ## Overview
 //!
 //! - [`app`] - Application state and update logic
-//! - [`action`] - Action types (messages)
-//! - [`service`] - Known services and their configuration
+//! - [`action`] - Action types (messages) and the commands they request
+//! - [`service`] - Known services, their configuration, and state files
+//! - [`registry`] - Service descriptors, built-in and user-defined
 //! - [`log`] - Log entry types and buffer
+//! - [`prefs`] - Dashboard preferences saved between launches
+//! - [`supervisor`] - Restart policy for supervised services
 
 pub mod action;
 pub mod app;
 pub mod log;
+pub mod prefs;
+pub mod registry;
 pub mod service;
+pub mod supervisor;
 
-pub use action::Action;
+pub use action::{Action, Command};
 pub use app::{AppState, PanelFocus};
-pub use log::{LogBuffer, LogEntry, LogLevel};
-pub use service::KnownService;
+pub use log::{
+    parse_log_file, parse_since, service_from_log_path, LevelTone, LogBuffer, LogEntry,
+    LogLevel, LOG_FILES,
+};
+pub use prefs::TuiPrefs;
+pub use registry::{match_service_name, HealthProbe, ServiceCatalog, ServiceDescriptor};
+pub use service::{KnownService, StateRead};
+pub use supervisor::{GiveUpReason, RestartDecision, RestartPolicy, RestartTracker};
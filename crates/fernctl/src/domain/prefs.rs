@@ -0,0 +1,160 @@
+//! # Dashboard Preferences
+//!
+//! View settings for `fernctl tui` that carry over between launches,
+//! stored in `~/.config/fern/tui.toml`:
+//!
+//! ```toml
+//! focus = "logs"
+//! log_lines = 100
+//! follow = true
+//! ```
+//!
+//! The dashboard loads the file on start and rewrites it on exit, so the
+//! file normally needs no hand editing. Missing keys keep their defaults.
+
+use super::app::{AppState, PanelFocus};
+use super::log::LogBuffer;
+use crate::error::{FernctlError, Result};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// Dashboard view settings persisted across launches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TuiPrefs {
+    /// Panel focused at startup.
+    pub focus: PanelFocus,
+
+    /// Number of log lines kept in the logs panel.
+    pub log_lines: usize,
+
+    /// Whether the logs panel follows the newest entry.
+    pub follow: bool,
+}
+
+impl Default for TuiPrefs {
+    fn default() -> Self {
+        Self {
+            focus: PanelFocus::default(),
+            log_lines: LogBuffer::DEFAULT_CAPACITY,
+            follow: false,
+        }
+    }
+}
+
+impl TuiPrefs {
+    /// Loads preferences from `path`.
+    ///
+    /// A missing file gives the defaults.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| FernctlError::io(format!("reading {}", path.display()), e))?;
+        Self::from_toml(&content)
+    }
+
+    /// Parses `tui.toml` content.
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error for invalid TOML or a zero `log_lines`.
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let prefs: Self =
+            toml::from_str(content).map_err(|e| FernctlError::parse("tui.toml", e.to_string()))?;
+
+        if prefs.log_lines == 0 {
+            return Err(FernctlError::parse(
+                "tui.toml",
+                "log_lines must be at least 1",
+            ));
+        }
+
+        Ok(prefs)
+    }
+
+    /// Writes the preferences to `path`, creating its directory if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be written.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)
+                .map_err(|e| FernctlError::io(format!("creating {}", parent.display()), e))?;
+        }
+
+        let content =
+            toml::to_string(self).map_err(|e| FernctlError::parse("tui.toml", e.to_string()))?;
+        std::fs::write(path, content)
+            .map_err(|e| FernctlError::io(format!("writing {}", path.display()), e))
+    }
+
+    /// Captures the current view settings from `state`.
+    #[must_use]
+    pub fn from_state(state: &AppState) -> Self {
+        Self {
+            focus: state.focus,
+            log_lines: state.logs.capacity(),
+            follow: state.logs.follow(),
+        }
+    }
+
+    /// Applies the preferences to `state`.
+    pub fn apply(&self, state: &mut AppState) {
+        state.focus = self.focus;
+        state.logs.set_capacity(self.log_lines);
+        state.logs.set_follow(self.follow);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_keys_keep_defaults() {
+        let prefs = TuiPrefs::from_toml("focus = \"logs\"").unwrap();
+        assert_eq!(prefs.focus, PanelFocus::Logs);
+        assert_eq!(prefs.log_lines, LogBuffer::DEFAULT_CAPACITY);
+        assert!(!prefs.follow);
+    }
+
+    #[test]
+    fn rejects_bad_values() {
+        assert!(TuiPrefs::from_toml("focus = \"sidebar\"").is_err());
+        assert!(TuiPrefs::from_toml("log_lines = 0").is_err());
+    }
+
+    #[test]
+    fn round_trips_through_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("fern/tui.toml");
+        let prefs = TuiPrefs {
+            focus: PanelFocus::Logs,
+            log_lines: 100,
+            follow: true,
+        };
+        prefs.save(&path).unwrap();
+
+        let mut state = AppState::new();
+        TuiPrefs::load(&path).unwrap().apply(&mut state);
+        assert_eq!(state.focus, PanelFocus::Logs);
+        assert_eq!(state.logs.capacity(), 100);
+        assert!(state.logs.follow());
+        assert_eq!(TuiPrefs::from_state(&state), prefs);
+    }
+
+    #[test]
+    fn missing_file_gives_defaults() {
+        let dir = tempfile::tempdir().unwrap();
+        let prefs = TuiPrefs::load(&dir.path().join("tui.toml")).unwrap();
+        assert_eq!(prefs, TuiPrefs::default());
+    }
+}
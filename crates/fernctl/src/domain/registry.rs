@@ -0,0 +1,586 @@
+//! # Service Registry
+//!
+//! Describes every service fernctl can manage: the built-in
+//! [`KnownService`]s plus any user-defined daemons declared in
+//! `~/.config/fern/services.toml`.
+//!
+//! ## Configuration
+//!
+//! ```toml
+//! [[service]]
+//! name = "wallpaper"
+//! display_name = "Wallpaper"
+//! start_cmd = ["swww-daemon"]
+//! stop_cmd = ["swww", "kill"]
+//! state_file = "wallpaper-state.json"
+//! health = { command = ["swww", "query"] }
+//! depends_on = ["theme-watcher"]
+//! ```
+//!
+//! Only `name` and `start_cmd` are required. Without a `stop_cmd` the
+//! process is terminated by name; without a `health` probe the service is
+//! considered running while a process named after the `start_cmd` binary
+//! exists. An entry whose `name` matches a built-in service replaces it.
+//!
+//! `depends_on` names services that must be healthy before this one
+//! starts. `fernctl watch-services` starts services in
+//! [dependency order](ServiceCatalog::start_order); a dependency cycle or
+//! an unknown name is rejected when the file is loaded.
+
+use super::service::KnownService;
+use crate::error::{FernctlError, Result};
+use fern_core::FernPaths;
+use fern_theme::commands::query::find_similar;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+/// How to tell whether a service is healthy.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthProbe {
+    /// Running while a process named after the binary exists.
+    #[default]
+    Process,
+    /// Running while this command exits successfully.
+    Command(Vec<String>),
+}
+
+/// Everything fernctl needs to manage a service.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ServiceDescriptor {
+    /// Service name used on the command line (e.g. `obs`).
+    pub name: String,
+
+    /// Human-readable name. Defaults to `name`.
+    #[serde(default)]
+    pub display_name: Option<String>,
+
+    /// Command and arguments that start the service.
+    pub start_cmd: Vec<String>,
+
+    /// Command that stops the service. Defaults to killing the process.
+    #[serde(default)]
+    pub stop_cmd: Option<Vec<String>>,
+
+    /// State file name in the state directory, if the service writes one.
+    #[serde(default)]
+    pub state_file: Option<String>,
+
+    /// Health probe.
+    #[serde(default)]
+    pub health: HealthProbe,
+
+    /// Services that must be healthy before this one starts.
+    #[serde(default)]
+    pub depends_on: Vec<String>,
+}
+
+impl ServiceDescriptor {
+    /// Returns the descriptor for a built-in service.
+    #[must_use]
+    pub fn builtin(service: KnownService) -> Self {
+        let mut start_cmd = vec![service.binary().to_string()];
+        start_cmd.extend(service.start_args().iter().map(ToString::to_string));
+
+        Self {
+            name: service.name().to_string(),
+            display_name: Some(service.display_name().to_string()),
+            start_cmd,
+            stop_cmd: None,
+            state_file: Some(service.state_file().to_string()),
+            health: HealthProbe::Process,
+            depends_on: service
+                .dependencies()
+                .iter()
+                .map(|dependency| dependency.name().to_string())
+                .collect(),
+        }
+    }
+
+    /// Returns the display name, falling back to the service name.
+    #[must_use]
+    pub fn display_name(&self) -> &str {
+        self.display_name.as_deref().unwrap_or(&self.name)
+    }
+
+    /// Returns the process name of the start command's binary.
+    ///
+    /// `/usr/bin/swww-daemon` becomes `swww-daemon`.
+    #[must_use]
+    pub fn binary(&self) -> &str {
+        self.start_cmd
+            .first()
+            .map_or("", |cmd| cmd.rsplit('/').next().unwrap_or(cmd))
+    }
+
+    /// Returns the built-in service this descriptor describes, if any.
+    #[must_use]
+    pub fn known(&self) -> Option<KnownService> {
+        KnownService::from_name(&self.name)
+    }
+
+    /// Checks that the descriptor is usable.
+    fn validate(&self) -> std::result::Result<(), String> {
+        if self.name.trim().is_empty() {
+            return Err("service name is empty".to_string());
+        }
+        if self.start_cmd.first().is_none_or(String::is_empty) {
+            return Err(format!("service '{}' has an empty start_cmd", self.name));
+        }
+        if matches!(&self.stop_cmd, Some(cmd) if cmd.is_empty()) {
+            return Err(format!("service '{}' has an empty stop_cmd", self.name));
+        }
+        if matches!(&self.health, HealthProbe::Command(cmd) if cmd.is_empty()) {
+            return Err(format!(
+                "service '{}' has an empty health command",
+                self.name
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// On-disk layout of `services.toml`.
+#[derive(Debug, Default, Deserialize)]
+struct ServicesFile {
+    #[serde(default)]
+    service: Vec<ServiceDescriptor>,
+}
+
+/// The set of services fernctl manages, built-ins first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceCatalog {
+    services: Vec<ServiceDescriptor>,
+}
+
+impl ServiceCatalog {
+    /// Returns a catalog of only the built-in services.
+    #[must_use]
+    pub fn builtin() -> Self {
+        Self {
+            services: KnownService::all()
+                .iter()
+                .copied()
+                .map(ServiceDescriptor::builtin)
+                .collect(),
+        }
+    }
+
+    /// Loads the catalog, merging `services.toml` over the built-ins.
+    ///
+    /// A missing file yields the built-ins.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load(paths: &FernPaths) -> Result<Self> {
+        Self::load_from(&paths.services_toml())
+    }
+
+    /// Loads the catalog from a specific file. See [`load`](Self::load).
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file exists but cannot be read or parsed.
+    pub fn load_from(path: &Path) -> Result<Self> {
+        if !path.exists() {
+            return Ok(Self::builtin());
+        }
+
+        let content = std::fs::read_to_string(path)
+            .map_err(|e| FernctlError::state_io(format!("reading {}", path.display()), e))?;
+        Self::from_toml(&content)
+    }
+
+    /// Parses `services.toml` content and merges it over the built-ins.
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error for invalid TOML, invalid descriptors, or
+    /// duplicate names within the file.
+    pub fn from_toml(content: &str) -> Result<Self> {
+        let file: ServicesFile = toml::from_str(content)
+            .map_err(|e| FernctlError::parse("services.toml", e.to_string()))?;
+
+        let mut catalog = Self::builtin();
+        let mut seen = Vec::new();
+
+        for descriptor in file.service {
+            descriptor
+                .validate()
+                .map_err(|e| FernctlError::parse("services.toml", e))?;
+
+            if seen.contains(&descriptor.name) {
+                return Err(FernctlError::parse(
+                    "services.toml",
+                    format!("service '{}' is defined twice", descriptor.name),
+                ));
+            }
+            seen.push(descriptor.name.clone());
+            catalog.insert(descriptor);
+        }
+
+        for service in &catalog.services {
+            if let Some(unknown) = service.depends_on.iter().find(|d| catalog.get(d).is_none()) {
+                return Err(FernctlError::parse(
+                    "services.toml",
+                    format!(
+                        "service '{}' depends on unknown service '{unknown}'",
+                        service.name
+                    ),
+                ));
+            }
+        }
+        catalog.start_order(&catalog.iter().collect::<Vec<_>>())?;
+
+        Ok(catalog)
+    }
+
+    /// Adds a descriptor, replacing any existing one with the same name.
+    pub fn insert(&mut self, descriptor: ServiceDescriptor) {
+        let existing = self.services.iter_mut().find(|s| {
+            s.name == descriptor.name || descriptor.known().is_some_and(|k| s.known() == Some(k))
+        });
+
+        match existing {
+            Some(slot) => *slot = descriptor,
+            None => self.services.push(descriptor),
+        }
+    }
+
+    /// Looks up a service by name.
+    ///
+    /// Built-in aliases (`fern-obs`, `theme`, ...) are accepted.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&ServiceDescriptor> {
+        self.services.iter().find(|s| s.name == name).or_else(|| {
+            let known = KnownService::from_name(name)?;
+            self.services.iter().find(|s| s.known() == Some(known))
+        })
+    }
+
+    /// Looks up a service by a name the user typed.
+    ///
+    /// Tries [`get`](Self::get) first, then a case-insensitive match, then
+    /// a unique prefix (`theme` finds `theme-watcher`). See
+    /// [`match_service_name`].
+    ///
+    /// # Errors
+    ///
+    /// Returns a service error naming the closest matches if the name is
+    /// unknown or is a prefix of more than one service.
+    pub fn resolve(&self, name: &str) -> Result<&ServiceDescriptor> {
+        if let Some(descriptor) = self.get(name) {
+            return Ok(descriptor);
+        }
+
+        let matched = match_service_name(name, self.names())
+            .map_err(|message| FernctlError::service(name, message))?;
+        self.get(matched)
+            .ok_or_else(|| FernctlError::service(name, format!("unknown service '{name}'")))
+    }
+
+    /// Returns all services, built-ins first.
+    pub fn iter(&self) -> impl Iterator<Item = &ServiceDescriptor> {
+        self.services.iter()
+    }
+
+    /// Returns all service names, built-ins first.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.services.iter().map(|s| s.name.as_str())
+    }
+
+    /// Returns the positions in `services` of each service's dependencies.
+    ///
+    /// Dependencies that are not in `services` are left out.
+    #[must_use]
+    pub fn dependency_indices(&self, services: &[&ServiceDescriptor]) -> Vec<Vec<usize>> {
+        services
+            .iter()
+            .map(|service| {
+                service
+                    .depends_on
+                    .iter()
+                    .filter_map(|name| self.get(name))
+                    .filter_map(|dep| services.iter().position(|s| s.name == dep.name))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Orders `services` so each one comes after its dependencies.
+    ///
+    /// Dependencies outside `services` are ignored, and services that do
+    /// not depend on each other keep their relative order, so the result
+    /// is the same on every run.
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error naming the cycle if services depend on each
+    /// other.
+    pub fn start_order<'a>(
+        &self,
+        services: &[&'a ServiceDescriptor],
+    ) -> Result<Vec<&'a ServiceDescriptor>> {
+        let deps = self.dependency_indices(services);
+        let mut placed = vec![false; services.len()];
+        let mut order = Vec::with_capacity(services.len());
+
+        while order.len() < services.len() {
+            let next =
+                (0..services.len()).find(|&i| !placed[i] && deps[i].iter().all(|&d| placed[d]));
+            let Some(next) = next else {
+                let cycle = find_cycle(&deps, &placed)
+                    .into_iter()
+                    .map(|i| services[i].name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" → ");
+                return Err(FernctlError::parse(
+                    "services.toml",
+                    format!("dependency cycle: {cycle}"),
+                ));
+            };
+            placed[next] = true;
+            order.push(services[next]);
+        }
+
+        Ok(order)
+    }
+}
+
+/// Follows unplaced dependencies from the first unplaced service until one
+/// repeats, returning the cycle with its first service repeated at the end.
+///
+/// Only called when no unplaced service has all its dependencies placed,
+/// so every step finds an unplaced dependency.
+fn find_cycle(deps: &[Vec<usize>], placed: &[bool]) -> Vec<usize> {
+    let mut path: Vec<usize> = placed.iter().position(|&p| !p).into_iter().collect();
+
+    while let Some(&last) = path.last() {
+        let Some(&next) = deps[last].iter().find(|&&d| !placed[d]) else {
+            break;
+        };
+        if let Some(start) = path.iter().position(|&i| i == next) {
+            path.drain(..start);
+            path.push(next);
+            break;
+        }
+        path.push(next);
+    }
+    path
+}
+
+/// Matches a user-typed service name against `candidates`.
+///
+/// An exact match ignoring case wins, then a candidate the name is a
+/// unique prefix of.
+///
+/// # Errors
+///
+/// Returns a message with "did you mean" suggestions when nothing matches
+/// or the prefix is ambiguous.
+pub fn match_service_name<'a>(
+    name: &str,
+    candidates: impl IntoIterator<Item = &'a str>,
+) -> std::result::Result<&'a str, String> {
+    let candidates: Vec<&str> = candidates.into_iter().collect();
+    let lower = name.to_lowercase();
+
+    if let Some(exact) = candidates.iter().find(|c| c.to_lowercase() == lower) {
+        return Ok(*exact);
+    }
+
+    let prefixed: Vec<&str> = candidates
+        .iter()
+        .copied()
+        .filter(|c| c.to_lowercase().starts_with(&lower))
+        .collect();
+    match prefixed.as_slice() {
+        [only] => return Ok(*only),
+        [] => {}
+        _ => {
+            return Err(format!(
+                "ambiguous service '{name}' (did you mean: {}?)",
+                prefixed.join(", ")
+            ))
+        }
+    }
+
+    let similar = find_similar(&lower, candidates.iter().copied());
+    if similar.is_empty() {
+        Err(format!(
+            "unknown service '{name}' (available: {})",
+            candidates.join(", ")
+        ))
+    } else {
+        Err(format!(
+            "unknown service '{name}' (did you mean: {}?)",
+            similar.join(", ")
+        ))
+    }
+}
+
+impl Default for ServiceCatalog {
+    fn default() -> Self {
+        Self::builtin()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_catalog_matches_known_services() {
+        let catalog = ServiceCatalog::builtin();
+        let names: Vec<_> = catalog.names().collect();
+        assert_eq!(names, vec!["obs", "shell", "theme-watcher"]);
+
+        let obs = catalog.get("fern-obs").unwrap();
+        assert_eq!(obs.binary(), "fern-obs");
+        assert_eq!(obs.start_cmd, vec!["fern-obs", "daemon"]);
+        assert_eq!(obs.known(), Some(KnownService::Obs));
+    }
+
+    #[test]
+    fn user_services_are_appended() {
+        let catalog = ServiceCatalog::from_toml(
+            r#"
+            [[service]]
+            name = "wallpaper"
+            start_cmd = ["/usr/bin/swww-daemon"]
+            stop_cmd = ["swww", "kill"]
+            health = { command = ["swww", "query"] }
+            "#,
+        )
+        .unwrap();
+
+        let names: Vec<_> = catalog.names().collect();
+        assert_eq!(names.last(), Some(&"wallpaper"));
+
+        let wallpaper = catalog.get("wallpaper").unwrap();
+        assert_eq!(wallpaper.binary(), "swww-daemon");
+        assert_eq!(wallpaper.display_name(), "wallpaper");
+        assert_eq!(
+            wallpaper.health,
+            HealthProbe::Command(vec!["swww".into(), "query".into()])
+        );
+        assert!(wallpaper.known().is_none());
+    }
+
+    #[test]
+    fn user_entry_overrides_builtin() {
+        let catalog = ServiceCatalog::from_toml(
+            r#"
+            [[service]]
+            name = "obs"
+            start_cmd = ["fern-obs", "daemon", "--port", "4456"]
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(catalog.iter().count(), KnownService::all().len());
+        assert_eq!(catalog.get("obs").unwrap().start_cmd.len(), 4);
+    }
+
+    #[test]
+    fn invalid_descriptors_are_rejected() {
+        assert!(ServiceCatalog::from_toml("[[service]]\nname = \"x\"\nstart_cmd = []\n").is_err());
+        assert!(ServiceCatalog::from_toml(
+            "[[service]]\nname = \"x\"\nstart_cmd = [\"a\"]\n[[service]]\nname = \"x\"\nstart_cmd = [\"b\"]\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn start_order_puts_dependencies_first() {
+        let catalog = ServiceCatalog::from_toml(
+            r#"
+            [[service]]
+            name = "bar"
+            start_cmd = ["waybar"]
+            depends_on = ["wallpaper", "fern-obs"]
+
+            [[service]]
+            name = "wallpaper"
+            start_cmd = ["swww-daemon"]
+            "#,
+        )
+        .unwrap();
+
+        let all: Vec<_> = catalog.iter().collect();
+        let order: Vec<_> = catalog
+            .start_order(&all)
+            .unwrap()
+            .iter()
+            .map(|s| s.name.as_str())
+            .collect();
+        assert_eq!(order, ["obs", "theme-watcher", "shell", "wallpaper", "bar"]);
+    }
+
+    #[test]
+    fn dependency_cycles_are_rejected() {
+        let error = ServiceCatalog::from_toml(
+            r#"
+            [[service]]
+            name = "a"
+            start_cmd = ["a"]
+            depends_on = ["b"]
+
+            [[service]]
+            name = "b"
+            start_cmd = ["b"]
+            depends_on = ["a"]
+            "#,
+        )
+        .unwrap_err();
+        assert!(error.to_string().contains("dependency cycle: a → b → a"));
+
+        assert!(ServiceCatalog::from_toml(
+            "[[service]]\nname = \"x\"\nstart_cmd = [\"x\"]\ndepends_on = [\"x\"]\n"
+        )
+        .is_err());
+        assert!(ServiceCatalog::from_toml(
+            "[[service]]\nname = \"x\"\nstart_cmd = [\"x\"]\ndepends_on = [\"nope\"]\n"
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn resolve_accepts_case_and_prefixes() {
+        let catalog = ServiceCatalog::from_toml(
+            r#"
+[[service]]
+name = "wallpaper"
+start_cmd = ["swww-daemon"]
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(catalog.resolve("OBS").unwrap().name, "obs");
+        assert_eq!(catalog.resolve("Fern-Obs").unwrap().name, "obs");
+        assert_eq!(catalog.resolve("theme-w").unwrap().name, "theme-watcher");
+        assert_eq!(catalog.resolve("wall").unwrap().name, "wallpaper");
+    }
+
+    #[test]
+    fn match_service_name_suggests_on_miss_and_ambiguity() {
+        let names = ["obs", "shell", "theme-watcher", "theme-sync"];
+
+        let ambiguous = match_service_name("theme-", names).unwrap_err();
+        assert!(ambiguous.contains("did you mean: theme-watcher, theme-sync?"));
+
+        let typo = match_service_name("shel1", names).unwrap_err();
+        assert!(typo.contains("did you mean: shell"));
+
+        let miss = match_service_name("zzz", names).unwrap_err();
+        assert!(miss.contains("available: obs, shell, theme-watcher, theme-sync"));
+    }
+
+    #[test]
+    fn missing_file_yields_builtins() {
+        let dir = tempfile::tempdir().unwrap();
+        let catalog = ServiceCatalog::load_from(&dir.path().join("services.toml")).unwrap();
+        assert_eq!(catalog, ServiceCatalog::builtin());
+    }
+}
@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::path::Path;
 
 /// Known services that fernctl can monitor and control.
 ///
@@ -79,6 +80,16 @@ impl KnownService {
         }
     }
 
+    /// Returns a compact name for space-constrained places like the footer.
+    #[must_use]
+    pub const fn short_name(&self) -> &'static str {
+        match self {
+            Self::Obs => "OBS",
+            Self::Shell => "Shell",
+            Self::ThemeWatcher => "Theme",
+        }
+    }
+
     /// Returns a short description of what this service does.
     #[must_use]
     pub const fn description(&self) -> &'static str {
@@ -104,6 +115,35 @@ impl KnownService {
         }
     }
 
+    /// Returns the state a freshly started, disconnected service writes.
+    ///
+    /// Used by `fernctl status --repair` to replace a corrupt state file.
+    #[must_use]
+    pub fn default_state(&self) -> serde_json::Value {
+        match self {
+            Self::Obs => serde_json::json!({
+                "connected": false,
+                "recording": { "active": false, "paused": false, "elapsed_secs": 0 },
+                "streaming": { "active": false, "elapsed_secs": 0, "reconnecting": false },
+                "scenes": [],
+            }),
+            Self::Shell => serde_json::json!([]),
+            Self::ThemeWatcher => serde_json::json!({ "watching": false }),
+        }
+    }
+
+    /// Returns the services this one needs running before it starts.
+    ///
+    /// The shell reads the OBS bridge's state and the theme watcher's
+    /// generated config, so it starts after both.
+    #[must_use]
+    pub const fn dependencies(&self) -> &'static [KnownService] {
+        match self {
+            Self::Obs | Self::ThemeWatcher => &[],
+            Self::Shell => &[Self::ThemeWatcher, Self::Obs],
+        }
+    }
+
     /// Returns whether this service should be automatically restarted.
     #[must_use]
     pub const fn auto_restart(&self) -> bool {
@@ -121,6 +161,60 @@ impl fmt::Display for KnownService {
     }
 }
 
+/// The outcome of reading a service's JSON state file.
+///
+/// A corrupt file (a partial write, or a bad manual edit) is reported
+/// rather than treated as an error, so one broken file never hides the
+/// status of the other services.
+#[derive(Debug, Clone, PartialEq)]
+pub enum StateRead {
+    /// No state file exists; the service has never run.
+    Missing,
+    /// The file exists but could not be read.
+    Unreadable(String),
+    /// The file is not valid JSON.
+    Corrupt(String),
+    /// The parsed state.
+    Valid(serde_json::Value),
+}
+
+impl StateRead {
+    /// Reads and parses the state file at `path`.
+    ///
+    /// Logs a warning when the file is unreadable or corrupt.
+    #[must_use]
+    pub fn read(path: &Path) -> Self {
+        let state = match std::fs::read_to_string(path) {
+            Ok(content) => Self::parse(&content),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Self::Missing,
+            Err(e) => Self::Unreadable(e.to_string()),
+        };
+
+        if let Self::Unreadable(e) | Self::Corrupt(e) = &state {
+            tracing::warn!("Ignoring state file {}: {e}", path.display());
+        }
+        state
+    }
+
+    /// Parses state file contents.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        match serde_json::from_str(content) {
+            Ok(json) => Self::Valid(json),
+            Err(e) => Self::Corrupt(e.to_string()),
+        }
+    }
+
+    /// Returns the parsed state, if the file was valid.
+    #[must_use]
+    pub const fn json(&self) -> Option<&serde_json::Value> {
+        match self {
+            Self::Valid(json) => Some(json),
+            _ => None,
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -157,4 +251,24 @@ mod tests {
             assert!(!service.state_file().is_empty());
         }
     }
+
+    #[test]
+    fn state_read_reports_corrupt_json() {
+        assert!(matches!(
+            StateRead::parse(r#"{"connected": tr"#),
+            StateRead::Corrupt(_)
+        ));
+        assert_eq!(
+            StateRead::parse(r#"{"connected": true}"#).json(),
+            Some(&serde_json::json!({ "connected": true }))
+        );
+    }
+
+    #[test]
+    fn state_read_missing_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let state = StateRead::read(&dir.path().join("obs-state.json"));
+        assert_eq!(state, StateRead::Missing);
+        assert!(state.json().is_none());
+    }
 }
@@ -0,0 +1,242 @@
+//! # Restart Policy
+//!
+//! Decides when a supervised service that exited should be restarted.
+//!
+//! Restarts back off exponentially from [`RestartPolicy::initial_backoff`]
+//! up to [`RestartPolicy::max_backoff`]. A service that stays up for at
+//! least `max_backoff` is considered healthy again and its backoff resets.
+//! The tracker gives up when the restart budget is spent or when the
+//! service exits too often within the crash-loop window.
+//!
+//! Time is passed in rather than read from the clock so the policy can be
+//! tested without sleeping.
+
+use std::collections::VecDeque;
+use std::time::{Duration, Instant};
+
+/// Restart behavior for supervised services.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Delay before the first restart.
+    pub initial_backoff: Duration,
+    /// Upper bound on the delay between restarts.
+    pub max_backoff: Duration,
+    /// Total restarts allowed, or `None` for no limit.
+    pub max_restarts: Option<u32>,
+    /// Window used by the crash-loop detector.
+    pub crash_loop_window: Duration,
+    /// Exits within the window that count as a crash loop.
+    pub crash_loop_threshold: u32,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+            max_restarts: None,
+            crash_loop_window: Duration::from_secs(60),
+            crash_loop_threshold: 5,
+        }
+    }
+}
+
+/// What to do after a service exits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RestartDecision {
+    /// Restart after the given delay.
+    Restart {
+        /// How long to wait before restarting.
+        delay: Duration,
+        /// Restart attempt number, starting at 1.
+        attempt: u32,
+    },
+    /// Stop supervising the service.
+    GiveUp(GiveUpReason),
+}
+
+/// Why the supervisor stopped restarting a service.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GiveUpReason {
+    /// The restart budget was spent.
+    MaxRestarts(u32),
+    /// The service exited too many times within the crash-loop window.
+    CrashLoop {
+        /// Exits observed within the window.
+        exits: u32,
+        /// Length of the window.
+        window: Duration,
+    },
+}
+
+impl std::fmt::Display for GiveUpReason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::MaxRestarts(n) => write!(f, "reached the limit of {n} restarts"),
+            Self::CrashLoop { exits, window } => write!(
+                f,
+                "crash loop detected ({exits} exits in {}s)",
+                window.as_secs()
+            ),
+        }
+    }
+}
+
+/// Tracks the exit history of one supervised service.
+#[derive(Debug, Clone)]
+pub struct RestartTracker {
+    policy: RestartPolicy,
+    restarts: u32,
+    consecutive_failures: u32,
+    recent_exits: VecDeque<Instant>,
+}
+
+impl RestartTracker {
+    /// Creates a tracker with the given policy.
+    #[must_use]
+    pub const fn new(policy: RestartPolicy) -> Self {
+        Self {
+            policy,
+            restarts: 0,
+            consecutive_failures: 0,
+            recent_exits: VecDeque::new(),
+        }
+    }
+
+    /// Returns the number of restarts so far.
+    #[must_use]
+    pub const fn restarts(&self) -> u32 {
+        self.restarts
+    }
+
+    /// Records an exit at `now` after the service ran for `uptime`.
+    pub fn on_exit(&mut self, now: Instant, uptime: Duration) -> RestartDecision {
+        self.recent_exits.push_back(now);
+        while self
+            .recent_exits
+            .front()
+            .is_some_and(|t| now.duration_since(*t) > self.policy.crash_loop_window)
+        {
+            self.recent_exits.pop_front();
+        }
+
+        let exits = u32::try_from(self.recent_exits.len()).unwrap_or(u32::MAX);
+        if exits >= self.policy.crash_loop_threshold {
+            return RestartDecision::GiveUp(GiveUpReason::CrashLoop {
+                exits,
+                window: self.policy.crash_loop_window,
+            });
+        }
+
+        if let Some(max) = self.policy.max_restarts {
+            if self.restarts >= max {
+                return RestartDecision::GiveUp(GiveUpReason::MaxRestarts(max));
+            }
+        }
+
+        if uptime >= self.policy.max_backoff {
+            self.consecutive_failures = 0;
+        }
+
+        let delay = self
+            .policy
+            .initial_backoff
+            .saturating_mul(2_u32.saturating_pow(self.consecutive_failures))
+            .min(self.policy.max_backoff);
+
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        self.restarts += 1;
+
+        RestartDecision::Restart {
+            delay,
+            attempt: self.restarts,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn policy() -> RestartPolicy {
+        RestartPolicy {
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(8),
+            max_restarts: None,
+            crash_loop_window: Duration::from_secs(60),
+            crash_loop_threshold: 100,
+        }
+    }
+
+    fn delay(decision: RestartDecision) -> Option<Duration> {
+        match decision {
+            RestartDecision::Restart { delay, .. } => Some(delay),
+            RestartDecision::GiveUp(_) => None,
+        }
+    }
+
+    #[test]
+    fn backoff_doubles_and_caps() {
+        let mut tracker = RestartTracker::new(policy());
+        let now = Instant::now();
+        let delays: Vec<_> = (0..6)
+            .map(|_| {
+                delay(tracker.on_exit(now, Duration::ZERO))
+                    .unwrap()
+                    .as_secs()
+            })
+            .collect();
+        assert_eq!(delays, vec![1, 2, 4, 8, 8, 8]);
+    }
+
+    #[test]
+    fn stable_run_resets_backoff() {
+        let mut tracker = RestartTracker::new(policy());
+        let now = Instant::now();
+        tracker.on_exit(now, Duration::ZERO);
+        tracker.on_exit(now, Duration::ZERO);
+
+        let d = delay(tracker.on_exit(now, Duration::from_secs(30)));
+        assert_eq!(d, Some(Duration::from_secs(1)));
+    }
+
+    #[test]
+    fn gives_up_after_max_restarts() {
+        let mut tracker = RestartTracker::new(RestartPolicy {
+            max_restarts: Some(2),
+            ..policy()
+        });
+        let now = Instant::now();
+        tracker.on_exit(now, Duration::ZERO);
+        tracker.on_exit(now, Duration::ZERO);
+
+        assert_eq!(
+            tracker.on_exit(now, Duration::ZERO),
+            RestartDecision::GiveUp(GiveUpReason::MaxRestarts(2))
+        );
+        assert_eq!(tracker.restarts(), 2);
+    }
+
+    #[test]
+    fn detects_crash_loop_within_window() {
+        let mut tracker = RestartTracker::new(RestartPolicy {
+            crash_loop_threshold: 3,
+            ..policy()
+        });
+        let start = Instant::now();
+
+        // Exits spread beyond the window don't count
+        tracker.on_exit(start, Duration::ZERO);
+        let later = start + Duration::from_secs(120);
+        assert!(matches!(
+            tracker.on_exit(later, Duration::ZERO),
+            RestartDecision::Restart { .. }
+        ));
+        tracker.on_exit(later, Duration::ZERO);
+
+        assert!(matches!(
+            tracker.on_exit(later, Duration::ZERO),
+            RestartDecision::GiveUp(GiveUpReason::CrashLoop { exits: 3, .. })
+        ));
+    }
+}
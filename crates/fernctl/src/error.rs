@@ -2,6 +2,7 @@
 //!
 //! Provides structured error types for all fernctl operations.
 
+use fern_theme::error::{FernError, FernExitCode};
 use miette::Diagnostic;
 use std::path::PathBuf;
 use thiserror::Error;
@@ -190,6 +191,24 @@ impl FernctlError {
         }
     }
 
+    /// Returns the process exit code for this error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | `Config`, `Parse` | [`FernExitCode::Config`] (1) |
+    /// | `State`, `Tui` | [`FernExitCode::Io`] (2) |
+    /// | `Watch` | [`FernExitCode::Watch`] (3) |
+    /// | `Service`, `Process` | [`FernExitCode::Service`] (5) |
+    #[must_use]
+    pub const fn exit_code(&self) -> FernExitCode {
+        match self {
+            Self::Config { .. } | Self::Parse { .. } => FernExitCode::Config,
+            Self::State { .. } | Self::Tui { .. } => FernExitCode::Io,
+            Self::Watch { .. } => FernExitCode::Watch,
+            Self::Service { .. } | Self::Process { .. } => FernExitCode::Service,
+        }
+    }
+
     /// Creates a generic IO error.
     pub fn io(message: impl Into<String>, source: std::io::Error) -> Self {
         Self::State {
@@ -200,10 +219,58 @@ impl FernctlError {
     }
 }
 
-impl From<fern_theme::error::FernError> for FernctlError {
-    fn from(err: fern_theme::error::FernError) -> Self {
-        FernctlError::Config {
-            message: err.to_string(),
+impl From<&FernctlError> for FernExitCode {
+    fn from(err: &FernctlError) -> Self {
+        err.exit_code()
+    }
+}
+
+// Keep I/O and watch failures distinct so they exit with their own codes
+impl From<FernError> for FernctlError {
+    fn from(err: FernError) -> Self {
+        match err {
+            FernError::Io { message, source } => Self::io(message, source),
+            FernError::Watch { message } => Self::watch(message),
+            err => Self::Config {
+                message: err.to_string(),
+            },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn exit_codes_follow_error_kind() {
+        assert_eq!(
+            FernctlError::config("bad").exit_code(),
+            FernExitCode::Config
+        );
+        assert_eq!(
+            FernctlError::parse("obs-state.json", "eof").exit_code(),
+            FernExitCode::Config
+        );
+        assert_eq!(FernctlError::state("missing").exit_code(), FernExitCode::Io);
+        assert_eq!(
+            FernctlError::watch("inotify").exit_code(),
+            FernExitCode::Watch
+        );
+        assert_eq!(
+            FernctlError::service("obs", "not installed").exit_code(),
+            FernExitCode::Service
+        );
+        assert_eq!(
+            FernctlError::process("no pid").exit_code(),
+            FernExitCode::Service
+        );
+    }
+
+    #[test]
+    fn theme_io_errors_keep_their_exit_code() {
+        let err: FernctlError =
+            FernError::io("reading config", std::io::Error::other("denied")).into();
+        assert_eq!(err.exit_code(), FernExitCode::Io);
+    }
+}
@@ -18,6 +18,7 @@
 //! ├─────────────────────────────────────────────────────────────────────┤
 //! │  obs-state.json  │ OBS daemon state (recording, streaming)         │
 //! │  shell-log.json  │ QML shell logs (ring buffer)                    │
+//! │  obs-log.json    │ OBS daemon logs (ring buffer, --log-to-file)    │
 //! │  services.json   │ Service registry (managed by fernctl)           │
 //! └─────────────────────────────────────────────────────────────────────┘
 //!           ▲                    ▲                    ▲
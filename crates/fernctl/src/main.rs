@@ -5,6 +5,10 @@
 //! ## Usage
 //!
 //! ```bash
+//! # Create ~/.config/fern/config.toml with commented defaults
+//! fernctl init
+//! fernctl init --convert      # also write config.json
+//!
 //! # Show service status
 //! fernctl status
 //! fernctl status obs
@@ -13,26 +17,66 @@
 //! fernctl logs
 //! fernctl logs -f
 //! fernctl logs --service obs
+//! fernctl logs --since 5m
 //!
 //! # Launch TUI dashboard
 //! fernctl tui
 //!
+//! # Print the dashboard once (for tmux panes or screenshots)
+//! fernctl dashboard --snapshot --width 100 --height 30
+//!
 //! # Control services
-//! fernctl obs start
-//! fernctl obs stop
-//! fernctl obs restart
+//! fernctl service obs start
+//! fernctl service wallpaper restart
+//! fernctl obs stop            # same as `service obs stop`
+//!
+//! # Supervise services without systemd
+//! fernctl watch-services --max-restarts 10
+//! fernctl watch-services --service obs --service wallpaper
 //!
 //! # Reload shell configuration
 //! fernctl reload
 //!
+//! # Switch theme and reload in one step
+//! fernctl reload --theme light
+//!
 //! # Theme management
 //! fernctl theme apply catppuccin-mocha
 //! fernctl theme current
+//! fernctl theme rollback            # restore config.json.bak and reload
+//! fernctl theme rollback --list
+//! fernctl theme list
+//! fernctl theme simulate --type deuteranopia
+//! fernctl theme import --format base16 mocha.yaml -o config.toml
+//! fernctl theme preview --out theme.png   # requires the `preview` feature
+//!
+//! # JSON Schema for editor validation (requires the `schema` feature)
+//! fernctl schema > ~/.config/fern/config.schema.json
+//!
+//! # Shell completions, including theme names
+//! source <(fernctl completions bash)
 //! ```
+//!
+//! ## Exit Codes
+//!
+//! Shared with `fern-theme` through [`FernExitCode`]:
+//!
+//! | Code | Meaning |
+//! |------|---------|
+//! | `0` | Success |
+//! | `1` | Configuration or parse error |
+//! | `2` | I/O error (state files, terminal) |
+//! | `3` | Watch system error |
+//! | `5` | Service control error |
 
-use clap::{Parser, Subcommand};
-use fernctl::cli::{logs, obs, reload, status, theme};
-use fernctl::error::Result;
+use clap::{CommandFactory, Parser, Subcommand};
+use fern_core::completions::{self, DynamicValues};
+use fern_theme::error::{exit_with, FernExitCode};
+use fernctl::adapters::DEFAULT_KEEP_BACKUPS;
+use fernctl::cli::{init, logs, obs, reload, service, status, supervise, theme};
+use fernctl::domain::parse_since;
+use fernctl::error::{FernctlError, Result};
+use std::process::ExitCode;
 
 #[cfg(feature = "tui")]
 use fernctl::tui::TuiApp;
@@ -66,17 +110,62 @@ enum Commands {
     /// Launch interactive TUI dashboard.
     ///
     /// Opens a full-screen terminal UI with service status, logs,
-    /// and configuration panels. Use Tab to navigate, q to quit.
+    /// and configuration panels. Use Tab and Shift-Tab to move between
+    /// panels, q to quit.
+    /// The focused panel, log line count, and follow mode are restored
+    /// from ~/.config/fern/tui.toml and saved there on exit.
+    /// Colors follow the theme set in config.toml.
+    /// With --snapshot, renders the dashboard once to stdout and exits.
     #[cfg(feature = "tui")]
-    Tui,
+    #[command(visible_alias = "dashboard")]
+    Tui {
+        /// Render once to stdout instead of opening the interactive UI.
+        #[arg(long)]
+        snapshot: bool,
+
+        /// Snapshot width in columns (defaults to the terminal width).
+        #[arg(long, requires = "snapshot")]
+        width: Option<u16>,
+
+        /// Snapshot height in rows (defaults to the terminal height).
+        #[arg(long, requires = "snapshot")]
+        height: Option<u16>,
+
+        /// Don't write preferences to tui.toml on exit.
+        #[arg(long, conflicts_with = "snapshot")]
+        no_save_prefs: bool,
+    },
+
+    /// Create a starter config.
+    ///
+    /// Writes ~/.config/fern/config.toml with every setting at its default
+    /// and a comment explaining it. Refuses to replace an existing file
+    /// unless --force is given.
+    Init {
+        /// Overwrite an existing config.toml.
+        #[arg(long)]
+        force: bool,
+
+        /// Also generate config.json for the shell.
+        #[arg(long)]
+        convert: bool,
+    },
 
     /// Show service status.
     ///
     /// Displays the current status of all services or a specific service.
     /// Shows connection state, activity, and health information.
+    /// With `--output json` and no service, prints one JSON array with an
+    /// entry per service, for status bar modules.
     Status {
         /// Specific service to show (obs, shell, theme-watcher).
+        ///
+        /// Case-insensitive; a unique prefix such as `theme` is enough.
         service: Option<String>,
+
+        /// Replace corrupt state files with a disconnected default.
+        #[arg(long)]
+        repair: bool,
     },
 
     /// View aggregated logs.
@@ -88,20 +177,44 @@ enum Commands {
         #[arg(short, long)]
         follow: bool,
 
-        /// Filter by service name.
+        /// Filter by service name (case-insensitive, unique prefixes allowed).
         #[arg(short, long)]
         service: Option<String>,
 
         /// Number of lines to show.
         #[arg(short = 'n', long, default_value = "50")]
         lines: usize,
+
+        /// Only show logs newer than a duration (5m, 1h) or RFC 3339 time.
+        #[arg(long)]
+        since: Option<String>,
     },
 
     /// Reload QuickShell configuration.
     ///
     /// Sends a reload signal to QuickShell, causing it to re-read
     /// the configuration file without restarting.
-    Reload,
+    ///
+    /// With --theme, validates and applies the theme first; nothing is
+    /// written if the theme is invalid or QuickShell is not running.
+    Reload {
+        /// Theme to switch to before reloading (see `theme list`).
+        #[arg(long)]
+        theme: Option<String>,
+    },
+
+    /// Control any registered service.
+    ///
+    /// Works for the built-in services and for user-defined services
+    /// declared in ~/.config/fern/services.toml.
+    Service {
+        /// Service name (e.g. obs, shell, or a name from services.toml).
+        name: String,
+
+        /// Action to perform.
+        #[command(subcommand)]
+        action: ServiceCommands,
+    },
 
     /// OBS daemon control.
     ///
@@ -110,7 +223,23 @@ enum Commands {
     Obs {
         /// Action to perform.
         #[command(subcommand)]
-        action: ObsCommands,
+        action: ServiceCommands,
+    },
+
+    /// Supervise services and restart them when they crash.
+    ///
+    /// Starts each service once the services it depends on are healthy,
+    /// restarts it with exponential backoff when it exits, and gives up
+    /// (with a desktop notification) after --max-restarts or when it
+    /// crash-loops. Events go to the shared log.
+    WatchServices {
+        /// Service to supervise (repeatable). Defaults to all services.
+        #[arg(long = "service", value_name = "NAME")]
+        services: Vec<String>,
+
+        /// Give up on a service after this many restarts.
+        #[arg(long)]
+        max_restarts: Option<u32>,
     },
 
     /// Theme management.
@@ -121,43 +250,144 @@ enum Commands {
         #[command(subcommand)]
         action: ThemeCommands,
     },
+
+    /// Print a JSON Schema for config.toml.
+    ///
+    /// Point a schema-aware editor at the output for inline validation
+    /// and autocomplete.
+    #[cfg(feature = "schema")]
+    Schema,
+
+    /// Print a shell completion script to stdout.
+    ///
+    /// Theme names for `theme apply` are completed from `fernctl theme list`.
+    Completions {
+        /// Shell to generate the script for.
+        #[arg(value_enum)]
+        shell: completions::Shell,
+    },
+
+    /// Write man pages for fernctl and each subcommand.
+    #[command(hide = true)]
+    Man {
+        /// Directory to write the pages to.
+        #[arg(long, default_value = "man")]
+        out_dir: std::path::PathBuf,
+    },
 }
 
-/// OBS subcommands
+/// Values completed at runtime by `fernctl completions`.
+const DYNAMIC_COMPLETIONS: [DynamicValues; 1] = [DynamicValues {
+    subcommand: "apply",
+    option: None,
+    command: "fernctl theme list",
+}];
+
+/// Service subcommands (shared by `service` and `obs`)
 #[derive(Subcommand, Debug)]
-enum ObsCommands {
-    /// Start the OBS daemon.
+enum ServiceCommands {
+    /// Start the service.
     Start,
-    /// Stop the OBS daemon.
+    /// Stop the service.
     Stop,
-    /// Restart the OBS daemon.
+    /// Restart the service.
     Restart,
-    /// Show OBS daemon status.
+    /// Show service status.
     Status,
 }
 
+impl From<ServiceCommands> for service::ServiceAction {
+    fn from(command: ServiceCommands) -> Self {
+        match command {
+            ServiceCommands::Start => Self::Start,
+            ServiceCommands::Stop => Self::Stop,
+            ServiceCommands::Restart => Self::Restart,
+            ServiceCommands::Status => Self::Status,
+        }
+    }
+}
+
 /// Theme subcommands
 #[derive(Subcommand, Debug)]
 enum ThemeCommands {
     /// Apply a theme by name.
+    ///
+    /// The previous config.json is backed up first; see `rollback`.
     Apply {
         /// Theme name to apply.
         name: String,
+
+        /// Number of timestamped config.json backups to keep.
+        #[arg(long, default_value_t = DEFAULT_KEEP_BACKUPS)]
+        keep_backups: usize,
+    },
+    /// Restore config.json from a backup and reload the shell.
+    ///
+    /// Restores config.json.bak (the config before the last theme change)
+    /// unless a backup from `rollback --list` is named.
+    Rollback {
+        /// Backup file name from `--list`, or a path.
+        backup: Option<String>,
+
+        /// List the timestamped backups, newest first.
+        #[arg(long, conflicts_with = "backup")]
+        list: bool,
     },
     /// Validate the current configuration.
     Validate,
     /// Show the current theme.
     Current,
+    /// List theme names accepted by `apply`.
+    List,
     /// Query a specific theme value.
     Query {
         /// Path to query (e.g., colors.background, bar.height).
         path: String,
     },
+    /// Preview the palette as seen with color blindness.
+    Simulate {
+        /// Deficiency to simulate: protanopia, deuteranopia, or tritanopia.
+        /// Shows all three when omitted.
+        #[arg(long = "type", value_name = "TYPE")]
+        kind: Option<String>,
+    },
+    /// Generate a config.toml from a Base16 color scheme.
+    ///
+    /// Colors without an exact Base16 equivalent are marked as guessed in
+    /// the output. Prints to stdout unless --output is given.
+    Import {
+        /// Scheme file to import.
+        path: std::path::PathBuf,
+
+        /// Scheme format.
+        #[arg(long, default_value = "base16")]
+        format: String,
+
+        /// Write the config here instead of stdout. Refuses to overwrite.
+        #[arg(short, long)]
+        output: Option<std::path::PathBuf>,
+    },
+    /// Render the current theme to a PNG swatch sheet.
+    ///
+    /// Shows a mock bar, every palette color with its hex value, and text
+    /// contrast ratios.
+    #[cfg(feature = "preview")]
+    Preview {
+        /// PNG file to write.
+        #[arg(long, default_value = "theme.png")]
+        out: std::path::PathBuf,
+    },
 }
 
-fn main() -> Result<()> {
-    let cli = Cli::parse();
+fn main() -> ExitCode {
+    match run(Cli::parse()) {
+        Ok(()) => FernExitCode::Success.into(),
+        Err(e) => exit_with(e),
+    }
+}
 
+/// Runs the selected command.
+fn run(cli: Cli) -> Result<()> {
     // Set up tracing based on verbosity
     if cli.verbose {
         tracing_subscriber::fmt()
@@ -172,16 +402,33 @@ fn main() -> Result<()> {
 
     match cli.command {
         #[cfg(feature = "tui")]
-        Commands::Tui => {
-            let mut app = TuiApp::new();
-            app.run()?;
+        Commands::Tui {
+            snapshot,
+            width,
+            height,
+            no_save_prefs,
+        } => {
+            if snapshot {
+                fernctl::tui::snapshot::run(&fernctl::tui::snapshot::SnapshotOptions {
+                    width,
+                    height,
+                })?;
+            } else {
+                let mut app = TuiApp::new().with_save_prefs(!no_save_prefs);
+                app.run()?;
+            }
+        }
+
+        Commands::Init { force, convert } => {
+            init::run(init::InitOptions { force, convert })?;
         }
 
-        Commands::Status { service } => {
+        Commands::Status { service, repair } => {
             status::run(status::StatusOptions {
                 service,
                 format: output_format,
                 verbose: cli.verbose,
+                repair,
             })?;
         }
 
@@ -189,35 +436,67 @@ fn main() -> Result<()> {
             follow,
             service,
             lines,
+            since,
         } => {
+            let since = since
+                .map(|s| parse_since(&s, chrono::Utc::now()).map_err(FernctlError::config))
+                .transpose()?;
             logs::run(logs::LogsOptions {
                 follow,
                 service,
                 lines,
                 level: None,
+                since,
+                format: output_format,
             })?;
         }
 
-        Commands::Reload => {
-            reload::run()?;
+        Commands::Reload { theme } => {
+            reload::run(theme.as_deref())?;
+        }
+
+        Commands::Service { name, action } => {
+            service::run(&name, action.into())?;
         }
 
         Commands::Obs { action } => {
-            let obs_action = match action {
-                ObsCommands::Start => obs::ObsAction::Start,
-                ObsCommands::Stop => obs::ObsAction::Stop,
-                ObsCommands::Restart => obs::ObsAction::Restart,
-                ObsCommands::Status => obs::ObsAction::Status,
-            };
-            obs::run(obs_action)?;
+            obs::run(action.into())?;
+        }
+
+        Commands::WatchServices {
+            services,
+            max_restarts,
+        } => {
+            supervise::run(&supervise::SupervisorOptions {
+                services,
+                max_restarts,
+            })?;
         }
 
         Commands::Theme { action } => {
             let theme_action = match action {
-                ThemeCommands::Apply { name } => theme::ThemeAction::Apply { name },
+                ThemeCommands::Apply { name, keep_backups } => {
+                    theme::ThemeAction::Apply { name, keep_backups }
+                }
+                ThemeCommands::Rollback { backup, list } => {
+                    theme::ThemeAction::Rollback { backup, list }
+                }
                 ThemeCommands::Validate => theme::ThemeAction::Validate,
                 ThemeCommands::Current => theme::ThemeAction::Current,
+                ThemeCommands::List => theme::ThemeAction::List,
                 ThemeCommands::Query { path } => theme::ThemeAction::Query { path },
+                ThemeCommands::Simulate { kind } => theme::ThemeAction::Simulate { kind },
+                ThemeCommands::Import {
+                    path,
+                    format,
+                    output,
+                } => theme::ThemeAction::Import {
+                    path,
+                    format,
+                    output,
+                },
+                #[cfg(feature = "preview")]
+                ThemeCommands::Preview { out } => theme::ThemeAction::Preview { out },
             };
             theme::run(theme::ThemeOptions {
                 action: theme_action,
@@ -225,6 +504,29 @@ fn main() -> Result<()> {
                 json: output_format == status::OutputFormat::Json,
             })?;
         }
+
+        #[cfg(feature = "schema")]
+        Commands::Schema => {
+            println!("{}", fern_theme::commands::schema::run());
+        }
+
+        Commands::Completions { shell } => {
+            completions::generate(
+                shell,
+                &mut Cli::command(),
+                &DYNAMIC_COMPLETIONS,
+                &mut std::io::stdout(),
+            )
+            .map_err(|e| FernctlError::io("writing completions", e))?;
+        }
+
+        Commands::Man { out_dir } => {
+            let pages = fern_core::man::generate_to(Cli::command(), &out_dir)
+                .map_err(|e| FernctlError::io("writing man pages", e))?;
+            for page in pages {
+                println!("{}", page.display());
+            }
+        }
     }
 
     Ok(())
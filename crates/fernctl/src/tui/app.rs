@@ -2,24 +2,31 @@
 //!
 //! Main TUI event loop and rendering.
 
-use crate::adapters::{reload_shell, ServiceController, StateWatcher};
-use crate::domain::{Action, AppState, KnownService};
+use crate::adapters::{check_config, reload_shell, ServiceController, StateWatcher};
+use crate::domain::{
+    parse_log_file, service_from_log_path, Action, AppState, Command, KnownService, LogEntry,
+    TuiPrefs, LOG_FILES,
+};
 use crate::error::{FernctlError, Result};
-use crate::tui::event::{key_to_action, poll_event};
-use crate::tui::layout::AppLayout;
-use crate::tui::widgets::{ConfigPanel, HelpPanel, LogsPanel, ServicesPanel};
+use crate::tui::event::{event_to_action, poll_event};
+use crate::tui::keymap::footer_hints;
+use crate::tui::layout::{AppLayout, LayoutMode, MIN_HEIGHT, MIN_WIDTH};
+use crate::tui::palette::Palette;
+use crate::tui::widgets::services_panel::status_indicator;
+use crate::tui::widgets::{ConfigErrorPanel, ConfigPanel, HelpPanel, LogsPanel, ServicesPanel};
 use crossterm::{
-    event::Event,
+    event::{DisableMouseCapture, EnableMouseCapture},
     execute,
     terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
 };
 use fern_core::FernPaths;
+use fern_theme::adapters::TomlConfigAdapter;
 use ratatui::{
     backend::CrosstermBackend,
     layout::{Alignment, Constraint, Direction, Layout, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::Paragraph,
+    widgets::{Block, Paragraph, Wrap},
     Frame, Terminal,
 };
 use std::io::{self, Stdout};
@@ -33,21 +40,46 @@ pub struct TuiApp {
     service_controller: ServiceController,
     state_watcher: Option<StateWatcher>,
     terminal: Option<Terminal<CrosstermBackend<Stdout>>>,
+    palette: Palette,
+    save_prefs: bool,
 }
 
 impl TuiApp {
     /// Creates a new TUI application.
+    ///
+    /// Restores the focused panel and log view from
+    /// [`tui.toml`](FernPaths::tui_toml). An unreadable file is reported
+    /// in the status bar and the defaults are used. Colors come from the
+    /// theme in `config.toml`; see [`Palette::load`].
     #[must_use]
     pub fn new() -> Self {
         let paths = FernPaths::new();
+        let palette = Palette::load(&paths);
+        let mut state = AppState::with_paths(paths.clone());
+        match TuiPrefs::load(&paths.tui_toml()) {
+            Ok(prefs) => prefs.apply(&mut state),
+            Err(e) => {
+                state.update(Action::Error(e.to_string()));
+            }
+        }
+
         Self {
-            state: AppState::with_paths(paths.clone()),
+            state,
             service_controller: ServiceController::new(paths),
             state_watcher: None,
             terminal: None,
+            palette,
+            save_prefs: true,
         }
     }
 
+    /// Sets whether preferences are written back to `tui.toml` on exit.
+    #[must_use]
+    pub fn with_save_prefs(mut self, save_prefs: bool) -> Self {
+        self.save_prefs = save_prefs;
+        self
+    }
+
     /// Runs the TUI application.
     ///
     /// This is the main event loop that handles input, state updates,
@@ -65,7 +97,11 @@ impl TuiApp {
 
         self.restore_terminal()?;
 
-        result
+        result?;
+        if self.save_prefs {
+            TuiPrefs::from_state(&self.state).save(&self.state.paths.tui_toml())?;
+        }
+        Ok(())
     }
 
     /// Sets up the terminal for TUI rendering.
@@ -73,7 +109,7 @@ impl TuiApp {
         enable_raw_mode().map_err(|e| FernctlError::tui_io("enabling raw mode", e))?;
 
         let mut stdout = io::stdout();
-        execute!(stdout, EnterAlternateScreen)
+        execute!(stdout, EnterAlternateScreen, EnableMouseCapture)
             .map_err(|e| FernctlError::tui_io("entering alternate screen", e))?;
 
         let backend = CrosstermBackend::new(stdout);
@@ -89,8 +125,12 @@ impl TuiApp {
         if let Some(ref mut terminal) = self.terminal {
             disable_raw_mode().map_err(|e| FernctlError::tui_io("disabling raw mode", e))?;
 
-            execute!(terminal.backend_mut(), LeaveAlternateScreen)
-                .map_err(|e| FernctlError::tui_io("leaving alternate screen", e))?;
+            execute!(
+                terminal.backend_mut(),
+                DisableMouseCapture,
+                LeaveAlternateScreen
+            )
+            .map_err(|e| FernctlError::tui_io("leaving alternate screen", e))?;
 
             terminal
                 .show_cursor()
@@ -110,32 +150,7 @@ impl TuiApp {
 
     /// Loads initial state from files.
     fn load_initial_state(&mut self) -> Result<()> {
-        // Load config
-        let config_path = self.state.paths.config_json();
-        if config_path.exists() {
-            if let Ok(content) = std::fs::read_to_string(&config_path) {
-                if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
-                    let summary = crate::domain::action::ConfigSummary::from_json(&json);
-                    self.state.update(Action::ConfigChanged(summary));
-                }
-            }
-        }
-
-        // Update service status
-        for service in KnownService::all() {
-            let is_running = self.service_controller.is_running(*service);
-            let status = if is_running {
-                fern_core::ServiceStatus::Running
-            } else {
-                fern_core::ServiceStatus::Stopped
-            };
-            let info = fern_core::ServiceInfo::new(service.name(), status);
-            self.state.update(Action::ServiceStateChanged {
-                name: service.name().to_string(),
-                info,
-            });
-        }
-
+        load_state(&mut self.state, &self.service_controller);
         Ok(())
     }
 
@@ -147,23 +162,24 @@ impl TuiApp {
 
             // Poll for events
             if let Some(event) = poll_event(Duration::from_millis(100)) {
-                if let Event::Key(key) = event {
-                    let action =
-                        key_to_action(key, self.state.focus, self.state.selected_service);
-
-                    if let Some(action) = action {
-                        self.handle_action(action)?;
-                    }
+                let area = self.terminal_area();
+                if let Some(action) = event_to_action(&event, &self.state, area) {
+                    self.dispatch(action);
                 }
             }
 
             // Check for state file changes
-            if let Some(ref watcher) = self.state_watcher {
-                while let Some(change) = watcher.try_recv() {
-                    if let Some(action) = StateWatcher::to_action(change) {
-                        self.state.update(action);
-                    }
-                }
+            let changes: Vec<Action> = self
+                .state_watcher
+                .as_ref()
+                .map(|watcher| {
+                    std::iter::from_fn(|| watcher.try_recv())
+                        .filter_map(StateWatcher::to_action)
+                        .collect()
+                })
+                .unwrap_or_default();
+            for action in changes {
+                self.dispatch(action);
             }
 
             // Check for quit
@@ -178,45 +194,48 @@ impl TuiApp {
         Ok(())
     }
 
-    /// Handles an action.
-    fn handle_action(&mut self, action: Action) -> Result<()> {
-        match &action {
-            Action::StartService(service) => {
-                if let Err(e) = self.service_controller.start(*service) {
-                    self.state.update(Action::Error(e.to_string()));
-                    return Ok(());
-                }
-            }
-            Action::StopService(service) => {
-                if let Err(e) = self.service_controller.stop(*service) {
-                    self.state.update(Action::Error(e.to_string()));
-                    return Ok(());
-                }
-            }
-            Action::RestartService(service) => {
-                if let Err(e) = self.service_controller.restart(*service) {
-                    self.state.update(Action::Error(e.to_string()));
-                    return Ok(());
-                }
-            }
-            Action::ReloadShell => {
-                if let Err(e) = reload_shell() {
-                    self.state.update(Action::Error(e.to_string()));
-                    return Ok(());
+    /// Applies an action, then performs any command it returns.
+    ///
+    /// The outcome of a command comes back as another action, so this
+    /// loops until the state settles.
+    fn dispatch(&mut self, action: Action) {
+        let mut next = Some(action);
+        while let Some(action) = next {
+            next = self
+                .state
+                .update(action)
+                .and_then(|command| self.execute(command));
+        }
+    }
+
+    /// Performs a command, returning the action that reports a failure.
+    fn execute(&mut self, command: Command) -> Option<Action> {
+        let (service, result) = match command {
+            Command::StartService(service) => (service, self.service_controller.start(service)),
+            Command::StopService(service) => (service, self.service_controller.stop(service)),
+            Command::RestartService(service) => (service, self.service_controller.restart(service)),
+            Command::ReloadShell => {
+                let config_toml = self.state.paths.config_toml();
+                if config_toml.exists() {
+                    if let Some(problem) = check_config(&config_toml, &TomlConfigAdapter::new()) {
+                        return Some(Action::ConfigInvalid(problem));
+                    }
                 }
+                return reload_shell().err().map(|e| Action::Error(e.to_string()));
             }
-            _ => {}
-        }
+        };
 
-        // Update state
-        self.state.update(action);
-        Ok(())
+        result.err().map(|e| Action::ServiceCommandFailed {
+            service,
+            message: e.to_string(),
+        })
     }
 
     /// Renders the TUI.
     fn render(&mut self) -> Result<()> {
         // Extract state needed for rendering to avoid borrow issues
         let state = &self.state;
+        let palette = &self.palette;
 
         let terminal = self
             .terminal
@@ -225,13 +244,19 @@ impl TuiApp {
 
         terminal
             .draw(|frame| {
-                render_frame(frame, state);
+                render_frame(frame, state, palette);
             })
             .map_err(|e| FernctlError::tui_io("drawing frame", e))?;
 
         Ok(())
     }
 
+    /// Returns the current terminal area, if the terminal is set up.
+    fn terminal_area(&self) -> Option<Rect> {
+        let size = self.terminal.as_ref()?.size().ok()?;
+        Some(Rect::new(0, 0, size.width, size.height))
+    }
+
     /// Returns a reference to the application state.
     #[must_use]
     pub fn state(&self) -> &AppState {
@@ -239,7 +264,7 @@ impl TuiApp {
     }
 
     /// Returns a mutable reference to the application state.
-    pub fn state_mut(&mut self) -> &mut AppState {
+    pub const fn state_mut(&mut self) -> &mut AppState {
         &mut self.state
     }
 }
@@ -250,25 +275,92 @@ impl Default for TuiApp {
     }
 }
 
+/// Loads config, service status, and existing logs into `state`.
+///
+/// Missing or unreadable files are skipped; the watcher picks them up
+/// once they appear.
+pub(crate) fn load_state(state: &mut AppState, service_controller: &ServiceController) {
+    // Load config
+    let config_path = state.paths.config_json();
+    if let Ok(content) = std::fs::read_to_string(&config_path) {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(&content) {
+            let summary = crate::domain::action::ConfigSummary::from_json(&json);
+            state.update(Action::ConfigChanged(summary));
+        }
+    }
+
+    // Update service status
+    for service in KnownService::all() {
+        let status = if service_controller.is_running(*service) {
+            fern_core::ServiceStatus::Running
+        } else {
+            fern_core::ServiceStatus::Stopped
+        };
+        let info = fern_core::ServiceInfo::new(service.name(), status);
+        state.update(Action::ServiceStateChanged {
+            name: service.name().to_string(),
+            info,
+        });
+    }
+
+    // Load existing logs, oldest first
+    let mut entries = Vec::new();
+    for name in LOG_FILES {
+        let path = state.paths.state_dir().join(name);
+        if let Ok(content) = std::fs::read_to_string(&path) {
+            entries.extend(parse_log_file(&content, &service_from_log_path(&path)));
+        }
+    }
+    entries.sort_by_key(LogEntry::time);
+    state.update(Action::LogsSync(entries));
+}
+
 /// Renders a single frame.
-fn render_frame(frame: &mut Frame, state: &AppState) {
-    let layout = AppLayout::new(frame.area());
+pub(crate) fn render_frame(frame: &mut Frame, state: &AppState, palette: &Palette) {
+    let layout = AppLayout::new(frame.area(), state.focus);
+
+    // Background
+    frame.render_widget(
+        Block::default().style(
+            Style::default()
+                .bg(palette.background)
+                .fg(palette.foreground),
+        ),
+        frame.area(),
+    );
+
+    if layout.mode == LayoutMode::TooSmall {
+        render_too_small(frame, layout.area, palette);
+        return;
+    }
 
     // Header
-    render_header(frame, layout.header, state);
+    render_header(frame, layout.header, state, palette);
 
     // Panels
-    frame.render_widget(ServicesPanel::new(state), layout.services);
-    frame.render_widget(LogsPanel::new(state), layout.logs);
-    frame.render_widget(ConfigPanel::new(state), layout.config);
+    if let Some(area) = layout.services {
+        frame.render_widget(ServicesPanel::new(state, palette), area);
+    }
+    if let Some(area) = layout.logs {
+        frame.render_widget(LogsPanel::new(state, palette), area);
+    }
+    if let Some(area) = layout.config {
+        frame.render_widget(ConfigPanel::new(state, palette), area);
+    }
 
     // Footer
-    render_footer(frame, layout.footer);
+    render_footer(frame, layout.footer, state, palette);
 
     // Help overlay
     if state.show_help {
         let help_area = centered_rect(60, 70, frame.area());
-        frame.render_widget(HelpPanel::new(), help_area);
+        frame.render_widget(HelpPanel::new(palette), help_area);
+    }
+
+    // Config error dialog
+    if let Some(ref problem) = state.config_error {
+        let dialog_area = centered_rect(60, 40, frame.area());
+        frame.render_widget(ConfigErrorPanel::new(problem, palette), dialog_area);
     }
 
     // Error message
@@ -280,13 +372,35 @@ fn render_frame(frame: &mut Frame, state: &AppState) {
             1,
         );
         let error_text =
-            Paragraph::new(format!("Error: {}", error)).style(Style::default().fg(Color::Red));
+            Paragraph::new(format!("Error: {}", error)).style(Style::default().fg(palette.error));
         frame.render_widget(error_text, error_area);
     }
 }
 
+/// Renders the "terminal too small" message in place of the dashboard.
+fn render_too_small(frame: &mut Frame, area: Rect, palette: &Palette) {
+    let message = Paragraph::new(vec![
+        Line::from(Span::styled(
+            "Terminal too small",
+            Style::default()
+                .fg(palette.warning)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(format!(
+            "{}x{} (need {MIN_WIDTH}x{MIN_HEIGHT})",
+            area.width, area.height
+        )),
+    ])
+    .alignment(Alignment::Center)
+    .wrap(Wrap { trim: true });
+
+    let y = area.height.saturating_sub(2) / 2;
+    let message_area = Rect::new(area.x, area.y + y, area.width, area.height - y);
+    frame.render_widget(message, message_area);
+}
+
 /// Renders the header.
-fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
+fn render_header(frame: &mut Frame, area: Rect, state: &AppState, palette: &Palette) {
     let running = state.running_service_count();
     let total = KnownService::all().len();
 
@@ -298,13 +412,13 @@ fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
     let header = Paragraph::new(title)
         .style(
             Style::default()
-                .fg(Color::Cyan)
+                .fg(palette.accent)
                 .add_modifier(Modifier::BOLD),
         )
         .alignment(Alignment::Left);
 
     let help_hint = Paragraph::new("[?] Help")
-        .style(Style::default().fg(Color::DarkGray))
+        .style(palette.dim())
         .alignment(Alignment::Right);
 
     // Split header area
@@ -317,27 +431,48 @@ fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
     frame.render_widget(help_hint, chunks[1]);
 }
 
-/// Renders the footer.
-fn render_footer(frame: &mut Frame, area: Rect) {
-    let footer = Paragraph::new(Line::from(vec![
-        Span::styled("[Tab]", Style::default().fg(Color::Cyan)),
-        Span::raw(" Switch  "),
-        Span::styled("[j/k]", Style::default().fg(Color::Cyan)),
-        Span::raw(" Navigate  "),
-        Span::styled("[s]", Style::default().fg(Color::Cyan)),
-        Span::raw("tart  "),
-        Span::styled("[t]", Style::default().fg(Color::Cyan)),
-        Span::raw("op  "),
-        Span::styled("[r]", Style::default().fg(Color::Cyan)),
-        Span::raw(" Reload  "),
-        Span::styled("[?]", Style::default().fg(Color::Cyan)),
-        Span::raw(" Help  "),
-        Span::styled("[q]", Style::default().fg(Color::Cyan)),
-        Span::raw(" Quit"),
-    ]))
-    .alignment(Alignment::Center);
-
-    frame.render_widget(footer, area);
+/// Renders the footer: keybinding hints on the left, clock and service
+/// health on the right.
+fn render_footer(frame: &mut Frame, area: Rect, state: &AppState, palette: &Palette) {
+    let mut spans = Vec::new();
+    for (keys, label) in footer_hints() {
+        spans.push(Span::styled(
+            format!("[{keys}]"),
+            Style::default().fg(palette.accent),
+        ));
+        spans.push(Span::raw(format!(" {label}  ")));
+    }
+
+    let time = chrono::Local::now().format("%H:%M:%S").to_string();
+    let status = status_line(state, &time, palette);
+    let status_width = u16::try_from(status.width()).unwrap_or(u16::MAX);
+
+    let chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Min(0), Constraint::Length(status_width)])
+        .split(area);
+
+    let hints = Paragraph::new(Line::from(spans)).alignment(Alignment::Left);
+
+    frame.render_widget(hints, chunks[0]);
+    frame.render_widget(
+        Paragraph::new(status).alignment(Alignment::Right),
+        chunks[1],
+    );
+}
+
+/// Builds the footer status segment, e.g. `OBS● Shell○ Theme○  12:34:56 `.
+fn status_line(state: &AppState, time: &str, palette: &Palette) -> Line<'static> {
+    let mut spans = Vec::new();
+    for service in KnownService::all() {
+        let status = state.service_info(*service).map(|info| &info.status);
+        let (indicator, color) = status_indicator(status, palette);
+        spans.push(Span::raw(service.short_name()));
+        spans.push(Span::styled(indicator, Style::default().fg(color)));
+        spans.push(Span::raw(" "));
+    }
+    spans.push(Span::styled(format!(" {time} "), palette.dim()));
+    Line::from(spans)
 }
 
 /// Creates a centered rectangle within the given area.
@@ -360,3 +495,34 @@ fn centered_rect(percent_x: u16, percent_y: u16, area: Rect) -> Rect {
         ])
         .split(popup_layout[1])[1]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use fern_core::{ServiceInfo, ServiceStatus};
+
+    fn line_text(line: &Line<'_>) -> String {
+        line.spans
+            .iter()
+            .map(|span| span.content.as_ref())
+            .collect()
+    }
+
+    #[test]
+    fn status_line_shows_each_service_and_time() {
+        let mut state = AppState::new();
+        state.update(Action::ServiceStateChanged {
+            name: KnownService::Obs.name().to_string(),
+            info: ServiceInfo::new(KnownService::Obs.name(), ServiceStatus::Running),
+        });
+
+        let text = line_text(&status_line(&state, "12:34:56", &Palette::DEFAULT));
+
+        for service in KnownService::all() {
+            assert!(text.contains(service.short_name()), "{text}");
+        }
+        assert!(text.contains("●"));
+        assert!(text.contains("○"));
+        assert!(text.ends_with("12:34:56 "));
+    }
+}
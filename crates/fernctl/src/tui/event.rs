@@ -1,9 +1,13 @@
 //! # TUI Event Handling
 //!
-//! Handles keyboard and terminal events.
+//! Handles keyboard, mouse, and terminal events.
 
-use crate::domain::{Action, KnownService, PanelFocus};
-use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crate::domain::{Action, AppState, KnownService, PanelFocus};
+use crate::tui::layout::AppLayout;
+use crossterm::event::{
+    self, Event, KeyCode, KeyEvent, KeyModifiers, MouseButton, MouseEvent, MouseEventKind,
+};
+use ratatui::layout::{Position, Rect};
 use std::time::Duration;
 
 /// Polls for the next event with a timeout.
@@ -17,7 +21,45 @@ pub fn poll_event(timeout: Duration) -> Option<Event> {
     }
 }
 
+/// Converts a terminal event to an action.
+///
+/// This is the only place input becomes an [`Action`]: an open dialog or
+/// help overlay takes the keys first, then the focused panel. `area` is the
+/// terminal area used to hit-test mouse events; without it they are ignored.
+pub fn event_to_action(event: &Event, state: &AppState, area: Option<Rect>) -> Option<Action> {
+    match *event {
+        Event::Key(key) if state.config_error.is_some() => config_error_key_to_action(key),
+        Event::Key(key) if state.show_help => help_key_to_action(key),
+        Event::Key(key) => key_to_action(key, state.focus, state.selected_service),
+        Event::Mouse(mouse) if !state.show_help && state.config_error.is_none() => {
+            mouse_to_action(mouse, &AppLayout::new(area?, state.focus))
+        }
+        _ => None,
+    }
+}
+
+/// Converts a key event to an action while the help overlay is open.
+///
+/// Only the dismiss keys (`?`, `Esc`, `q`) do anything; everything else is
+/// swallowed so keys don't act on the panels hidden behind the overlay.
+pub fn help_key_to_action(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Char('?' | 'q') | KeyCode::Esc => Some(Action::ToggleHelp),
+        _ => None,
+    }
+}
+
+/// Converts a key event to an action while the config error dialog is open.
+pub fn config_error_key_to_action(key: KeyEvent) -> Option<Action> {
+    match key.code {
+        KeyCode::Esc | KeyCode::Enter | KeyCode::Char('q') => Some(Action::DismissConfigError),
+        _ => None,
+    }
+}
+
 /// Converts a key event to an action based on current focus.
+///
+/// Keep the [`keymap`](super::keymap) table in sync when adding keys here.
 pub fn key_to_action(key: KeyEvent, focus: PanelFocus, selected_service: usize) -> Option<Action> {
     // Global keys
     match key.code {
@@ -56,6 +98,7 @@ pub fn key_to_action(key: KeyEvent, focus: PanelFocus, selected_service: usize)
             KeyCode::Char('G') => Some(Action::ScrollLogs(i32::MAX)),
             KeyCode::Char('g') => Some(Action::ScrollLogs(i32::MIN)),
             KeyCode::Char('c') => Some(Action::ClearLogs),
+            KeyCode::Char('f') => Some(Action::ToggleFollow),
             KeyCode::PageDown => Some(Action::ScrollLogs(10)),
             KeyCode::PageUp => Some(Action::ScrollLogs(-10)),
             _ => None,
@@ -67,3 +110,143 @@ pub fn key_to_action(key: KeyEvent, focus: PanelFocus, selected_service: usize)
         },
     }
 }
+
+/// Lines scrolled per mouse wheel notch.
+const WHEEL_SCROLL_LINES: i32 = 3;
+
+/// Converts a mouse event to an action using the current layout.
+///
+/// A left click focuses the panel under the cursor; the wheel scrolls the
+/// logs panel when the cursor is over it.
+pub fn mouse_to_action(mouse: MouseEvent, layout: &AppLayout) -> Option<Action> {
+    let position = Position::new(mouse.column, mouse.row);
+    let hit = |area: Option<Rect>| area.is_some_and(|r| r.contains(position));
+
+    match mouse.kind {
+        MouseEventKind::Down(MouseButton::Left) => {
+            if hit(layout.services) {
+                Some(Action::FocusPanel(PanelFocus::Services))
+            } else if hit(layout.logs) {
+                Some(Action::FocusPanel(PanelFocus::Logs))
+            } else if hit(layout.config) {
+                Some(Action::FocusPanel(PanelFocus::Config))
+            } else {
+                None
+            }
+        }
+        MouseEventKind::ScrollDown if hit(layout.logs) => {
+            Some(Action::ScrollLogs(WHEEL_SCROLL_LINES))
+        }
+        MouseEventKind::ScrollUp if hit(layout.logs) => {
+            Some(Action::ScrollLogs(-WHEEL_SCROLL_LINES))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mouse(kind: MouseEventKind, column: u16, row: u16) -> MouseEvent {
+        MouseEvent {
+            kind,
+            column,
+            row,
+            modifiers: KeyModifiers::NONE,
+        }
+    }
+
+    #[test]
+    fn tab_and_back_tab_cycle_focus() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert!(matches!(
+            key_to_action(key(KeyCode::Tab), PanelFocus::Services, 0),
+            Some(Action::FocusNext)
+        ));
+        assert!(matches!(
+            key_to_action(key(KeyCode::BackTab), PanelFocus::Logs, 0),
+            Some(Action::FocusPrev)
+        ));
+    }
+
+    #[test]
+    fn help_overlay_takes_keys_first() {
+        let mut state = AppState::new();
+        state.update(Action::ToggleHelp);
+
+        let key = |code| Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+        assert!(event_to_action(&key(KeyCode::Tab), &state, None).is_none());
+        assert!(matches!(
+            event_to_action(&key(KeyCode::Esc), &state, None),
+            Some(Action::ToggleHelp)
+        ));
+
+        let click = Event::Mouse(mouse(MouseEventKind::Down(MouseButton::Left), 1, 1));
+        let area = Some(Rect::new(0, 0, 160, 40));
+        assert!(event_to_action(&click, &state, area).is_none());
+    }
+
+    fn wide_layout() -> AppLayout {
+        AppLayout::new(Rect::new(0, 0, 160, 40), PanelFocus::Services)
+    }
+
+    #[test]
+    fn click_focuses_panel_under_cursor() {
+        let layout = wide_layout();
+        let logs = layout.logs.unwrap();
+        let config = layout.config.unwrap();
+
+        let action = mouse_to_action(
+            mouse(
+                MouseEventKind::Down(MouseButton::Left),
+                logs.x + 1,
+                logs.y + 1,
+            ),
+            &layout,
+        );
+        assert!(matches!(action, Some(Action::FocusPanel(PanelFocus::Logs))));
+
+        let action = mouse_to_action(
+            mouse(
+                MouseEventKind::Down(MouseButton::Left),
+                config.x + 1,
+                config.y + 1,
+            ),
+            &layout,
+        );
+        assert!(matches!(
+            action,
+            Some(Action::FocusPanel(PanelFocus::Config))
+        ));
+    }
+
+    #[test]
+    fn wheel_scrolls_logs_only_over_logs_panel() {
+        let layout = wide_layout();
+        let logs = layout.logs.unwrap();
+        let services = layout.services.unwrap();
+
+        let action = mouse_to_action(
+            mouse(MouseEventKind::ScrollDown, logs.x + 1, logs.y + 1),
+            &layout,
+        );
+        assert!(matches!(action, Some(Action::ScrollLogs(n)) if n > 0));
+
+        let action = mouse_to_action(
+            mouse(MouseEventKind::ScrollUp, services.x + 1, services.y + 1),
+            &layout,
+        );
+        assert!(action.is_none());
+    }
+
+    #[test]
+    fn click_outside_panels_does_nothing() {
+        let layout = wide_layout();
+        let action = mouse_to_action(
+            mouse(MouseEventKind::Down(MouseButton::Left), 0, layout.header.y),
+            &layout,
+        );
+        assert!(action.is_none());
+    }
+}
@@ -0,0 +1,160 @@
+//! # TUI Keybindings
+//!
+//! The single table of keybindings shown to the user. Both the footer hints
+//! and the help overlay render from [`KEYBINDINGS`], so they cannot drift
+//! apart. When adding a key to [`event`](super::event), add it here too.
+
+/// Where a keybinding applies.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeyContext {
+    /// Works regardless of the focused panel.
+    Global,
+    /// Services panel focused.
+    Services,
+    /// Logs panel focused.
+    Logs,
+    /// Config panel focused.
+    Config,
+    /// Help overlay open.
+    Help,
+}
+
+impl KeyContext {
+    /// Returns the section title used in the help overlay.
+    #[must_use]
+    pub const fn title(self) -> &'static str {
+        match self {
+            Self::Global => "General",
+            Self::Services => "Services",
+            Self::Logs => "Logs",
+            Self::Config => "Config",
+            Self::Help => "Help",
+        }
+    }
+
+    /// Returns all contexts in help overlay order.
+    #[must_use]
+    pub const fn all() -> &'static [KeyContext] {
+        &[
+            Self::Global,
+            Self::Services,
+            Self::Logs,
+            Self::Config,
+            Self::Help,
+        ]
+    }
+}
+
+/// A documented keybinding.
+#[derive(Debug, Clone, Copy)]
+pub struct KeyBinding {
+    /// Key label as shown to the user (e.g. `j/↓`).
+    pub keys: &'static str,
+    /// What the key does.
+    pub description: &'static str,
+    /// Where the key applies.
+    pub context: KeyContext,
+    /// Short label for the footer, if the key is shown there.
+    pub hint: Option<&'static str>,
+}
+
+const fn bind(
+    keys: &'static str,
+    description: &'static str,
+    context: KeyContext,
+    hint: Option<&'static str>,
+) -> KeyBinding {
+    KeyBinding {
+        keys,
+        description,
+        context,
+        hint,
+    }
+}
+
+/// Every keybinding, in display order.
+pub const KEYBINDINGS: &[KeyBinding] = &[
+    // General
+    bind("Tab", "Focus next panel", KeyContext::Global, Some("Next")),
+    bind(
+        "Shift-Tab",
+        "Focus previous panel",
+        KeyContext::Global,
+        Some("Prev"),
+    ),
+    bind(
+        "r",
+        "Reload shell config",
+        KeyContext::Global,
+        Some("Reload"),
+    ),
+    bind("?", "Toggle this help", KeyContext::Global, Some("Help")),
+    bind("q", "Quit", KeyContext::Global, Some("Quit")),
+    // Services
+    bind(
+        "j/↓",
+        "Select next service",
+        KeyContext::Services,
+        Some("Navigate"),
+    ),
+    bind("k/↑", "Select previous service", KeyContext::Services, None),
+    bind(
+        "s",
+        "Start selected service",
+        KeyContext::Services,
+        Some("Start"),
+    ),
+    bind(
+        "t",
+        "Stop selected service",
+        KeyContext::Services,
+        Some("Stop"),
+    ),
+    bind("R", "Restart selected service", KeyContext::Services, None),
+    // Logs
+    bind("j/↓", "Scroll down one line", KeyContext::Logs, None),
+    bind("k/↑", "Scroll up one line", KeyContext::Logs, None),
+    bind("PgDn", "Scroll down one page", KeyContext::Logs, None),
+    bind("PgUp", "Scroll up one page", KeyContext::Logs, None),
+    bind("G", "Jump to end", KeyContext::Logs, None),
+    bind("g", "Jump to start", KeyContext::Logs, None),
+    bind("c", "Clear logs", KeyContext::Logs, None),
+    bind("f", "Toggle follow mode", KeyContext::Logs, None),
+    // Config
+    bind("j/↓", "Select next item", KeyContext::Config, None),
+    bind("k/↑", "Select previous item", KeyContext::Config, None),
+    // Help overlay
+    bind("?/Esc/q", "Close this help", KeyContext::Help, None),
+];
+
+/// Returns the bindings for a context, in display order.
+pub fn bindings_for(context: KeyContext) -> impl Iterator<Item = &'static KeyBinding> {
+    KEYBINDINGS.iter().filter(move |b| b.context == context)
+}
+
+/// Returns `(keys, label)` pairs for the footer, in display order.
+pub fn footer_hints() -> impl Iterator<Item = (&'static str, &'static str)> {
+    KEYBINDINGS
+        .iter()
+        .filter_map(|b| b.hint.map(|hint| (b.keys, hint)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn every_context_has_bindings() {
+        for context in KeyContext::all() {
+            assert!(bindings_for(*context).next().is_some(), "{context:?}");
+        }
+    }
+
+    #[test]
+    fn footer_hints_come_from_table() {
+        let hints: Vec<_> = footer_hints().collect();
+        assert!(hints.contains(&("?", "Help")));
+        assert!(hints.contains(&("q", "Quit")));
+        assert!(hints.contains(&("Shift-Tab", "Prev")));
+    }
+}
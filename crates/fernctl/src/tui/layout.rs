@@ -1,37 +1,91 @@
 //! # TUI Layout
 //!
 //! Defines the panel arrangement for the TUI dashboard.
+//!
+//! The arrangement depends on the terminal size:
+//!
+//! - Below [`MIN_WIDTH`] × [`MIN_HEIGHT`]: [`LayoutMode::TooSmall`], no
+//!   panels, just a "terminal too small" message.
+//! - Narrower than [`NARROW_WIDTH`]: [`LayoutMode::Single`], only the focused
+//!   panel is shown and Tab cycles between panels.
+//! - Otherwise: [`LayoutMode::Wide`], services and config on the left, logs
+//!   on the right.
+//!
+//! In wide mode, terminals shorter than [`SHORT_HEIGHT`] collapse the left
+//! column to a single panel: services, or config while it has focus.
 
+use crate::domain::PanelFocus;
 use ratatui::layout::{Constraint, Direction, Layout, Rect};
 
+/// Minimum usable terminal width.
+pub const MIN_WIDTH: u16 = 40;
+
+/// Minimum usable terminal height.
+pub const MIN_HEIGHT: u16 = 10;
+
+/// Below this width only the focused panel is shown.
+pub const NARROW_WIDTH: u16 = 100;
+
+/// Below this height the config panel is collapsed in wide mode.
+pub const SHORT_HEIGHT: u16 = 24;
+
+/// How the panels are arranged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LayoutMode {
+    /// Left column (services + config) beside the logs panel.
+    Wide,
+    /// One panel at a time, chosen by focus.
+    Single,
+    /// Terminal is below the minimum size; no panels are drawn.
+    TooSmall,
+}
+
 /// Layout areas for the TUI dashboard.
+///
+/// Panel areas are `None` when the panel is hidden in the current mode.
 #[derive(Debug, Clone)]
 pub struct AppLayout {
+    /// Arrangement in use.
+    pub mode: LayoutMode,
+    /// Whole terminal area.
+    pub area: Rect,
     /// Header area (title bar).
     pub header: Rect,
-    /// Left column (services + config).
-    pub left: Rect,
     /// Services panel area.
-    pub services: Rect,
+    pub services: Option<Rect>,
     /// Config panel area.
-    pub config: Rect,
-    /// Logs panel area (right side).
-    pub logs: Rect,
+    pub config: Option<Rect>,
+    /// Logs panel area.
+    pub logs: Option<Rect>,
     /// Footer area (keybindings).
     pub footer: Rect,
 }
 
 impl AppLayout {
-    /// Creates the layout for the given terminal size.
+    /// Creates the layout for the given terminal size and focused panel.
     #[must_use]
-    pub fn new(area: Rect) -> Self {
+    pub fn new(area: Rect, focus: PanelFocus) -> Self {
+        let mode = Self::mode_for(area);
+
+        if mode == LayoutMode::TooSmall {
+            return Self {
+                mode,
+                area,
+                header: Rect::default(),
+                services: None,
+                config: None,
+                logs: None,
+                footer: Rect::default(),
+            };
+        }
+
         // Main vertical split: header, body, footer
         let vertical = Layout::default()
             .direction(Direction::Vertical)
             .constraints([
-                Constraint::Length(1),  // Header
-                Constraint::Min(10),    // Body
-                Constraint::Length(1),  // Footer
+                Constraint::Length(1), // Header
+                Constraint::Min(1),    // Body
+                Constraint::Length(1), // Footer
             ])
             .split(area);
 
@@ -39,6 +93,25 @@ impl AppLayout {
         let body = vertical[1];
         let footer = vertical[2];
 
+        let mut layout = Self {
+            mode,
+            area,
+            header,
+            services: None,
+            config: None,
+            logs: None,
+            footer,
+        };
+
+        if mode == LayoutMode::Single {
+            match focus {
+                PanelFocus::Services => layout.services = Some(body),
+                PanelFocus::Logs => layout.logs = Some(body),
+                PanelFocus::Config => layout.config = Some(body),
+            }
+            return layout;
+        }
+
         // Body horizontal split: left panel, logs panel
         let horizontal = Layout::default()
             .direction(Direction::Horizontal)
@@ -49,7 +122,16 @@ impl AppLayout {
             .split(body);
 
         let left = horizontal[0];
-        let logs = horizontal[1];
+        layout.logs = Some(horizontal[1]);
+
+        if area.height < SHORT_HEIGHT {
+            if focus == PanelFocus::Config {
+                layout.config = Some(left);
+            } else {
+                layout.services = Some(left);
+            }
+            return layout;
+        }
 
         // Left column vertical split: services, config
         let left_vertical = Layout::default()
@@ -60,16 +142,68 @@ impl AppLayout {
             ])
             .split(left);
 
-        let services = left_vertical[0];
-        let config = left_vertical[1];
+        layout.services = Some(left_vertical[0]);
+        layout.config = Some(left_vertical[1]);
+        layout
+    }
 
-        Self {
-            header,
-            left,
-            services,
-            config,
-            logs,
-            footer,
+    /// Returns the layout mode for a terminal area.
+    #[must_use]
+    pub const fn mode_for(area: Rect) -> LayoutMode {
+        if area.width < MIN_WIDTH || area.height < MIN_HEIGHT {
+            LayoutMode::TooSmall
+        } else if area.width < NARROW_WIDTH {
+            LayoutMode::Single
+        } else {
+            LayoutMode::Wide
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layout_for(width: u16, height: u16, focus: PanelFocus) -> AppLayout {
+        AppLayout::new(Rect::new(0, 0, width, height), focus)
+    }
+
+    #[test]
+    fn wide_terminal_shows_all_panels() {
+        let layout = layout_for(160, 40, PanelFocus::Services);
+        assert_eq!(layout.mode, LayoutMode::Wide);
+        assert!(layout.services.is_some());
+        assert!(layout.config.is_some());
+        assert!(layout.logs.is_some());
+    }
+
+    #[test]
+    fn narrow_terminal_shows_focused_panel_only() {
+        let layout = layout_for(80, 24, PanelFocus::Logs);
+        assert_eq!(layout.mode, LayoutMode::Single);
+        assert!(layout.services.is_none());
+        assert!(layout.config.is_none());
+        assert_eq!(layout.logs.map(|r| r.width), Some(80));
+    }
+
+    #[test]
+    fn short_terminal_collapses_config() {
+        let layout = layout_for(160, 20, PanelFocus::Services);
+        assert_eq!(layout.mode, LayoutMode::Wide);
+        assert!(layout.services.is_some());
+        assert!(layout.config.is_none());
+
+        let layout = layout_for(160, 20, PanelFocus::Config);
+        assert!(layout.services.is_none());
+        assert!(layout.config.is_some());
+    }
+
+    #[test]
+    fn tiny_terminal_is_too_small() {
+        for (w, h) in [(0, 0), (10, 40), (160, 5)] {
+            let layout = layout_for(w, h, PanelFocus::Services);
+            assert_eq!(layout.mode, LayoutMode::TooSmall);
+            assert!(layout.services.is_none() && layout.logs.is_none());
         }
     }
 }
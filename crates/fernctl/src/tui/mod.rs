@@ -25,7 +25,7 @@
 //! │                       │                                               │
 //! │  [e]dit [R]eload      │                                               │
 //! ├───────────────────────┴───────────────────────────────────────────────┤
-//! │  [Tab] Switch panel  [q] Quit  [r] Reload  [?] Help                   │
+//! │  [Tab] Next  [Shift-Tab] Prev  [q] Quit  [r] Reload  [?] Help         │
 //! └───────────────────────────────────────────────────────────────────────┘
 //! ```
 //!
@@ -33,12 +33,18 @@
 //!
 //! - [`app`] - Main TUI application loop
 //! - [`event`] - Event handling
+//! - [`keymap`] - Keybinding table for the footer and help overlay
 //! - [`layout`] - Panel arrangement
+//! - [`palette`] - Colors from the active Fern theme
+//! - [`snapshot`] - One-shot render to stdout
 //! - [`widgets`] - UI components
 
 pub mod app;
 pub mod event;
+pub mod keymap;
 pub mod layout;
+pub mod palette;
+pub mod snapshot;
 pub mod widgets;
 
 pub use app::TuiApp;
@@ -0,0 +1,170 @@
+//! # TUI Palette
+//!
+//! Colors the dashboard draws with, taken from the active Fern theme so the
+//! TUI matches the shell.
+//!
+//! | Token            | Used for                                   |
+//! |------------------|--------------------------------------------|
+//! | `background`     | Screen background                          |
+//! | `surface`        | Selected log line                          |
+//! | `foreground`     | Body text                                  |
+//! | `foreground_dim` | Unfocused borders, labels, timestamps      |
+//! | `accent`         | Focused border, keys, selected service     |
+//! | `error`          | Failed services, error logs, error dialogs |
+//! | `warning`        | Transitional services, warning logs, help  |
+//! | `success`        | Running services, info logs                |
+//!
+//! Without a valid `config.toml`, [`Palette::DEFAULT`] keeps the terminal's
+//! own background and uses the standard ANSI colors.
+
+use fern_core::FernPaths;
+use fern_theme::adapters::TomlConfigAdapter;
+use fern_theme::commands::validate;
+use fern_theme::domain::theme::Theme;
+use fern_theme::domain::tokens::color::{ColorRole, ColorToken};
+use ratatui::style::{Color, Style};
+use std::path::Path;
+
+/// Colors used by the TUI widgets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Palette {
+    /// Screen background. [`Color::Reset`] leaves the terminal's own.
+    pub background: Color,
+    /// Highlight behind the selected log line.
+    pub surface: Color,
+    /// Body text.
+    pub foreground: Color,
+    /// Secondary text and unfocused borders.
+    pub foreground_dim: Color,
+    /// Focus and selection.
+    pub accent: Color,
+    /// Errors and failed services.
+    pub error: Color,
+    /// Warnings and services in transition.
+    pub warning: Color,
+    /// Running services and informational logs.
+    pub success: Color,
+}
+
+impl Palette {
+    /// Standard ANSI colors on the terminal's background.
+    pub const DEFAULT: Self = Self {
+        background: Color::Reset,
+        surface: Color::DarkGray,
+        foreground: Color::White,
+        foreground_dim: Color::DarkGray,
+        accent: Color::Cyan,
+        error: Color::Red,
+        warning: Color::Yellow,
+        success: Color::Green,
+    };
+
+    /// Maps a Fern theme's color tokens onto the palette.
+    #[must_use]
+    pub fn from_theme(theme: &Theme) -> Self {
+        let colors = &theme.colors;
+        Self {
+            background: rgb(&colors.background),
+            surface: rgb(&colors.surface),
+            foreground: rgb(&colors.foreground),
+            foreground_dim: rgb(&colors.foreground_dim),
+            accent: rgb(&colors.accent),
+            error: rgb(&colors.error),
+            warning: rgb(&colors.warning),
+            success: rgb(&colors.success),
+        }
+    }
+
+    /// Loads the palette for the theme configured in `config.toml`.
+    ///
+    /// Falls back to [`DEFAULT`](Self::DEFAULT) if the file is missing or
+    /// does not validate.
+    #[must_use]
+    pub fn load(paths: &FernPaths) -> Self {
+        Self::load_from(&paths.config_toml())
+    }
+
+    /// Loads the palette from a specific config file. See [`load`](Self::load).
+    #[must_use]
+    pub fn load_from(config_toml: &Path) -> Self {
+        if !config_toml.exists() {
+            return Self::DEFAULT;
+        }
+
+        validate::run(
+            config_toml,
+            &validate::ValidateOptions::default(),
+            &TomlConfigAdapter::new(),
+        )
+        .map_or(Self::DEFAULT, |result| Self::from_theme(&result.theme))
+    }
+
+    /// Returns the border style for a panel.
+    #[must_use]
+    pub fn border(&self, focused: bool) -> Style {
+        let color = if focused {
+            self.accent
+        } else {
+            self.foreground_dim
+        };
+        Style::default().fg(color)
+    }
+
+    /// Returns the style for secondary text.
+    #[must_use]
+    pub fn dim(&self) -> Style {
+        Style::default().fg(self.foreground_dim)
+    }
+
+    /// Returns the style for a selected row drawn on the accent color.
+    ///
+    /// The text uses the background color, or black when the terminal's
+    /// background is kept.
+    #[must_use]
+    pub fn selected(&self) -> Style {
+        let text = match self.background {
+            Color::Reset => Color::Black,
+            color => color,
+        };
+        Style::default().bg(self.accent).fg(text)
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+/// Converts a theme color to a 24-bit terminal color.
+fn rgb<Role: ColorRole>(token: &ColorToken<Role>) -> Color {
+    let (r, g, b) = token.to_rgb_tuple();
+    Color::Rgb(r, g, b)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_theme_tokens() {
+        let theme = Theme::dark();
+        let palette = Palette::from_theme(&theme);
+
+        let (r, g, b) = theme.colors.accent.to_rgb_tuple();
+        assert_eq!(palette.accent, Color::Rgb(r, g, b));
+        let (r, g, b) = theme.colors.background.to_rgb_tuple();
+        assert_eq!(palette.selected().fg, Some(Color::Rgb(r, g, b)));
+    }
+
+    #[test]
+    fn falls_back_without_valid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let config_toml = dir.path().join("config.toml");
+        assert_eq!(Palette::load_from(&config_toml), Palette::DEFAULT);
+
+        std::fs::write(&config_toml, "[appearance\n").unwrap();
+        assert_eq!(Palette::load_from(&config_toml), Palette::DEFAULT);
+        assert_eq!(Palette::DEFAULT.selected().fg, Some(Color::Black));
+    }
+}
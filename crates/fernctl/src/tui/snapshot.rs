@@ -0,0 +1,222 @@
+//! # Dashboard Snapshot
+//!
+//! Renders the dashboard once into an off-screen buffer and prints it, for
+//! tmux status panes, screenshots, or bug reports.
+//!
+//! The same [`render_frame`] used by the interactive TUI draws into a
+//! [`TestBackend`], and the resulting buffer is dumped as plain text or,
+//! when stdout is a terminal, with ANSI colors.
+
+use crate::adapters::ServiceController;
+use crate::domain::AppState;
+use crate::error::{FernctlError, Result};
+use crate::tui::app::{load_state, render_frame};
+use crate::tui::palette::Palette;
+use fern_core::FernPaths;
+use ratatui::{
+    backend::TestBackend,
+    buffer::Buffer,
+    style::{Color, Modifier},
+    Terminal,
+};
+use std::io::IsTerminal;
+
+/// Default snapshot width when the terminal size is unknown.
+pub const DEFAULT_WIDTH: u16 = 120;
+
+/// Default snapshot height when the terminal size is unknown.
+pub const DEFAULT_HEIGHT: u16 = 40;
+
+/// Options for the snapshot command.
+#[derive(Debug, Clone, Default)]
+pub struct SnapshotOptions {
+    /// Width in columns. Defaults to the terminal width.
+    pub width: Option<u16>,
+    /// Height in rows. Defaults to the terminal height.
+    pub height: Option<u16>,
+}
+
+/// Renders the current dashboard to stdout and returns.
+///
+/// Colors are emitted only when stdout is a terminal.
+///
+/// # Errors
+///
+/// Returns an error if rendering fails.
+pub fn run(options: &SnapshotOptions) -> Result<()> {
+    let (term_width, term_height) =
+        crossterm::terminal::size().unwrap_or((DEFAULT_WIDTH, DEFAULT_HEIGHT));
+    let width = options.width.unwrap_or(term_width);
+    let height = options.height.unwrap_or(term_height);
+
+    let paths = FernPaths::new();
+    let palette = Palette::load(&paths);
+    let mut state = AppState::with_paths(paths.clone());
+    load_state(&mut state, &ServiceController::new(paths));
+
+    let ansi = std::io::stdout().is_terminal();
+    print!(
+        "{}",
+        render_snapshot(&state, &palette, width, height, ansi)?
+    );
+    Ok(())
+}
+
+/// Renders `state` in `palette` at the given size and returns it as text.
+///
+/// # Errors
+///
+/// Returns an error if drawing into the off-screen buffer fails.
+pub fn render_snapshot(
+    state: &AppState,
+    palette: &Palette,
+    width: u16,
+    height: u16,
+    ansi: bool,
+) -> Result<String> {
+    let mut terminal = Terminal::new(TestBackend::new(width, height))
+        .map_err(|e| FernctlError::tui_io("creating snapshot backend", e))?;
+
+    terminal
+        .draw(|frame| render_frame(frame, state, palette))
+        .map_err(|e| FernctlError::tui_io("drawing snapshot", e))?;
+
+    Ok(buffer_to_text(terminal.backend().buffer(), ansi))
+}
+
+/// Converts a buffer to text, one line per row.
+///
+/// Trailing whitespace is trimmed in plain mode. In ANSI mode, SGR codes are
+/// emitted whenever the style changes and reset at the end of each row.
+#[must_use]
+pub fn buffer_to_text(buffer: &Buffer, ansi: bool) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+
+    for y in area.top()..area.bottom() {
+        let mut line = String::new();
+        let mut current = None;
+
+        for x in area.left()..area.right() {
+            let Some(cell) = buffer.cell((x, y)) else {
+                continue;
+            };
+
+            if ansi {
+                let style = (cell.fg, cell.bg, cell.modifier);
+                if current != Some(style) {
+                    line.push_str(&sgr(cell.fg, cell.bg, cell.modifier));
+                    current = Some(style);
+                }
+            }
+            line.push_str(cell.symbol());
+        }
+
+        if ansi {
+            line.push_str("\x1b[0m");
+        } else {
+            line.truncate(line.trim_end().len());
+        }
+        out.push_str(&line);
+        out.push('\n');
+    }
+
+    out
+}
+
+/// Builds an SGR escape sequence for a cell style.
+fn sgr(fg: Color, bg: Color, modifier: Modifier) -> String {
+    let mut codes = vec!["0".to_string()];
+    if modifier.contains(Modifier::BOLD) {
+        codes.push("1".to_string());
+    }
+    if modifier.contains(Modifier::DIM) {
+        codes.push("2".to_string());
+    }
+    if modifier.contains(Modifier::ITALIC) {
+        codes.push("3".to_string());
+    }
+    if modifier.contains(Modifier::UNDERLINED) {
+        codes.push("4".to_string());
+    }
+    if modifier.contains(Modifier::REVERSED) {
+        codes.push("7".to_string());
+    }
+    if let Some(code) = color_code(fg, false) {
+        codes.push(code);
+    }
+    if let Some(code) = color_code(bg, true) {
+        codes.push(code);
+    }
+
+    format!("\x1b[{}m", codes.join(";"))
+}
+
+/// Returns the SGR parameter for a color, or `None` for the default.
+fn color_code(color: Color, background: bool) -> Option<String> {
+    let base = if background { 40 } else { 30 };
+    let code = match color {
+        Color::Reset => return None,
+        Color::Black => base,
+        Color::Red => base + 1,
+        Color::Green => base + 2,
+        Color::Yellow => base + 3,
+        Color::Blue => base + 4,
+        Color::Magenta => base + 5,
+        Color::Cyan => base + 6,
+        Color::Gray => base + 7,
+        Color::DarkGray => base + 60,
+        Color::LightRed => base + 61,
+        Color::LightGreen => base + 62,
+        Color::LightYellow => base + 63,
+        Color::LightBlue => base + 64,
+        Color::LightMagenta => base + 65,
+        Color::LightCyan => base + 66,
+        Color::White => base + 67,
+        Color::Indexed(i) => return Some(format!("{};5;{i}", base + 8)),
+        Color::Rgb(r, g, b) => return Some(format!("{};2;{r};{g};{b}", base + 8)),
+    };
+    Some(code.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_snapshot_has_one_line_per_row() {
+        let state = AppState::new();
+        let text = render_snapshot(&state, &Palette::DEFAULT, 120, 30, false).unwrap();
+
+        assert_eq!(text.lines().count(), 30);
+        assert!(text.contains("Fern Control Plane"));
+        assert!(!text.contains('\x1b'));
+    }
+
+    #[test]
+    fn ansi_snapshot_resets_each_row() {
+        let state = AppState::new();
+        let text = render_snapshot(&state, &Palette::DEFAULT, 120, 30, true).unwrap();
+
+        assert!(text.lines().all(|line| line.ends_with("\x1b[0m")));
+    }
+
+    #[test]
+    fn tiny_snapshot_renders_size_guard() {
+        let state = AppState::new();
+        let text = render_snapshot(&state, &Palette::DEFAULT, 30, 5, false).unwrap();
+
+        assert!(text.contains("too small"));
+    }
+
+    #[test]
+    fn color_codes() {
+        assert_eq!(color_code(Color::Reset, false), None);
+        assert_eq!(color_code(Color::Red, false).as_deref(), Some("31"));
+        assert_eq!(color_code(Color::Red, true).as_deref(), Some("41"));
+        assert_eq!(
+            color_code(Color::Rgb(1, 2, 3), false).as_deref(),
+            Some("38;2;1;2;3")
+        );
+    }
+}
@@ -0,0 +1,76 @@
+//! # Config Error Panel Widget
+//!
+//! Dialog shown when a reload is blocked because the config failed
+//! validation.
+
+use crate::domain::action::ConfigProblem;
+use crate::tui::palette::Palette;
+use ratatui::{
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
+};
+
+/// Config error dialog widget.
+pub struct ConfigErrorPanel<'a> {
+    problem: &'a ConfigProblem,
+    palette: &'a Palette,
+}
+
+impl<'a> ConfigErrorPanel<'a> {
+    /// Creates a new config error panel.
+    #[must_use]
+    pub const fn new(problem: &'a ConfigProblem, palette: &'a Palette) -> Self {
+        Self { problem, palette }
+    }
+
+    /// Creates the dialog content.
+    fn lines(&self) -> Vec<Line<'a>> {
+        let mut lines = vec![
+            Line::from(Span::styled(
+                self.problem.title.as_str(),
+                Style::default()
+                    .fg(self.palette.error)
+                    .add_modifier(Modifier::BOLD),
+            )),
+            Line::from(""),
+            Line::from(self.problem.message.as_str()),
+        ];
+
+        if let Some(ref suggestion) = self.problem.suggestion {
+            lines.push(Line::from(""));
+            lines.push(Line::from(vec![
+                Span::styled("Suggestion: ", Style::default().fg(self.palette.accent)),
+                Span::raw(suggestion.as_str()),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            "The shell was not reloaded.",
+            self.palette.dim(),
+        )));
+        lines
+    }
+}
+
+impl Widget for ConfigErrorPanel<'_> {
+    fn render(self, area: Rect, buf: &mut Buffer) {
+        // Clear the area first (overlay)
+        Clear.render(area, buf);
+
+        let block = Block::default()
+            .title(" Config invalid (Esc/Enter to dismiss) ")
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(self.palette.error));
+
+        let paragraph = Paragraph::new(self.lines())
+            .block(block)
+            .wrap(Wrap { trim: false })
+            .alignment(Alignment::Left);
+
+        Widget::render(paragraph, area, buf);
+    }
+}
@@ -3,10 +3,11 @@
 //! Displays current configuration overview.
 
 use crate::domain::{AppState, PanelFocus};
+use crate::tui::palette::Palette;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph, Widget},
 };
@@ -14,51 +15,50 @@ use ratatui::{
 /// Config panel widget.
 pub struct ConfigPanel<'a> {
     state: &'a AppState,
+    palette: &'a Palette,
 }
 
 impl<'a> ConfigPanel<'a> {
     /// Creates a new config panel.
     #[must_use]
-    pub fn new(state: &'a AppState) -> Self {
-        Self { state }
+    pub fn new(state: &'a AppState, palette: &'a Palette) -> Self {
+        Self { state, palette }
     }
 
     /// Returns the border style based on focus.
     fn border_style(&self) -> Style {
-        if self.state.focus == PanelFocus::Config {
-            Style::default().fg(Color::Cyan)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        }
+        self.palette.border(self.state.focus == PanelFocus::Config)
     }
 
     /// Creates the config display lines.
     fn config_lines(&self) -> Vec<Line<'a>> {
         let config = &self.state.config;
+        let label = self.palette.dim();
+        let value = Style::default().fg(self.palette.foreground);
 
         let mut lines = vec![];
 
         // Theme
         if let Some(ref theme) = config.theme {
             lines.push(Line::from(vec![
-                Span::styled("Theme: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(theme.clone(), Style::default().fg(Color::White)),
+                Span::styled("Theme: ", label),
+                Span::styled(theme.clone(), value),
             ]));
         }
 
         // Variant
         if let Some(ref variant) = config.variant {
             lines.push(Line::from(vec![
-                Span::styled("Variant: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(variant.clone(), Style::default().fg(Color::White)),
+                Span::styled("Variant: ", label),
+                Span::styled(variant.clone(), value),
             ]));
         }
 
         // Accent color
         if let Some(ref accent) = config.accent_color {
             lines.push(Line::from(vec![
-                Span::styled("Accent: ", Style::default().fg(Color::DarkGray)),
-                Span::styled(accent.clone(), Style::default().fg(Color::Magenta)),
+                Span::styled("Accent: ", label),
+                Span::styled(accent.clone(), Style::default().fg(self.palette.accent)),
             ]));
         }
 
@@ -71,16 +71,13 @@ impl<'a> ConfigPanel<'a> {
         };
 
         lines.push(Line::from(vec![
-            Span::styled("Bar: ", Style::default().fg(Color::DarkGray)),
-            Span::styled(bar_info, Style::default().fg(Color::White)),
+            Span::styled("Bar: ", label),
+            Span::styled(bar_info, value),
         ]));
 
         // If no config loaded, show placeholder
         if lines.is_empty() {
-            lines.push(Line::from(vec![Span::styled(
-                "No config loaded",
-                Style::default().fg(Color::DarkGray),
-            )]));
+            lines.push(Line::from(vec![Span::styled("No config loaded", label)]));
         }
 
         lines
@@ -100,3 +97,46 @@ impl Widget for ConfigPanel<'_> {
         Widget::render(paragraph, area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::action::ConfigSummary;
+    use crate::domain::Action;
+    use crate::tui::widgets::render_to_text;
+
+    #[test]
+    fn renders_loaded_config() {
+        let mut state = AppState::new();
+        state.update(Action::ConfigChanged(ConfigSummary {
+            theme: Some("catppuccin".to_string()),
+            variant: Some("mocha".to_string()),
+            bar_position: Some("top".to_string()),
+            bar_height: Some(32),
+            accent_color: Some("#cba6f7".to_string()),
+        }));
+
+        let expected = "\
+┌ Config ────────────────────┐
+│Theme: catppuccin           │
+│Variant: mocha              │
+│Accent: #cba6f7             │
+│Bar: top, 32px              │
+│                            │
+└────────────────────────────┘
+";
+        assert_eq!(
+            render_to_text(ConfigPanel::new(&state, &Palette::DEFAULT), 30, 7),
+            expected
+        );
+    }
+
+    #[test]
+    fn empty_config_shows_default_bar() {
+        let state = AppState::new();
+        let text = render_to_text(ConfigPanel::new(&state, &Palette::DEFAULT), 30, 4);
+
+        assert_eq!(text.lines().nth(1), Some("│Bar: default                │"));
+        assert!(!text.contains("Theme:"));
+    }
+}
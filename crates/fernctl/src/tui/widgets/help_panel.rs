@@ -1,116 +1,78 @@
 //! # Help Panel Widget
 //!
-//! Displays keyboard shortcuts and help information.
+//! Displays keyboard shortcuts grouped by panel, rendered from the shared
+//! [`keymap`](crate::tui::keymap) table.
 
+use crate::tui::keymap::{bindings_for, KeyContext, KEYBINDINGS};
+use crate::tui::palette::Palette;
 use ratatui::{
     buffer::Buffer,
     layout::{Alignment, Rect},
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
     widgets::{Block, Borders, Clear, Paragraph, Widget, Wrap},
 };
 
 /// Help panel widget (overlay).
-pub struct HelpPanel;
+pub struct HelpPanel<'a> {
+    palette: &'a Palette,
+}
 
-impl HelpPanel {
+impl<'a> HelpPanel<'a> {
     /// Creates a new help panel.
     #[must_use]
-    pub fn new() -> Self {
-        Self
+    pub const fn new(palette: &'a Palette) -> Self {
+        Self { palette }
     }
 
-    /// Creates the help content.
-    fn help_lines() -> Vec<Line<'static>> {
+    /// Creates the help content from the keybinding table.
+    fn help_lines(&self) -> Vec<Line<'static>> {
         let key_style = Style::default()
-            .fg(Color::Cyan)
+            .fg(self.palette.accent)
             .add_modifier(Modifier::BOLD);
-        let desc_style = Style::default().fg(Color::White);
+        let desc_style = Style::default().fg(self.palette.foreground);
         let section_style = Style::default()
-            .fg(Color::Yellow)
+            .fg(self.palette.warning)
             .add_modifier(Modifier::BOLD);
 
-        vec![
-            Line::from(vec![Span::styled("Navigation", section_style)]),
-            Line::from(vec![
-                Span::styled("Tab      ", key_style),
-                Span::styled("Switch panel focus", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("j/↓      ", key_style),
-                Span::styled("Select next item", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("k/↑      ", key_style),
-                Span::styled("Select previous item", desc_style),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled("Services", section_style)]),
-            Line::from(vec![
-                Span::styled("s        ", key_style),
-                Span::styled("Start selected service", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("t        ", key_style),
-                Span::styled("Stop selected service", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("R        ", key_style),
-                Span::styled("Restart selected service", desc_style),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled("Logs", section_style)]),
-            Line::from(vec![
-                Span::styled("/        ", key_style),
-                Span::styled("Filter logs", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("c        ", key_style),
-                Span::styled("Clear logs", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("G        ", key_style),
-                Span::styled("Jump to end", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("g        ", key_style),
-                Span::styled("Jump to start", desc_style),
-            ]),
-            Line::from(""),
-            Line::from(vec![Span::styled("General", section_style)]),
-            Line::from(vec![
-                Span::styled("r        ", key_style),
-                Span::styled("Reload shell config", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("?        ", key_style),
-                Span::styled("Toggle this help", desc_style),
-            ]),
-            Line::from(vec![
-                Span::styled("q        ", key_style),
-                Span::styled("Quit", desc_style),
-            ]),
-        ]
-    }
-}
+        let key_width = KEYBINDINGS
+            .iter()
+            .map(|b| b.keys.chars().count())
+            .max()
+            .unwrap_or(0)
+            + 2;
 
-impl Default for HelpPanel {
-    fn default() -> Self {
-        Self::new()
+        let mut lines = Vec::new();
+        for context in KeyContext::all() {
+            if !lines.is_empty() {
+                lines.push(Line::from(""));
+            }
+            lines.push(Line::from(vec![Span::styled(
+                context.title(),
+                section_style,
+            )]));
+            for binding in bindings_for(*context) {
+                lines.push(Line::from(vec![
+                    Span::styled(format!("{:<key_width$}", binding.keys), key_style),
+                    Span::styled(binding.description, desc_style),
+                ]));
+            }
+        }
+        lines
     }
 }
 
-impl Widget for HelpPanel {
+impl Widget for HelpPanel<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         // Clear the area first (overlay)
         Clear.render(area, buf);
 
         let block = Block::default()
-            .title(" Help (press ? to close) ")
+            .title(" Help (?/Esc/q to close) ")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Yellow));
+            .border_style(Style::default().fg(self.palette.warning));
 
-        let lines = Self::help_lines();
+        let lines = self.help_lines();
         let paragraph = Paragraph::new(lines)
             .block(block)
             .wrap(Wrap { trim: false })
@@ -2,44 +2,41 @@
 //!
 //! Displays scrollable log entries.
 
-use crate::domain::{AppState, LogLevel, PanelFocus};
+use crate::domain::{AppState, LevelTone, LogLevel, PanelFocus};
+use crate::tui::palette::Palette;
 use ratatui::{
     buffer::Buffer,
     layout::Rect,
-    style::{Color, Modifier, Style},
+    style::{Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, List, ListItem, Widget},
+    widgets::{Block, Borders, List, ListItem, ListState, StatefulWidget, Widget},
 };
 
 /// Logs panel widget.
 pub struct LogsPanel<'a> {
     state: &'a AppState,
+    palette: &'a Palette,
 }
 
 impl<'a> LogsPanel<'a> {
     /// Creates a new logs panel.
     #[must_use]
-    pub fn new(state: &'a AppState) -> Self {
-        Self { state }
+    pub fn new(state: &'a AppState, palette: &'a Palette) -> Self {
+        Self { state, palette }
     }
 
     /// Returns the border style based on focus.
     fn border_style(&self) -> Style {
-        if self.state.focus == PanelFocus::Logs {
-            Style::default().fg(Color::Cyan)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        }
+        self.palette.border(self.state.focus == PanelFocus::Logs)
     }
 
-    /// Returns the color for a log level.
-    fn level_color(level: LogLevel) -> Color {
-        match level {
-            LogLevel::Trace => Color::DarkGray,
-            LogLevel::Debug => Color::Cyan,
-            LogLevel::Info => Color::Green,
-            LogLevel::Warn => Color::Yellow,
-            LogLevel::Error => Color::Red,
+    /// Returns the style for a log level.
+    fn level_style(&self, level: LogLevel) -> Style {
+        match level.tone() {
+            LevelTone::Dim => self.palette.dim().add_modifier(Modifier::DIM),
+            LevelTone::Normal => Style::default().fg(self.palette.success),
+            LevelTone::Warning => Style::default().fg(self.palette.warning),
+            LevelTone::Error => Style::default().fg(self.palette.error),
         }
     }
 
@@ -56,25 +53,22 @@ impl<'a> LogsPanel<'a> {
                 let is_selected = is_focused && selected == Some(idx);
 
                 let line = Line::from(vec![
-                    Span::styled(
-                        format!("{} ", entry.formatted_time()),
-                        Style::default().fg(Color::DarkGray),
-                    ),
+                    Span::styled(format!("{} ", entry.formatted_time()), self.palette.dim()),
                     Span::styled(
                         format!("[{:5}] ", entry.level.label()),
-                        Style::default().fg(Self::level_color(entry.level)),
+                        self.level_style(entry.level),
                     ),
                     Span::styled(
                         format!("{}: ", entry.source),
                         Style::default()
-                            .fg(Color::White)
+                            .fg(self.palette.foreground)
                             .add_modifier(Modifier::BOLD),
                     ),
                     Span::raw(&entry.message),
                 ]);
 
                 let style = if is_selected {
-                    Style::default().bg(Color::DarkGray)
+                    Style::default().bg(self.palette.surface)
                 } else {
                     Style::default()
                 };
@@ -88,11 +82,13 @@ impl<'a> LogsPanel<'a> {
 impl Widget for LogsPanel<'_> {
     fn render(self, area: Rect, buf: &mut Buffer) {
         let filter = self.state.logs.filter();
-        let title = if filter.is_empty() {
-            " Logs ".to_string()
-        } else {
-            format!(" Logs [filter: {}] ", filter)
-        };
+        let mut title = " Logs ".to_string();
+        if !filter.is_empty() {
+            title.push_str(&format!("[filter: {filter}] "));
+        }
+        if self.state.logs.follow() {
+            title.push_str("[follow] ");
+        }
 
         let block = Block::default()
             .title(title)
@@ -101,9 +97,74 @@ impl Widget for LogsPanel<'_> {
 
         let items = self.log_items();
 
-        // Auto-scroll to bottom if no selection
+        // Keep the selected entry in view
         let list = List::new(items).block(block);
+        let mut list_state = ListState::default().with_selected(self.state.logs.selected());
+
+        StatefulWidget::render(list, area, buf, &mut list_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::{Action, LogEntry};
+    use crate::tui::widgets::render_to_text;
+
+    #[test]
+    fn renders_entries_oldest_first() {
+        let mut state = AppState::new();
+        let connected = LogEntry::info("Obs", "connected");
+        let lost = LogEntry::error("Obs", "disconnected");
+        let rows = [
+            format!("{} [INFO ] Obs: connected", connected.formatted_time()),
+            format!("{} [ERROR] Obs: disconnected", lost.formatted_time()),
+        ];
+        state.update(Action::LogReceived(connected));
+        state.update(Action::LogReceived(lost));
+
+        let expected = format!(
+            "\
+┌ Logs ──────────────────────────────────────────┐
+│{:<48}│
+│{:<48}│
+│                                                │
+└────────────────────────────────────────────────┘
+",
+            rows[0], rows[1]
+        );
+        assert_eq!(
+            render_to_text(LogsPanel::new(&state, &Palette::DEFAULT), 50, 5),
+            expected
+        );
+    }
+
+    #[test]
+    fn title_shows_filter_and_follow() {
+        let mut state = AppState::new();
+        state.update(Action::SetLogFilter("obs".to_string()));
+        state.update(Action::ToggleFollow);
+
+        let text = render_to_text(LogsPanel::new(&state, &Palette::DEFAULT), 50, 5);
+        assert!(
+            text.starts_with("┌ Logs [filter: obs] [follow] ─"),
+            "{text}"
+        );
+    }
+
+    #[test]
+    fn follow_keeps_newest_entry_in_view() {
+        let mut state = AppState::new();
+        state.update(Action::ToggleFollow);
+        for i in 0..10 {
+            state.update(Action::LogReceived(LogEntry::info(
+                "test",
+                format!("line {i}"),
+            )));
+        }
 
-        Widget::render(list, area, buf);
+        let text = render_to_text(LogsPanel::new(&state, &Palette::DEFAULT), 50, 5);
+        assert!(text.contains("test: line 9"), "{text}");
+        assert!(!text.contains("test: line 0"), "{text}");
     }
 }
@@ -7,14 +7,35 @@
 //! - [`ServicesPanel`] - Service status list
 //! - [`LogsPanel`] - Scrollable log viewer
 //! - [`ConfigPanel`] - Configuration overview
+//! - [`ConfigErrorPanel`] - Config validation error dialog
 //! - [`HelpPanel`] - Keyboard shortcuts help
 
+pub mod config_error_panel;
 pub mod config_panel;
 pub mod help_panel;
 pub mod logs_panel;
 pub mod services_panel;
 
+pub use config_error_panel::ConfigErrorPanel;
 pub use config_panel::ConfigPanel;
 pub use help_panel::HelpPanel;
 pub use logs_panel::LogsPanel;
 pub use services_panel::ServicesPanel;
+
+/// Renders `widget` into a `width` × `height` test terminal and returns the
+/// screen as plain text, one line per row, for comparing against an
+/// expected snapshot.
+#[cfg(test)]
+pub(crate) fn render_to_text(
+    widget: impl ratatui::widgets::Widget,
+    width: u16,
+    height: u16,
+) -> String {
+    use ratatui::{backend::TestBackend, Terminal};
+
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal
+        .draw(|frame| frame.render_widget(widget, frame.area()))
+        .unwrap();
+    super::snapshot::buffer_to_text(terminal.backend().buffer(), false)
+}
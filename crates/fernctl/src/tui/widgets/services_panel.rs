@@ -3,6 +3,7 @@
 //! Displays the status of all known services.
 
 use crate::domain::{AppState, KnownService, PanelFocus};
+use crate::tui::palette::Palette;
 use fern_core::state::ServiceStatus;
 use ratatui::{
     buffer::Buffer,
@@ -12,25 +13,41 @@ use ratatui::{
     widgets::{Block, Borders, List, ListItem, Widget},
 };
 
+/// Returns the indicator symbol and color for a service status.
+///
+/// Shared with the footer's health summary so both read the same way.
+#[must_use]
+pub const fn status_indicator(
+    status: Option<&ServiceStatus>,
+    palette: &Palette,
+) -> (&'static str, Color) {
+    match status {
+        Some(ServiceStatus::Running) => ("●", palette.success),
+        Some(ServiceStatus::Starting | ServiceStatus::Stopping) => ("◐", palette.warning),
+        Some(ServiceStatus::Failed(_)) => ("✗", palette.error),
+        Some(ServiceStatus::Stopped | ServiceStatus::Disabled) | None => {
+            ("○", palette.foreground_dim)
+        }
+    }
+}
+
 /// Services panel widget.
 pub struct ServicesPanel<'a> {
     state: &'a AppState,
+    palette: &'a Palette,
 }
 
 impl<'a> ServicesPanel<'a> {
     /// Creates a new services panel.
     #[must_use]
-    pub fn new(state: &'a AppState) -> Self {
-        Self { state }
+    pub fn new(state: &'a AppState, palette: &'a Palette) -> Self {
+        Self { state, palette }
     }
 
     /// Returns the border style based on focus.
     fn border_style(&self) -> Style {
-        if self.state.focus == PanelFocus::Services {
-            Style::default().fg(Color::Cyan)
-        } else {
-            Style::default().fg(Color::DarkGray)
-        }
+        self.palette
+            .border(self.state.focus == PanelFocus::Services)
     }
 
     /// Creates list items for services.
@@ -43,15 +60,8 @@ impl<'a> ServicesPanel<'a> {
                 let is_selected = idx == self.state.selected_service
                     && self.state.focus == PanelFocus::Services;
 
-                let (indicator, indicator_color) = match info.map(|i| &i.status) {
-                    Some(ServiceStatus::Running) => ("●", Color::Green),
-                    Some(ServiceStatus::Starting) => ("◐", Color::Yellow),
-                    Some(ServiceStatus::Stopping) => ("◐", Color::Yellow),
-                    Some(ServiceStatus::Failed(_)) => ("✗", Color::Red),
-                    Some(ServiceStatus::Stopped) | Some(ServiceStatus::Disabled) | None => {
-                        ("○", Color::DarkGray)
-                    }
-                };
+                let (indicator, indicator_color) =
+                    status_indicator(info.map(|i| &i.status), self.palette);
 
                 let status_text = match info.map(|i| &i.status) {
                     Some(ServiceStatus::Running) => "running",
@@ -69,23 +79,18 @@ impl<'a> ServicesPanel<'a> {
                         Style::default().fg(indicator_color),
                     ),
                     Span::styled(
-                        format!("{:12}", service.display_name()),
+                        format!("{:13} ", service.display_name()),
                         Style::default().add_modifier(if is_selected {
                             Modifier::BOLD
                         } else {
                             Modifier::empty()
                         }),
                     ),
-                    Span::styled(
-                        status_text,
-                        Style::default().fg(Color::DarkGray),
-                    ),
+                    Span::styled(status_text, self.palette.dim()),
                 ]);
 
                 let style = if is_selected {
-                    Style::default()
-                        .bg(Color::Rgb(94, 234, 212))
-                        .fg(Color::Black)
+                    self.palette.selected()
                 } else {
                     Style::default()
                 };
@@ -109,3 +114,50 @@ impl Widget for ServicesPanel<'_> {
         Widget::render(list, area, buf);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::Action;
+    use crate::tui::widgets::render_to_text;
+    use fern_core::ServiceInfo;
+
+    fn set_status(state: &mut AppState, service: KnownService, status: ServiceStatus) {
+        state.update(Action::ServiceStateChanged {
+            name: service.name().to_string(),
+            info: ServiceInfo::new(service.name(), status),
+        });
+    }
+
+    #[test]
+    fn renders_each_service_with_status() {
+        let mut state = AppState::new();
+        set_status(&mut state, KnownService::Obs, ServiceStatus::Running);
+        set_status(
+            &mut state,
+            KnownService::Shell,
+            ServiceStatus::Failed("exit 1".to_string()),
+        );
+
+        let expected = "\
+┌ Services ────────────────────────────┐
+│● OBS Bridge    running               │
+│✗ Fern Shell    exit 1                │
+│○ Theme Watcher stopped               │
+└──────────────────────────────────────┘
+";
+        assert_eq!(
+            render_to_text(ServicesPanel::new(&state, &Palette::DEFAULT), 40, 5),
+            expected
+        );
+    }
+
+    #[test]
+    fn clips_services_to_panel_height() {
+        let state = AppState::new();
+        let text = render_to_text(ServicesPanel::new(&state, &Palette::DEFAULT), 40, 4);
+
+        assert!(text.contains("Fern Shell"));
+        assert!(!text.contains("Theme Watcher"));
+    }
+}
@@ -105,6 +105,8 @@ Singleton {
                 height: 40,
                 position: "top",
                 margin: 0,
+                opacity: 1.0,
+                blur: false,
                 modules_left: ["workspaces"],
                 modules_center: ["clock"],
                 modules_right: ["tray"]
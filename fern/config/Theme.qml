@@ -92,6 +92,10 @@ QtObject {
     readonly property string fontMono: config.typography?.mono ?? config.appearance?.font_mono ?? "JetBrainsMono Nerd Font"
     readonly property string fontIcon: config.typography?.icon ?? config.appearance?.font_icon ?? "Material Symbols Rounded"
 
+    // Vertical rhythm - line height multiplier and letter spacing (px)
+    readonly property real lineHeight: config.typography?.line_height ?? 1.2
+    readonly property real letterSpacing: config.typography?.letter_spacing ?? 0
+
     // Font size scale - from fern-theme or defaults
     readonly property var fontSize: config.typography?.size ?? ({
         xs: 10,
@@ -154,9 +158,15 @@ QtObject {
     // -- Bar --
     readonly property int barHeight: config.bar?.height ?? 40
     readonly property string barPosition: config.bar?.position ?? "left"
+    readonly property bool barVertical: barPosition === "left" || barPosition === "right"
     readonly property int barMargin: config.bar?.margin ?? 0
-    readonly property color barBackground: background
-    readonly property int barPadding: spacing.sm
+    readonly property real barOpacity: config.bar?.opacity ?? 1.0
+    readonly property bool barBlur: config.bar?.blur ?? false
+    readonly property color barBackground: Qt.rgba(background.r, background.g, background.b, background.a * barOpacity)
+    // Component padding comes from fern-theme's spacing scale (components.*)
+    readonly property int barPaddingH: config.components?.bar?.horizontal ?? spacing.md
+    readonly property int barPaddingV: config.components?.bar?.vertical ?? spacing.sm
+    readonly property int barPadding: barPaddingV
     readonly property int barRadius: radius.none
 
     // -- Bar (Vertical/Left) --
@@ -174,13 +184,17 @@ QtObject {
     // -- Module (generic container) --
     readonly property int moduleRadius: radius.md
     readonly property int moduleSpacing: spacing.lg
-    readonly property int modulePadding: spacing.md
+    readonly property int modulePaddingH: config.components?.module?.horizontal ?? spacing.lg
+    readonly property int modulePaddingV: config.components?.module?.vertical ?? spacing.md
+    readonly property int modulePadding: modulePaddingV
     readonly property color moduleBackground: surface
     readonly property color moduleBackgroundHover: surfaceHover
 
     // -- Button / Interactive --
     readonly property int buttonRadius: radius.sm
-    readonly property int buttonPadding: spacing.sm
+    readonly property int buttonPaddingH: config.components?.button?.horizontal ?? spacing.md
+    readonly property int buttonPaddingV: config.components?.button?.vertical ?? spacing.sm
+    readonly property int buttonPadding: buttonPaddingV
     readonly property color buttonBackground: Qt.rgba(0, 0, 0, 0)
     readonly property color buttonBackgroundHover: surfaceHover
     readonly property color buttonBackgroundActive: overlay
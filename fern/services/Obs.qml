@@ -18,6 +18,7 @@ Singleton {
     // === CONNECTION STATE ===
     readonly property bool connected: state.connected ?? false
     readonly property string errorMessage: state.error ?? ""
+    readonly property string warningMessage: state.warning ?? ""
 
     // === RECORDING STATE ===
     readonly property bool isRecording: state.recording?.active ?? false
@@ -89,11 +90,7 @@ Singleton {
 
     // Toggle recording (start if stopped, stop if started)
     function toggleRecording(): void {
-        if (isRecording) {
-            stopRecording();
-        } else {
-            startRecording();
-        }
+        Quickshell.execDetached("fern-obs", ["toggle-recording"]);
     }
 
     // Toggle recording pause
@@ -113,11 +110,7 @@ Singleton {
 
     // Toggle streaming
     function toggleStreaming(): void {
-        if (isStreaming) {
-            stopStreaming();
-        } else {
-            startStreaming();
-        }
+        Quickshell.execDetached("fern-obs", ["toggle-streaming"]);
     }
 
     // Set scene by name
@@ -125,6 +118,24 @@ Singleton {
         Quickshell.execDetached("fern-obs", ["scene", sceneName]);
     }
 
+    // Set scene with an explicit transition (e.g. "Cut", or "Fade" for 300ms)
+    function setSceneWithTransition(sceneName: string, transition: string, durationMs: int): void {
+        Quickshell.execDetached("fern-obs", [
+            "scene", sceneName,
+            "--transition", transition,
+            "--duration", durationMs.toString()
+        ]);
+    }
+
+    // Cycle through the scene list
+    function nextScene(): void {
+        Quickshell.execDetached("fern-obs", ["scene", "--next"]);
+    }
+
+    function prevScene(): void {
+        Quickshell.execDetached("fern-obs", ["scene", "--prev"]);
+    }
+
     // Start the daemon if not running
     function startDaemon(): void {
         Quickshell.execDetached("fern-obs", ["daemon"]);
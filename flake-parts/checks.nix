@@ -13,6 +13,12 @@
       # --- Builds the packages (catches missing deps early)
       build = self'.packages.fern-shell;
       build-fern-theme = self'.packages.fern-theme;
+
+      # --- Optional features the packages leave off
+      build-fernctl-preview = self'.packages.fernctl.overrideAttrs (old: {
+        cargoBuildFeatures = old.cargoBuildFeatures ++ [ "preview" ];
+        cargoCheckFeatures = old.cargoCheckFeatures ++ [ "preview" ];
+      });
     };
   };
 }
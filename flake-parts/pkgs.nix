@@ -63,6 +63,14 @@
         # Build with CLI feature
         buildFeatures = [ "cli" ];
 
+        # Man pages come from the built binary's hidden `man` subcommand
+        nativeBuildInputs = [ pkgs.installShellFiles ];
+        postInstall = lib.optionalString
+          (pkgs.stdenv.buildPlatform.canExecute pkgs.stdenv.hostPlatform) ''
+            $out/bin/fern-obs man --out-dir man
+            installManPage man/*.1
+          '';
+
         meta = with lib; {
           description = "OBS WebSocket bridge daemon for Fern Shell";
           homepage = "https://github.com/adanoelle/fern-shell";
@@ -86,6 +94,14 @@
         # Build with CLI and TUI features
         buildFeatures = [ "cli" "tui" ];
 
+        # Man pages come from the built binary's hidden `man` subcommand
+        nativeBuildInputs = [ pkgs.installShellFiles ];
+        postInstall = lib.optionalString
+          (pkgs.stdenv.buildPlatform.canExecute pkgs.stdenv.hostPlatform) ''
+            $out/bin/fernctl man --out-dir man
+            installManPage man/*.1
+          '';
+
         meta = with lib; {
           description = "Control plane for Fern Shell (CLI + TUI dashboard)";
           homepage = "https://github.com/adanoelle/fern-shell";